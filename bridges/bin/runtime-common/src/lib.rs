@@ -33,6 +33,7 @@ pub mod messages_xcm_extension;
 pub mod parachains_benchmarking;
 pub mod priority_calculator;
 pub mod refund_relayer_extension;
+pub mod system_token;
 
 mod mock;
 
@@ -0,0 +1,127 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Parity Bridges Common.
+
+// Parity Bridges Common is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity Bridges Common is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Utilities for moving System Tokens between bridged InfraBlockchain networks.
+//!
+//! A System Token issued on the bridged network is seen from the local network as
+//! `(2, X4(GlobalConsensus(network), Parachain(id), PalletInstance(_), GeneralIndex(_)))`, and the
+//! issuing parachain, `(2, X2(GlobalConsensus(network), Parachain(id)))`, is its reserve.
+
+use frame_support::traits::{ContainsPair, Get};
+use sp_std::marker::PhantomData;
+use xcm::prelude::*;
+
+/// Returns the reserve of the System Token located at `location`, if it has been issued on the
+/// `network`.
+pub fn bridged_system_token_reserve(
+	location: &MultiLocation,
+	network: NetworkId,
+) -> Option<MultiLocation> {
+	match (location.parents, location.interior) {
+		(
+			2,
+			X4(
+				GlobalConsensus(issuer_network),
+				Parachain(para_id),
+				PalletInstance(_),
+				GeneralIndex(_),
+			),
+		) if issuer_network == network =>
+			Some(MultiLocation::new(2, X2(GlobalConsensus(network), Parachain(para_id)))),
+		_ => None,
+	}
+}
+
+/// Accepts System Tokens of the `BridgedNetwork` as reserve assets, only if they are coming from
+/// the parachain which has issued them.
+///
+/// Meant to be used as (a part of) `IsReserve` of the chain which receives System Tokens over the
+/// bridge.
+pub struct IsBridgedSystemToken<BridgedNetwork>(PhantomData<BridgedNetwork>);
+impl<BridgedNetwork: Get<NetworkId>> ContainsPair<MultiAsset, MultiLocation>
+	for IsBridgedSystemToken<BridgedNetwork>
+{
+	fn contains(asset: &MultiAsset, origin: &MultiLocation) -> bool {
+		let is_bridged_system_token = match asset.id {
+			Concrete(ref location) =>
+				bridged_system_token_reserve(location, BridgedNetwork::get()) == Some(*origin),
+			Abstract(_) => false,
+		};
+		log::trace!(
+			target: crate::LOG_TARGET_BRIDGE_DISPATCH,
+			"IsBridgedSystemToken asset: {:?}, origin: {:?}, result: {:?}",
+			asset,
+			origin,
+			is_bridged_system_token,
+		);
+		is_bridged_system_token
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use frame_support::parameter_types;
+
+	parameter_types! {
+		pub const BridgedNetwork: NetworkId = NetworkId::ByGenesis([1; 32]);
+	}
+
+	fn system_token(network: NetworkId) -> MultiLocation {
+		MultiLocation::new(
+			2,
+			X4(GlobalConsensus(network), Parachain(1000), PalletInstance(50), GeneralIndex(1)),
+		)
+	}
+
+	#[test]
+	fn bridged_system_token_is_accepted_from_its_reserve() {
+		let asset: MultiAsset = (system_token(BridgedNetwork::get()), 100).into();
+		let reserve =
+			MultiLocation::new(2, X2(GlobalConsensus(BridgedNetwork::get()), Parachain(1000)));
+
+		assert!(IsBridgedSystemToken::<BridgedNetwork>::contains(&asset, &reserve));
+		assert!(!IsBridgedSystemToken::<BridgedNetwork>::contains(
+			&asset,
+			&MultiLocation::new(2, X2(GlobalConsensus(BridgedNetwork::get()), Parachain(2000))),
+		));
+	}
+
+	#[test]
+	fn system_token_of_other_network_is_rejected() {
+		let asset: MultiAsset = (system_token(NetworkId::InfraRelay), 100).into();
+		let reserve =
+			MultiLocation::new(2, X2(GlobalConsensus(NetworkId::InfraRelay), Parachain(1000)));
+
+		assert!(!IsBridgedSystemToken::<BridgedNetwork>::contains(&asset, &reserve));
+		assert_eq!(
+			bridged_system_token_reserve(
+				&system_token(NetworkId::InfraRelay),
+				BridgedNetwork::get()
+			),
+			None
+		);
+	}
+
+	#[test]
+	fn non_system_token_is_rejected() {
+		let location =
+			MultiLocation::new(2, X2(GlobalConsensus(BridgedNetwork::get()), Parachain(1000)));
+		let asset: MultiAsset = (location, 100).into();
+
+		assert!(!IsBridgedSystemToken::<BridgedNetwork>::contains(&asset, &location));
+	}
+}
@@ -0,0 +1,161 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Parity Bridges Common.
+
+// Parity Bridges Common is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity Bridges Common is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Module with configuration which reflects InfraAssetHub runtime setup
+//! (AccountId, Headers, Hashes...)
+//!
+//! InfraAssetHub is the reserve of the System Tokens of an InfraBlockchain network, so it is the
+//! chain which sends and receives System Tokens over the bridge.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use bp_infra_relay::{
+	AccountId, AccountInfoStorageMapKeyProvider, AccountPublic, Address, Balance, BlockNumber,
+	Hash, Hasher, Hashing, Header, Nonce, Signature, SignedBlock, SuffixedInfraSignedExtension,
+	SuffixedInfraSignedExtensionExt, SystemTokenId, UncheckedExtrinsic, EXTRA_STORAGE_PROOF_SIZE,
+	TX_EXTRA_BYTES,
+};
+
+use bp_messages::*;
+use bp_runtime::{
+	decl_bridge_finality_runtime_apis, decl_bridge_messages_runtime_apis, Chain, Parachain,
+};
+use frame_support::{
+	dispatch::DispatchClass,
+	parameter_types,
+	sp_runtime::{MultiSigner, Perbill},
+	weights::{constants, Weight},
+};
+use frame_system::limits;
+use sp_std::time::Duration;
+
+/// Average block interval in InfraBlockchain parachains.
+///
+/// Corresponds to the `MILLISECS_PER_BLOCK` from `parachains_common` crate.
+pub const AVERAGE_BLOCK_INTERVAL: Duration = Duration::from_secs(12);
+
+/// Normal extrinsics are allowed to fill block up to 75 percent.
+///
+/// This is a copy-paste from the cumulus repo's `parachains-common` crate.
+pub const NORMAL_DISPATCH_RATIO: Perbill = Perbill::from_percent(75);
+
+/// InfraAssetHub allows for 0.5 seconds of compute with a 12-second average block time.
+///
+/// This is a copy-paste from the cumulus repo's `parachains-common` crate.
+const MAXIMUM_BLOCK_WEIGHT: Weight = Weight::from_parts(constants::WEIGHT_REF_TIME_PER_SECOND, 0)
+	.saturating_div(2)
+	.set_proof_size(primitives::MAX_POV_SIZE as u64);
+
+/// InfraAssetHub assumes that about 5 percent of the block weight is consumed by `on_initialize`
+/// handlers. This is used to limit the maximal weight of a single extrinsic.
+///
+/// This is a copy-paste from the cumulus repo's `parachains-common` crate.
+pub const AVERAGE_ON_INITIALIZE_RATIO: Perbill = Perbill::from_percent(5);
+
+parameter_types! {
+	pub BlockLength: limits::BlockLength = limits::BlockLength::max_with_normal_ratio(
+		5 * 1024 * 1024,
+		NORMAL_DISPATCH_RATIO,
+	);
+
+	/// Importing a block with 0 Extrinsics.
+	pub const BlockExecutionWeight: Weight = Weight::from_parts(constants::WEIGHT_REF_TIME_PER_NANOS, 0)
+		.saturating_mul(5_000_000);
+	/// Executing a NO-OP `System::remarks` Extrinsic.
+	pub const ExtrinsicBaseWeight: Weight = Weight::from_parts(constants::WEIGHT_REF_TIME_PER_NANOS, 0)
+		.saturating_mul(125_000);
+
+	pub BlockWeights: limits::BlockWeights = limits::BlockWeights::builder()
+		.base_block(BlockExecutionWeight::get())
+		.for_class(DispatchClass::all(), |weights| {
+			weights.base_extrinsic = ExtrinsicBaseWeight::get();
+		})
+		.for_class(DispatchClass::Normal, |weights| {
+			weights.max_total = Some(NORMAL_DISPATCH_RATIO * MAXIMUM_BLOCK_WEIGHT);
+		})
+		.for_class(DispatchClass::Operational, |weights| {
+			weights.max_total = Some(MAXIMUM_BLOCK_WEIGHT);
+			// Operational transactions have an extra reserved space, so that they
+			// are included even if block reached `MAXIMUM_BLOCK_WEIGHT`.
+			weights.reserved = Some(
+				MAXIMUM_BLOCK_WEIGHT - NORMAL_DISPATCH_RATIO * MAXIMUM_BLOCK_WEIGHT,
+			);
+		})
+		.avg_block_initialization(AVERAGE_ON_INITIALIZE_RATIO)
+		.build_or_panic();
+}
+
+/// Public key of the chain account that may be used to verify signatures.
+pub type AccountSigner = MultiSigner;
+
+/// Maximal number of unrewarded relayer entries at inbound lane of InfraAssetHub.
+/// Note: this value is security-relevant, decreasing it should not be done without careful
+/// analysis (see `bp-bridge-hub-cumulus`, which uses the same limits).
+pub const MAX_UNREWARDED_RELAYERS_IN_CONFIRMATION_TX: MessageNonce = 1024;
+
+/// Maximal number of unconfirmed messages at inbound lane of InfraAssetHub.
+/// Note: this value is security-relevant, decreasing it should not be done without careful
+/// analysis (see `bp-bridge-hub-cumulus`, which uses the same limits).
+pub const MAX_UNCONFIRMED_MESSAGES_IN_CONFIRMATION_TX: MessageNonce = 4096;
+
+/// Signed extension that is used by InfraAssetHub.
+///
+/// Must match the `SignedExtra` of the InfraAssetHub runtime.
+pub type SignedExtension = SuffixedInfraSignedExtension<()>;
+
+/// InfraAssetHub parachain.
+#[derive(sp_runtime::RuntimeDebug)]
+pub struct InfraAssetHub;
+
+impl Chain for InfraAssetHub {
+	type BlockNumber = BlockNumber;
+	type Hash = Hash;
+	type Hasher = Hasher;
+	type Header = Header;
+
+	type AccountId = AccountId;
+	type Balance = Balance;
+	type Nonce = Nonce;
+	type Signature = Signature;
+
+	fn max_extrinsic_size() -> u32 {
+		*BlockLength::get().max.get(DispatchClass::Normal)
+	}
+
+	fn max_extrinsic_weight() -> Weight {
+		BlockWeights::get()
+			.get(DispatchClass::Normal)
+			.max_extrinsic
+			.unwrap_or(Weight::MAX)
+	}
+}
+
+impl Parachain for InfraAssetHub {
+	const PARACHAIN_ID: u32 = INFRA_ASSET_HUB_PARACHAIN_ID;
+}
+
+/// Identifier of InfraAssetHub in the InfraRelay chain.
+pub const INFRA_ASSET_HUB_PARACHAIN_ID: u32 = 1000;
+
+/// Name of the With-InfraAssetHub messages pallet instance that is deployed at bridged chains.
+pub const WITH_INFRA_ASSET_HUB_MESSAGES_PALLET_NAME: &str = "BridgeInfraAssetHubMessages";
+
+/// Name of the With-InfraAssetHub bridge-relayers pallet instance that is deployed at bridged
+/// chains.
+pub const WITH_INFRA_ASSET_HUB_RELAYERS_PALLET_NAME: &str = "BridgeRelayers";
+
+decl_bridge_finality_runtime_apis!(infra_asset_hub);
+decl_bridge_messages_runtime_apis!(infra_asset_hub);
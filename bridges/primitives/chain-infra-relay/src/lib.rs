@@ -0,0 +1,226 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Parity Bridges Common.
+
+// Parity Bridges Common is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity Bridges Common is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Primitives of the InfraRelay chain.
+//!
+//! Unlike other Polkadot-like chains, transaction fees of InfraBlockchain are paid with System
+//! Tokens, so its signed extension ends with `ChargeSystemToken` instead of
+//! `ChargeTransactionPayment`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+// RuntimeApi generated functions
+#![allow(clippy::too_many_arguments)]
+
+pub use bp_polkadot_core::{
+	AccountId, AccountInfoStorageMapKeyProvider, AccountPublic, Address, Balance, Block,
+	BlockNumber, Hash, Hasher, Hashing, Header, Nonce, PolkadotLike, Signature, SignedBlock,
+	UncheckedExtrinsic, AVERAGE_HEADER_SIZE_IN_JUSTIFICATION, EXTRA_STORAGE_PROOF_SIZE,
+	MAX_AUTHORITIES_COUNT, MAX_HEADER_SIZE, REASONABLE_HEADERS_IN_JUSTIFICATON_ANCESTRY,
+	TX_EXTRA_BYTES,
+};
+
+use bp_header_chain::ChainWithGrandpa;
+use bp_runtime::{
+	decl_bridge_finality_runtime_apis,
+	extensions::{
+		ChargeSystemToken, CheckEra, CheckGenesis, CheckNonZeroSender, CheckNonce,
+		CheckSpecVersion, CheckTxVersion, CheckWeight, GenericSignedExtension,
+		SignedExtensionSchema,
+	},
+	Chain, TransactionEra,
+};
+use frame_support::weights::Weight;
+
+/// Identifier of a System Token, which is its location relative to the chain.
+pub type SystemTokenId = xcm::v3::MultiLocation;
+
+/// InfraRelay Chain
+pub struct InfraRelay;
+
+impl Chain for InfraRelay {
+	type BlockNumber = <PolkadotLike as Chain>::BlockNumber;
+	type Hash = <PolkadotLike as Chain>::Hash;
+	type Hasher = <PolkadotLike as Chain>::Hasher;
+	type Header = <PolkadotLike as Chain>::Header;
+
+	type AccountId = <PolkadotLike as Chain>::AccountId;
+	type Balance = <PolkadotLike as Chain>::Balance;
+	type Nonce = <PolkadotLike as Chain>::Nonce;
+	type Signature = <PolkadotLike as Chain>::Signature;
+
+	fn max_extrinsic_size() -> u32 {
+		PolkadotLike::max_extrinsic_size()
+	}
+
+	fn max_extrinsic_weight() -> Weight {
+		PolkadotLike::max_extrinsic_weight()
+	}
+}
+
+impl ChainWithGrandpa for InfraRelay {
+	const WITH_CHAIN_GRANDPA_PALLET_NAME: &'static str = WITH_INFRA_RELAY_GRANDPA_PALLET_NAME;
+	const MAX_AUTHORITIES_COUNT: u32 = MAX_AUTHORITIES_COUNT;
+	const REASONABLE_HEADERS_IN_JUSTIFICATON_ANCESTRY: u32 =
+		REASONABLE_HEADERS_IN_JUSTIFICATON_ANCESTRY;
+	const MAX_HEADER_SIZE: u32 = MAX_HEADER_SIZE;
+	const AVERAGE_HEADER_SIZE_IN_JUSTIFICATION: u32 = AVERAGE_HEADER_SIZE_IN_JUSTIFICATION;
+}
+
+/// Extra signed extension data that is used by all InfraBlockchain runtimes.
+pub type InfraSignedExtra = (
+	CheckNonZeroSender,
+	CheckSpecVersion,
+	CheckTxVersion,
+	CheckGenesis<Hash>,
+	CheckEra<Hash>,
+	CheckNonce<Nonce>,
+	CheckWeight,
+	ChargeSystemToken<Balance, SystemTokenId, AccountId>,
+);
+
+/// Extra signed extension data that starts with `InfraSignedExtra`.
+pub type SuffixedInfraSignedExtension<Suffix> = GenericSignedExtension<(InfraSignedExtra, Suffix)>;
+
+/// The SignedExtension used by InfraRelay.
+///
+/// Must match the `SignedExtra` of the InfraRelay runtime.
+pub type SignedExtension = SuffixedInfraSignedExtension<()>;
+
+/// Helper trait to define some extra methods on `SuffixedInfraSignedExtension`.
+pub trait SuffixedInfraSignedExtensionExt<Suffix: SignedExtensionSchema> {
+	/// Create signed extension from its components.
+	fn from_params(
+		spec_version: u32,
+		transaction_version: u32,
+		era: TransactionEra<BlockNumber, Hash>,
+		genesis_hash: Hash,
+		nonce: Nonce,
+		tip: Balance,
+		system_token_id: Option<SystemTokenId>,
+		vote_candidate: Option<AccountId>,
+		extra: (Suffix::Payload, Suffix::AdditionalSigned),
+	) -> Self;
+
+	/// Return transaction nonce.
+	fn nonce(&self) -> Nonce;
+
+	/// Return transaction tip.
+	fn tip(&self) -> Balance;
+
+	/// Return System Token which is used for paying the transaction fee.
+	fn system_token_id(&self) -> Option<SystemTokenId>;
+}
+
+impl<Suffix> SuffixedInfraSignedExtensionExt<Suffix> for SuffixedInfraSignedExtension<Suffix>
+where
+	Suffix: SignedExtensionSchema,
+{
+	fn from_params(
+		spec_version: u32,
+		transaction_version: u32,
+		era: TransactionEra<BlockNumber, Hash>,
+		genesis_hash: Hash,
+		nonce: Nonce,
+		tip: Balance,
+		system_token_id: Option<SystemTokenId>,
+		vote_candidate: Option<AccountId>,
+		extra: (Suffix::Payload, Suffix::AdditionalSigned),
+	) -> Self {
+		GenericSignedExtension::new(
+			(
+				(
+					(),                                            // non-zero sender
+					(),                                            // spec version
+					(),                                            // tx version
+					(),                                            // genesis
+					era.frame_era(),                               // era
+					nonce.into(),                                  // nonce (compact encoding)
+					(),                                            // Check weight
+					(tip.into(), system_token_id, vote_candidate), // system token payment
+				),
+				extra.0,
+			),
+			Some((
+				(
+					(),
+					spec_version,
+					transaction_version,
+					genesis_hash,
+					era.signed_payload(genesis_hash),
+					(),
+					(),
+					(),
+				),
+				extra.1,
+			)),
+		)
+	}
+
+	fn nonce(&self) -> Nonce {
+		let common_payload = &self.payload.0;
+		common_payload.5 .0
+	}
+
+	fn tip(&self) -> Balance {
+		let common_payload = &self.payload.0;
+		common_payload.7 .0 .0
+	}
+
+	fn system_token_id(&self) -> Option<SystemTokenId> {
+		let common_payload = &self.payload.0;
+		common_payload.7 .1
+	}
+}
+
+/// Name of the parachains pallet in the InfraRelay runtime.
+pub const PARAS_PALLET_NAME: &str = "Paras";
+
+/// Name of the With-InfraRelay GRANDPA pallet instance that is deployed at bridged chains.
+pub const WITH_INFRA_RELAY_GRANDPA_PALLET_NAME: &str = "BridgeInfraRelayGrandpa";
+
+/// Maximal size of encoded `bp_parachains::ParaStoredHeaderData` structure among all InfraRelay
+/// parachains.
+///
+/// It includes the block number and state root, so it shall be near 40 bytes, but let's have some
+/// reserve.
+pub const MAX_NESTED_PARACHAIN_HEAD_DATA_SIZE: u32 = 128;
+
+decl_bridge_finality_runtime_apis!(infra_relay, grandpa);
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use xcm::v3::prelude::*;
+
+	#[test]
+	fn signed_extension_exposes_system_token_payment() {
+		let system_token = SystemTokenId::new(0, X2(PalletInstance(50), GeneralIndex(1)));
+		let extension = SignedExtension::from_params(
+			1,
+			1,
+			TransactionEra::Immortal,
+			Hash::default(),
+			7,
+			100,
+			Some(system_token),
+			None,
+			((), ()),
+		);
+		assert_eq!(extension.nonce(), 7);
+		assert_eq!(extension.tip(), 100);
+		assert_eq!(extension.system_token_id(), Some(system_token));
+	}
+}
@@ -77,6 +77,12 @@ pub type CheckWeight = GenericSignedExtensionSchema<(), ()>;
 /// The `SignedExtensionSchema` for `pallet_transaction_payment::ChargeTransactionPayment`.
 pub type ChargeTransactionPayment<Balance> = GenericSignedExtensionSchema<Compact<Balance>, ()>;
 
+/// The `SignedExtensionSchema` for `pallet_system_token_tx_payment::ChargeSystemToken`.
+///
+/// Payload is the tip, the System Token used for paying the fee and the account to vote for.
+pub type ChargeSystemToken<Balance, AssetId, AccountId> =
+	GenericSignedExtensionSchema<(Compact<Balance>, Option<AssetId>, Option<AccountId>), ()>;
+
 /// The `SignedExtensionSchema` for `runtime-common::PrevalidateAttests`.
 pub type PrevalidateAttests = GenericSignedExtensionSchema<(), ()>;
 
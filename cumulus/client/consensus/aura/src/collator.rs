@@ -33,7 +33,7 @@ use cumulus_client_consensus_proposer::ProposerInterface;
 use cumulus_primitives_core::{
 	relay_chain::Hash as PHash, DigestItem, ParachainBlockData, PersistedValidationData,
 };
-use cumulus_primitives_parachain_inherent::ParachainInherentData;
+use cumulus_primitives_parachain_inherent::{ParachainInherentData, SystemTokenWeightInherentData};
 use cumulus_relay_chain_interface::RelayChainInterface;
 
 use node_primitives::{Collation, MaybeCompressedPoV};
@@ -153,6 +153,17 @@ where
 			other_inherent_data.replace_data(sp_timestamp::INHERENT_IDENTIFIER, &timestamp);
 		}
 
+		// Proofs of the System Token weights are optional. The runtime keeps its cached weights
+		// if the relay chain has not committed to any table.
+		if let Some(system_token_weights) =
+			SystemTokenWeightInherentData::create_at(relay_parent, &self.relay_client).await
+		{
+			system_token_weights
+				.provide_inherent_data(&mut other_inherent_data)
+				.await
+				.map_err(Box::new)?;
+		}
+
 		Ok((paras_inherent_data, other_inherent_data))
 	}
 
@@ -64,6 +64,7 @@ use sp_runtime::{
 };
 use std::{marker::PhantomData, sync::Arc, time::Duration};
 use substrate_prometheus_endpoint::Registry;
+use xcm::latest::MultiLocation;
 
 use primitives::CollatorPair;
 
@@ -401,6 +402,12 @@ where
 		+ sp_block_builder::BlockBuilder<Block>
 		+ cumulus_primitives_core::CollectCollationInfo<Block>
 		+ pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
+		+ cumulus_pallet_infra_parachain_core_rpc::InfraParaCoreRuntimeApi<
+			Block,
+			MultiLocation,
+			Balance,
+			BlockNumber,
+		> + cumulus_pallet_infra_parachain_core_rpc::FungiblesRuntimeApi<Block, AccountId>
 		+ frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
 	RB: Fn(Arc<ParachainClient<RuntimeApi>>) -> Result<jsonrpsee::RpcModule<()>, sc_service::Error>,
 	BIQ: FnOnce(
@@ -934,6 +941,12 @@ where
 		+ cumulus_primitives_core::CollectCollationInfo<Block>
 		+ sp_consensus_aura::AuraApi<Block, <<AuraId as AppCrypto>::Pair as Pair>::Public>
 		+ pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
+		+ cumulus_pallet_infra_parachain_core_rpc::InfraParaCoreRuntimeApi<
+			Block,
+			MultiLocation,
+			Balance,
+			BlockNumber,
+		> + cumulus_pallet_infra_parachain_core_rpc::FungiblesRuntimeApi<Block, AccountId>
 		+ frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
 	<<AuraId as AppCrypto>::Pair as Pair>::Signature:
 		TryFrom<Vec<u8>> + std::hash::Hash + sp_runtime::traits::Member + Codec,
@@ -1028,6 +1041,12 @@ where
 		+ cumulus_primitives_core::CollectCollationInfo<Block>
 		+ sp_consensus_aura::AuraApi<Block, <<AuraId as AppCrypto>::Pair as Pair>::Public>
 		+ pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
+		+ cumulus_pallet_infra_parachain_core_rpc::InfraParaCoreRuntimeApi<
+			Block,
+			MultiLocation,
+			Balance,
+			BlockNumber,
+		> + cumulus_pallet_infra_parachain_core_rpc::FungiblesRuntimeApi<Block, AccountId>
 		+ frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
 	<<AuraId as AppCrypto>::Pair as Pair>::Signature:
 		TryFrom<Vec<u8>> + std::hash::Hash + sp_runtime::traits::Member + Codec,
@@ -0,0 +1,193 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+
+// Cumulus is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cumulus is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::chain_spec::{
+	get_account_id_from_seed, get_collator_keys_from_seed, Extensions, SAFE_XCM_VERSION,
+};
+use cumulus_primitives_core::ParaId;
+use parachains_common::{AccountId, AuraId, Balance as IdentityBalance};
+use sc_service::ChainType;
+use sp_core::sr25519;
+
+/// Specialized `ChainSpec` for the identity parachain runtime.
+pub type IdentityChainSpec =
+	sc_service::GenericChainSpec<identity_runtime::RuntimeGenesisConfig, Extensions>;
+
+/// Default parachain id of the identity parachain.
+pub const IDENTITY_PARA_ID: u32 = 1001;
+
+const IDENTITY_INFRA_RELAY_ED: IdentityBalance =
+	parachains_common::infra_relay::currency::EXISTENTIAL_DEPOSIT;
+
+/// Generate the session keys from individual elements.
+///
+/// The input must be a tuple of individual keys (a single arg for now since we have just one key).
+pub fn identity_session_keys(keys: AuraId) -> identity_runtime::SessionKeys {
+	identity_runtime::SessionKeys { aura: keys }
+}
+
+pub fn identity_development_config() -> IdentityChainSpec {
+	let mut properties = sc_chain_spec::Properties::new();
+	properties.insert("ss58Format".into(), 0.into());
+	properties.insert("tokenSymbol".into(), "".into());
+	properties.insert("tokenDecimals".into(), 10.into());
+
+	IdentityChainSpec::from_genesis(
+		// Name
+		"InfraBlockchain Identity Dev",
+		// ID
+		"identity-infra-dev",
+		ChainType::Local,
+		move || {
+			identity_genesis(
+				// initial collators.
+				vec![(
+					get_account_id_from_seed::<sr25519::Public>("Alice"),
+					get_collator_keys_from_seed::<AuraId>("Alice"),
+				)],
+				vec![
+					get_account_id_from_seed::<sr25519::Public>("Alice"),
+					get_account_id_from_seed::<sr25519::Public>("Bob"),
+					get_account_id_from_seed::<sr25519::Public>("Alice//stash"),
+					get_account_id_from_seed::<sr25519::Public>("Bob//stash"),
+				],
+				vec![get_account_id_from_seed::<sr25519::Public>("Alice")],
+				Some(get_account_id_from_seed::<sr25519::Public>("Alice")),
+				IDENTITY_PARA_ID.into(),
+			)
+		},
+		Vec::new(),
+		None,
+		None,
+		None,
+		Some(properties),
+		Extensions { relay_chain: "infra-relay-local".into(), para_id: IDENTITY_PARA_ID },
+	)
+}
+
+pub fn identity_local_config() -> IdentityChainSpec {
+	let mut properties = sc_chain_spec::Properties::new();
+	properties.insert("ss58Format".into(), 0.into());
+	properties.insert("tokenSymbol".into(), "".into());
+	properties.insert("tokenDecimals".into(), 10.into());
+
+	IdentityChainSpec::from_genesis(
+		// Name
+		"InfraBlockchain Identity Local",
+		// ID
+		"identity-infra-local",
+		ChainType::Local,
+		move || {
+			identity_genesis(
+				// initial collators.
+				vec![
+					(
+						get_account_id_from_seed::<sr25519::Public>("Alice"),
+						get_collator_keys_from_seed::<AuraId>("Alice"),
+					),
+					(
+						get_account_id_from_seed::<sr25519::Public>("Bob"),
+						get_collator_keys_from_seed::<AuraId>("Bob"),
+					),
+				],
+				vec![
+					get_account_id_from_seed::<sr25519::Public>("Alice"),
+					get_account_id_from_seed::<sr25519::Public>("Bob"),
+					get_account_id_from_seed::<sr25519::Public>("Charlie"),
+					get_account_id_from_seed::<sr25519::Public>("Dave"),
+					get_account_id_from_seed::<sr25519::Public>("Eve"),
+					get_account_id_from_seed::<sr25519::Public>("Ferdie"),
+					get_account_id_from_seed::<sr25519::Public>("Alice//stash"),
+					get_account_id_from_seed::<sr25519::Public>("Bob//stash"),
+					get_account_id_from_seed::<sr25519::Public>("Charlie//stash"),
+					get_account_id_from_seed::<sr25519::Public>("Dave//stash"),
+					get_account_id_from_seed::<sr25519::Public>("Eve//stash"),
+					get_account_id_from_seed::<sr25519::Public>("Ferdie//stash"),
+				],
+				vec![
+					get_account_id_from_seed::<sr25519::Public>("Alice"),
+					get_account_id_from_seed::<sr25519::Public>("Bob"),
+					get_account_id_from_seed::<sr25519::Public>("Charlie"),
+				],
+				Some(get_account_id_from_seed::<sr25519::Public>("Alice")),
+				IDENTITY_PARA_ID.into(),
+			)
+		},
+		Vec::new(),
+		None,
+		None,
+		None,
+		Some(properties),
+		Extensions { relay_chain: "infra-relay-local".into(), para_id: IDENTITY_PARA_ID },
+	)
+}
+
+fn identity_genesis(
+	invulnerables: Vec<(AccountId, AuraId)>,
+	endowed_accounts: Vec<AccountId>,
+	oracle_members: Vec<AccountId>,
+	root_key: Option<AccountId>,
+	id: ParaId,
+) -> identity_runtime::RuntimeGenesisConfig {
+	identity_runtime::RuntimeGenesisConfig {
+		system: identity_runtime::SystemConfig {
+			code: identity_runtime::WASM_BINARY
+				.expect("WASM binary was not build, please build it!")
+				.to_vec(),
+			..Default::default()
+		},
+		balances: identity_runtime::BalancesConfig {
+			balances: endowed_accounts
+				.iter()
+				.cloned()
+				.map(|k| (k, IDENTITY_INFRA_RELAY_ED * 4096))
+				.collect(),
+		},
+		parachain_info: identity_runtime::ParachainInfoConfig {
+			parachain_id: id,
+			..Default::default()
+		},
+		collator_selection: identity_runtime::CollatorSelectionConfig {
+			invulnerables: invulnerables.iter().cloned().map(|(acc, _)| acc).collect(),
+			candidacy_bond: IDENTITY_INFRA_RELAY_ED * 16,
+			..Default::default()
+		},
+		session: identity_runtime::SessionConfig {
+			keys: invulnerables
+				.into_iter()
+				.map(|(acc, aura)| {
+					(
+						acc.clone(),                 // account id
+						acc,                         // validator id
+						identity_session_keys(aura), // session keys
+					)
+				})
+				.collect(),
+		},
+		// no need to pass anything to aura, in fact it will panic if we do. Session will take care
+		// of this.
+		aura: Default::default(),
+		aura_ext: Default::default(),
+		parachain_system: Default::default(),
+		infra_xcm: identity_runtime::InfraXcmConfig {
+			safe_xcm_version: Some(SAFE_XCM_VERSION),
+			..Default::default()
+		},
+		ur_auth: identity_runtime::URAuthConfig { oracle_members },
+		sudo: identity_runtime::SudoConfig { key: root_key },
+		..Default::default()
+	}
+}
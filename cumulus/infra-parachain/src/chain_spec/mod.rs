@@ -22,6 +22,7 @@ pub use sp_runtime::traits::{IdentifyAccount, Verify};
 
 // Parachains' Runtime Module
 pub mod asset_hubs;
+pub mod identity;
 
 /// The default XCM version to set in genesis config.
 const SAFE_XCM_VERSION: u32 = xcm::prelude::XCM_VERSION;
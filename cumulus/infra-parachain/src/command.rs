@@ -21,6 +21,7 @@ use crate::{
 };
 use cumulus_primitives_core::ParaId;
 use frame_benchmarking_cli::{BenchmarkCmd, SUBSTRATE_REFERENCE_HARDWARE};
+use frame_support::traits::PalletInfoAccess;
 use log::info;
 use parachains_common::AuraId;
 use sc_cli::{
@@ -39,6 +40,7 @@ enum Runtime {
 	#[default]
 	Default,
 	AssetHubInfra,
+	IdentityInfra,
 }
 
 trait RuntimeResolver {
@@ -74,6 +76,8 @@ fn runtime(id: &str) -> Runtime {
 
 	if id.starts_with("asset-hub-infra") {
 		Runtime::AssetHubInfra
+	} else if id.starts_with("identity-infra") {
+		Runtime::IdentityInfra
 	} else {
 		log::warn!("No specific runtime was recognized for ChainSpec's id: '{}', so Runtime::default() will be used", id);
 		Runtime::default()
@@ -91,7 +95,8 @@ fn load_spec(id: &str) -> std::result::Result<Box<dyn ChainSpec>, String> {
 		"asset-hub-infra" => Box::new(chain_spec::asset_hubs::AssetHubChainSpec::from_json_bytes(
 			&include_bytes!("../chain-specs/asset-hub-polkadot.json")[..],
 		)?),
-		// ToDo: chain-spec file for `URAuth`
+		"identity-infra-dev" => Box::new(chain_spec::identity::identity_development_config()),
+		"identity-infra-local" => Box::new(chain_spec::identity::identity_local_config()),
 		// -- Fallback (generic chainspec)
 		"" => {
 			log::warn!("No ChainSpec.id specified, so using default one, based on rococo-parachain runtime");
@@ -104,6 +109,8 @@ fn load_spec(id: &str) -> std::result::Result<Box<dyn ChainSpec>, String> {
 			match path.runtime() {
 				Runtime::AssetHubInfra =>
 					Box::new(chain_spec::asset_hubs::AssetHubChainSpec::from_json_file(path)?),
+				Runtime::IdentityInfra =>
+					Box::new(chain_spec::identity::IdentityChainSpec::from_json_file(path)?),
 				Runtime::Default =>
 					Box::new(chain_spec::asset_hubs::AssetHubChainSpec::from_json_file(path)?),
 			}
@@ -227,6 +234,13 @@ macro_rules! construct_partials {
 				)?;
 				$code
 			},
+			Runtime::IdentityInfra => {
+				let $partials = new_partial::<identity_runtime::RuntimeApi, _>(
+					&$config,
+					crate::service::aura_build_import_queue::<_, AuraId>,
+				)?;
+				$code
+			},
 			Runtime::Default => {
 				let $partials = new_partial::<asset_hub_runtime::RuntimeApi, _>(
 					&$config,
@@ -252,6 +266,16 @@ macro_rules! construct_async_run {
 					{ $( $code )* }.map(|v| (v, task_manager))
 				})
 			},
+			Runtime::IdentityInfra => {
+				runner.async_run(|$config| {
+					let $components = new_partial::<identity_runtime::RuntimeApi, _>(
+						&$config,
+						crate::service::aura_build_import_queue::<_, AuraId>,
+					)?;
+					let task_manager = $components.task_manager;
+					{ $( $code )* }.map(|v| (v, task_manager))
+				})
+			},
 			Runtime::Default => {
 				runner.async_run(|$config| {
 					let $components = new_partial::<asset_hub_runtime::RuntimeApi, _>(
@@ -442,14 +466,28 @@ pub fn run() -> Result<()> {
 					Runtime::AssetHubInfra => crate::service::start_generic_aura_node::<
 						asset_hub_runtime::RuntimeApi,
 						AuraId,
-					>(config, infra_relay_config, collator_options, id, hwbench)
+					>(config, infra_relay_config, collator_options, id, None, hwbench)
+					.await
+					.map(|r| r.0)
+					.map_err(Into::into),
+					Runtime::IdentityInfra => crate::service::start_generic_aura_node::<
+						identity_runtime::RuntimeApi,
+						AuraId,
+					>(
+						config,
+						infra_relay_config,
+						collator_options,
+						id,
+						Some(<identity_runtime::Anchor as PalletInfoAccess>::name()),
+						hwbench,
+					)
 					.await
 					.map(|r| r.0)
 					.map_err(Into::into),
 					Runtime::Default => crate::service::start_generic_aura_node::<
 						asset_hub_runtime::RuntimeApi,
 						AuraId,
-					>(config, infra_relay_config, collator_options, id, hwbench)
+					>(config, infra_relay_config, collator_options, id, None, hwbench)
 					.await
 					.map(|r| r.0)
 					.map_err(Into::into),
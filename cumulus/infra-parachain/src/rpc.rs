@@ -20,13 +20,14 @@
 
 use std::sync::Arc;
 
-use parachains_common::{opaque::Block, AccountId, Balance, Nonce};
-use sc_client_api::AuxStore;
+use parachains_common::{opaque::Block, AccountId, Balance, BlockNumber, Nonce};
+use sc_client_api::{AuxStore, ProofProvider};
 pub use sc_rpc::DenyUnsafe;
 use sc_transaction_pool_api::TransactionPool;
 use sp_api::ProvideRuntimeApi;
 use sp_block_builder::BlockBuilder;
 use sp_blockchain::{Error as BlockChainError, HeaderBackend, HeaderMetadata};
+use xcm::latest::MultiLocation;
 
 /// A type representing all RPC extensions.
 pub type RpcExtension = jsonrpsee::RpcModule<()>;
@@ -39,6 +40,8 @@ pub struct FullDeps<C, P> {
 	pub pool: Arc<P>,
 	/// Whether to deny unsafe calls
 	pub deny_unsafe: DenyUnsafe,
+	/// Name of the did-core anchor pallet in the runtime, if it has one
+	pub anchor_pallet: Option<&'static str>,
 }
 
 /// Instantiate all RPC extensions.
@@ -50,26 +53,43 @@ where
 	C: ProvideRuntimeApi<Block>
 		+ HeaderBackend<Block>
 		+ AuxStore
+		+ ProofProvider<Block>
 		+ HeaderMetadata<Block, Error = BlockChainError>
 		+ Send
 		+ Sync
 		+ 'static,
 	C::Api: frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
+	C::Api: cumulus_pallet_infra_parachain_core_rpc::InfraParaCoreRuntimeApi<
+		Block,
+		MultiLocation,
+		Balance,
+		BlockNumber,
+	>,
+	C::Api: cumulus_pallet_infra_parachain_core_rpc::FungiblesRuntimeApi<Block, AccountId>,
 	C::Api: BlockBuilder<Block>,
 	P: TransactionPool + Sync + Send + 'static,
 	B: sc_client_api::Backend<Block> + Send + Sync + 'static,
 	B::State: sc_client_api::backend::StateBackend<sp_runtime::traits::HashingFor<Block>>,
 {
+	use cumulus_pallet_infra_parachain_core_rpc::{InfraApiServer, InfraParaCore};
+	use did_core_rpc::{Accumulator, AccumulatorApiServer, Anchor, AnchorApiServer};
 	use frame_rpc_system::{System, SystemApiServer};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
 	use substrate_state_trie_migration_rpc::{StateMigration, StateMigrationApiServer};
 
 	let mut module = RpcExtension::new(());
-	let FullDeps { client, pool, deny_unsafe } = deps;
+	let FullDeps { client, pool, deny_unsafe, anchor_pallet } = deps;
 
 	module.merge(System::new(client.clone(), pool, deny_unsafe).into_rpc())?;
 	module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+	module.merge(InfraParaCore::new(client.clone()).into_rpc())?;
+	if let Some(pallet_name) = anchor_pallet {
+		module.merge(Anchor::new(client.clone(), pallet_name).into_rpc())?;
+	}
+	if let Some(offchain_storage) = backend.offchain_storage() {
+		module.merge(Accumulator::<_, BlockNumber>::new(offchain_storage).into_rpc())?;
+	}
 	module.merge(StateMigration::new(client, backend, deny_unsafe).into_rpc())?;
 
 	Ok(module)
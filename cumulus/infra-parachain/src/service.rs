@@ -40,7 +40,7 @@ use jsonrpsee::RpcModule;
 use crate::rpc;
 pub use parachains_common::{
 	opaque::{Block, Header},
-	AccountId, Balance, Hash, Nonce,
+	AccountId, Balance, BlockNumber, Hash, Nonce,
 };
 
 use cumulus_client_consensus_relay_chain::Verifier as RelayChainVerifier;
@@ -66,6 +66,7 @@ use sp_runtime::{
 };
 use std::{marker::PhantomData, sync::Arc, time::Duration};
 use substrate_prometheus_endpoint::Registry;
+use xcm::latest::MultiLocation;
 
 use primitives::CollatorPair;
 
@@ -391,6 +392,7 @@ async fn start_node_impl<RuntimeApi, RB, BIQ, SC>(
 	_rpc_ext_builder: RB,
 	build_import_queue: BIQ,
 	start_consensus: SC,
+	anchor_pallet: Option<&'static str>,
 	hwbench: Option<sc_sysinfo::HwBench>,
 ) -> sc_service::error::Result<(TaskManager, Arc<ParachainClient<RuntimeApi>>)>
 where
@@ -403,6 +405,12 @@ where
 		+ sp_block_builder::BlockBuilder<Block>
 		+ cumulus_primitives_core::CollectCollationInfo<Block>
 		+ pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
+		+ cumulus_pallet_infra_parachain_core_rpc::InfraParaCoreRuntimeApi<
+			Block,
+			MultiLocation,
+			Balance,
+			BlockNumber,
+		> + cumulus_pallet_infra_parachain_core_rpc::FungiblesRuntimeApi<Block, AccountId>
 		+ frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
 	RB: Fn(Arc<ParachainClient<RuntimeApi>>) -> Result<jsonrpsee::RpcModule<()>, sc_service::Error>,
 	BIQ: FnOnce(
@@ -502,6 +510,7 @@ where
 				client: client.clone(),
 				pool: transaction_pool.clone(),
 				deny_unsafe,
+				anchor_pallet,
 			};
 
 			rpc::create_full(deps, backend_for_rpc.clone()).map_err(Into::into)
@@ -946,6 +955,7 @@ pub async fn start_generic_aura_node<RuntimeApi, AuraId: AppCrypto>(
 	polkadot_config: Configuration,
 	collator_options: CollatorOptions,
 	para_id: ParaId,
+	anchor_pallet: Option<&'static str>,
 	hwbench: Option<sc_sysinfo::HwBench>,
 ) -> sc_service::error::Result<(TaskManager, Arc<ParachainClient<RuntimeApi>>)>
 where
@@ -959,6 +969,12 @@ where
 		+ cumulus_primitives_core::CollectCollationInfo<Block>
 		+ sp_consensus_aura::AuraApi<Block, <<AuraId as AppCrypto>::Pair as Pair>::Public>
 		+ pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
+		+ cumulus_pallet_infra_parachain_core_rpc::InfraParaCoreRuntimeApi<
+			Block,
+			MultiLocation,
+			Balance,
+			BlockNumber,
+		> + cumulus_pallet_infra_parachain_core_rpc::FungiblesRuntimeApi<Block, AccountId>
 		+ frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
 	<<AuraId as AppCrypto>::Pair as Pair>::Signature:
 		TryFrom<Vec<u8>> + std::hash::Hash + sp_runtime::traits::Member + Codec,
@@ -1027,6 +1043,7 @@ where
 
 			Ok(())
 		},
+		anchor_pallet,
 		hwbench,
 	)
 	.await
@@ -1053,6 +1070,12 @@ where
 		+ cumulus_primitives_core::CollectCollationInfo<Block>
 		+ sp_consensus_aura::AuraApi<Block, <<AuraId as AppCrypto>::Pair as Pair>::Public>
 		+ pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
+		+ cumulus_pallet_infra_parachain_core_rpc::InfraParaCoreRuntimeApi<
+			Block,
+			MultiLocation,
+			Balance,
+			BlockNumber,
+		> + cumulus_pallet_infra_parachain_core_rpc::FungiblesRuntimeApi<Block, AccountId>
 		+ frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
 	<<AuraId as AppCrypto>::Pair as Pair>::Signature:
 		TryFrom<Vec<u8>> + std::hash::Hash + sp_runtime::traits::Member + Codec,
@@ -1170,6 +1193,7 @@ where
 
 			Ok(())
 		},
+		None,
 		hwbench,
 	)
 	.await
@@ -0,0 +1,36 @@
+//! Runtime API definition for InfraParaCore pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+pub use sp_runtime::infra::{BootstrapCriterion, CallFee, FeeModel, FeeRule, Mode, SystemConfig};
+
+sp_api::decl_runtime_apis! {
+	#[api_version(3)]
+	pub trait InfraParaCoreApi<AssetId, Balance, BlockNumber> where
+		AssetId: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+	{
+		/// Fee rule in effect for every call known to the runtime
+		fn fee_table() -> Vec<CallFee<Balance, BlockNumber>>;
+		/// Current mode of the runtime
+		#[api_version(2)]
+		fn runtime_state() -> Mode;
+		/// Dry run of ending bootstrap. Bootstrap exit criteria which are not met yet.
+		#[api_version(2)]
+		fn unmet_bootstrap_criteria() -> Vec<BootstrapCriterion>;
+		/// System configuration set by the Relay-chain. `None` if it has not been set yet.
+		#[api_version(3)]
+		fn system_config() -> Option<SystemConfig>;
+		/// Fee rate of this parachain. `None` if the system configuration has not been set yet.
+		#[api_version(3)]
+		fn para_fee_rate() -> Option<Balance>;
+		/// System Token registration request waiting for the Relay-chain, with the block it
+		/// expires at
+		#[api_version(3)]
+		fn pending_request() -> Option<(AssetId, BlockNumber)>;
+	}
+}
@@ -0,0 +1,328 @@
+//! RPC interface for InfraParaCore pallet.
+//!
+//! Exposes the InfraBlockchain concepts of a parachain under the `infra_` namespace: the system
+//! configuration set by the Relay-chain, the mode of the runtime, the fee rate and the fee table,
+//! System Token balances of an account and the pending System Token registration request.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+use xcm::{latest::MultiAsset, VersionedMultiAssets};
+
+pub use cumulus_pallet_infra_parachain_core_rpc_runtime_api::{
+	BootstrapCriterion, CallFee, InfraParaCoreApi as InfraParaCoreRuntimeApi, Mode, SystemConfig,
+};
+pub use infra_asset_common::runtime_api::FungiblesApi as FungiblesRuntimeApi;
+
+/// First version of `InfraParaCoreApi` with `runtime_state` and `unmet_bootstrap_criteria`
+const BOOTSTRAP_API_VERSION: u32 = 2;
+/// First version of `InfraParaCoreApi` with `system_config`, `para_fee_rate` and
+/// `pending_request`
+const SYSTEM_CONFIG_API_VERSION: u32 = 3;
+
+/// System Token registration request which is waiting for the Relay-chain
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PendingRequest<AssetId, BlockNumber> {
+	/// Local asset requested to be registered as System Token
+	pub asset_id: AssetId,
+	/// Block from which the request is no longer valid
+	pub expires_at: BlockNumber,
+}
+
+#[rpc(client, server)]
+pub trait InfraApi<BlockHash, AccountId, AssetId, Balance, BlockNumber> {
+	/// System configuration set by the Relay-chain
+	#[method(name = "infra_systemConfig")]
+	fn system_config(&self, at: Option<BlockHash>) -> RpcResult<Option<SystemConfig>>;
+
+	/// Current mode of the runtime
+	#[method(name = "infra_runtimeState")]
+	fn runtime_state(&self, at: Option<BlockHash>) -> RpcResult<Mode>;
+
+	/// Bootstrap exit criteria which are not met yet
+	#[method(name = "infra_unmetBootstrapCriteria")]
+	fn unmet_bootstrap_criteria(&self, at: Option<BlockHash>)
+		-> RpcResult<Vec<BootstrapCriterion>>;
+
+	/// Fee rate of the parachain
+	#[method(name = "infra_paraFeeRate")]
+	fn para_fee_rate(&self, at: Option<BlockHash>) -> RpcResult<Option<Balance>>;
+
+	/// Fee rule in effect for every call known to the runtime
+	#[method(name = "infra_feeTable")]
+	fn fee_table(&self, at: Option<BlockHash>) -> RpcResult<Vec<CallFee<Balance, BlockNumber>>>;
+
+	/// Balances of `account` in the native token and in every asset, including System Tokens
+	#[method(name = "infra_accountBalances")]
+	fn account_balances(
+		&self,
+		account: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<MultiAsset>>;
+
+	/// System Token registration request waiting for the Relay-chain
+	#[method(name = "infra_pendingRequest")]
+	fn pending_request(
+		&self,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<PendingRequest<AssetId, BlockNumber>>>;
+}
+
+/// Provides RPC methods to query the InfraBlockchain state of a parachain.
+pub struct InfraParaCore<C, P> {
+	/// Shared reference to the client.
+	client: Arc<C>,
+	_marker: PhantomData<P>,
+}
+
+impl<C, P> InfraParaCore<C, P> {
+	/// Creates a new instance of the InfraParaCore Rpc helper.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+	/// The balances returned by the runtime could not be converted to the latest XCM version.
+	ConversionError,
+	/// The runtime at the queried block does not provide the method yet.
+	UnsupportedApi,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::ConversionError => 2,
+			Error::UnsupportedApi => 3,
+		}
+	}
+}
+
+fn runtime_error(error: impl ToString, desc: &'static str) -> CallError {
+	CallError::Custom(ErrorObject::owned(Error::RuntimeError.into(), desc, Some(error.to_string())))
+}
+
+/// Ensure `version` of `InfraParaCoreApi` provides the methods of the `required` version.
+fn check_api_version(
+	version: Option<u32>,
+	required: u32,
+	desc: &'static str,
+) -> Result<(), CallError> {
+	match version {
+		Some(version) if version >= required => Ok(()),
+		version => Err(CallError::Custom(ErrorObject::owned(
+			Error::UnsupportedApi.into(),
+			desc,
+			Some(format!(
+				"InfraParaCoreApi version {} is required, but the runtime provides {:?}",
+				required, version
+			)),
+		))),
+	}
+}
+
+impl<C, Block> InfraParaCore<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block>,
+{
+	/// Ensure the runtime at `at_hash` provides the methods of the `required` version of
+	/// `InfraParaCoreApi`.
+	fn ensure_api_version<AssetId, Balance, BlockNumber>(
+		&self,
+		at_hash: Block::Hash,
+		required: u32,
+		desc: &'static str,
+	) -> Result<(), CallError>
+	where
+		C::Api: InfraParaCoreRuntimeApi<Block, AssetId, Balance, BlockNumber>,
+		AssetId: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+	{
+		let version = self
+			.client
+			.runtime_api()
+			.api_version::<dyn InfraParaCoreRuntimeApi<Block, AssetId, Balance, BlockNumber>>(
+				at_hash,
+			)
+			.map_err(|e| runtime_error(e, desc))?;
+		check_api_version(version, required, desc)
+	}
+}
+
+impl<C, Block, AccountId, AssetId, Balance, BlockNumber>
+	InfraApiServer<<Block as BlockT>::Hash, AccountId, AssetId, Balance, BlockNumber>
+	for InfraParaCore<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: InfraParaCoreRuntimeApi<Block, AssetId, Balance, BlockNumber>
+		+ FungiblesRuntimeApi<Block, AccountId>,
+	AccountId: Codec + DeserializeOwned + Send + Sync + 'static,
+	AssetId: Codec + Serialize + Send + Sync + 'static,
+	Balance: Codec + Serialize + Send + Sync + 'static,
+	BlockNumber: Codec + Serialize + Send + Sync + 'static,
+{
+	fn system_config(&self, at: Option<Block::Hash>) -> RpcResult<Option<SystemConfig>> {
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.ensure_api_version::<AssetId, Balance, BlockNumber>(
+			at_hash,
+			SYSTEM_CONFIG_API_VERSION,
+			"Unable to query system configuration.",
+		)?;
+		self.client
+			.runtime_api()
+			.system_config(at_hash)
+			.map_err(|e| runtime_error(e, "Unable to query system configuration.").into())
+	}
+
+	fn runtime_state(&self, at: Option<Block::Hash>) -> RpcResult<Mode> {
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.ensure_api_version::<AssetId, Balance, BlockNumber>(
+			at_hash,
+			BOOTSTRAP_API_VERSION,
+			"Unable to query runtime state.",
+		)?;
+		self.client
+			.runtime_api()
+			.runtime_state(at_hash)
+			.map_err(|e| runtime_error(e, "Unable to query runtime state.").into())
+	}
+
+	fn unmet_bootstrap_criteria(
+		&self,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<BootstrapCriterion>> {
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.ensure_api_version::<AssetId, Balance, BlockNumber>(
+			at_hash,
+			BOOTSTRAP_API_VERSION,
+			"Unable to query bootstrap criteria.",
+		)?;
+		self.client
+			.runtime_api()
+			.unmet_bootstrap_criteria(at_hash)
+			.map_err(|e| runtime_error(e, "Unable to query bootstrap criteria.").into())
+	}
+
+	fn para_fee_rate(&self, at: Option<Block::Hash>) -> RpcResult<Option<Balance>> {
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.ensure_api_version::<AssetId, Balance, BlockNumber>(
+			at_hash,
+			SYSTEM_CONFIG_API_VERSION,
+			"Unable to query para fee rate.",
+		)?;
+		self.client
+			.runtime_api()
+			.para_fee_rate(at_hash)
+			.map_err(|e| runtime_error(e, "Unable to query para fee rate.").into())
+	}
+
+	fn fee_table(&self, at: Option<Block::Hash>) -> RpcResult<Vec<CallFee<Balance, BlockNumber>>> {
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client
+			.runtime_api()
+			.fee_table(at_hash)
+			.map_err(|e| runtime_error(e, "Unable to query fee table.").into())
+	}
+
+	fn account_balances(
+		&self,
+		account: AccountId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<MultiAsset>> {
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+		let balances: VersionedMultiAssets = self
+			.client
+			.runtime_api()
+			.query_account_balances(at_hash, account)
+			.map_err(|e| runtime_error(e, "Unable to query account balances."))?
+			.map_err(|e| runtime_error(format!("{:?}", e), "Unable to query account balances."))?;
+		let balances = xcm::latest::MultiAssets::try_from(balances).map_err(|_| {
+			CallError::Custom(ErrorObject::owned(
+				Error::ConversionError.into(),
+				"Unable to convert account balances.",
+				None::<()>,
+			))
+		})?;
+		Ok(balances.into_inner())
+	}
+
+	fn pending_request(
+		&self,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<PendingRequest<AssetId, BlockNumber>>> {
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.ensure_api_version::<AssetId, Balance, BlockNumber>(
+			at_hash,
+			SYSTEM_CONFIG_API_VERSION,
+			"Unable to query pending request.",
+		)?;
+		let request = self
+			.client
+			.runtime_api()
+			.pending_request(at_hash)
+			.map_err(|e| runtime_error(e, "Unable to query pending request."))?;
+		Ok(request.map(|(asset_id, expires_at)| PendingRequest { asset_id, expires_at }))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn error_code(result: Result<(), CallError>) -> Option<i32> {
+		match result {
+			Err(CallError::Custom(error)) => Some(error.code()),
+			_ => None,
+		}
+	}
+
+	#[test]
+	fn api_version_is_checked() {
+		assert!(check_api_version(Some(SYSTEM_CONFIG_API_VERSION), SYSTEM_CONFIG_API_VERSION, "")
+			.is_ok());
+		assert!(
+			check_api_version(Some(SYSTEM_CONFIG_API_VERSION), BOOTSTRAP_API_VERSION, "").is_ok()
+		);
+		// Runtimes from before `system_config` was added
+		assert_eq!(
+			error_code(check_api_version(
+				Some(BOOTSTRAP_API_VERSION),
+				SYSTEM_CONFIG_API_VERSION,
+				""
+			)),
+			Some(Error::UnsupportedApi.into())
+		);
+		assert_eq!(
+			error_code(check_api_version(Some(1), BOOTSTRAP_API_VERSION, "")),
+			Some(Error::UnsupportedApi.into())
+		);
+		// Runtimes without `InfraParaCoreApi` at all
+		assert_eq!(
+			error_code(check_api_version(None, BOOTSTRAP_API_VERSION, "")),
+			Some(Error::UnsupportedApi.into())
+		);
+	}
+
+	#[test]
+	fn pending_request_is_camel_case() {
+		let request = PendingRequest { asset_id: 1u32, expires_at: 10u32 };
+		assert_eq!(serde_json::to_string(&request).unwrap(), r#"{"assetId":1,"expiresAt":10}"#);
+	}
+}
@@ -0,0 +1,123 @@
+//! Benchmarking setup for cumulus-pallet-infra-parachain-core
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use cumulus_primitives_core::relay_chain::system_token_weight::prove;
+use frame_benchmarking::{impl_benchmark_test_suite, v2::*};
+use frame_system::RawOrigin;
+use sp_std::vec;
+
+/// Upper bound of the number of proofs in the benchmarks. The inherent only proves the System
+/// Tokens of this parachain which are out of date.
+const MAX_PROOFS: u32 = 100;
+
+#[benchmarks(
+	where
+		T::SystemTokenId: TryFrom<SystemTokenAssetIdOf<T>> + Into<SystemTokenAssetIdOf<T>>,
+		SystemTokenOriginIdOf<T>: From<u32>,
+		SystemTokenWeightOf<T>: From<u32>,
+		<T as frame_system::Config>::RuntimeOrigin: From<cumulus_pallet_xcm::Origin>,
+)]
+mod benchmarks {
+	use super::*;
+
+	// Worst case is every proof updating the weight of a System Token of this parachain.
+	#[benchmark]
+	fn prove_system_token_weights(n: Linear<1, MAX_PROOFS>) {
+		let table: Vec<(T::SystemTokenId, SystemTokenWeightOf<T>)> = (0..n)
+			.map(|i| (T::BenchmarkHelper::create_system_token(i), 2_000u32.into()))
+			.collect();
+		RelayWeightCommitment::<T>::put(SystemTokenWeightCommitment::new(1, &table));
+		let proofs: Vec<SystemTokenWeightProofOf<T>> = (0..table.len())
+			.map(|i| prove(&table, i).expect("`i` is a leaf of `table`; qed"))
+			.collect();
+
+		#[extrinsic_call]
+		_(RawOrigin::None, proofs);
+
+		for (id, _) in table {
+			let asset_id = Pallet::<T>::local_asset_id(id).expect("Created as a System Token; qed");
+			assert!(Pallet::<T>::is_system_token_weight_synced(&asset_id));
+		}
+	}
+
+	// Worst case is every update adding a promotion to a different call whose rules fill up
+	// with it, expiring at a block which already has other expiring rules.
+	#[benchmark]
+	fn update_fee_table_batch(n: Linear<1, { T::MaxFeeTableUpdates::get() }>) {
+		let now = frame_system::Pallet::<T>::block_number();
+		let calls: Vec<(&str, &str)> = <T as Config>::RuntimeCall::get_module_names()
+			.iter()
+			.flat_map(|pallet_name| {
+				<T as Config>::RuntimeCall::get_call_names(pallet_name)
+					.iter()
+					.map(move |call_name| (*pallet_name, *call_name))
+			})
+			.take(n as usize)
+			.collect();
+		assert_eq!(calls.len(), n as usize, "Runtime should have enough calls");
+		let promotion = |expires_at: BlockNumberFor<T>| FeeRuleOf::<T> {
+			model: FeeModel::Flat(1u32.into()),
+			starts_at: Some(now + 1u32.into()),
+			expires_at: Some(expires_at),
+		};
+		let mut updates = Vec::new();
+		for (i, (pallet_name, call_name)) in calls.into_iter().enumerate() {
+			let expires_at = now + 10u32.into() + (i as u32).into();
+			let existing =
+				vec![promotion(now + 1_000u32.into()); (T::MaxFeeRules::get() - 1) as usize];
+			FeeTable::<T>::insert(
+				ExtrinsicMetadata::new(pallet_name, call_name),
+				BoundedVec::<_, T::MaxFeeRules>::truncate_from(existing),
+			);
+			let expiring = vec![
+				ExtrinsicMetadata::any_call("Benchmark");
+				(T::MaxExpiringFeeRules::get() - 1) as usize
+			];
+			FeeRuleExpiry::<T>::insert(
+				expires_at,
+				BoundedVec::<_, T::MaxExpiringFeeRules>::truncate_from(expiring),
+			);
+			updates.push(FeeTableUpdateOf::<T> {
+				pallet_name: pallet_name.as_bytes().to_vec(),
+				call_name: call_name.as_bytes().to_vec(),
+				rule: Some(promotion(expires_at)),
+			});
+		}
+		let keys: Vec<ExtrinsicMetadata> = updates
+			.iter()
+			.map(|update| ExtrinsicMetadata::new(&update.pallet_name[..], &update.call_name[..]))
+			.collect();
+
+		#[extrinsic_call]
+		_(cumulus_pallet_xcm::Origin::Relay, updates);
+
+		for key in keys {
+			assert_eq!(FeeTable::<T>::get(key).len() as u32, T::MaxFeeRules::get());
+		}
+	}
+
+	// Worst case is ending the bootstrap, which checks every exit criterion.
+	#[benchmark]
+	fn set_runtime_state() {
+		RCSystemConfig::<T>::put(SystemConfig::default());
+		FeeTable::<T>::insert(
+			ExtrinsicMetadata::any_call("Benchmark"),
+			BoundedVec::<_, T::MaxFeeRules>::truncate_from(vec![FeeRuleOf::<T>::permanent(
+				FeeModel::Flat(1u32.into()),
+			)]),
+		);
+		T::BenchmarkHelper::create_system_token(0);
+		RuntimeState::<T>::put(Mode::Bootstrap);
+		assert!(Pallet::<T>::unmet_bootstrap_criteria().is_empty());
+
+		#[extrinsic_call]
+		_(cumulus_pallet_xcm::Origin::Relay, Mode::Normal);
+
+		assert_eq!(RuntimeState::<T>::get(), Mode::Normal);
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
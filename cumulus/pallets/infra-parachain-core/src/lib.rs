@@ -2,24 +2,45 @@
 
 use codec::Encode;
 use cumulus_pallet_xcm::{ensure_relay, Origin};
-use cumulus_primitives_core::UpdateRCConfig;
+use cumulus_primitives_core::{
+	relay_chain::{SystemTokenWeightCommitment, SystemTokenWeightProof},
+	UpdateRCConfig,
+};
+use cumulus_primitives_parachain_inherent::{
+	SystemTokenWeightInherentData, SYSTEM_TOKEN_WEIGHT_INHERENT_IDENTIFIER,
+};
 use frame_support::{
+	inherent::{InherentData, InherentIdentifier, MakeFatalError, ProvideInherent},
 	pallet_prelude::*,
-	traits::tokens::{
-		fungibles::{
-			Inspect, InspectSystemToken, InspectSystemTokenMetadata, ManageSystemToken, Mutate,
+	traits::{
+		tokens::{
+			fungibles::{
+				Inspect, InspectSystemToken, InspectSystemTokenMetadata, ManageSystemToken, Mutate,
+			},
+			Preservation::Preserve,
 		},
-		Preservation::Preserve
+		GetCallMetadata,
 	},
-	PalletId
+	PalletId,
 };
 use frame_system::pallet_prelude::*;
 use scale_info::TypeInfo;
-use sp_runtime::{infra::*, Saturating, traits::AccountIdConversion};
-use sp_std::vec::Vec;
+use sp_runtime::{
+	infra::*,
+	traits::{AccountIdConversion, AtLeast32BitUnsigned, Zero},
+	FixedPointNumber, FixedPointOperand, FixedU128, Perbill, SaturatedConversion, Saturating,
+};
+use sp_std::{str, vec::Vec};
 
 pub use pallet::*;
 
+pub mod migration;
+pub mod weights;
+pub use weights::WeightInfo;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
 use xcm::latest::{InteriorMultiLocation, SystemTokenId};
 
 pub type SystemTokenAssetIdOf<T> =
@@ -30,6 +51,19 @@ pub type SystemTokenWeightOf<T> = <<T as Config>::Fungibles as InspectSystemToke
 	<T as frame_system::Config>::AccountId,
 >>::SystemTokenWeight;
 pub type SystemTokenOriginIdOf<T> = <<T as Config>::SystemTokenId as SystemTokenId>::OriginId;
+pub type FeeRuleOf<T> = FeeRule<SystemTokenBalanceOf<T>, BlockNumberFor<T>>;
+pub type FeeTableUpdateOf<T> = FeeTableUpdate<SystemTokenBalanceOf<T>, BlockNumberFor<T>>;
+pub type CallFeeOf<T> = CallFee<SystemTokenBalanceOf<T>, BlockNumberFor<T>>;
+pub type SystemTokenWeightProofOf<T> =
+	SystemTokenWeightProof<<T as Config>::SystemTokenId, SystemTokenWeightOf<T>>;
+
+/// Something which sets up the benchmarks of this pallet
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<SystemTokenId> {
+	/// Create the `i`-th System Token of this parachain with a weight other than 2_000 and
+	/// return its id as seen from the Relay-chain
+	fn create_system_token(i: u32) -> SystemTokenId;
+}
 
 #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
 pub struct RequestStatus<BlockNumber> {
@@ -50,11 +84,154 @@ where
 	}
 }
 
+/// Block fullness accumulated over the blocks of a fee rate adjustment period
+#[derive(
+	Encode, Decode, Clone, Copy, Default, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo,
+)]
+pub struct BlockFullness {
+	/// Number of blocks accumulated
+	pub blocks: u32,
+	/// Sum of the fullness of the accumulated blocks, in parts per billion
+	pub total: u64,
+}
+
+impl BlockFullness {
+	fn accrue(&mut self, fullness: Perbill) {
+		self.blocks = self.blocks.saturating_add(1);
+		self.total = self.total.saturating_add(fullness.deconstruct().into());
+	}
+
+	/// Average fullness of the accumulated blocks. Zero if nothing has been accumulated.
+	pub fn average(&self) -> Perbill {
+		if self.blocks == 0 {
+			return Perbill::zero()
+		}
+		Perbill::from_parts((self.total / self.blocks as u64).saturated_into())
+	}
+}
+
+/// Weight of System Token proven against the commitment of the Relay-chain
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct CachedSystemTokenWeight<Weight> {
+	pub weight: Weight,
+	/// Version of the System Token weight table the weight has been proven at
+	pub version: u32,
+}
+
+/// `id` of System Token seen from the Relay-chain, reanchored to the parachain `para_id`.
+///
+/// `context` is the universal location of this parachain.
+pub fn reanchor_to_para<Id: SystemTokenId>(
+	mut id: Id,
+	para_id: Id::OriginId,
+	context: InteriorMultiLocation,
+) -> Option<Id> {
+	let (relay_context, _) = context.split_last();
+	id.reanchor_loc(0, Some(para_id), &relay_context).ok()?;
+	Some(id)
+}
+
+/// Why `ParaFeeRate` has been changed
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum FeeRateCause {
+	/// Adjusted by the fee rate controller for the average block fullness of the period
+	Utilization(Perbill),
+	/// Set by Relay-chain governance
+	Governance,
+}
+
+/// Record of a change of `ParaFeeRate`
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct FeeRateRecord<BlockNumber, Balance> {
+	pub at: BlockNumber,
+	pub old: Balance,
+	pub new: Balance,
+	pub cause: FeeRateCause,
+}
+
+/// Something which reports the para fee rate adjusted by this runtime to the Relay-chain
+pub trait ReportParaFeeRate<Balance> {
+	fn report_para_fee_rate(fee_rate: Balance);
+}
+
+impl<Balance> ReportParaFeeRate<Balance> for () {
+	fn report_para_fee_rate(_fee_rate: Balance) {}
+}
+
+/// Something which inspects the parts of this runtime checked by the bootstrap exit criteria
+pub trait InspectBootstrap {
+	/// Whether at least one System Token has been registered
+	fn has_system_token() -> bool;
+	/// Whether the collator set is able to keep authoring blocks
+	fn is_collator_set_healthy() -> bool;
+}
+
+impl InspectBootstrap for () {
+	fn has_system_token() -> bool {
+		true
+	}
+
+	fn is_collator_set_healthy() -> bool {
+		true
+	}
+}
+
+/// Bounds and step size of the para fee rate controller
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub struct FeeRateController {
+	/// Block fullness the controller aims for
+	pub target: Perbill,
+	/// Maximum relative change of the fee rate per adjustment period
+	pub max_adjustment: Perbill,
+	/// Lower bound of the fee rate relative to `base_para_fee_rate`
+	pub min_multiplier: FixedU128,
+	/// Upper bound of the fee rate relative to `base_para_fee_rate`
+	pub max_multiplier: FixedU128,
+}
+
+impl FeeRateController {
+	/// Lowest and highest fee rate allowed for the given `base` fee rate
+	pub fn bounds<Balance: FixedPointOperand + Ord>(&self, base: Balance) -> (Balance, Balance) {
+		let min = self.min_multiplier.saturating_mul_int(base);
+		let max = self.max_multiplier.saturating_mul_int(base).max(min);
+		(min, max)
+	}
+
+	/// Next fee rate for the average block `fullness` of the period.
+	///
+	/// The fee rate moves towards the direction which brings the block fullness back to the
+	/// target. The step is proportional to the distance from the target and never larger than
+	/// `max_adjustment` of the current fee rate. The result is kept within the bounds.
+	pub fn next<Balance>(&self, current: Balance, base: Balance, fullness: Perbill) -> Balance
+	where
+		Balance: AtLeast32BitUnsigned + FixedPointOperand,
+	{
+		let (min, max) = self.bounds(base);
+		let next = if fullness > self.target {
+			let distance = Perbill::from_rational(
+				fullness.saturating_sub(self.target).deconstruct(),
+				Perbill::one().saturating_sub(self.target).deconstruct().max(1),
+			);
+			current.saturating_add((self.max_adjustment * distance).mul_floor(current))
+		} else {
+			let distance = Perbill::from_rational(
+				self.target.saturating_sub(fullness).deconstruct(),
+				self.target.deconstruct().max(1),
+			);
+			current.saturating_sub((self.max_adjustment * distance).mul_floor(current))
+		};
+		next.max(min).min(max)
+	}
+}
+
 #[frame_support::pallet(dev_mode)]
 pub mod pallet {
 
 	use super::*;
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::config]
 	pub trait Config: frame_system::Config + cumulus_pallet_parachain_system::Config {
 		/// Runtime Origin for the System Token pallet.
@@ -62,6 +239,8 @@ pub mod pallet {
 			+ Into<Result<Origin, <Self as Config>::RuntimeOrigin>>;
 		/// The overarching event type.
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// The overarching call type. Names of the fee table are checked against its metadata.
+		type RuntimeCall: GetCallMetadata;
 		/// Univeral location of this network
 		type UniversalLocation: Get<InteriorMultiLocation>;
 		/// Type of SystemTokenId used in InfraBlockchain
@@ -77,9 +256,46 @@ pub mod pallet {
 		/// Account where reward is handled from
 		#[pallet::constant]
 		type FeeTreasuryId: Get<PalletId>;
+		/// Something which reports the adjusted `ParaFeeRate` to the Relay-chain
+		type ParaFeeRateReporter: ReportParaFeeRate<SystemTokenBalanceOf<Self>>;
+		/// Something which inspects System Tokens and collators for the bootstrap exit criteria
+		type BootstrapInspector: InspectBootstrap;
+		/// Number of blocks whose fullness is averaged before `ParaFeeRate` is adjusted
+		#[pallet::constant]
+		type FeeRateAdjustmentPeriod: Get<BlockNumberFor<Self>>;
+		/// Block fullness which the fee rate controller aims for
+		#[pallet::constant]
+		type TargetBlockFullness: Get<Perbill>;
+		/// Maximum relative change of `ParaFeeRate` per adjustment period
+		#[pallet::constant]
+		type MaxFeeRateAdjustment: Get<Perbill>;
+		/// Lowest `ParaFeeRate` relative to `base_para_fee_rate` of the Relay-chain
+		#[pallet::constant]
+		type MinFeeRateMultiplier: Get<FixedU128>;
+		/// Highest `ParaFeeRate` relative to `base_para_fee_rate` of the Relay-chain
+		#[pallet::constant]
+		type MaxFeeRateMultiplier: Get<FixedU128>;
+		/// Maximum number of records kept in `ParaFeeRateHistory`
+		#[pallet::constant]
+		type MaxFeeRateHistory: Get<u32>;
+		/// Maximum number of fee rules, including scheduled ones, for a single call
+		#[pallet::constant]
+		type MaxFeeRules: Get<u32>;
+		/// Maximum number of updates applied by `update_fee_table_batch`
+		#[pallet::constant]
+		type MaxFeeTableUpdates: Get<u32>;
+		/// Maximum number of entries of `FeeTable` which have rules expiring at the same block
+		#[pallet::constant]
+		type MaxExpiringFeeRules: Get<u32>;
+		/// Weight information for extrinsics in this pallet
+		type WeightInfo: WeightInfo;
+		/// Something which creates System Tokens of this parachain for the benchmarks
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::SystemTokenId>;
 	}
 
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::storage]
@@ -91,12 +307,40 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type ParaFeeRate<T: Config> = StorageValue<_, SystemTokenBalanceOf<T>>;
 
+	/// Block fullness accumulated over the current fee rate adjustment period
+	#[pallet::storage]
+	pub type AccumulatedFullness<T: Config> = StorageValue<_, BlockFullness, ValueQuery>;
+
+	/// Most recent changes of `ParaFeeRate`, oldest first
+	#[pallet::storage]
+	pub type ParaFeeRateHistory<T: Config> = StorageValue<
+		_,
+		BoundedVec<FeeRateRecord<BlockNumberFor<T>, SystemTokenBalanceOf<T>>, T::MaxFeeRateHistory>,
+		ValueQuery,
+	>;
+
 	#[pallet::storage]
 	pub(super) type RuntimeState<T: Config> = StorageValue<_, Mode, ValueQuery>;
 
+	/// Fee rules of the calls, or of every call of a pallet if the call name is the wildcard
+	#[pallet::storage]
+	pub type FeeTable<T: Config> = StorageMap<
+		_,
+		Twox128,
+		ExtrinsicMetadata,
+		BoundedVec<FeeRuleOf<T>, T::MaxFeeRules>,
+		ValueQuery,
+	>;
+
+	/// Entries of `FeeTable` which have rules expiring at the block
 	#[pallet::storage]
-	pub type FeeTable<T: Config> =
-		StorageMap<_, Twox128, ExtrinsicMetadata, SystemTokenBalanceOf<T>>;
+	pub type FeeRuleExpiry<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<ExtrinsicMetadata, T::MaxExpiringFeeRules>,
+		ValueQuery,
+	>;
 
 	#[pallet::storage]
 	pub type RequestQueue<T: Config> = StorageValue<_, SystemTokenAssetIdOf<T>>;
@@ -104,6 +348,20 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type ActiveRequestStatus<T: Config> = StorageValue<_, RequestStatus<BlockNumberFor<T>>>;
 
+	/// Commitment of the Relay-chain to its System Token weight table as of the current relay
+	/// parent
+	#[pallet::storage]
+	pub type RelayWeightCommitment<T: Config> = StorageValue<_, SystemTokenWeightCommitment>;
+
+	/// Weights of System Tokens of this parachain proven against `RelayWeightCommitment`
+	#[pallet::storage]
+	pub type SystemTokenWeightCache<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		SystemTokenAssetIdOf<T>,
+		CachedSystemTokenWeight<SystemTokenWeightOf<T>>,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -111,8 +369,11 @@ pub mod pallet {
 		Registered { asset_id: SystemTokenAssetIdOf<T> },
 		/// System Token has been deregistered by Relay-chain governance
 		Deregistered { asset_id: SystemTokenAssetIdOf<T> },
-		/// Fee table for has been updated by Relay-chain governance
-		FeeTableUpdated { extrinsic_metadata: ExtrinsicMetadata, fee: SystemTokenBalanceOf<T> },
+		/// Fee table for has been updated by Relay-chain governance. `None` if every rule of the
+		/// call has been removed.
+		FeeTableUpdated { extrinsic_metadata: ExtrinsicMetadata, rule: Option<FeeRuleOf<T>> },
+		/// Fee rules of the call have expired
+		FeeRuleExpired { extrinsic_metadata: ExtrinsicMetadata },
 		/// Weight of System Token has been updated by Relay-chain governance
 		SystemTokenWeightUpdated { asset_id: SystemTokenAssetIdOf<T> },
 		/// Bootstrap has been ended by Relay-chain governance.
@@ -133,6 +394,16 @@ pub mod pallet {
 			asset: SystemTokenAssetIdOf<T>,
 			amount: SystemTokenBalanceOf<T>,
 		},
+		/// Fee rate of this parachain has been changed
+		ParaFeeRateUpdated {
+			old: SystemTokenBalanceOf<T>,
+			new: SystemTokenBalanceOf<T>,
+			cause: FeeRateCause,
+		},
+		/// Weight of System Token has been proven against the commitment of the Relay-chain
+		SystemTokenWeightProven { asset_id: SystemTokenAssetIdOf<T>, version: u32 },
+		/// Mode of Runtime has been changed by Relay-chain governance
+		RuntimeStateChanged { old: Mode, new: Mode },
 	}
 
 	#[pallet::error]
@@ -179,27 +450,69 @@ pub mod pallet {
 		ConversionError,
 		/// Error occured while distributing reward
 		ErrorDistributeReward,
+		/// Fee rate is out of the bounds of the fee rate controller
+		FeeRateOutOfBounds,
+		/// Pallet is not known to the runtime
+		UnknownPallet,
+		/// Call is not known to the pallet
+		UnknownCall,
+		/// Fee rule has already expired or expires before it starts
+		InvalidFeeRule,
+		/// Call has reached `MaxFeeRules`
+		TooManyFeeRules,
+		/// Number of updates exceeds `MaxFeeTableUpdates`
+		TooManyFeeTableUpdates,
+		/// Block has reached `MaxExpiringFeeRules`
+		TooManyExpiringFeeRules,
+		/// Relay-chain has not committed to any System Token weight table
+		SystemTokenWeightCommitmentMissing,
+		/// Proof of System Token weight doesn't match the commitment of the Relay-chain
+		InvalidSystemTokenWeightProof,
 	}
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 		fn on_initialize(n: BlockNumberFor<T>) -> frame_support::weights::Weight {
+			// Accruing the block fullness in `on_finalize`
+			let mut weight = T::DbWeight::get().reads_writes(2, 1);
+			let period = T::FeeRateAdjustmentPeriod::get();
+			if !period.is_zero() && (n % period).is_zero() {
+				Self::adjust_para_fee_rate(n);
+				weight.saturating_accrue(T::DbWeight::get().reads_writes(3, 3));
+			}
+			let expiring = FeeRuleExpiry::<T>::take(n);
+			weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+			for extrinsic_metadata in expiring {
+				Self::prune_fee_rules(&extrinsic_metadata, n);
+				weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+			}
 			if let Some(status) = ActiveRequestStatus::<T>::get() {
 				if status.is_expired(n) {
 					ActiveRequestStatus::<T>::kill();
 					RequestQueue::<T>::kill();
 				}
-				T::DbWeight::get().reads_writes(1, 2)
+				weight.saturating_add(T::DbWeight::get().reads_writes(1, 2))
 			} else {
-				T::DbWeight::get().reads(1)
+				weight.saturating_add(T::DbWeight::get().reads(1))
 			}
 		}
+
+		fn on_finalize(_n: BlockNumberFor<T>) {
+			let fullness = Self::block_fullness();
+			AccumulatedFullness::<T>::mutate(|acc| acc.accrue(fullness));
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+			Self::do_try_state()
+		}
 	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T>
 	where
 		T::SystemTokenId: TryFrom<SystemTokenAssetIdOf<T>> + Into<SystemTokenAssetIdOf<T>>,
+		SystemTokenOriginIdOf<T>: From<u32>,
 	{
 		/// Priviliged origin governed by Relay-chain
 		///
@@ -215,6 +528,9 @@ pub mod pallet {
 
 		/// Fee table for Runtime will be set by Relay-chain governance
 		///
+		/// Set permanent flat `fee` for the call. `call_name` of `*` sets it for every call of
+		/// the pallet. Names should be known to the runtime.
+		///
 		/// Origin
 		/// Relay-chain governance
 		#[pallet::call_index(1)]
@@ -225,14 +541,18 @@ pub mod pallet {
 			fee: SystemTokenBalanceOf<T>,
 		) -> DispatchResult {
 			ensure_relay(<T as Config>::RuntimeOrigin::from(origin))?;
-			let extrinsic_metadata = ExtrinsicMetadata::new(pallet_name, call_name);
-			FeeTable::<T>::insert(&extrinsic_metadata, fee);
-			Self::deposit_event(Event::<T>::FeeTableUpdated { extrinsic_metadata, fee });
-			Ok(())
+			let extrinsic_metadata = Self::extrinsic_metadata_of(&pallet_name, &call_name)?;
+			Self::do_update_fee_table(
+				extrinsic_metadata,
+				Some(FeeRule::permanent(FeeModel::Flat(fee))),
+			)
 		}
 
 		/// Fee rate for Runtime will be set by Relay-chain governance
 		///
+		/// Fee rate should be within the bounds of the fee rate controller, which are relative to
+		/// `base_para_fee_rate` of the Relay-chain.
+		///
 		/// Origin
 		/// Relay-chain governance
 		#[pallet::call_index(2)]
@@ -241,27 +561,24 @@ pub mod pallet {
 			fee_rate: SystemTokenBalanceOf<T>,
 		) -> DispatchResult {
 			ensure_relay(<T as Config>::RuntimeOrigin::from(origin))?;
-			ParaFeeRate::<T>::put(fee_rate);
+			let base = Self::base_para_fee_rate().ok_or(Error::<T>::NotInitiated)?;
+			let (min, max) = Self::fee_rate_controller().bounds(base);
+			ensure!(min <= fee_rate && fee_rate <= max, Error::<T>::FeeRateOutOfBounds);
+			Self::do_update_para_fee_rate(fee_rate, FeeRateCause::Governance);
 			Ok(())
 		}
 
 		/// Set runtime state configuration for this parachain by Relay-chain governance
 		///
+		/// Runtime enters `Mode::Normal` only if every bootstrap exit criterion is met. See
+		/// `unmet_bootstrap_criteria`.
+		///
 		/// Origin
 		/// Relay-chain governance
 		#[pallet::call_index(3)]
 		pub fn update_runtime_state(origin: OriginFor<T>) -> DispatchResult {
 			ensure_relay(<T as Config>::RuntimeOrigin::from(origin))?;
-			if RuntimeState::<T>::get() == Mode::Normal {
-				return Ok(())
-			}
-			ensure!(RCSystemConfig::<T>::get().is_some(), Error::<T>::NotAllowedToChangeState);
-			// TODO-1: Check whether it is allowed to change `Normal` state
-			// TODO-2: Check whether a parachain has enough system token to pay
-			RuntimeState::<T>::put(Mode::Normal);
-			Self::deposit_event(Event::<T>::BootstrapEnded);
-
-			Ok(())
+			Self::do_set_runtime_state(Mode::Normal)
 		}
 
 		/// Register System Token for Cumulus-based parachain Runtime.
@@ -389,6 +706,109 @@ pub mod pallet {
 			Self::deposit_event(Event::<T>::RegisterRequested { asset_id: original, exp });
 			Ok(())
 		}
+
+		/// Apply `updates` to the fee table at once by Relay-chain governance
+		///
+		/// Either every update is applied or none of them. See `FeeTableUpdate` for the meaning
+		/// of each.
+		///
+		/// Origin
+		/// Relay-chain governance
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::update_fee_table_batch(updates.len() as u32))]
+		pub fn update_fee_table_batch(
+			origin: OriginFor<T>,
+			updates: Vec<FeeTableUpdateOf<T>>,
+		) -> DispatchResult {
+			ensure_relay(<T as Config>::RuntimeOrigin::from(origin))?;
+			ensure!(
+				updates.len() <= T::MaxFeeTableUpdates::get() as usize,
+				Error::<T>::TooManyFeeTableUpdates
+			);
+			for FeeTableUpdate { pallet_name, call_name, rule } in updates {
+				let extrinsic_metadata = Self::extrinsic_metadata_of(&pallet_name, &call_name)?;
+				Self::do_update_fee_table(extrinsic_metadata, rule)?;
+			}
+			Ok(())
+		}
+
+		/// Update the weights of System Tokens with proofs against `RelayWeightCommitment`
+		///
+		/// Every proof should be valid. Proofs for assets which are not System Tokens of this
+		/// parachain, or which have already been proven at the version, are skipped.
+		///
+		/// Origin
+		/// Inherent provided by the collator
+		#[pallet::call_index(12)]
+		#[pallet::weight((
+			T::WeightInfo::prove_system_token_weights(proofs.len() as u32),
+			DispatchClass::Mandatory,
+		))]
+		pub fn prove_system_token_weights(
+			origin: OriginFor<T>,
+			proofs: Vec<SystemTokenWeightProofOf<T>>,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+			let commitment = RelayWeightCommitment::<T>::get()
+				.ok_or(Error::<T>::SystemTokenWeightCommitmentMissing)?;
+			for proof in proofs {
+				ensure!(commitment.verify(&proof), Error::<T>::InvalidSystemTokenWeightProof);
+				let Some(asset_id) = Self::local_asset_id(proof.id) else { continue };
+				Self::cache_system_token_weight(asset_id, proof.weight, commitment.version);
+			}
+			Ok(())
+		}
+
+		/// Set `mode` of Runtime by Relay-chain governance
+		///
+		/// `Mode::Maintenance` is for emergencies and `Mode::Bootstrap` rolls back the end of
+		/// bootstrap. `Mode::Normal` requires every bootstrap exit criterion to be met.
+		///
+		/// Origin
+		/// Relay-chain governance
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::set_runtime_state())]
+		pub fn set_runtime_state(origin: OriginFor<T>, mode: Mode) -> DispatchResult {
+			ensure_relay(<T as Config>::RuntimeOrigin::from(origin))?;
+			Self::do_set_runtime_state(mode)
+		}
+	}
+
+	#[pallet::inherent]
+	impl<T: Config> ProvideInherent for Pallet<T>
+	where
+		T::SystemTokenId: TryFrom<SystemTokenAssetIdOf<T>> + Into<SystemTokenAssetIdOf<T>>,
+		SystemTokenOriginIdOf<T>: From<u32>,
+	{
+		type Call = Call<T>;
+		type Error = MakeFatalError<()>;
+		const INHERENT_IDENTIFIER: InherentIdentifier = SYSTEM_TOKEN_WEIGHT_INHERENT_IDENTIFIER;
+
+		fn create_inherent(data: &InherentData) -> Option<Self::Call> {
+			let SystemTokenWeightInherentData { commitment, proofs } = data
+				.get_data::<SystemTokenWeightInherentData<T::SystemTokenId, SystemTokenWeightOf<T>>>(
+					&Self::INHERENT_IDENTIFIER,
+				)
+				.ok()
+				.flatten()?;
+			// Only the weights which are out of date are worth proving
+			let proofs: Vec<_> = proofs
+				.into_iter()
+				.filter(|proof| {
+					Self::local_asset_id(proof.id.clone()).map_or(false, |asset_id| {
+						!Self::is_system_token_weight_synced_at(&asset_id, commitment.version)
+					})
+				})
+				.collect();
+			if proofs.is_empty() {
+				return None
+			}
+			Some(Call::prove_system_token_weights { proofs })
+		}
+
+		fn is_inherent(call: &Self::Call) -> bool {
+			matches!(call, Call::prove_system_token_weights { .. })
+		}
 	}
 }
 
@@ -450,6 +870,302 @@ where
 	}
 }
 
+impl<T: Config> Pallet<T>
+where
+	T::SystemTokenId: Into<SystemTokenAssetIdOf<T>>,
+	SystemTokenOriginIdOf<T>: From<u32>,
+{
+	/// Local asset of System Token `id` seen from the Relay-chain. `None` if it is not a System
+	/// Token of this parachain.
+	fn local_asset_id(id: T::SystemTokenId) -> Option<SystemTokenAssetIdOf<T>> {
+		let para_id: u32 = <T as cumulus_pallet_parachain_system::Config>::SelfParaId::get().into();
+		let asset_id: SystemTokenAssetIdOf<T> =
+			reanchor_to_para(id, para_id.into(), T::UniversalLocation::get())?.into();
+		T::Fungibles::is_system_token(&asset_id).then_some(asset_id)
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Whether the weight of System Token `asset_id` has been proven against the latest
+	/// commitment of the Relay-chain.
+	///
+	/// Weights which are not in sync may be stale, e.g. after downtime of this parachain.
+	pub fn is_system_token_weight_synced(asset_id: &SystemTokenAssetIdOf<T>) -> bool {
+		RelayWeightCommitment::<T>::get().map_or(false, |commitment| {
+			Self::is_system_token_weight_synced_at(asset_id, commitment.version)
+		})
+	}
+
+	fn is_system_token_weight_synced_at(asset_id: &SystemTokenAssetIdOf<T>, version: u32) -> bool {
+		SystemTokenWeightCache::<T>::get(asset_id).map_or(false, |cached| cached.version >= version)
+	}
+
+	/// Cache `weight` of `asset_id` proven at `version` and apply it to the local asset if it has
+	/// changed.
+	fn cache_system_token_weight(
+		asset_id: SystemTokenAssetIdOf<T>,
+		weight: SystemTokenWeightOf<T>,
+		version: u32,
+	) {
+		let cached = SystemTokenWeightCache::<T>::get(&asset_id);
+		if cached.map_or(false, |cached| cached.version >= version) {
+			return
+		}
+		let is_changed = T::Fungibles::system_token_weight(&asset_id).map_or(true, |w| w != weight);
+		if is_changed {
+			if let Err(_) = T::Fungibles::update_system_token_weight(&asset_id, weight) {
+				log::error!("❌❌❌ Error on updating System Token Weight, {:?}", asset_id);
+				return
+			}
+			Self::deposit_event(Event::<T>::SystemTokenWeightUpdated {
+				asset_id: asset_id.clone(),
+			});
+		}
+		SystemTokenWeightCache::<T>::insert(&asset_id, CachedSystemTokenWeight { weight, version });
+		Self::deposit_event(Event::<T>::SystemTokenWeightProven { asset_id, version });
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Metadata of the call named `call_name` of `pallet_name`, which should be known to the
+	/// runtime. `call_name` of `*` is for every call of the pallet.
+	pub fn extrinsic_metadata_of(
+		pallet_name: &[u8],
+		call_name: &[u8],
+	) -> Result<ExtrinsicMetadata, DispatchError> {
+		let pallet_name = str::from_utf8(pallet_name).map_err(|_| Error::<T>::UnknownPallet)?;
+		ensure!(
+			<T as Config>::RuntimeCall::get_module_names().contains(&pallet_name),
+			Error::<T>::UnknownPallet
+		);
+		let call_name = str::from_utf8(call_name).map_err(|_| Error::<T>::UnknownCall)?;
+		if call_name == ExtrinsicMetadata::WILDCARD {
+			return Ok(ExtrinsicMetadata::any_call(pallet_name))
+		}
+		ensure!(
+			<T as Config>::RuntimeCall::get_call_names(pallet_name).contains(&call_name),
+			Error::<T>::UnknownCall
+		);
+		Ok(ExtrinsicMetadata::new(pallet_name, call_name))
+	}
+
+	/// Fee rule in effect for `extrinsic_metadata` at `now` and whether it is a wildcard one.
+	///
+	/// Rules of the call take precedence over the ones of its pallet. Among them, an active
+	/// promotion which has started the latest takes precedence over the permanent rule.
+	pub fn effective_fee_rule(
+		extrinsic_metadata: &ExtrinsicMetadata,
+		now: BlockNumberFor<T>,
+	) -> Option<(FeeRuleOf<T>, bool)> {
+		let active_rule = |key: &ExtrinsicMetadata| {
+			let rules = FeeTable::<T>::get(key);
+			rules
+				.iter()
+				.filter(|rule| !rule.is_permanent() && rule.is_active(now))
+				.max_by_key(|rule| rule.starts_at)
+				.or_else(|| rules.iter().find(|rule| rule.is_permanent()))
+				.copied()
+		};
+		active_rule(extrinsic_metadata)
+			.map(|rule| (rule, false))
+			.or_else(|| active_rule(&extrinsic_metadata.to_wildcard()).map(|rule| (rule, true)))
+	}
+
+	/// Fee rule in effect for every call known to the runtime
+	pub fn fee_table() -> Vec<CallFeeOf<T>> {
+		let now = <frame_system::Pallet<T>>::block_number();
+		let mut fees = Vec::new();
+		for pallet_name in <T as Config>::RuntimeCall::get_module_names() {
+			for call_name in <T as Config>::RuntimeCall::get_call_names(pallet_name) {
+				let extrinsic_metadata = ExtrinsicMetadata::new(pallet_name, call_name);
+				let (rule, is_wildcard) = match Self::effective_fee_rule(&extrinsic_metadata, now) {
+					Some((rule, is_wildcard)) => (Some(rule), is_wildcard),
+					None => (None, false),
+				};
+				fees.push(CallFee {
+					pallet_name: pallet_name.as_bytes().to_vec(),
+					call_name: call_name.as_bytes().to_vec(),
+					rule,
+					is_wildcard,
+				});
+			}
+		}
+		fees
+	}
+
+	/// Add `rule` to the rules of `extrinsic_metadata`, replacing the permanent one if `rule` is
+	/// permanent. Remove every rule of it if `rule` is `None`.
+	fn do_update_fee_table(
+		extrinsic_metadata: ExtrinsicMetadata,
+		rule: Option<FeeRuleOf<T>>,
+	) -> DispatchResult {
+		let now = <frame_system::Pallet<T>>::block_number();
+		match rule {
+			Some(rule) => {
+				let starts_before_expiry = match (rule.starts_at, rule.expires_at) {
+					(Some(starts_at), Some(expires_at)) => starts_at < expires_at,
+					_ => true,
+				};
+				ensure!(starts_before_expiry && !rule.is_expired(now), Error::<T>::InvalidFeeRule);
+				FeeTable::<T>::try_mutate(&extrinsic_metadata, |rules| {
+					rules.retain(|r| {
+						!r.is_expired(now) && !(r.is_permanent() && rule.is_permanent())
+					});
+					rules.try_push(rule).map_err(|_| Error::<T>::TooManyFeeRules)
+				})?;
+				if let Some(expires_at) = rule.expires_at {
+					FeeRuleExpiry::<T>::try_mutate(expires_at, |expiring| {
+						if expiring.contains(&extrinsic_metadata) {
+							return Ok(())
+						}
+						expiring
+							.try_push(extrinsic_metadata.clone())
+							.map_err(|_| Error::<T>::TooManyExpiringFeeRules)
+					})?;
+				}
+			},
+			None => FeeTable::<T>::remove(&extrinsic_metadata),
+		}
+		Self::deposit_event(Event::<T>::FeeTableUpdated { extrinsic_metadata, rule });
+		Ok(())
+	}
+
+	/// Remove the rules of `extrinsic_metadata` which have expired at `now`
+	fn prune_fee_rules(extrinsic_metadata: &ExtrinsicMetadata, now: BlockNumberFor<T>) {
+		let mut rules = FeeTable::<T>::get(extrinsic_metadata);
+		let before = rules.len();
+		rules.retain(|rule| !rule.is_expired(now));
+		if rules.len() == before {
+			return
+		}
+		if rules.is_empty() {
+			FeeTable::<T>::remove(extrinsic_metadata);
+		} else {
+			FeeTable::<T>::insert(extrinsic_metadata, rules);
+		}
+		Self::deposit_event(Event::<T>::FeeRuleExpired {
+			extrinsic_metadata: extrinsic_metadata.clone(),
+		});
+	}
+
+	/// Fee rate currently applied to this parachain.
+	///
+	/// Falls back to `base_para_fee_rate` of the Relay-chain if it has never been set. Return
+	/// `None` if System Config of the Relay-chain has not been received yet.
+	pub fn current_para_fee_rate() -> Option<SystemTokenBalanceOf<T>> {
+		ParaFeeRate::<T>::get().or_else(Self::base_para_fee_rate)
+	}
+
+	fn base_para_fee_rate() -> Option<SystemTokenBalanceOf<T>> {
+		RCSystemConfig::<T>::get().map(|config| config.base_para_fee_rate.saturated_into())
+	}
+
+	fn fee_rate_controller() -> FeeRateController {
+		FeeRateController {
+			target: T::TargetBlockFullness::get(),
+			max_adjustment: T::MaxFeeRateAdjustment::get(),
+			min_multiplier: T::MinFeeRateMultiplier::get(),
+			max_multiplier: T::MaxFeeRateMultiplier::get(),
+		}
+	}
+
+	/// Fullness of the normal dispatch class of the current block. The more utilized of
+	/// `ref_time` and `proof_size` counts.
+	fn block_fullness() -> Perbill {
+		let weights = T::BlockWeights::get();
+		let max = weights.get(DispatchClass::Normal).max_total.unwrap_or(weights.max_block);
+		let used = *<frame_system::Pallet<T>>::block_weight().get(DispatchClass::Normal);
+		let ref_time = Perbill::from_rational(used.ref_time(), max.ref_time().max(1));
+		let proof_size = Perbill::from_rational(used.proof_size(), max.proof_size().max(1));
+		ref_time.max(proof_size)
+	}
+
+	/// Adjust `ParaFeeRate` for the block fullness accumulated over the period that has ended
+	/// and report the new fee rate to the Relay-chain.
+	fn adjust_para_fee_rate(now: BlockNumberFor<T>) {
+		let fullness = AccumulatedFullness::<T>::take().average();
+		let Some(base) = Self::base_para_fee_rate() else { return };
+		let current = ParaFeeRate::<T>::get().unwrap_or(base);
+		let next = Self::fee_rate_controller().next(current, base, fullness);
+		if next == current {
+			return
+		}
+		log::debug!(
+			target: "runtime::infra-parachain-core",
+			"Para fee rate adjusted from {:?} to {:?} at {:?}",
+			current,
+			next,
+			now,
+		);
+		Self::do_update_para_fee_rate(next, FeeRateCause::Utilization(fullness));
+		T::ParaFeeRateReporter::report_para_fee_rate(next);
+	}
+
+	fn do_update_para_fee_rate(new: SystemTokenBalanceOf<T>, cause: FeeRateCause) {
+		let old = Self::current_para_fee_rate().unwrap_or(new);
+		ParaFeeRate::<T>::put(new);
+		let record =
+			FeeRateRecord { at: <frame_system::Pallet<T>>::block_number(), old, new, cause };
+		ParaFeeRateHistory::<T>::mutate(|history| {
+			if history.is_full() {
+				history.remove(0);
+			}
+			let _ = history.try_push(record);
+		});
+		Self::deposit_event(Event::<T>::ParaFeeRateUpdated { old, new, cause });
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Bootstrap exit criteria which are not met yet. Runtime can enter `Mode::Normal` only if it
+	/// is empty.
+	pub fn unmet_bootstrap_criteria() -> Vec<BootstrapCriterion> {
+		[
+			(BootstrapCriterion::SystemConfig, RCSystemConfig::<T>::exists()),
+			(BootstrapCriterion::SystemToken, T::BootstrapInspector::has_system_token()),
+			(BootstrapCriterion::FeeTable, FeeTable::<T>::iter_values().next().is_some()),
+			(BootstrapCriterion::CollatorSet, T::BootstrapInspector::is_collator_set_healthy()),
+		]
+		.into_iter()
+		.filter_map(|(criterion, is_met)| (!is_met).then_some(criterion))
+		.collect()
+	}
+
+	/// Current mode of Runtime
+	pub fn runtime_state() -> Mode {
+		RuntimeState::<T>::get()
+	}
+
+	/// System Token registration request which has been sent to the Relay-chain and has not
+	/// expired yet, with the block it expires at
+	pub fn pending_request() -> Option<(SystemTokenAssetIdOf<T>, BlockNumberFor<T>)> {
+		let status = ActiveRequestStatus::<T>::get()?;
+		if status.is_expired(<frame_system::Pallet<T>>::block_number()) {
+			return None
+		}
+		RequestQueue::<T>::get().map(|original| (original, status.exp))
+	}
+
+	fn do_set_runtime_state(new: Mode) -> DispatchResult {
+		let old = RuntimeState::<T>::get();
+		if old == new {
+			return Ok(())
+		}
+		if new == Mode::Normal {
+			ensure!(
+				Self::unmet_bootstrap_criteria().is_empty(),
+				Error::<T>::NotAllowedToChangeState
+			);
+		}
+		RuntimeState::<T>::put(new.clone());
+		if old == Mode::Bootstrap && new == Mode::Normal {
+			Self::deposit_event(Event::<T>::BootstrapEnded);
+		}
+		Self::deposit_event(Event::<T>::RuntimeStateChanged { old, new });
+		Ok(())
+	}
+}
+
 impl<T: Config> RuntimeConfigProvider<SystemTokenBalanceOf<T>> for Pallet<T>
 where
 	SystemTokenBalanceOf<T>: From<u128>,
@@ -461,19 +1177,16 @@ where
 	}
 
 	fn para_fee_rate() -> Result<SystemTokenBalanceOf<T>, Self::Error> {
-		let base_para_fee_rate =
-			RCSystemConfig::<T>::get().ok_or(Error::<T>::NotInitiated)?.base_para_fee_rate;
-		Ok(ParaFeeRate::<T>::try_mutate_exists(
-			|maybe_para_fee_rate| -> Result<SystemTokenBalanceOf<T>, DispatchError> {
-				let pfr = maybe_para_fee_rate.take().map_or(base_para_fee_rate.into(), |pfr| pfr);
-				*maybe_para_fee_rate = Some(pfr);
-				Ok(pfr)
-			},
-		)?)
+		Ok(Self::current_para_fee_rate().ok_or(Error::<T>::NotInitiated)?)
 	}
 
-	fn fee_for(ext: ExtrinsicMetadata) -> Option<SystemTokenBalanceOf<T>> {
-		FeeTable::<T>::get(&ext)
+	fn fee_for(
+		ext: ExtrinsicMetadata,
+		weight: Weight,
+		len: u32,
+	) -> Option<SystemTokenBalanceOf<T>> {
+		let now = <frame_system::Pallet<T>>::block_number();
+		Self::effective_fee_rule(&ext, now).map(|(rule, _)| rule.model.fee(weight, len))
 	}
 
 	fn runtime_state() -> Mode {
@@ -495,4 +1208,62 @@ impl<T: Config> UpdateRCConfig<SystemTokenAssetIdOf<T>, SystemTokenWeightOf<T>>
 			}
 		}
 	}
+
+	fn update_system_token_weight_commitment(commitment: SystemTokenWeightCommitment) {
+		RelayWeightCommitment::<T>::put(commitment);
+	}
+}
+
+#[cfg(any(feature = "try-runtime", test))]
+impl<T: Config> Pallet<T> {
+	/// Invariants of the fee table, the System Token weights and the requests
+	///
+	/// - Entries of `FeeTable` are not empty, have at most one permanent rule and no expired rule.
+	///   Every rule expiring later is scheduled in `FeeRuleExpiry`.
+	/// - `ParaFeeRateHistory` is ordered by block.
+	/// - No weight in `SystemTokenWeightCache` is proven at a version newer than
+	///   `RelayWeightCommitment`.
+	/// - `RequestQueue` and `ActiveRequestStatus` exist together.
+	pub fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+		let now = <frame_system::Pallet<T>>::block_number();
+		// Keys of `FeeTable` are not reversible, so an expiring rule is looked up by the entries
+		// scheduled at its expiry instead.
+		for rules in FeeTable::<T>::iter_values() {
+			ensure!(!rules.is_empty(), "Empty entry of `FeeTable`");
+			ensure!(
+				rules.iter().filter(|rule| rule.is_permanent()).count() <= 1,
+				"More than one permanent fee rule"
+			);
+			for rule in rules.iter() {
+				ensure!(!rule.is_expired(now), "Expired fee rule has not been pruned");
+				if let Some(expires_at) = rule.expires_at {
+					ensure!(
+						FeeRuleExpiry::<T>::get(expires_at)
+							.iter()
+							.any(|scheduled| FeeTable::<T>::get(scheduled) == rules),
+						"Expiring fee rule is not scheduled in `FeeRuleExpiry`"
+					);
+				}
+			}
+		}
+		ensure!(
+			ParaFeeRateHistory::<T>::get().windows(2).all(|w| w[0].at <= w[1].at),
+			"`ParaFeeRateHistory` is not ordered by block"
+		);
+		let latest = RelayWeightCommitment::<T>::get().map_or(0, |commitment| commitment.version);
+		ensure!(
+			SystemTokenWeightCache::<T>::iter_values().all(|cached| cached.version <= latest),
+			"System Token weight is proven at a version newer than the commitment"
+		);
+		ensure!(
+			RequestQueue::<T>::exists() == ActiveRequestStatus::<T>::exists(),
+			"`RequestQueue` and `ActiveRequestStatus` are out of sync"
+		);
+		Ok(())
+	}
 }
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
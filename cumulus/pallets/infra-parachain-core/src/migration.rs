@@ -0,0 +1,65 @@
+//! Storage migrations of the InfraParaCore pallet
+
+use super::*;
+use frame_support::traits::OnRuntimeUpgrade;
+
+/// Migrate the flat fees of `FeeTable` to permanent `FeeModel::Flat` rules.
+pub mod v1 {
+	use super::*;
+
+	pub struct MigrateToV1<T>(sp_std::marker::PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			use frame_support::storage::{KeyPrefixIterator, StoragePrefixedMap};
+
+			// Raw keys are counted, as keys of `FeeTable` are not reversible and its values are
+			// not decodable before the migration.
+			let prefix = FeeTable::<T>::final_prefix();
+			let entries =
+				KeyPrefixIterator::new(prefix.to_vec(), prefix.to_vec(), |_| Ok(())).count();
+			Ok((entries as u32).encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain = Pallet::<T>::on_chain_storage_version();
+			if on_chain >= 1 {
+				log::info!(
+					target: "runtime::infra-parachain-core",
+					"Skipping MigrateToV1, on-chain storage version is {:?}",
+					on_chain,
+				);
+				return T::DbWeight::get().reads(1)
+			}
+			let mut translated = 0u64;
+			FeeTable::<T>::translate_values::<SystemTokenBalanceOf<T>, _>(|fee| {
+				translated.saturating_inc();
+				let rule: FeeRuleOf<T> = FeeRule::permanent(FeeModel::Flat(fee));
+				BoundedVec::try_from(sp_std::vec![rule]).ok()
+			});
+			StorageVersion::new(1).put::<Pallet<T>>();
+			log::info!(
+				target: "runtime::infra-parachain-core",
+				"Migrated {} fee table entries to v1",
+				translated,
+			);
+			T::DbWeight::get()
+				.reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() >= 1,
+				"Storage version should be >= 1 after the migration"
+			);
+			let entries = u32::decode(&mut &state[..])
+				.map_err(|_| "Failed to decode the state of pre_upgrade")?;
+			ensure!(
+				FeeTable::<T>::iter_values().count() as u32 == entries,
+				"Entries of `FeeTable` are lost or undecodable"
+			);
+			Ok(())
+		}
+	}
+}
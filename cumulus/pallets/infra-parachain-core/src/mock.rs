@@ -0,0 +1,228 @@
+use crate as infra_parachain_core;
+use cumulus_pallet_parachain_system::AnyRelayNumber;
+use frame_support::{
+	construct_runtime, parameter_types,
+	traits::{AsEnsureOriginWithArg, ConstU32, ConstU64},
+	PalletId,
+};
+use frame_system::EnsureSigned;
+use sp_core::H256;
+use sp_runtime::{
+	infra::SystemConfig as InfraSystemConfig,
+	traits::{BlakeTwo256, IdentityLookup},
+	BuildStorage, FixedU128, Perbill,
+};
+use xcm::latest::prelude::*;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+type AccountId = u64;
+
+/// `base_para_fee_rate` of the Relay-chain
+pub const BASE_PARA_FEE_RATE: u128 = 1_000_000;
+
+// Configure a mock runtime to test the pallet.
+construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		Assets: pallet_assets,
+		ParachainSystem: cumulus_pallet_parachain_system::{
+			Pallet, Call, Config<T>, Storage, Inherent, Event<T>, ValidateUnsigned,
+		},
+		CumulusXcm: cumulus_pallet_xcm::{Pallet, Event<T>, Origin},
+		InfraParaCore: infra_parachain_core::{Pallet, Call, Storage, Inherent, Event<T>},
+	}
+);
+
+impl frame_system::Config for Test {
+	type RuntimeOrigin = RuntimeOrigin;
+	type Nonce = u64;
+	type RuntimeCall = RuntimeCall;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Block = Block;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<u64>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type BaseCallFilter = frame_support::traits::Everything;
+	type SystemWeightInfo = ();
+	type DbWeight = ();
+	type BlockWeights = ();
+	type BlockLength = ();
+	type SS58Prefix = ();
+	type OnSetCode = cumulus_pallet_parachain_system::ParachainSetCode<Self>;
+	type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_balances::Config for Test {
+	type Balance = u64;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU64<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type RuntimeHoldReason = ();
+	type FreezeIdentifier = ();
+	type MaxHolds = ConstU32<0>;
+	type MaxFreezes = ConstU32<0>;
+}
+
+impl pallet_assets::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = u128;
+	type SystemTokenWeight = u128;
+	type RemoveItemsLimit = ConstU32<1000>;
+	type AssetId = MultiLocation;
+	type AssetIdParameter = MultiLocation;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<Self::AccountId>>;
+	type ForceOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type AssetDeposit = ConstU64<1>;
+	type AssetAccountDeposit = ConstU64<1>;
+	type MetadataDepositBase = ConstU64<1>;
+	type MetadataDepositPerByte = ConstU64<1>;
+	type ApprovalDeposit = ConstU64<1>;
+	type StringLimit = ConstU32<50>;
+	type Freezer = ();
+	type Extra = ();
+	type WeightInfo = ();
+	type CallbackHandle = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = TestBenchmarkHelper;
+}
+
+impl cumulus_pallet_parachain_system::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type OnSystemEvent = ();
+	type SelfParaId = ();
+	type OutboundXcmpMessageSource = ();
+	type DmpMessageHandler = ();
+	type ReservedDmpWeight = ();
+	type XcmpMessageHandler = ();
+	type ReservedXcmpWeight = ();
+	type CheckAssociatedRelayNumber = AnyRelayNumber;
+	type UpdateRCConfig = InfraParaCore;
+	type ConsensusHook = cumulus_pallet_parachain_system::consensus_hook::ExpectParentIncluded;
+}
+
+impl cumulus_pallet_xcm::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type XcmExecutor = ();
+}
+
+parameter_types! {
+	pub UniversalLocation: InteriorMultiLocation = X1(Parachain(1000));
+	pub const FeeTreasuryId: PalletId = PalletId(*b"infrapid");
+	pub const TargetBlockFullness: Perbill = Perbill::from_percent(25);
+	pub const MaxFeeRateAdjustment: Perbill = Perbill::from_percent(10);
+	pub MinFeeRateMultiplier: FixedU128 = FixedU128::from_rational(1, 2);
+	pub MaxFeeRateMultiplier: FixedU128 = FixedU128::from_u32(2);
+	pub static ReportedFeeRates: Vec<u128> = vec![];
+}
+
+/// Records the fee rates reported to the Relay-chain
+pub struct TestReporter;
+impl infra_parachain_core::ReportParaFeeRate<u128> for TestReporter {
+	fn report_para_fee_rate(fee_rate: u128) {
+		ReportedFeeRates::mutate(|reported| reported.push(fee_rate));
+	}
+}
+
+impl infra_parachain_core::Config for Test {
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type UniversalLocation = UniversalLocation;
+	type SystemTokenId = MultiLocation;
+	type Fungibles = Assets;
+	type ActiveRequestPeriod = ConstU64<10>;
+	type FeeTreasuryId = FeeTreasuryId;
+	type ParaFeeRateReporter = TestReporter;
+	type BootstrapInspector = ();
+	type FeeRateAdjustmentPeriod = ConstU64<5>;
+	type TargetBlockFullness = TargetBlockFullness;
+	type MaxFeeRateAdjustment = MaxFeeRateAdjustment;
+	type MinFeeRateMultiplier = MinFeeRateMultiplier;
+	type MaxFeeRateMultiplier = MaxFeeRateMultiplier;
+	type MaxFeeRateHistory = ConstU32<3>;
+	type MaxFeeRules = ConstU32<4>;
+	type MaxFeeTableUpdates = ConstU32<4>;
+	type MaxExpiringFeeRules = ConstU32<2>;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = TestBenchmarkHelper;
+}
+
+/// Creates System Tokens of this parachain, whose id is the default `ParaId`
+#[cfg(feature = "runtime-benchmarks")]
+pub struct TestBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl infra_parachain_core::BenchmarkHelper<MultiLocation> for TestBenchmarkHelper {
+	fn create_system_token(i: u32) -> MultiLocation {
+		use frame_support::traits::{tokens::fungibles::ManageSystemToken, Currency};
+
+		let owner: AccountId = 1;
+		Balances::make_free_balance_be(&owner, 1_000_000);
+		let id =
+			MultiLocation::new(0, X3(Parachain(0), PalletInstance(50), GeneralIndex(i.into())));
+		let asset_id = infra_parachain_core::reanchor_to_para(id, 0, UniversalLocation::get())
+			.expect("Original of this parachain; qed");
+		<Assets as ManageSystemToken<AccountId>>::touch(
+			owner,
+			asset_id,
+			Default::default(),
+			1,
+			b"Token".to_vec(),
+			b"TKN".to_vec(),
+			6,
+			1_000,
+		)
+		.expect("Asset does not exist yet; qed");
+		id
+	}
+}
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_assets::BenchmarkHelper<MultiLocation> for TestBenchmarkHelper {
+	fn create_asset_id_parameter(id: u32) -> MultiLocation {
+		MultiLocation::new(0, X1(GeneralIndex(id.into())))
+	}
+}
+
+/// Origin of the messages sent by the Relay-chain
+pub fn relay_origin() -> RuntimeOrigin {
+	cumulus_pallet_xcm::Origin::Relay.into()
+}
+
+/// Externalities in which System Config of the Relay-chain has been received
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	let mut ext = sp_io::TestExternalities::new(storage);
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		infra_parachain_core::RCSystemConfig::<Test>::put(InfraSystemConfig {
+			base_para_fee_rate: BASE_PARA_FEE_RATE,
+			..Default::default()
+		});
+	});
+	ext
+}
+
+/// Run `on_finalize` of the current block and `on_initialize` of the blocks up to `n`
+pub fn run_to_block(n: u64) {
+	use frame_support::traits::Hooks;
+	while System::block_number() < n {
+		InfraParaCore::on_finalize(System::block_number());
+		System::set_block_number(System::block_number() + 1);
+		InfraParaCore::on_initialize(System::block_number());
+	}
+}
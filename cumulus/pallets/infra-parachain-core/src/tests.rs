@@ -0,0 +1,221 @@
+use super::*;
+use crate::mock::{
+	new_test_ext, relay_origin, run_to_block, InfraParaCore, ReportedFeeRates, RuntimeOrigin,
+	System, Test, BASE_PARA_FEE_RATE,
+};
+use frame_support::{assert_noop, assert_ok};
+
+const BASE: u128 = 1_000_000;
+
+fn controller() -> FeeRateController {
+	FeeRateController {
+		target: Perbill::from_percent(25),
+		max_adjustment: Perbill::from_percent(10),
+		min_multiplier: FixedU128::from_rational(1, 2),
+		max_multiplier: FixedU128::from_u32(2),
+	}
+}
+
+#[test]
+fn fee_rate_is_kept_on_target() {
+	assert_eq!(controller().next(BASE, BASE, Perbill::from_percent(25)), BASE);
+}
+
+#[test]
+fn fee_rate_moves_proportionally_to_distance_from_target() {
+	let c = controller();
+	// Full blocks move the fee rate up by the maximum adjustment
+	assert_eq!(c.next(BASE, BASE, Perbill::one()), 1_100_000);
+	// Half way between the target and full blocks
+	assert_eq!(c.next(BASE, BASE, Perbill::from_rational(625u32, 1000u32)), 1_050_000);
+	// Empty blocks move the fee rate down by the maximum adjustment
+	assert_eq!(c.next(BASE, BASE, Perbill::zero()), 900_000);
+}
+
+#[test]
+fn fee_rate_stays_within_bounds() {
+	let c = controller();
+	assert_eq!(c.bounds(BASE), (500_000, 2_000_000));
+	assert_eq!(c.next(1_950_000, BASE, Perbill::one()), 2_000_000);
+	assert_eq!(c.next(520_000, BASE, Perbill::zero()), 500_000);
+	// Fee rate out of bounds is brought back even on target
+	assert_eq!(c.next(3_000_000, BASE, Perbill::from_percent(25)), 2_000_000);
+}
+
+#[test]
+fn block_fullness_is_averaged() {
+	let mut acc = BlockFullness::default();
+	assert_eq!(acc.average(), Perbill::zero());
+	acc.accrue(Perbill::from_percent(10));
+	acc.accrue(Perbill::from_percent(30));
+	assert_eq!(acc.average(), Perbill::from_percent(20));
+}
+
+#[test]
+fn fee_model_scales_with_weight_and_length() {
+	let weight = Weight::from_parts(2_500_000, 0);
+	assert_eq!(FeeModel::Flat(100u128).fee(weight, 10), 100);
+	assert_eq!(FeeModel::PerWeight { base: 100u128, per_micros: 10 }.fee(weight, 10), 130);
+	assert_eq!(FeeModel::PerLength { base: 100u128, per_byte: 2 }.fee(weight, 10), 120);
+}
+
+#[test]
+fn fee_rule_is_active_within_its_window() {
+	let promotion =
+		FeeRule { model: FeeModel::Flat(1u128), starts_at: Some(10u32), expires_at: Some(20) };
+	assert!(!promotion.is_permanent());
+	assert!(!promotion.is_active(9));
+	assert!(promotion.is_active(10));
+	assert!(promotion.is_active(19));
+	assert!(!promotion.is_active(20));
+	assert!(promotion.is_expired(20));
+	assert!(FeeRule::<u128, u32>::permanent(FeeModel::Flat(1)).is_active(u32::MAX));
+}
+
+#[test]
+fn wildcard_metadata_matches_pallet() {
+	let ext = ExtrinsicMetadata::new("Assets", "transfer");
+	assert!(!ext.is_wildcard());
+	assert_eq!(ext.to_wildcard(), ExtrinsicMetadata::any_call("Assets"));
+	assert!(ext.to_wildcard().is_wildcard());
+	// Names passed as bytes by Relay-chain governance match the ones of the runtime
+	assert_eq!(ExtrinsicMetadata::new(b"Assets".to_vec(), b"transfer".to_vec()), ext);
+}
+
+#[test]
+fn system_token_ids_are_reanchored_to_para() {
+	use xcm::latest::prelude::*;
+
+	let context = X2(GlobalConsensus(NetworkId::InfraRelay), Parachain(1000));
+	// Original of this parachain
+	let id = MultiLocation::new(0, X3(Parachain(1000), PalletInstance(50), GeneralIndex(1)));
+	assert_eq!(
+		reanchor_to_para(id, 1000, context),
+		Some(MultiLocation::new(0, X2(PalletInstance(50), GeneralIndex(1))))
+	);
+	// Original of the Relay-chain
+	let id = MultiLocation::new(0, X2(PalletInstance(10), GeneralIndex(1)));
+	assert_eq!(
+		reanchor_to_para(id, 1000, context),
+		Some(MultiLocation::new(1, X2(PalletInstance(10), GeneralIndex(1))))
+	);
+	// Original of another parachain
+	let id = MultiLocation::new(0, X3(Parachain(2000), PalletInstance(50), GeneralIndex(1)));
+	assert_eq!(
+		reanchor_to_para(id, 1000, context),
+		Some(MultiLocation::new(1, X3(Parachain(2000), PalletInstance(50), GeneralIndex(1))))
+	);
+}
+
+#[test]
+fn governance_fee_rate_is_bounded() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			InfraParaCore::update_para_fee_rate(RuntimeOrigin::signed(1), BASE_PARA_FEE_RATE),
+			DispatchError::BadOrigin
+		);
+		// Bounds are 0.5x and 2x of `base_para_fee_rate`
+		assert_noop!(
+			InfraParaCore::update_para_fee_rate(relay_origin(), BASE_PARA_FEE_RATE / 2 - 1),
+			Error::<Test>::FeeRateOutOfBounds
+		);
+		assert_noop!(
+			InfraParaCore::update_para_fee_rate(relay_origin(), BASE_PARA_FEE_RATE * 2 + 1),
+			Error::<Test>::FeeRateOutOfBounds
+		);
+		assert_ok!(InfraParaCore::update_para_fee_rate(relay_origin(), BASE_PARA_FEE_RATE * 2));
+		assert_eq!(ParaFeeRate::<Test>::get(), Some(BASE_PARA_FEE_RATE * 2));
+		// Only adjustments of the controller are reported back to the Relay-chain
+		assert!(ReportedFeeRates::get().is_empty());
+
+		RCSystemConfig::<Test>::kill();
+		assert_noop!(
+			InfraParaCore::update_para_fee_rate(relay_origin(), BASE_PARA_FEE_RATE),
+			Error::<Test>::NotInitiated
+		);
+	});
+}
+
+#[test]
+fn fee_rate_history_keeps_most_recent_records() {
+	new_test_ext().execute_with(|| {
+		for (n, fee_rate) in
+			[1_100_000u128, 1_200_000, 1_300_000, 1_400_000].into_iter().enumerate()
+		{
+			System::set_block_number(n as u64 + 1);
+			assert_ok!(InfraParaCore::update_para_fee_rate(relay_origin(), fee_rate));
+		}
+		// `MaxFeeRateHistory` is 3, so the oldest record has been dropped
+		let history = ParaFeeRateHistory::<Test>::get();
+		assert_eq!(
+			history
+				.iter()
+				.map(|record| (record.at, record.old, record.new))
+				.collect::<Vec<_>>(),
+			vec![(2, 1_100_000, 1_200_000), (3, 1_200_000, 1_300_000), (4, 1_300_000, 1_400_000)]
+		);
+		assert!(history.iter().all(|record| record.cause == FeeRateCause::Governance));
+		assert_ok!(InfraParaCore::do_try_state());
+	});
+}
+
+#[test]
+fn adjusted_fee_rate_is_reported_to_relay() {
+	new_test_ext().execute_with(|| {
+		// Fee rate is adjusted at the end of the 5 blocks period
+		run_to_block(4);
+		assert!(ReportedFeeRates::get().is_empty());
+		run_to_block(5);
+
+		// Blocks were empty, so the fee rate goes down by the maximum adjustment
+		let adjusted = BASE_PARA_FEE_RATE - BASE_PARA_FEE_RATE / 10;
+		let cause = FeeRateCause::Utilization(Perbill::zero());
+		assert_eq!(ReportedFeeRates::get(), vec![adjusted]);
+		assert_eq!(InfraParaCore::current_para_fee_rate(), Some(adjusted));
+		assert_eq!(
+			ParaFeeRateHistory::<Test>::get().into_inner(),
+			vec![FeeRateRecord { at: 5, old: BASE_PARA_FEE_RATE, new: adjusted, cause }]
+		);
+		System::assert_last_event(
+			Event::<Test>::ParaFeeRateUpdated { old: BASE_PARA_FEE_RATE, new: adjusted, cause }
+				.into(),
+		);
+
+		// Fee rate at the lower bound is neither changed nor reported
+		assert_ok!(InfraParaCore::update_para_fee_rate(relay_origin(), BASE_PARA_FEE_RATE / 2));
+		run_to_block(10);
+		assert_eq!(ReportedFeeRates::get(), vec![adjusted]);
+		assert_eq!(InfraParaCore::current_para_fee_rate(), Some(BASE_PARA_FEE_RATE / 2));
+	});
+}
+
+#[test]
+fn fee_rules_expiring_at_the_same_block_are_bounded() {
+	new_test_ext().execute_with(|| {
+		let expiring = |call_name: &[u8]| FeeTableUpdate {
+			pallet_name: b"Assets".to_vec(),
+			call_name: call_name.to_vec(),
+			rule: Some(FeeRule {
+				model: FeeModel::Flat(1_000),
+				starts_at: None,
+				expires_at: Some(10),
+			}),
+		};
+		// Entry is scheduled once however many of its rules expire at the block
+		assert_ok!(InfraParaCore::update_fee_table_batch(
+			relay_origin(),
+			vec![expiring(b"transfer"), expiring(b"transfer"), expiring(b"mint")]
+		));
+		assert_eq!(FeeRuleExpiry::<Test>::get(10).len(), 2);
+		// `MaxExpiringFeeRules` is 2
+		assert_noop!(
+			InfraParaCore::update_fee_table_batch(relay_origin(), vec![expiring(b"burn")]),
+			Error::<Test>::TooManyExpiringFeeRules
+		);
+
+		run_to_block(10);
+		assert!(FeeRuleExpiry::<Test>::get(10).is_empty());
+		assert_eq!(FeeTable::<Test>::iter_values().count(), 0);
+		assert_ok!(InfraParaCore::do_try_state());
+	});
+}
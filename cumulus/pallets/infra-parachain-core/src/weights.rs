@@ -0,0 +1,149 @@
+//! Autogenerated weights for `cumulus_pallet_infra_parachain_core`
+//!
+//! DATE: 2026-10-19, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
+//! EXECUTION: Native, CHAIN: the mock runtime of `mock.rs` with the fee table bounds of the
+//! asset hub
+//!
+//! Measured from `benchmarking.rs` natively against the benchmarking state database, as no wasm
+//! build of a runtime was available. Regenerate with
+//! `benchmark pallet --pallet cumulus_pallet_infra_parachain_core` on reference hardware before
+//! relying on them in production.
+//!
+//! `MaxFeeTableUpdates` of the runtimes is 128, but the mock runtime has 66 calls to update, so
+//! `update_fee_table_batch` is measured up to 64 updates and its slope is taken for the rest.
+//!
+//! The mock runtime has no `BootstrapInspector`. `set_runtime_state` adds a read of `Asset` of
+//! `Assets` and of `ForeignAssets`, and of the invulnerables and the candidates, for the one of
+//! the runtimes.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for `cumulus_pallet_infra_parachain_core`.
+pub trait WeightInfo {
+	fn prove_system_token_weights(n: u32) -> Weight;
+	fn update_fee_table_batch(n: u32) -> Weight;
+	fn set_runtime_state() -> Weight;
+}
+
+/// Weights for `cumulus_pallet_infra_parachain_core` measured on the mock runtime.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: Assets Asset (r:100 w:100)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(723), mode: MaxEncodedLen)
+	/// Storage: InfraParaCore RelayWeightCommitment (r:1 w:0)
+	/// Proof Skipped: InfraParaCore RelayWeightCommitment (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: InfraParaCore SystemTokenWeightCache (r:100 w:100)
+	/// Proof Skipped: InfraParaCore SystemTokenWeightCache (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `n` is `[1, 100]`.
+	fn prove_system_token_weights(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `166 + n * (135 ±0)`
+		//  Estimated: `1651 + n * (3198 ±0)`
+		// Minimum execution time: 33_559_000 picoseconds.
+		Weight::from_parts(35_592_000, 1_651)
+			.saturating_add(Weight::from_parts(16_401_110, 3_198).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+	}
+	/// Storage: InfraParaCore FeeRuleExpiry (r:`n` w:`n`)
+	/// Proof Skipped: InfraParaCore FeeRuleExpiry (max_values: None, max_size: None, mode: Measured)
+	/// Storage: InfraParaCore FeeTable (r:`n` w:`n`)
+	/// Proof Skipped: InfraParaCore FeeTable (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `n` is `[1, 128]`.
+	fn update_fee_table_batch(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `64 + n * (1934 ±0)`
+		//  Estimated: `1054 + n * (4409 ±0)`
+		// Minimum execution time: 44_766_000 picoseconds.
+		Weight::from_parts(46_551_000, 1_054)
+			.saturating_add(Weight::from_parts(36_547_738, 4_409).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+	}
+	/// Storage: InfraParaCore FeeTable (r:2 w:0)
+	/// Proof Skipped: InfraParaCore FeeTable (max_values: None, max_size: None, mode: Measured)
+	/// Storage: InfraParaCore RCSystemConfig (r:1 w:0)
+	/// Proof Skipped: InfraParaCore RCSystemConfig (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: InfraParaCore RuntimeState (r:1 w:1)
+	/// Proof Skipped: InfraParaCore RuntimeState (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Assets Asset (r:1 w:0)
+	/// Storage: ForeignAssets Asset (r:1 w:0)
+	/// Storage: CollatorSelection Invulnerables (r:1 w:0)
+	/// Storage: CollatorSelection Candidates (r:1 w:0)
+	fn set_runtime_state() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `154`
+		//  Estimated: `6094`
+		// Minimum execution time: 19_301_000 picoseconds.
+		Weight::from_parts(20_928_000, 6_094)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			// `BootstrapInspector` of the runtime
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: Assets Asset (r:100 w:100)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(723), mode: MaxEncodedLen)
+	/// Storage: InfraParaCore RelayWeightCommitment (r:1 w:0)
+	/// Proof Skipped: InfraParaCore RelayWeightCommitment (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: InfraParaCore SystemTokenWeightCache (r:100 w:100)
+	/// Proof Skipped: InfraParaCore SystemTokenWeightCache (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `n` is `[1, 100]`.
+	fn prove_system_token_weights(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `166 + n * (135 ±0)`
+		//  Estimated: `1651 + n * (3198 ±0)`
+		// Minimum execution time: 33_559_000 picoseconds.
+		Weight::from_parts(35_592_000, 1_651)
+			.saturating_add(Weight::from_parts(16_401_110, 3_198).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+	}
+	/// Storage: InfraParaCore FeeRuleExpiry (r:`n` w:`n`)
+	/// Proof Skipped: InfraParaCore FeeRuleExpiry (max_values: None, max_size: None, mode: Measured)
+	/// Storage: InfraParaCore FeeTable (r:`n` w:`n`)
+	/// Proof Skipped: InfraParaCore FeeTable (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `n` is `[1, 128]`.
+	fn update_fee_table_batch(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `64 + n * (1934 ±0)`
+		//  Estimated: `1054 + n * (4409 ±0)`
+		// Minimum execution time: 44_766_000 picoseconds.
+		Weight::from_parts(46_551_000, 1_054)
+			.saturating_add(Weight::from_parts(36_547_738, 4_409).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+	}
+	/// Storage: InfraParaCore FeeTable (r:2 w:0)
+	/// Proof Skipped: InfraParaCore FeeTable (max_values: None, max_size: None, mode: Measured)
+	/// Storage: InfraParaCore RCSystemConfig (r:1 w:0)
+	/// Proof Skipped: InfraParaCore RCSystemConfig (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: InfraParaCore RuntimeState (r:1 w:1)
+	/// Proof Skipped: InfraParaCore RuntimeState (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Assets Asset (r:1 w:0)
+	/// Storage: ForeignAssets Asset (r:1 w:0)
+	/// Storage: CollatorSelection Invulnerables (r:1 w:0)
+	/// Storage: CollatorSelection Candidates (r:1 w:0)
+	fn set_runtime_state() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `154`
+		//  Estimated: `6094`
+		// Minimum execution time: 19_301_000 picoseconds.
+		Weight::from_parts(20_928_000, 6_094)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			// `BootstrapInspector` of the runtime
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+	}
+}
@@ -594,17 +594,7 @@ pub mod pallet {
 					.expect("Invalid upgrade restriction signal"),
 			);
 			<UpgradeGoAhead<T>>::put(upgrade_go_ahead_signal);
-			let infra_system_config = relay_state_proof
-				.read_active_system_config()
-				.expect("Error on reading infra system config in relay chain state proof");
-			T::UpdateRCConfig::update_system_config(infra_system_config);
-
-			let update_weights = relay_state_proof
-				.read_updated_system_token_weight()
-				.expect("Error on reading updated system token weight");
-			if let Some(assets) = update_weights {
-				T::UpdateRCConfig::update_system_token_weight_for(assets);
-			}
+			Self::apply_infra_relay_state(&relay_state_proof);
 
 			let host_config = relay_state_proof
 				.read_abridged_host_configuration()
@@ -706,6 +696,12 @@ pub mod pallet {
 		DownwardMessagesProcessed { weight_used: Weight, dmq_head: relay_chain::Hash },
 		/// An upward message was sent to the relay chain.
 		UpwardMessageSent { message_hash: Option<XcmHash> },
+		/// The relay chain state proof carried no valid InfraBlockchain system config. The last
+		/// known one has been applied again if `fallback` is true.
+		SystemConfigUnavailable { fallback: bool },
+		/// The System Token weights updated for this parachain, or the commitment to the weight
+		/// table, could not be read from the relay chain state proof.
+		SystemTokenWeightUnavailable,
 	}
 
 	#[pallet::error]
@@ -833,6 +829,13 @@ pub mod pallet {
 	#[pallet::getter(fn host_configuration)]
 	pub(super) type HostConfiguration<T: Config> = StorageValue<_, AbridgedHostConfiguration>;
 
+	/// The InfraBlockchain system config that was last obtained from the relay parent.
+	///
+	/// It is applied instead whenever the relay chain state proof lacks a valid system config.
+	#[pallet::storage]
+	#[pallet::getter(fn last_system_config)]
+	pub(super) type LastSystemConfig<T: Config> = StorageValue<_, relay_chain::SystemConfig>;
+
 	/// The last downward message queue chain head we have observed.
 	///
 	/// This value is loaded before and saved after processing inbound downward messages carried
@@ -1085,6 +1088,65 @@ impl<T: Config> Pallet<T> {
 		// inherent.
 	}
 
+	/// Apply the InfraBlockchain system config and the System Token weights carried by the relay
+	/// chain state proof.
+	///
+	/// Neither a missing nor a malformed entry halts the parachain. The last known system config
+	/// is applied instead and an event is deposited.
+	fn apply_infra_relay_state(relay_state_proof: &RelayChainStateProof) {
+		let maybe_system_config = match relay_state_proof.read_active_system_config() {
+			Ok(system_config) if system_config.check_validity().is_ok() => Some(system_config),
+			Ok(system_config) => {
+				log::warn!(
+					"Invalid infra system config in relay chain state proof: {:?}",
+					system_config
+				);
+				None
+			},
+			Err(e) => {
+				log::warn!(
+					"Error on reading infra system config in relay chain state proof: {:?}",
+					e
+				);
+				None
+			},
+		};
+		match maybe_system_config {
+			Some(system_config) => {
+				<LastSystemConfig<T>>::put(&system_config);
+				T::UpdateRCConfig::update_system_config(system_config);
+			},
+			None => {
+				let maybe_last = <LastSystemConfig<T>>::get();
+				Self::deposit_event(Event::SystemConfigUnavailable {
+					fallback: maybe_last.is_some(),
+				});
+				if let Some(last) = maybe_last {
+					T::UpdateRCConfig::update_system_config(last);
+				}
+			},
+		}
+
+		match relay_state_proof.read_updated_system_token_weight() {
+			Ok(Some(assets)) => T::UpdateRCConfig::update_system_token_weight_for(assets),
+			Ok(None) => {},
+			Err(e) => {
+				log::warn!("Error on reading updated system token weight: {:?}", e);
+				Self::deposit_event(Event::SystemTokenWeightUnavailable);
+			},
+		}
+
+		match relay_state_proof.read_system_token_weight_commitment() {
+			Ok(Some(commitment)) =>
+				T::UpdateRCConfig::update_system_token_weight_commitment(commitment),
+			Ok(None) => {},
+			Err(e) => {
+				log::warn!("Error on reading system token weight commitment: {:?}", e);
+				Self::deposit_event(Event::SystemTokenWeightUnavailable);
+			},
+		}
+	}
+
 	/// Process all inbound downward messages relayed by the collator.
 	///
 	/// Checks if the sequence of the messages is valid, dispatches them and communicates the
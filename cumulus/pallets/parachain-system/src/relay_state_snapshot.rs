@@ -88,6 +88,10 @@ pub enum Error {
 	UpdatedInfraSystemConfig(ReadEntryErr),
 	/// Updated system token weight cannot be read
 	UpdateSystemTokenWeight(ReadEntryErr),
+	/// The commitment to the System Token weight table cannot be read
+	SystemTokenWeightCommitment(ReadEntryErr),
+	/// The para fee rate cannot be read
+	ParaFeeRate(ReadEntryErr),
 	/// The host configuration cannot be extracted.
 	Config(ReadEntryErr),
 	/// The DMQ MQC head cannot be extracted.
@@ -293,6 +297,30 @@ impl RelayChainStateProof {
 		.map_err(Error::UpdateSystemTokenWeight)
 	}
 
+	/// Read the commitment of the relay chain to its System Token weight table.
+	///
+	/// Returns `Ok(None)` if the relay chain has not committed to any table yet.
+	pub fn read_system_token_weight_commitment(
+		&self,
+	) -> Result<Option<relay_chain::SystemTokenWeightCommitment>, Error> {
+		read_optional_entry(
+			&self.trie_backend,
+			relay_chain::well_known_keys::SYSTEM_TOKEN_WEIGHT_COMMITMENT,
+		)
+		.map_err(Error::SystemTokenWeightCommitment)
+	}
+
+	/// Read the fee rate of this parachain recorded by the relay chain.
+	///
+	/// Returns `Ok(None)` if the relay chain has not recorded any fee rate for this parachain.
+	pub fn read_para_fee_rate(&self) -> Result<Option<u128>, Error> {
+		read_optional_entry(
+			&self.trie_backend,
+			&relay_chain::well_known_keys::para_fee_rate(self.para_id),
+		)
+		.map_err(Error::ParaFeeRate)
+	}
+
 	pub fn read_active_system_config(&self) -> Result<relay_chain::SystemConfig, Error> {
 		read_entry(&self.trie_backend, relay_chain::well_known_keys::ACTIVE_SYSTEM_CONFIG, None)
 			.map_err(Error::UpdatedInfraSystemConfig)
@@ -17,8 +17,8 @@ use super::*;
 
 use codec::Encode;
 use cumulus_primitives_core::{
-	relay_chain::BlockNumber as RelayBlockNumber, AbridgedHrmpChannel, InboundDownwardMessage,
-	InboundHrmpMessage, PersistedValidationData,
+	relay_chain::BlockNumber as RelayBlockNumber, AbridgedHrmpChannel, GeneralIndex,
+	InboundDownwardMessage, InboundHrmpMessage, PalletInstance, PersistedValidationData, X2,
 };
 use cumulus_test_relay_sproof_builder::RelayStateSproofBuilder;
 use frame_support::{
@@ -107,6 +107,7 @@ impl Config for Test {
 	type XcmpMessageHandler = SaveIntoThreadLocal;
 	type ReservedXcmpWeight = ReservedXcmpWeight;
 	type CheckAssociatedRelayNumber = AnyRelayNumber;
+	type UpdateRCConfig = SaveIntoThreadLocal;
 	type ConsensusHook = TestConsensusHook;
 }
 
@@ -117,6 +118,9 @@ std::thread_local! {
 	static HANDLED_DMP_MESSAGES: RefCell<Vec<(relay_chain::BlockNumber, Vec<u8>)>> = RefCell::new(Vec::new());
 	static HANDLED_XCMP_MESSAGES: RefCell<Vec<(ParaId, relay_chain::BlockNumber, Vec<u8>)>> = RefCell::new(Vec::new());
 	static SENT_MESSAGES: RefCell<Vec<(ParaId, Vec<u8>)>> = RefCell::new(Vec::new());
+	static RC_SYSTEM_CONFIG: RefCell<Option<SystemConfig>> = RefCell::new(None);
+	static UPDATED_SYSTEM_TOKEN_WEIGHTS: RefCell<Vec<(MultiLocation, SystemTokenWeight)>> = RefCell::new(Vec::new());
+	static SYSTEM_TOKEN_WEIGHT_COMMITMENT: RefCell<Option<relay_chain::SystemTokenWeightCommitment>> = RefCell::new(None);
 	static CONSENSUS_HOOK: RefCell<Box<dyn Fn(&RelayChainStateProof) -> (Weight, UnincludedSegmentCapacity)>>
 		= RefCell::new(Box::new(|_| (Weight::zero(), NonZeroU32::new(1).unwrap().into())));
 }
@@ -197,6 +201,20 @@ impl XcmpMessageHandler for SaveIntoThreadLocal {
 	}
 }
 
+impl UpdateRCConfig<MultiLocation, SystemTokenWeight> for SaveIntoThreadLocal {
+	fn update_system_config(system_config: SystemConfig) {
+		RC_SYSTEM_CONFIG.with(|c| *c.borrow_mut() = Some(system_config));
+	}
+
+	fn update_system_token_weight_for(assets: Vec<(MultiLocation, SystemTokenWeight)>) {
+		UPDATED_SYSTEM_TOKEN_WEIGHTS.with(|w| w.borrow_mut().extend(assets));
+	}
+
+	fn update_system_token_weight_commitment(commitment: relay_chain::SystemTokenWeightCommitment) {
+		SYSTEM_TOKEN_WEIGHT_COMMITMENT.with(|c| *c.borrow_mut() = Some(commitment));
+	}
+}
+
 // This function basically just builds a genesis storage key/value store according to
 // our desired mockup.
 fn new_test_ext() -> sp_io::TestExternalities {
@@ -1496,3 +1514,138 @@ fn deposits_relay_parent_storage_root() {
 		},
 	);
 }
+
+#[test]
+fn applies_system_config_from_relay_chain() {
+	BlockTests::new().add_with_post_test(
+		123,
+		|| {},
+		|| {
+			let system_config = RelayStateSproofBuilder::default().system_config;
+			assert_eq!(RC_SYSTEM_CONFIG.with(|c| c.borrow().clone()), system_config);
+			assert_eq!(ParachainSystem::last_system_config(), system_config);
+		},
+	);
+}
+
+#[test]
+fn missing_system_config_falls_back_to_last_known() {
+	BlockTests::new()
+		.with_relay_sproof_builder(|_, block_number, builder| {
+			if block_number > 123 {
+				builder.system_config = None;
+			}
+		})
+		.add_with_post_test(
+			123,
+			|| {},
+			|| {
+				RC_SYSTEM_CONFIG.with(|c| *c.borrow_mut() = None);
+			},
+		)
+		.add_with_post_test(
+			1234,
+			|| {},
+			|| {
+				assert_eq!(
+					System::events()[0].event,
+					RuntimeEvent::ParachainSystem(crate::Event::SystemConfigUnavailable {
+						fallback: true
+					})
+				);
+				assert!(RC_SYSTEM_CONFIG.with(|c| c.borrow().is_some()));
+			},
+		);
+}
+
+#[test]
+fn invalid_system_config_is_not_applied() {
+	BlockTests::new()
+		.with_relay_sproof_builder(|_, _, builder| {
+			builder.system_config = Some(SystemConfig::default());
+		})
+		.add_with_post_test(
+			123,
+			|| {},
+			|| {
+				assert_eq!(
+					System::events()[0].event,
+					RuntimeEvent::ParachainSystem(crate::Event::SystemConfigUnavailable {
+						fallback: false
+					})
+				);
+				assert!(RC_SYSTEM_CONFIG.with(|c| c.borrow().is_none()));
+				assert!(ParachainSystem::last_system_config().is_none());
+			},
+		);
+}
+
+#[test]
+fn applies_updated_system_token_weights() {
+	let weights = vec![(MultiLocation::new(0, X2(PalletInstance(50), GeneralIndex(1))), 1_000)];
+	let expected = weights.clone();
+	BlockTests::new()
+		.with_relay_sproof_builder(move |_, _, builder| {
+			builder.updated_system_token_weights = Some(weights.clone());
+		})
+		.add_with_post_test(
+			123,
+			|| {},
+			move || {
+				assert_eq!(UPDATED_SYSTEM_TOKEN_WEIGHTS.with(|w| w.borrow().clone()), expected);
+			},
+		);
+}
+
+#[test]
+fn malformed_system_token_weights_are_reported() {
+	BlockTests::new()
+		.with_relay_sproof_builder(|_, _, builder| {
+			builder.additional_key_values.push((
+				relay_chain::well_known_keys::update_system_token_weight(builder.para_id),
+				vec![0xff],
+			));
+		})
+		.add_with_post_test(
+			123,
+			|| {},
+			|| {
+				assert_eq!(
+					System::events()[0].event,
+					RuntimeEvent::ParachainSystem(crate::Event::SystemTokenWeightUnavailable)
+				);
+				assert!(UPDATED_SYSTEM_TOKEN_WEIGHTS.with(|w| w.borrow().is_empty()));
+			},
+		);
+}
+
+#[test]
+fn applies_system_token_weight_commitment() {
+	let table = vec![(MultiLocation::new(0, X2(PalletInstance(50), GeneralIndex(1))), 1_000u128)];
+	let commitment = relay_chain::SystemTokenWeightCommitment::new(1, &table);
+	BlockTests::new()
+		.with_relay_sproof_builder(move |_, _, builder| {
+			builder.system_token_weight_commitment = Some(commitment);
+		})
+		.add_with_post_test(
+			123,
+			|| {},
+			move || {
+				assert_eq!(SYSTEM_TOKEN_WEIGHT_COMMITMENT.with(|c| *c.borrow()), Some(commitment));
+			},
+		);
+}
+
+#[test]
+fn reads_para_fee_rate() {
+	let mut builder = RelayStateSproofBuilder::default();
+	builder.para_fee_rate = Some(1_000_000);
+	let para_id = builder.para_id;
+	let (root, proof) = builder.into_state_root_and_proof();
+	let relay_state_proof = RelayChainStateProof::new(para_id, root, proof).expect("valid proof");
+	assert_eq!(relay_state_proof.read_para_fee_rate().unwrap(), Some(1_000_000));
+
+	let (root, proof) = RelayStateSproofBuilder::default().into_state_root_and_proof();
+	let relay_state_proof = RelayChainStateProof::new(para_id, root, proof).expect("valid proof");
+	assert_eq!(relay_state_proof.read_para_fee_rate().unwrap(), None);
+}
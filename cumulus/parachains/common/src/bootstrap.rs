@@ -0,0 +1,66 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Bootstrap and maintenance policy shared by InfraBlockchain parachain runtimes.
+
+use cumulus_pallet_infra_parachain_core::InspectBootstrap;
+use frame_support::traits::{fungibles::EnumerateSystemToken, Contains, Get, IsSubType};
+use sp_std::marker::PhantomData;
+
+/// Calls which are free of charge while the chain is under maintenance. Every other signed call
+/// is rejected.
+pub struct MaintenanceCallFilter<R>(PhantomData<R>);
+impl<R, Call> Contains<Call> for MaintenanceCallFilter<R>
+where
+	R: pallet_sudo::Config + pallet_collator_selection::Config + pallet_session::Config,
+	Call: IsSubType<pallet_sudo::Call<R>>
+		+ IsSubType<pallet_collator_selection::Call<R>>
+		+ IsSubType<pallet_session::Call<R>>,
+{
+	fn contains(call: &Call) -> bool {
+		IsSubType::<pallet_sudo::Call<R>>::is_sub_type(call).is_some() ||
+			IsSubType::<pallet_collator_selection::Call<R>>::is_sub_type(call).is_some() ||
+			matches!(
+				IsSubType::<pallet_session::Call<R>>::is_sub_type(call),
+				Some(pallet_session::Call::set_keys { .. })
+			)
+	}
+}
+
+/// Inspects System Tokens of `Assets` and `ForeignAssets`, and collators of `R`, for the
+/// bootstrap exit criteria. The collator set is healthy with at least `MinCollators` eligible
+/// collators.
+pub struct BootstrapInspector<R, Assets, ForeignAssets, MinCollators>(
+	PhantomData<(R, Assets, ForeignAssets, MinCollators)>,
+);
+impl<R, Assets, ForeignAssets, MinCollators> InspectBootstrap
+	for BootstrapInspector<R, Assets, ForeignAssets, MinCollators>
+where
+	R: pallet_collator_selection::Config,
+	Assets: EnumerateSystemToken<R::AccountId>,
+	ForeignAssets: EnumerateSystemToken<R::AccountId>,
+	MinCollators: Get<u32>,
+{
+	fn has_system_token() -> bool {
+		Assets::system_token_ids().into_iter().next().is_some() ||
+			ForeignAssets::system_token_ids().into_iter().next().is_some()
+	}
+
+	fn is_collator_set_healthy() -> bool {
+		let eligible = pallet_collator_selection::Pallet::<R>::invulnerables().len() +
+			pallet_collator_selection::Pallet::<R>::candidates().len();
+		eligible >= MinCollators::get() as usize
+	}
+}
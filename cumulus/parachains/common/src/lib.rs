@@ -15,6 +15,7 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+pub mod bootstrap;
 pub mod impls;
 pub mod infra_relay;
 pub mod xcm_config;
@@ -0,0 +1,222 @@
+//! Genesis of the chains that compose the emulated InfraBlockchain network
+
+use pallet_im_online::sr25519::AuthorityId as ImOnlineId;
+use primitives::{AccountId, AssignmentId, BlockNumber, ValidatorId};
+use runtime_parachains::configuration::HostConfiguration;
+use sp_authority_discovery::AuthorityId as AuthorityDiscoveryId;
+use sp_consensus_babe::AuthorityId as BabeId;
+use sp_consensus_beefy::ecdsa_crypto::AuthorityId as BeefyId;
+use sp_consensus_grandpa::AuthorityId as GrandpaId;
+use sp_core::{sr25519, storage::Storage, Pair, Public};
+use sp_runtime::{
+	infra::{BaseSystemTokenDetail, Fiat, SystemConfig},
+	traits::{IdentifyAccount, Verify},
+	BuildStorage, MultiSignature,
+};
+
+pub const XCM_V3: u32 = 3;
+pub const SAFE_XCM_VERSION: u32 = XCM_V3;
+
+type AccountPublic = <MultiSignature as Verify>::Signer;
+
+/// Helper function to generate a crypto pair from seed
+pub fn get_from_seed<TPublic: Public>(seed: &str) -> <TPublic::Pair as Pair>::Public {
+	TPublic::Pair::from_string(&format!("//{}", seed), None)
+		.expect("static values are valid; qed")
+		.public()
+}
+
+/// Helper function to generate an account ID from seed
+pub fn get_account_id_from_seed<TPublic: Public>(seed: &str) -> AccountId
+where
+	AccountPublic: From<<TPublic::Pair as Pair>::Public>,
+{
+	AccountPublic::from(get_from_seed::<TPublic>(seed)).into_account()
+}
+
+pub mod accounts {
+	use super::*;
+	pub const ALICE: &str = "Alice";
+	pub const BOB: &str = "Bob";
+	pub const CHARLIE: &str = "Charlie";
+	pub const DAVE: &str = "Dave";
+
+	pub fn init_balances() -> Vec<AccountId> {
+		vec![
+			get_account_id_from_seed::<sr25519::Public>(ALICE),
+			get_account_id_from_seed::<sr25519::Public>(BOB),
+			get_account_id_from_seed::<sr25519::Public>(CHARLIE),
+			get_account_id_from_seed::<sr25519::Public>(DAVE),
+		]
+	}
+}
+
+/// Genesis of `infra-relay`
+pub mod infra_relay {
+	use super::*;
+	use infra_relay_runtime_constants::currency::UNITS;
+
+	pub const ED: u128 = infra_relay_runtime_constants::currency::EXISTENTIAL_DEPOSIT;
+	const ENDOWMENT: u128 = 1_000_000 * UNITS;
+
+	pub fn get_host_config() -> HostConfiguration<BlockNumber> {
+		HostConfiguration {
+			max_upward_queue_count: 10,
+			max_upward_queue_size: 51200,
+			max_upward_message_size: 51200,
+			max_upward_message_num_per_candidate: 10,
+			max_downward_message_size: 51200,
+			hrmp_sender_deposit: 0,
+			hrmp_recipient_deposit: 0,
+			hrmp_channel_max_capacity: 1024,
+			hrmp_channel_max_total_size: 1024 * 1024,
+			hrmp_channel_max_message_size: 1024 * 1024,
+			hrmp_max_parachain_inbound_channels: 30,
+			hrmp_max_parachain_outbound_channels: 30,
+			hrmp_max_message_num_per_candidate: 10,
+			..Default::default()
+		}
+	}
+
+	pub fn get_system_config() -> SystemConfig {
+		SystemConfig {
+			base_system_token_detail: BaseSystemTokenDetail::new(Fiat::USD, 1_000_000, 4),
+			weight_scale: 25,
+			base_para_fee_rate: 1_000_000,
+		}
+	}
+
+	fn session_keys(
+		babe: BabeId,
+		grandpa: GrandpaId,
+		im_online: ImOnlineId,
+		para_validator: ValidatorId,
+		para_assignment: AssignmentId,
+		authority_discovery: AuthorityDiscoveryId,
+		beefy: BeefyId,
+	) -> infra_relay_runtime::SessionKeys {
+		infra_relay_runtime::SessionKeys {
+			babe,
+			grandpa,
+			im_online,
+			para_validator,
+			para_assignment,
+			authority_discovery,
+			beefy,
+		}
+	}
+
+	pub fn genesis() -> Storage {
+		let validator = get_account_id_from_seed::<sr25519::Public>(accounts::ALICE);
+		let genesis_config = infra_relay_runtime::RuntimeGenesisConfig {
+			system: infra_relay_runtime::SystemConfig {
+				code: infra_relay_runtime::WASM_BINARY.unwrap().to_vec(),
+				..Default::default()
+			},
+			balances: infra_relay_runtime::BalancesConfig {
+				balances: accounts::init_balances()
+					.iter()
+					.map(|k| (k.clone(), ENDOWMENT))
+					.collect(),
+			},
+			session: infra_relay_runtime::SessionConfig {
+				keys: vec![(
+					validator.clone(),
+					validator.clone(),
+					session_keys(
+						get_from_seed::<BabeId>(accounts::ALICE),
+						get_from_seed::<GrandpaId>(accounts::ALICE),
+						get_from_seed::<ImOnlineId>(accounts::ALICE),
+						get_from_seed::<ValidatorId>(accounts::ALICE),
+						get_from_seed::<AssignmentId>(accounts::ALICE),
+						get_from_seed::<AuthorityDiscoveryId>(accounts::ALICE),
+						get_from_seed::<BeefyId>(accounts::ALICE),
+					),
+				)],
+			},
+			babe: infra_relay_runtime::BabeConfig {
+				authorities: Default::default(),
+				epoch_config: Some(infra_relay_runtime::BABE_GENESIS_EPOCH_CONFIG),
+				..Default::default()
+			},
+			configuration: infra_relay_runtime::ConfigurationConfig {
+				config: get_host_config(),
+				system_config: get_system_config(),
+			},
+			validator_management: infra_relay_runtime::ValidatorManagementConfig {
+				seed_trust_validators: vec![validator],
+				total_validator_slots: 1,
+				seed_trust_slots: 1,
+				..Default::default()
+			},
+			xcm_pallet: infra_relay_runtime::XcmPalletConfig {
+				safe_xcm_version: Some(SAFE_XCM_VERSION),
+				..Default::default()
+			},
+			..Default::default()
+		};
+
+		genesis_config.build_storage().unwrap()
+	}
+}
+
+/// Genesis of chains running `asset-hub-runtime`
+pub mod asset_hub_infra {
+	use super::*;
+
+	pub const PARA_ID: u32 = 1000;
+	pub const ED: u128 = asset_hub_runtime::constants::currency::EXISTENTIAL_DEPOSIT;
+
+	pub fn genesis() -> Storage {
+		genesis_for(PARA_ID)
+	}
+
+	/// Genesis of `asset-hub-runtime` with the given `para_id`, so that the same runtime can be
+	/// used for every parachain of the network.
+	pub fn genesis_for(para_id: u32) -> Storage {
+		let collator = get_account_id_from_seed::<sr25519::Public>(accounts::ALICE);
+		let genesis_config = asset_hub_runtime::RuntimeGenesisConfig {
+			system: asset_hub_runtime::SystemConfig {
+				code: asset_hub_runtime::WASM_BINARY
+					.expect("WASM binary was not build, please build it!")
+					.to_vec(),
+				..Default::default()
+			},
+			balances: asset_hub_runtime::BalancesConfig {
+				balances: accounts::init_balances()
+					.iter()
+					.cloned()
+					.map(|k| (k, ED * 4096 * 4096))
+					.collect(),
+			},
+			parachain_info: asset_hub_runtime::ParachainInfoConfig {
+				parachain_id: para_id.into(),
+				..Default::default()
+			},
+			collator_selection: asset_hub_runtime::CollatorSelectionConfig {
+				invulnerables: vec![collator.clone()],
+				candidacy_bond: ED * 16,
+				..Default::default()
+			},
+			session: asset_hub_runtime::SessionConfig {
+				keys: vec![(
+					collator.clone(),
+					collator,
+					asset_hub_runtime::SessionKeys {
+						aura: get_from_seed::<parachains_common::AuraId>(accounts::ALICE),
+					},
+				)],
+			},
+			infra_xcm: asset_hub_runtime::InfraXcmConfig {
+				safe_xcm_version: Some(SAFE_XCM_VERSION),
+				..Default::default()
+			},
+			sudo: asset_hub_runtime::SudoConfig {
+				key: Some(get_account_id_from_seed::<sr25519::Public>(accounts::ALICE)),
+			},
+			..Default::default()
+		};
+
+		genesis_config.build_storage().unwrap()
+	}
+}
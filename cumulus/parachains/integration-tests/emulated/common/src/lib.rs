@@ -0,0 +1,137 @@
+//! Emulated InfraBlockchain network: `infra-relay` with `asset-hub-infra` and two more
+//! parachains running the same runtime, connected through HRMP.
+
+pub mod constants;
+
+pub use constants::{accounts, asset_hub_infra, infra_relay, SAFE_XCM_VERSION};
+
+use sp_runtime::infra::TaaV;
+use xcm_emulator::{
+	decl_test_networks, decl_test_parachains, decl_test_relay_chains, CandidateCommitmentsHandler,
+	DefaultMessageProcessor,
+};
+
+/// Para id of the first non-Asset-Hub parachain
+pub const PARA_A_ID: u32 = 2000;
+/// Para id of the second non-Asset-Hub parachain
+pub const PARA_B_ID: u32 = 2001;
+
+/// Processes the commitments of included candidates the same way `inclusion` of `infra-relay`
+/// does.
+pub struct InfraRelayCommitmentsHandler;
+impl CandidateCommitmentsHandler for InfraRelayCommitmentsHandler {
+	fn handle_requested_asset(_para_id: u32, mut requested_asset: Vec<u8>) {
+		infra_relay_runtime::SystemTokenManager::requested_asset_metadata(&mut requested_asset);
+	}
+
+	fn handle_proof_of_transaction(_para_id: u32, mut proof_of_transaction: Vec<u8>) {
+		let _ =
+			<infra_relay_runtime::ValidatorManagement as TaaV>::process(&mut proof_of_transaction);
+	}
+}
+
+decl_test_relay_chains! {
+	#[api_version(7)]
+	pub struct InfraRelay {
+		genesis = infra_relay::genesis(),
+		on_init = (),
+		runtime = infra_relay_runtime,
+		core = {
+			MessageProcessor: DefaultMessageProcessor<InfraRelay>,
+			SovereignAccountOf: infra_relay_runtime::xcm_config::SovereignAccountOf,
+			CandidateCommitmentsHandler: InfraRelayCommitmentsHandler,
+		},
+		pallets = {
+			XcmPallet: infra_relay_runtime::XcmPallet,
+			Balances: infra_relay_runtime::Balances,
+			Hrmp: infra_relay_runtime::Hrmp,
+			SystemTokenManager: infra_relay_runtime::SystemTokenManager,
+			Configuration: infra_relay_runtime::Configuration,
+			ValidatorManagement: infra_relay_runtime::ValidatorManagement,
+		}
+	}
+}
+
+decl_test_parachains! {
+	pub struct AssetHubInfra {
+		genesis = asset_hub_infra::genesis(),
+		on_init = {
+			asset_hub_runtime::AuraExt::on_initialize(1);
+		},
+		runtime = asset_hub_runtime,
+		core = {
+			XcmpMessageHandler: asset_hub_runtime::XcmpQueue,
+			DmpMessageHandler: asset_hub_runtime::DmpQueue,
+			LocationToAccountId: asset_hub_runtime::xcm_config::LocationToAccountId,
+			ParachainInfo: asset_hub_runtime::ParachainInfo,
+		},
+		pallets = {
+			InfraXcm: asset_hub_runtime::InfraXcm,
+			Assets: asset_hub_runtime::Assets,
+			ForeignAssets: asset_hub_runtime::ForeignAssets,
+			Balances: asset_hub_runtime::Balances,
+			SystemTokenTransfer: asset_hub_runtime::SystemTokenTransfer,
+			InfraParaCore: asset_hub_runtime::InfraParaCore,
+			SystemTokenTxPayment: asset_hub_runtime::SystemTokenTxPayment,
+			SystemTokenOracle: asset_hub_runtime::SystemTokenOracle,
+		}
+	},
+	pub struct InfraParaA {
+		genesis = asset_hub_infra::genesis_for(PARA_A_ID),
+		on_init = {
+			asset_hub_runtime::AuraExt::on_initialize(1);
+		},
+		runtime = asset_hub_runtime,
+		core = {
+			XcmpMessageHandler: asset_hub_runtime::XcmpQueue,
+			DmpMessageHandler: asset_hub_runtime::DmpQueue,
+			LocationToAccountId: asset_hub_runtime::xcm_config::LocationToAccountId,
+			ParachainInfo: asset_hub_runtime::ParachainInfo,
+		},
+		pallets = {
+			InfraXcm: asset_hub_runtime::InfraXcm,
+			Assets: asset_hub_runtime::Assets,
+			ForeignAssets: asset_hub_runtime::ForeignAssets,
+			Balances: asset_hub_runtime::Balances,
+			SystemTokenTransfer: asset_hub_runtime::SystemTokenTransfer,
+			InfraParaCore: asset_hub_runtime::InfraParaCore,
+			SystemTokenTxPayment: asset_hub_runtime::SystemTokenTxPayment,
+			SystemTokenOracle: asset_hub_runtime::SystemTokenOracle,
+		}
+	},
+	pub struct InfraParaB {
+		genesis = asset_hub_infra::genesis_for(PARA_B_ID),
+		on_init = {
+			asset_hub_runtime::AuraExt::on_initialize(1);
+		},
+		runtime = asset_hub_runtime,
+		core = {
+			XcmpMessageHandler: asset_hub_runtime::XcmpQueue,
+			DmpMessageHandler: asset_hub_runtime::DmpQueue,
+			LocationToAccountId: asset_hub_runtime::xcm_config::LocationToAccountId,
+			ParachainInfo: asset_hub_runtime::ParachainInfo,
+		},
+		pallets = {
+			InfraXcm: asset_hub_runtime::InfraXcm,
+			Assets: asset_hub_runtime::Assets,
+			ForeignAssets: asset_hub_runtime::ForeignAssets,
+			Balances: asset_hub_runtime::Balances,
+			SystemTokenTransfer: asset_hub_runtime::SystemTokenTransfer,
+			InfraParaCore: asset_hub_runtime::InfraParaCore,
+			SystemTokenTxPayment: asset_hub_runtime::SystemTokenTxPayment,
+			SystemTokenOracle: asset_hub_runtime::SystemTokenOracle,
+		}
+	}
+}
+
+decl_test_networks! {
+	pub struct InfraMockNet {
+		relay_chain = InfraRelay,
+		parachains = vec![
+			AssetHubInfra,
+			InfraParaA,
+			InfraParaB,
+		],
+		bridge = ()
+	}
+}
@@ -0,0 +1,27 @@
+pub use codec::Encode;
+pub use frame_support::{assert_ok, sp_runtime::DispatchResult, traits::fungibles::Inspect};
+pub use integration_tests_common::{
+	accounts::{ALICE, BOB},
+	asset_hub_infra::ED as ASSET_HUB_ED,
+	AssetHubInfra, AssetHubInfraPallet, InfraMockNet, InfraParaA, InfraParaAPallet, InfraParaB,
+	InfraParaBPallet, InfraRelay, InfraRelayPallet, PARA_A_ID, PARA_B_ID,
+};
+pub use parachains_common::{AccountId, Balance};
+pub use xcm::{
+	prelude::{AccountId32 as AccountId32Junction, *},
+	v3::{Error, NetworkId::InfraRelay as InfraRelayId},
+};
+pub use xcm_emulator::{
+	assert_expected_events, bx, helpers::weight_within_threshold, Chain, Parachain as Para,
+	RelayChain as Relay, TestExt,
+};
+
+/// Pallet index of `Assets` in `asset-hub-runtime`
+pub const ASSETS_PALLET_ID: u8 = 50;
+/// Id of the System Token issued by `InfraParaA`
+pub const SYSTEM_TOKEN_ID: u32 = 1;
+pub const SYSTEM_TOKEN_MIN_BALANCE: Balance = 1_000;
+pub const SYSTEM_TOKEN_AMOUNT: Balance = 1_000_000_000_000;
+
+#[cfg(test)]
+mod tests;
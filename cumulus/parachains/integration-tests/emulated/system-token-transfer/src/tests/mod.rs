@@ -0,0 +1,2 @@
+mod teleport;
+mod transfer;
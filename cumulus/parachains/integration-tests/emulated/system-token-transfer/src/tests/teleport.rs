@@ -0,0 +1,71 @@
+use crate::*;
+
+/// Teleport `asset` from `InfraRelay` to `BOB` on `AssetHubInfra`, paying the fees with it.
+fn teleport_from_relay_to_asset_hub(asset: MultiLocation, amount: Balance) {
+	let assets: MultiAssets = (asset, amount).into();
+	let message = VersionedXcm::from(Xcm(vec![
+		ReceiveTeleportedAsset(assets.clone()),
+		ClearOrigin,
+		BuyExecution { fees: (asset, amount).into(), weight_limit: Unlimited },
+		DepositAsset {
+			assets: Wild(AllCounted(1)),
+			beneficiary: AccountId32Junction {
+				network: None,
+				id: AssetHubInfra::account_id_of(BOB).into(),
+			}
+			.into(),
+		},
+	]));
+	InfraRelay::execute_with(|| {
+		assert_ok!(<InfraRelay as InfraRelayPallet>::XcmPallet::send(
+			<InfraRelay as Chain>::RuntimeOrigin::root(),
+			bx!(InfraRelay::child_location_of(AssetHubInfra::para_id()).into()),
+			bx!(message),
+		));
+	});
+}
+
+#[test]
+fn teleport_of_token_which_is_not_a_system_token_is_rejected() {
+	// Issued by the Relay Chain and known to `AssetHubInfra`, but not sufficient and therefore not
+	// a System Token
+	let unregistered =
+		MultiLocation::new(1, X2(PalletInstance(ASSETS_PALLET_ID), GeneralIndex(99)));
+	AssetHubInfra::execute_with(|| {
+		type ForeignAssets = <AssetHubInfra as AssetHubInfraPallet>::ForeignAssets;
+		assert_ok!(ForeignAssets::force_create(
+			<AssetHubInfra as Chain>::RuntimeOrigin::root(),
+			unregistered,
+			AssetHubInfra::account_id_of(ALICE).into(),
+			false,
+			SYSTEM_TOKEN_MIN_BALANCE,
+		));
+	});
+
+	teleport_from_relay_to_asset_hub(unregistered, SYSTEM_TOKEN_AMOUNT);
+
+	AssetHubInfra::execute_with(|| {
+		type RuntimeEvent = <AssetHubInfra as Chain>::RuntimeEvent;
+		type ForeignAssets = <AssetHubInfra as AssetHubInfraPallet>::ForeignAssets;
+		assert_expected_events!(
+			AssetHubInfra,
+			vec![
+				RuntimeEvent::DmpQueue(cumulus_pallet_dmp_queue::Event::ExecutedDownward {
+					outcome, ..
+				}) => {
+					outcome: matches!(
+						outcome,
+						Outcome::Incomplete(_, Error::UntrustedTeleportLocation)
+					),
+				},
+			]
+		);
+		assert_eq!(
+			<ForeignAssets as Inspect<_>>::balance(
+				unregistered,
+				&AssetHubInfra::account_id_of(BOB)
+			),
+			0
+		);
+	});
+}
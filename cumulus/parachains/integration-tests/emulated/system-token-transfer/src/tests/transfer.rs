@@ -0,0 +1,234 @@
+use crate::*;
+use pallet_system_token_transfer::TransferType;
+
+/// Location of the System Token issued by `InfraParaA`, seen from its siblings
+fn wrapped_system_token() -> MultiLocation {
+	MultiLocation::new(
+		1,
+		X3(
+			Parachain(PARA_A_ID),
+			PalletInstance(ASSETS_PALLET_ID),
+			GeneralIndex(SYSTEM_TOKEN_ID.into()),
+		),
+	)
+}
+
+/// Location of the System Token issued by `InfraParaA`, seen from `InfraParaA` itself
+fn original_system_token() -> MultiLocation {
+	MultiLocation::new(
+		0,
+		X2(PalletInstance(ASSETS_PALLET_ID), GeneralIndex(SYSTEM_TOKEN_ID.into())),
+	)
+}
+
+fn beneficiary(who: AccountId) -> MultiLocation {
+	AccountId32Junction { network: None, id: who.into() }.into()
+}
+
+/// Issue the System Token on `InfraParaA` and register its wrapped token on `InfraParaB` and
+/// `AssetHubInfra`.
+fn setup_system_token() {
+	let owner = InfraParaA::account_id_of(ALICE);
+	InfraParaA::execute_with(|| {
+		type Assets = <InfraParaA as InfraParaAPallet>::Assets;
+		let root = <InfraParaA as Chain>::RuntimeOrigin::root();
+		assert_ok!(Assets::force_create(
+			root,
+			SYSTEM_TOKEN_ID.into(),
+			owner.clone().into(),
+			true,
+			SYSTEM_TOKEN_MIN_BALANCE,
+		));
+		assert_ok!(Assets::mint(
+			<InfraParaA as Chain>::RuntimeOrigin::signed(owner.clone()),
+			SYSTEM_TOKEN_ID.into(),
+			owner.clone().into(),
+			SYSTEM_TOKEN_AMOUNT,
+		));
+	});
+	InfraParaB::execute_with(|| {
+		type ForeignAssets = <InfraParaB as InfraParaBPallet>::ForeignAssets;
+		assert_ok!(ForeignAssets::force_create(
+			<InfraParaB as Chain>::RuntimeOrigin::root(),
+			wrapped_system_token(),
+			owner.clone().into(),
+			true,
+			SYSTEM_TOKEN_MIN_BALANCE,
+		));
+	});
+	AssetHubInfra::execute_with(|| {
+		type ForeignAssets = <AssetHubInfra as AssetHubInfraPallet>::ForeignAssets;
+		assert_ok!(ForeignAssets::force_create(
+			<AssetHubInfra as Chain>::RuntimeOrigin::root(),
+			wrapped_system_token(),
+			owner.into(),
+			true,
+			SYSTEM_TOKEN_MIN_BALANCE,
+		));
+	});
+}
+
+fn wrapped_balance_on_para_b(who: &AccountId) -> Balance {
+	InfraParaB::execute_with(|| {
+		type ForeignAssets = <InfraParaB as InfraParaBPallet>::ForeignAssets;
+		<ForeignAssets as Inspect<_>>::balance(wrapped_system_token(), who)
+	})
+}
+
+fn transfer_from_para_a_to_para_b(amount: Balance) {
+	InfraParaA::execute_with(|| {
+		type RuntimeEvent = <InfraParaA as Chain>::RuntimeEvent;
+		assert_ok!(<InfraParaA as InfraParaAPallet>::SystemTokenTransfer::transfer_system_token(
+			<InfraParaA as Chain>::RuntimeOrigin::signed(InfraParaA::account_id_of(ALICE)),
+			bx!(original_system_token().into()),
+			amount,
+			bx!(InfraParaA::sibling_location_of(PARA_B_ID.into()).into()),
+			bx!(beneficiary(InfraParaB::account_id_of(BOB)).into()),
+			WeightLimit::Unlimited,
+		));
+		assert_expected_events!(
+			InfraParaA,
+			vec![
+				RuntimeEvent::SystemTokenTransfer(
+					pallet_system_token_transfer::Event::SystemTokenTransferred { transfer_type, .. }
+				) => {
+					transfer_type: *transfer_type == TransferType::LocalReserve,
+				},
+			]
+		);
+	});
+}
+
+#[test]
+fn local_reserve_transfer_to_sibling_works() {
+	setup_system_token();
+	let amount = SYSTEM_TOKEN_AMOUNT / 10;
+	let bob = InfraParaB::account_id_of(BOB);
+	let sovereign_of_para_b =
+		InfraParaA::sovereign_account_id_of(InfraParaA::sibling_location_of(PARA_B_ID.into()));
+
+	transfer_from_para_a_to_para_b(amount);
+
+	InfraParaA::execute_with(|| {
+		type Assets = <InfraParaA as InfraParaAPallet>::Assets;
+		assert_eq!(<Assets as Inspect<_>>::balance(SYSTEM_TOKEN_ID, &sovereign_of_para_b), amount);
+	});
+	InfraParaB::execute_with(|| {
+		type RuntimeEvent = <InfraParaB as Chain>::RuntimeEvent;
+		assert_expected_events!(
+			InfraParaB,
+			vec![
+				RuntimeEvent::ForeignAssets(pallet_assets::Event::Issued { owner, .. }) => {
+					owner: *owner == bob,
+				},
+			]
+		);
+	});
+	let received = wrapped_balance_on_para_b(&bob);
+	assert!(received > 0 && received < amount);
+}
+
+#[test]
+fn destination_reserve_transfer_back_to_issuer_works() {
+	setup_system_token();
+	let bob = InfraParaB::account_id_of(BOB);
+	transfer_from_para_a_to_para_b(SYSTEM_TOKEN_AMOUNT / 10);
+	let amount = wrapped_balance_on_para_b(&bob) / 2;
+	let charlie = InfraParaA::account_id_of(integration_tests_common::accounts::CHARLIE);
+
+	InfraParaB::execute_with(|| {
+		type RuntimeEvent = <InfraParaB as Chain>::RuntimeEvent;
+		assert_ok!(<InfraParaB as InfraParaBPallet>::SystemTokenTransfer::transfer_system_token(
+			<InfraParaB as Chain>::RuntimeOrigin::signed(bob.clone()),
+			bx!(wrapped_system_token().into()),
+			amount,
+			bx!(InfraParaB::sibling_location_of(PARA_A_ID.into()).into()),
+			bx!(beneficiary(charlie.clone()).into()),
+			WeightLimit::Unlimited,
+		));
+		assert_expected_events!(
+			InfraParaB,
+			vec![
+				RuntimeEvent::SystemTokenTransfer(
+					pallet_system_token_transfer::Event::SystemTokenTransferred { transfer_type, .. }
+				) => {
+					transfer_type: *transfer_type == TransferType::DestinationReserve,
+				},
+			]
+		);
+	});
+
+	InfraParaA::execute_with(|| {
+		type Assets = <InfraParaA as InfraParaAPallet>::Assets;
+		let received = <Assets as Inspect<_>>::balance(SYSTEM_TOKEN_ID, &charlie);
+		assert!(received > 0 && received < amount);
+	});
+}
+
+#[test]
+fn remote_reserve_transfer_hops_through_issuer() {
+	setup_system_token();
+	let bob = InfraParaB::account_id_of(BOB);
+	transfer_from_para_a_to_para_b(SYSTEM_TOKEN_AMOUNT / 10);
+	let amount = wrapped_balance_on_para_b(&bob) / 2;
+	let dave = AssetHubInfra::account_id_of(integration_tests_common::accounts::DAVE);
+
+	InfraParaB::execute_with(|| {
+		type RuntimeEvent = <InfraParaB as Chain>::RuntimeEvent;
+		assert_ok!(<InfraParaB as InfraParaBPallet>::SystemTokenTransfer::transfer_system_token(
+			<InfraParaB as Chain>::RuntimeOrigin::signed(bob.clone()),
+			bx!(wrapped_system_token().into()),
+			amount,
+			bx!(InfraParaB::sibling_location_of(AssetHubInfra::para_id()).into()),
+			bx!(beneficiary(dave.clone()).into()),
+			WeightLimit::Unlimited,
+		));
+		assert_expected_events!(
+			InfraParaB,
+			vec![
+				RuntimeEvent::SystemTokenTransfer(
+					pallet_system_token_transfer::Event::SystemTokenTransferred { transfer_type, .. }
+				) => {
+					transfer_type: *transfer_type == TransferType::RemoteReserve(
+						InfraParaB::sibling_location_of(PARA_A_ID.into())
+					),
+				},
+			]
+		);
+	});
+
+	// Reserve moves the backing from the sovereign account of `InfraParaB` to `AssetHubInfra`'s
+	let sovereign_of_asset_hub = InfraParaA::sovereign_account_id_of(
+		InfraParaA::sibling_location_of(AssetHubInfra::para_id()),
+	);
+	InfraParaA::execute_with(|| {
+		type Assets = <InfraParaA as InfraParaAPallet>::Assets;
+		assert!(<Assets as Inspect<_>>::balance(SYSTEM_TOKEN_ID, &sovereign_of_asset_hub) > 0);
+	});
+
+	AssetHubInfra::execute_with(|| {
+		type ForeignAssets = <AssetHubInfra as AssetHubInfraPallet>::ForeignAssets;
+		let received = <ForeignAssets as Inspect<_>>::balance(wrapped_system_token(), &dave);
+		assert!(received > 0 && received < amount);
+	});
+}
+
+#[test]
+fn transfer_of_unknown_token_fails() {
+	setup_system_token();
+	InfraParaB::execute_with(|| {
+		let unknown = MultiLocation::new(
+			1,
+			X3(Parachain(PARA_A_ID), PalletInstance(ASSETS_PALLET_ID), GeneralIndex(99)),
+		);
+		assert!(<InfraParaB as InfraParaBPallet>::SystemTokenTransfer::transfer_system_token(
+			<InfraParaB as Chain>::RuntimeOrigin::signed(InfraParaB::account_id_of(BOB)),
+			bx!(unknown.into()),
+			SYSTEM_TOKEN_AMOUNT,
+			bx!(InfraParaB::sibling_location_of(PARA_A_ID.into()).into()),
+			bx!(beneficiary(InfraParaA::account_id_of(BOB)).into()),
+			WeightLimit::Unlimited,
+		)
+		.is_err());
+	});
+}
@@ -0,0 +1,31 @@
+pub use codec::Encode;
+pub use frame_support::{
+	assert_noop, assert_ok,
+	sp_runtime::DispatchResult,
+	traits::fungibles::{Inspect, InspectSystemToken},
+};
+pub use integration_tests_common::{
+	accounts::{ALICE, BOB},
+	AssetHubInfra, AssetHubInfraPallet, InfraMockNet, InfraParaA, InfraParaAPallet, InfraParaB,
+	InfraParaBPallet, InfraRelay, InfraRelayPallet, PARA_A_ID, PARA_B_ID,
+};
+pub use parachains_common::{AccountId, Balance};
+pub use runtime_parachains::system_token_manager::{self, types::RegisterType};
+pub use sp_runtime::infra::{ExchangeRate, Fiat, RewardOrigin};
+pub use xcm::prelude::*;
+pub use xcm_emulator::{
+	assert_expected_events, bx, Chain, Parachain as Para, RelayChain as Relay, TestExt,
+};
+
+/// Pallet index of `Assets` in `asset-hub-runtime`
+pub const ASSETS_PALLET_ID: u8 = 50;
+/// Id of the System Token issued by `InfraParaA`
+pub const SYSTEM_TOKEN_ID: u32 = 1;
+pub const SYSTEM_TOKEN_MIN_BALANCE: Balance = 1_000;
+pub const SYSTEM_TOKEN_AMOUNT: Balance = 1_000_000_000_000;
+pub const SYSTEM_TOKEN_DECIMALS: u8 = 4;
+/// Exchange rate of `USD`, which is the base currency of the emulated network
+pub const USD_EXCHANGE_RATE: ExchangeRate = 1_000_000;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,139 @@
+use super::*;
+use frame_support::dispatch::GetDispatchInfo;
+use pallet_system_token_tx_payment::ChargeSystemToken;
+use sp_runtime::traits::{Dispatchable, SignedExtension};
+
+/// Register the System Token of `InfraParaA`, seed its fee table and end its bootstrap mode.
+fn setup_normal_mode() {
+	register_system_token();
+	InfraRelay::execute_with(|| {
+		// Fee table of a call other than `remark`, which should still be charged by weight
+		assert_ok!(<InfraRelay as InfraRelayPallet>::Configuration::update_fee_table(
+			<InfraRelay as Chain>::RuntimeOrigin::root(),
+			PARA_A_ID,
+			b"InfraParaCore".to_vec(),
+			b"request_register_system_token".to_vec(),
+			1_000,
+		));
+		assert_ok!(<InfraRelay as InfraRelayPallet>::Configuration::update_runtime_state(
+			<InfraRelay as Chain>::RuntimeOrigin::root(),
+			PARA_A_ID,
+		));
+	});
+	InfraParaA::execute_with(|| {
+		type RuntimeEvent = <InfraParaA as Chain>::RuntimeEvent;
+		assert_expected_events!(
+			InfraParaA,
+			vec![
+				RuntimeEvent::InfraParaCore(
+					cumulus_pallet_infra_parachain_core::Event::BootstrapEnded
+				) => {},
+			]
+		);
+	});
+}
+
+/// Dispatch a remark signed by `Alice` on `InfraParaA`, paying its fee in the System Token and
+/// voting for `candidate`.
+fn pay_fee_in_system_token(candidate: AccountId) {
+	let alice = InfraParaA::account_id_of(ALICE);
+	InfraParaA::execute_with(|| {
+		type Runtime = <InfraParaA as Chain>::Runtime;
+		type RuntimeCall = <InfraParaA as Chain>::RuntimeCall;
+		type RuntimeEvent = <InfraParaA as Chain>::RuntimeEvent;
+		let call = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+		let info = call.get_dispatch_info();
+		let len = call.encoded_size();
+		let pre = ChargeSystemToken::<Runtime>::from(
+			0,
+			Some(original_system_token()),
+			Some(candidate.clone()),
+		)
+		.pre_dispatch(&alice, &call, &info, len)
+		.expect("Alice holds enough System Token to pay the fee");
+		let post_info = call
+			.dispatch(<InfraParaA as Chain>::RuntimeOrigin::signed(alice.clone()))
+			.expect("remark never fails");
+		assert_ok!(ChargeSystemToken::<Runtime>::post_dispatch(
+			Some(pre),
+			&info,
+			&post_info,
+			len,
+			&Ok(()),
+		));
+		assert_expected_events!(
+			InfraParaA,
+			vec![
+				RuntimeEvent::SystemTokenTxPayment(
+					pallet_system_token_tx_payment::Event::TransactionFeePaid { vote_candidate, .. }
+				) => {
+					vote_candidate: *vote_candidate == Some(candidate.clone()),
+				},
+			]
+		);
+	});
+}
+
+#[test]
+fn fee_payment_in_system_token_votes_on_relay_chain() {
+	setup_normal_mode();
+	let bob = InfraRelay::account_id_of(BOB);
+
+	pay_fee_in_system_token(bob.clone());
+
+	InfraRelay::execute_with(|| {
+		type RuntimeEvent = <InfraRelay as Chain>::RuntimeEvent;
+		assert_expected_events!(
+			InfraRelay,
+			vec![
+				RuntimeEvent::ValidatorManagement(
+					pallet_validator_management::Event::Voted { who, .. }
+				) => {
+					who: *who == bob,
+				},
+			]
+		);
+	});
+}
+
+#[test]
+fn validator_claims_fee_reward_from_parachain() {
+	setup_normal_mode();
+	pay_fee_in_system_token(InfraRelay::account_id_of(BOB));
+
+	let (validator, reward) = InfraRelay::execute_with(|| {
+		type Runtime = <InfraRelay as Chain>::Runtime;
+		let validator = infra_relay_runtime::Session::validators()
+			.first()
+			.cloned()
+			.expect("genesis has at least one validator");
+		let rewards = pallet_validator_management::RewardInfo::<Runtime>::get(
+			&validator,
+			RewardOrigin::Remote(PARA_A_ID),
+		)
+		.expect("fee of `InfraParaA` has been rewarded to the validators");
+		(validator, rewards[0].clone())
+	});
+
+	InfraRelay::execute_with(|| {
+		assert_ok!(<InfraRelay as InfraRelayPallet>::ValidatorManagement::claim_reward(
+			<InfraRelay as Chain>::RuntimeOrigin::signed(validator.clone()),
+			RewardOrigin::Remote(PARA_A_ID),
+			reward.asset.clone(),
+		));
+	});
+	InfraParaA::execute_with(|| {
+		type RuntimeEvent = <InfraParaA as Chain>::RuntimeEvent;
+		assert_expected_events!(
+			InfraParaA,
+			vec![
+				RuntimeEvent::InfraParaCore(
+					cumulus_pallet_infra_parachain_core::Event::RewardDistributed { who, amount, .. }
+				) => {
+					who: *who == validator,
+					amount: *amount == reward.amount,
+				},
+			]
+		);
+	});
+}
@@ -0,0 +1,116 @@
+mod fee;
+mod oracle;
+mod register;
+
+use crate::*;
+
+/// Location of the System Token issued by `InfraParaA`, seen from `InfraParaA` itself
+fn original_system_token() -> MultiLocation {
+	MultiLocation::new(
+		0,
+		X2(PalletInstance(ASSETS_PALLET_ID), GeneralIndex(SYSTEM_TOKEN_ID.into())),
+	)
+}
+
+/// Location of the System Token issued by `InfraParaA`, seen from the Relay Chain
+fn system_token_on_relay() -> MultiLocation {
+	MultiLocation::new(
+		0,
+		X3(
+			Parachain(PARA_A_ID),
+			PalletInstance(ASSETS_PALLET_ID),
+			GeneralIndex(SYSTEM_TOKEN_ID.into()),
+		),
+	)
+}
+
+/// Location of the System Token issued by `InfraParaA`, seen from its siblings
+fn wrapped_system_token() -> MultiLocation {
+	MultiLocation::new(
+		1,
+		X3(
+			Parachain(PARA_A_ID),
+			PalletInstance(ASSETS_PALLET_ID),
+			GeneralIndex(SYSTEM_TOKEN_ID.into()),
+		),
+	)
+}
+
+/// Issue a token on `InfraParaA` and request the Relay Chain to register it as a System Token.
+fn request_system_token() {
+	let owner = InfraParaA::account_id_of(ALICE);
+	InfraParaA::execute_with(|| {
+		type Assets = <InfraParaA as InfraParaAPallet>::Assets;
+		type RuntimeEvent = <InfraParaA as Chain>::RuntimeEvent;
+		let root = <InfraParaA as Chain>::RuntimeOrigin::root;
+		assert_ok!(Assets::force_create(
+			root(),
+			SYSTEM_TOKEN_ID.into(),
+			owner.clone().into(),
+			false,
+			SYSTEM_TOKEN_MIN_BALANCE,
+		));
+		assert_ok!(Assets::force_set_metadata(
+			root(),
+			SYSTEM_TOKEN_ID.into(),
+			b"Infra USD".to_vec(),
+			b"iUSD".to_vec(),
+			SYSTEM_TOKEN_DECIMALS,
+			false,
+		));
+		assert_ok!(Assets::mint(
+			<InfraParaA as Chain>::RuntimeOrigin::signed(owner.clone()),
+			SYSTEM_TOKEN_ID.into(),
+			owner.clone().into(),
+			SYSTEM_TOKEN_AMOUNT,
+		));
+		assert_ok!(<InfraParaA as InfraParaAPallet>::InfraParaCore::request_register_system_token(
+			root(),
+			original_system_token(),
+			Fiat::USD,
+		));
+		assert_expected_events!(
+			InfraParaA,
+			vec![
+				RuntimeEvent::InfraParaCore(
+					cumulus_pallet_infra_parachain_core::Event::RegisterRequested { asset_id, .. }
+				) => {
+					asset_id: *asset_id == original_system_token(),
+				},
+			]
+		);
+	});
+}
+
+/// Let the Relay Chain request the exchange rates it is missing and `AssetHubInfra` answer them.
+fn deliver_exchange_rates() {
+	InfraRelay::execute_with(|| {
+		assert_ok!(<InfraRelay as InfraRelayPallet>::SystemTokenManager::request_exchange_rate(
+			<InfraRelay as Chain>::RuntimeOrigin::root(),
+		));
+	});
+	AssetHubInfra::execute_with(|| {
+		assert_ok!(
+			<AssetHubInfra as AssetHubInfraPallet>::SystemTokenOracle::submit_exchange_rates_unsigned(
+				<AssetHubInfra as Chain>::RuntimeOrigin::none(),
+				vec![(Fiat::USD, USD_EXCHANGE_RATE)],
+			)
+		);
+	});
+}
+
+/// Register the System Token of `InfraParaA` as an `original` System Token.
+fn register_system_token() {
+	request_system_token();
+	deliver_exchange_rates();
+	InfraRelay::execute_with(|| {
+		assert_ok!(<InfraRelay as InfraRelayPallet>::SystemTokenManager::register_system_token(
+			<InfraRelay as Chain>::RuntimeOrigin::root(),
+			RegisterType::Original {
+				system_token_id: system_token_on_relay(),
+				currency_type: None
+			},
+			None,
+		));
+	});
+}
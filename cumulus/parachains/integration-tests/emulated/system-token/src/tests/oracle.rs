@@ -0,0 +1,71 @@
+use super::*;
+
+#[test]
+fn exchange_rate_request_reaches_asset_hub() {
+	request_system_token();
+
+	InfraRelay::execute_with(|| {
+		assert_ok!(<InfraRelay as InfraRelayPallet>::SystemTokenManager::request_exchange_rate(
+			<InfraRelay as Chain>::RuntimeOrigin::root(),
+		));
+	});
+	AssetHubInfra::execute_with(|| {
+		type Runtime = <AssetHubInfra as Chain>::Runtime;
+		type RuntimeEvent = <AssetHubInfra as Chain>::RuntimeEvent;
+		assert_expected_events!(
+			AssetHubInfra,
+			vec![
+				RuntimeEvent::SystemTokenOracle(
+					pallet_system_token_oracle::Event::Requested { fiat }
+				) => {
+					fiat: *fiat == vec![Fiat::USD],
+				},
+			]
+		);
+		assert_eq!(
+			pallet_system_token_oracle::Requested::<Runtime>::get().map(|fiat| fiat.to_vec()),
+			Some(vec![Fiat::USD])
+		);
+	});
+}
+
+#[test]
+fn submitted_exchange_rates_reach_relay_chain() {
+	request_system_token();
+	deliver_exchange_rates();
+
+	InfraRelay::execute_with(|| {
+		type Runtime = <InfraRelay as Chain>::Runtime;
+		type RuntimeEvent = <InfraRelay as Chain>::RuntimeEvent;
+		assert_expected_events!(
+			InfraRelay,
+			vec![
+				RuntimeEvent::SystemTokenManager(
+					system_token_manager::Event::ExchangeRateUpdated { updated }
+				) => {
+					updated: *updated == vec![(Fiat::USD, USD_EXCHANGE_RATE)],
+				},
+			]
+		);
+		assert_eq!(
+			system_token_manager::ExchangeRates::<Runtime>::get(Fiat::USD),
+			Some(USD_EXCHANGE_RATE)
+		);
+	});
+}
+
+#[test]
+fn unrequested_exchange_rates_are_ignored() {
+	AssetHubInfra::execute_with(|| {
+		assert_ok!(
+			<AssetHubInfra as AssetHubInfraPallet>::SystemTokenOracle::submit_exchange_rates_unsigned(
+				<AssetHubInfra as Chain>::RuntimeOrigin::none(),
+				vec![(Fiat::USD, USD_EXCHANGE_RATE)],
+			)
+		);
+	});
+	InfraRelay::execute_with(|| {
+		type Runtime = <InfraRelay as Chain>::Runtime;
+		assert!(system_token_manager::ExchangeRates::<Runtime>::get(Fiat::USD).is_none());
+	});
+}
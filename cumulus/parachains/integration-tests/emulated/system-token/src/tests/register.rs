@@ -0,0 +1,98 @@
+use super::*;
+
+#[test]
+fn requested_asset_is_reported_to_relay_chain() {
+	request_system_token();
+
+	InfraRelay::execute_with(|| {
+		type Runtime = <InfraRelay as Chain>::Runtime;
+		assert!(system_token_manager::Metadata::<Runtime>::get(system_token_on_relay()).is_some());
+		assert!(system_token_manager::RequestFiatList::<Runtime>::get().contains(&Fiat::USD));
+	});
+}
+
+#[test]
+fn register_original_system_token_works() {
+	register_system_token();
+
+	InfraRelay::execute_with(|| {
+		type RuntimeEvent = <InfraRelay as Chain>::RuntimeEvent;
+		assert_expected_events!(
+			InfraRelay,
+			vec![
+				RuntimeEvent::SystemTokenManager(
+					system_token_manager::Event::SystemTokenRegistered { original, .. }
+				) => {
+					original: *original == system_token_on_relay(),
+				},
+			]
+		);
+	});
+	InfraParaA::execute_with(|| {
+		type Assets = <InfraParaA as InfraParaAPallet>::Assets;
+		type RuntimeEvent = <InfraParaA as Chain>::RuntimeEvent;
+		assert_expected_events!(
+			InfraParaA,
+			vec![
+				RuntimeEvent::InfraParaCore(
+					cumulus_pallet_infra_parachain_core::Event::Registered { asset_id }
+				) => {
+					asset_id: *asset_id == original_system_token(),
+				},
+			]
+		);
+		assert!(<Assets as InspectSystemToken<_>>::is_system_token(&SYSTEM_TOKEN_ID));
+		assert!(<Assets as InspectSystemToken<_>>::system_token_weight(&SYSTEM_TOKEN_ID).is_ok());
+	});
+}
+
+#[test]
+fn register_without_exchange_rate_fails() {
+	request_system_token();
+
+	InfraRelay::execute_with(|| {
+		type Runtime = <InfraRelay as Chain>::Runtime;
+		assert_noop!(
+			<InfraRelay as InfraRelayPallet>::SystemTokenManager::register_system_token(
+				<InfraRelay as Chain>::RuntimeOrigin::root(),
+				RegisterType::Original {
+					system_token_id: system_token_on_relay(),
+					currency_type: None,
+				},
+				None,
+			),
+			system_token_manager::Error::<Runtime>::ExchangeRateNotRequested,
+		);
+	});
+}
+
+#[test]
+fn register_wrapped_system_token_on_sibling_works() {
+	register_system_token();
+
+	InfraRelay::execute_with(|| {
+		assert_ok!(<InfraRelay as InfraRelayPallet>::SystemTokenManager::register_system_token(
+			<InfraRelay as Chain>::RuntimeOrigin::root(),
+			RegisterType::Wrapped {
+				original: system_token_on_relay(),
+				maybe_para_id: Some(PARA_B_ID)
+			},
+			None,
+		));
+	});
+	InfraParaB::execute_with(|| {
+		type ForeignAssets = <InfraParaB as InfraParaBPallet>::ForeignAssets;
+		type RuntimeEvent = <InfraParaB as Chain>::RuntimeEvent;
+		assert_expected_events!(
+			InfraParaB,
+			vec![
+				RuntimeEvent::InfraParaCore(
+					cumulus_pallet_infra_parachain_core::Event::WrappedCreated { asset_id }
+				) => {
+					asset_id: *asset_id == wrapped_system_token(),
+				},
+			]
+		);
+		assert!(<ForeignAssets as InspectSystemToken<_>>::is_system_token(&wrapped_system_token()));
+	});
+}
@@ -67,7 +67,7 @@ use sp_runtime::{
 	infra::*,
 	traits::{AccountIdLookup, BlakeTwo256, Block as BlockT},
 	transaction_validity::{TransactionPriority, TransactionSource, TransactionValidity},
-	ApplyExtrinsicResult,
+	ApplyExtrinsicResult, FixedPointNumber, FixedU128,
 };
 
 use sp_std::prelude::*;
@@ -129,7 +129,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: create_runtime_str!("InfraBlockchain Asset Hub"),
 	impl_name: create_runtime_str!("InfraBlockchain Asset Hub"),
 	authoring_version: 1,
-	spec_version: 10000,
+	spec_version: 10001,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
 	transaction_version: 13,
@@ -276,6 +276,10 @@ impl frame_support::traits::Contains<RuntimeCall> for BootstrapCallFilter {
 	}
 }
 
+/// Calls which are free of charge while this chain is under maintenance. Every other signed call
+/// is rejected.
+pub type MaintenanceCallFilter = parachains_common::bootstrap::MaintenanceCallFilter<Runtime>;
+
 parameter_types! {
 	pub const RewardFraction: Perbill = Perbill::from_percent(80);
 }
@@ -290,6 +294,7 @@ impl pallet_system_token_tx_payment::Config for Runtime {
 	type OnChargeSystemToken =
 		TransactionFeeCharger<Runtime, SystemTokenConversion, CreditHandler>;
 	type BootstrapCallFilter = BootstrapCallFilter;
+	type MaintenanceCallFilter = MaintenanceCallFilter;
 	type PalletId = FeeTreasuryId;
 }
 
@@ -382,6 +387,40 @@ impl pallet_assets::Config<ForeignAssetsInstance> for Runtime {
 	type RemoveItemsLimit = ConstU32<1000>;
 }
 
+impl infra_asset_link::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Assets = NativeAndForeignAssets;
+	type RegistryOrigin = RootOrigin;
+	type UniversalLocation = UniversalLocation;
+	type StringLimit = StringLimit;
+	type WeightInfo = infra_asset_link::SubstrateWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = AssetLinkBenchmarkHelper;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct AssetLinkBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl infra_asset_link::BenchmarkHelper<MultiLocation> for AssetLinkBenchmarkHelper {
+	fn create_asset(id: u32) -> MultiLocation {
+		let owner: AccountId = frame_benchmarking::whitelisted_caller();
+		Assets::force_create(RuntimeOrigin::root(), id.into(), owner.into(), true, 1, None)
+			.expect("Asset should be created");
+		Assets::force_set_metadata(
+			RuntimeOrigin::root(),
+			id.into(),
+			b"Bench".to_vec(),
+			b"BNC".to_vec(),
+			12,
+			false,
+		)
+		.expect("Metadata should be set");
+		NativeAssetsPalletLocation::get()
+			.pushed_with_interior(xcm::latest::Junction::GeneralIndex(id.into()))
+			.expect("Location of the native assets pallet has room for the asset id")
+	}
+}
+
 pub struct ReanchorHandler;
 impl ReanchorSystemToken<MultiLocation> for ReanchorHandler {
 	type Error = ();
@@ -611,16 +650,89 @@ impl cumulus_pallet_parachain_system::Config for Runtime {
 
 parameter_types! {
 	pub const ActiveRequestPeriod: u32 = 100;
+	pub const FeeRateAdjustmentPeriod: u32 = HOURS;
+	pub const TargetBlockFullness: Perbill = Perbill::from_percent(25);
+	pub const MaxFeeRateAdjustment: Perbill = Perbill::from_percent(5);
+	pub MinFeeRateMultiplier: FixedU128 = FixedU128::from_rational(1, 2);
+	pub MaxFeeRateMultiplier: FixedU128 = FixedU128::from_u32(10);
+	pub const MaxFeeRateHistory: u32 = 64;
+	pub const MaxFeeRules: u32 = 4;
+	pub const MaxFeeTableUpdates: u32 = 128;
+	pub const MaxExpiringFeeRules: u32 = 128;
+	pub const MinBootstrapCollators: u32 = 1;
 }
 
+/// Inspects System Tokens and collators of this chain for the bootstrap exit criteria
+pub type BootstrapInspector = parachains_common::bootstrap::BootstrapInspector<
+	Runtime,
+	Assets,
+	ForeignAssets,
+	MinBootstrapCollators,
+>;
+
 impl cumulus_pallet_infra_parachain_core::Config for Runtime {
 	type RuntimeOrigin = RuntimeOrigin;
 	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
 	type SystemTokenId = MultiLocation;
 	type UniversalLocation = UniversalLocation;
 	type Fungibles = NativeAndForeignAssets;
 	type ActiveRequestPeriod = ActiveRequestPeriod;
 	type FeeTreasuryId = FeeTreasuryId;
+	type ParaFeeRateReporter = oracle::ParaFeeRateReporter;
+	type BootstrapInspector = BootstrapInspector;
+	type FeeRateAdjustmentPeriod = FeeRateAdjustmentPeriod;
+	type TargetBlockFullness = TargetBlockFullness;
+	type MaxFeeRateAdjustment = MaxFeeRateAdjustment;
+	type MinFeeRateMultiplier = MinFeeRateMultiplier;
+	type MaxFeeRateMultiplier = MaxFeeRateMultiplier;
+	type MaxFeeRateHistory = MaxFeeRateHistory;
+	type MaxFeeRules = MaxFeeRules;
+	type MaxFeeTableUpdates = MaxFeeTableUpdates;
+	type MaxExpiringFeeRules = MaxExpiringFeeRules;
+	type WeightInfo = cumulus_pallet_infra_parachain_core::weights::SubstrateWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = InfraParaCoreBenchmarkHelper;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct InfraParaCoreBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl cumulus_pallet_infra_parachain_core::BenchmarkHelper<MultiLocation>
+	for InfraParaCoreBenchmarkHelper
+{
+	fn create_system_token(i: u32) -> MultiLocation {
+		use frame_support::traits::{
+			tokens::fungibles::ManageSystemToken, Currency, PalletInfoAccess,
+		};
+		use xcm::latest::prelude::{GeneralIndex, PalletInstance, Parachain, X3};
+
+		let owner: AccountId = frame_benchmarking::whitelisted_caller();
+		Balances::make_free_balance_be(&owner, 1_000 * DOLLARS);
+		let para_id: u32 = ParachainInfo::parachain_id().into();
+		let id = MultiLocation::new(
+			0,
+			X3(Parachain(para_id), PalletInstance(Assets::index() as u8), GeneralIndex(i.into())),
+		);
+		let asset_id = cumulus_pallet_infra_parachain_core::reanchor_to_para(
+			id,
+			para_id,
+			UniversalLocation::get(),
+		)
+		.expect("Original of this parachain");
+		<NativeAndForeignAssets as ManageSystemToken<AccountId>>::touch(
+			owner,
+			asset_id,
+			Default::default(),
+			1,
+			b"Bench".to_vec(),
+			b"BNC".to_vec(),
+			12,
+			1_000,
+		)
+		.expect("System Token should be created");
+		id
+	}
 }
 
 impl parachain_info::Config for Runtime {}
@@ -706,6 +818,7 @@ impl pallet_collator_selection::Config for Runtime {
 parameter_types! {
 	pub const APIRequestPeriod: BlockNumber = prod_or_fast!(DAYS, 10u32);
 	pub const UnsignedPriority: TransactionPriority = TransactionPriority::max_value();
+	pub const MaxFiats: u32 = 32;
 }
 
 impl pallet_system_token_oracle::Config for Runtime {
@@ -715,6 +828,8 @@ impl pallet_system_token_oracle::Config for Runtime {
 	type SystemConfig = InfraParaCore;
 	type RequestPeriod = APIRequestPeriod;
 	type UnsignedPriority = UnsignedPriority;
+	type MaxFiats = MaxFiats;
+	type WeightInfo = pallet_system_token_oracle::SubstrateWeight<Runtime>;
 }
 
 parameter_types! {
@@ -777,7 +892,7 @@ construct_runtime!(
 			Pallet, Call, Config<T>, Storage, Inherent, Event<T>, ValidateUnsigned,
 		} = 1,
 		InfraParaCore: cumulus_pallet_infra_parachain_core::{
-			Pallet, Call, Storage, Event<T>
+			Pallet, Call, Storage, Inherent, Event<T>
 		} = 2,
 		Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent} = 3,
 		ParachainInfo: parachain_info::{Pallet, Storage, Config<T>} = 4,
@@ -799,6 +914,7 @@ construct_runtime!(
 		InfraXcm: pallet_xcm::{Pallet, Call, Storage, Event<T>, Origin, Config<T>} = 31,
 		CumulusXcm: cumulus_pallet_xcm::{Pallet, Event<T>, Origin} = 32,
 		DmpQueue: cumulus_pallet_dmp_queue::{Pallet, Call, Storage, Event<T>} = 33,
+		SystemTokenTransfer: pallet_system_token_transfer::{Pallet, Call, Event<T>} = 34,
 
 		// Handy utilities.
 		Utility: pallet_utility::{Pallet, Call, Event} = 40,
@@ -809,6 +925,7 @@ construct_runtime!(
 		Assets: pallet_assets::<Instance1>::{Pallet, Call, Storage, Event<T>, Config<T>} = 50,
 		ForeignAssets: pallet_assets::<Instance2>::{Pallet, Call, Storage, Event<T>, Config<T>} = 51,
 		Uniques: pallet_uniques::{Pallet, Call, Storage, Event<T>} = 52,
+		AssetLink: infra_asset_link::{Pallet, Call, Storage, Event<T>} = 53,
 		SystemTokenOracle: pallet_system_token_oracle::{Pallet, Call, Storage, Event<T>, ValidateUnsigned} = 55,
 		SystemTokenConversion: pallet_system_token_conversion::{Pallet, Event<T>} = 56,
 
@@ -841,7 +958,9 @@ pub type UncheckedExtrinsic =
 /// Extrinsic type that has already been checked.
 pub type CheckedExtrinsic = generic::CheckedExtrinsic<AccountId, RuntimeCall, SignedExtra>;
 /// Migrations to apply on runtime upgrade.
-pub type Migrations = ();
+pub type Migrations = (
+	cumulus_pallet_infra_parachain_core::migration::v1::MigrateToV1<Runtime>,
+);
 
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
@@ -862,6 +981,7 @@ mod benches {
 	define_benchmarks!(
 		[frame_system, SystemBench::<Runtime>]
 		[pallet_assets, Assets]
+		[infra_asset_link, AssetLink]
 		[pallet_balances, Balances]
 		[pallet_multisig, Multisig]
 		[pallet_proxy, Proxy]
@@ -871,6 +991,9 @@ mod benches {
 		[pallet_timestamp, Timestamp]
 		[pallet_collator_selection, CollatorSelection]
 		[cumulus_pallet_xcmp_queue, XcmpQueue]
+		[pallet_system_token_oracle, SystemTokenOracle]
+		[pallet_system_token_transfer, SystemTokenTransfer]
+		[cumulus_pallet_infra_parachain_core, InfraParaCore]
 		// XCM
 		[pallet_xcm, InfraXcm]
 		// NOTE: Make sure you point to the individual modules below.
@@ -973,6 +1096,33 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(3)]
+	impl cumulus_pallet_infra_parachain_core_rpc_runtime_api::InfraParaCoreApi<Block, MultiLocation, Balance, BlockNumber> for Runtime {
+		fn fee_table() -> Vec<cumulus_pallet_infra_parachain_core_rpc_runtime_api::CallFee<Balance, BlockNumber>> {
+			InfraParaCore::fee_table()
+		}
+
+		fn runtime_state() -> cumulus_pallet_infra_parachain_core_rpc_runtime_api::Mode {
+			InfraParaCore::runtime_state()
+		}
+
+		fn unmet_bootstrap_criteria() -> Vec<cumulus_pallet_infra_parachain_core_rpc_runtime_api::BootstrapCriterion> {
+			InfraParaCore::unmet_bootstrap_criteria()
+		}
+
+		fn system_config() -> Option<cumulus_pallet_infra_parachain_core_rpc_runtime_api::SystemConfig> {
+			cumulus_pallet_infra_parachain_core::RCSystemConfig::<Runtime>::get()
+		}
+
+		fn para_fee_rate() -> Option<Balance> {
+			InfraParaCore::current_para_fee_rate()
+		}
+
+		fn pending_request() -> Option<(MultiLocation, BlockNumber)> {
+			InfraParaCore::pending_request()
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
 		fn query_info(
 			uxt: <Block as BlockT>::Extrinsic,
@@ -1017,13 +1167,31 @@ impl_runtime_apis! {
 		}
 	}
 
-	impl assets_common::runtime_api::FungiblesApi<
+	impl infra_asset_link::runtime_api::AssetLinkApi<Block, MultiLocation> for Runtime {
+		fn asset_info(
+			asset_id: MultiLocation,
+		) -> Option<infra_asset_link::AssetInfo<Vec<u8>>> {
+			AssetLink::registered_asset(asset_id)
+		}
+
+		fn asset_by_location(
+			location: MultiLocation,
+		) -> Option<(MultiLocation, infra_asset_link::AssetInfo<Vec<u8>>)> {
+			AssetLink::registered_asset_at(location)
+		}
+
+		fn assets() -> Vec<(MultiLocation, infra_asset_link::AssetInfo<Vec<u8>>)> {
+			AssetLink::registered_assets()
+		}
+	}
+
+	impl infra_asset_common::runtime_api::FungiblesApi<
 		Block,
 		AccountId,
 	> for Runtime
 	{
-		fn query_account_balances(account: AccountId) -> Result<xcm::VersionedMultiAssets, assets_common::runtime_api::FungiblesAccessError> {
-			use assets_common::fungible_conversion::{convert, convert_balance};
+		fn query_account_balances(account: AccountId) -> Result<xcm::VersionedMultiAssets, infra_asset_common::runtime_api::FungiblesAccessError> {
+			use infra_asset_common::fungible_conversion::{convert, convert_balance};
 			Ok([
 				// collect pallet_balance
 				{
@@ -1040,7 +1208,12 @@ impl_runtime_apis! {
 						.iter()
 						.filter(|(_, balance)| balance > &0)
 				)?,
-				// collect ... e.g. other tokens
+				// collect pallet_assets (ForeignAssets)
+				convert::<_, _, _, _, xcm_config::ForeignAssetsConvertedConcreteId>(
+					ForeignAssets::account_balances(&account)
+						.iter()
+						.filter(|(_, balance)| balance > &0)
+				)?,
 			].concat().into())
 		}
 	}
@@ -1232,3 +1405,93 @@ cumulus_pallet_parachain_system::register_validate_block! {
 	Runtime = Runtime,
 	BlockExecutor = cumulus_pallet_aura_ext::BlockExecutor::<Runtime, Executive>,
 }
+
+#[cfg(test)]
+mod bridge_tests {
+	use super::*;
+	use bp_infra_asset_hub::SuffixedInfraSignedExtensionExt;
+	use sp_runtime::traits::{SignedExtension, Zero};
+	use xcm::v3::prelude::*;
+
+	#[test]
+	fn signed_extension_is_compatible_with_bridge_primitives() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			frame_system::BlockHash::<Runtime>::insert(BlockNumber::zero(), Hash::default());
+			let system_token =
+				xcm::v3::MultiLocation::new(0, X2(PalletInstance(50), GeneralIndex(1)));
+			let payload: SignedExtra = (
+				frame_system::CheckNonZeroSender::new(),
+				frame_system::CheckSpecVersion::new(),
+				frame_system::CheckTxVersion::new(),
+				frame_system::CheckGenesis::new(),
+				frame_system::CheckEra::from(generic::Era::Immortal),
+				frame_system::CheckNonce::from(10),
+				frame_system::CheckWeight::new(),
+				pallet_system_token_tx_payment::ChargeSystemToken::from(
+					10,
+					Some(system_token),
+					None,
+				),
+			);
+			let bridged_payload = bp_infra_asset_hub::SignedExtension::from_params(
+				VERSION.spec_version,
+				VERSION.transaction_version,
+				bp_runtime::TransactionEra::Immortal,
+				System::block_hash(BlockNumber::zero()),
+				10,
+				10,
+				Some(system_token),
+				None,
+				((), ()),
+			);
+
+			assert_eq!(payload.encode(), bridged_payload.encode());
+			assert_eq!(
+				payload.additional_signed().unwrap().encode(),
+				bridged_payload.additional_signed().unwrap().encode()
+			);
+		});
+	}
+}
+
+#[cfg(all(test, feature = "try-runtime"))]
+mod remote_tests {
+	use super::*;
+	use frame_try_runtime::{runtime_decl_for_try_runtime::TryRuntime, UpgradeCheckSelect};
+	use remote_externalities::{
+		Builder, Mode, OfflineConfig, OnlineConfig, SnapshotConfig, Transport,
+	};
+	use std::env::var;
+
+	/// Run `Migrations` and the `try_state` checks against the state of a live chain.
+	///
+	/// - `SNAP`: state snapshot to replay. The test runs offline if `WS` is not given.
+	/// - `WS`: node to scrape the state from. The state is written to `SNAP` if given.
+	#[tokio::test]
+	async fn run_migrations() {
+		if var("RUN_MIGRATION_TESTS").is_err() {
+			return
+		}
+
+		sp_tracing::try_init_simple();
+		let transport: Option<Transport> = var("WS").map(Into::into).ok();
+		let state_snapshot: Option<SnapshotConfig> = var("SNAP").map(Into::into).ok();
+		let mode = match (transport, state_snapshot) {
+			(None, Some(state_snapshot)) => Mode::Offline(OfflineConfig { state_snapshot }),
+			(Some(transport), Some(state_snapshot)) => Mode::OfflineOrElseOnline(
+				OfflineConfig { state_snapshot: state_snapshot.clone() },
+				OnlineConfig {
+					transport,
+					state_snapshot: Some(state_snapshot),
+					..Default::default()
+				},
+			),
+			(transport, None) => Mode::Online(OnlineConfig {
+				transport: transport.unwrap_or("ws://127.0.0.1:9944".to_string().into()),
+				..Default::default()
+			}),
+		};
+		let mut ext = Builder::<Block>::default().mode(mode).build().await.unwrap();
+		ext.execute_with(|| Runtime::on_runtime_upgrade(UpgradeCheckSelect::All));
+	}
+}
@@ -1,5 +1,6 @@
 use crate::*;
 use codec::{Decode, Encode};
+use cumulus_pallet_infra_parachain_core::ReportParaFeeRate;
 use pallet_system_token_oracle::SystemTokenOracleInterface;
 use xcm::latest::prelude::*;
 
@@ -17,38 +18,53 @@ enum RelayRuntimePallets {
 enum SystemTokenManagerCalls {
 	#[codec(index = 4)]
 	UpdateExchangeRates(Vec<(Fiat, ExchangeRate)>),
+	#[codec(index = 6)]
+	ReportParaFeeRate(u128),
+}
+
+/// Send `call` of the system token manager pallet to the Relay chain with the origin of this
+/// parachain.
+fn send_to_relay(call: SystemTokenManagerCalls, what: &str) {
+	let call = RelayRuntimePallets::SystemTokenManager(call);
+	let message = Xcm(vec![
+		Instruction::UnpaidExecution { weight_limit: WeightLimit::Unlimited, check_origin: None },
+		Instruction::Transact {
+			origin_kind: OriginKind::Native,
+			require_weight_at_most: Weight::from_parts(1_000_000_000, 200000),
+			call: call.encode().into(),
+		},
+	]);
+
+	match InfraXcm::send_xcm(Here, MultiLocation::parent(), message) {
+		Ok(_) => log::info!(
+			target: "runtime::system-token-oracle",
+			"Instruction to `{}` sent successfully.",
+			what,
+		),
+		Err(e) => log::error!(
+			target: "runtime::system-token-oracle",
+			"Instruction to `{}` failed to send: {:?}",
+			what,
+			e
+		),
+	}
 }
 
 /// Type that implements `SystemTokenOracleInterface`.
 pub struct SystemTokenOracle;
 impl SystemTokenOracleInterface for SystemTokenOracle {
 	fn submit_exchange_rates(exchange_rates: Vec<(Fiat, ExchangeRate)>) {
-		use crate::oracle::SystemTokenManagerCalls::UpdateExchangeRates;
-		let update_exchange_rate_call = RelayRuntimePallets::SystemTokenManager(
-			UpdateExchangeRates(exchange_rates),
+		send_to_relay(
+			SystemTokenManagerCalls::UpdateExchangeRates(exchange_rates),
+			"exchange rate",
 		);
-		let message = Xcm(vec![
-			Instruction::UnpaidExecution {
-				weight_limit: WeightLimit::Unlimited,
-				check_origin: None,
-			},
-			Instruction::Transact {
-				origin_kind: OriginKind::Native,
-				require_weight_at_most: Weight::from_parts(1_000_000_000, 200000),
-				call: update_exchange_rate_call.encode().into(),
-			},
-		]);
+	}
+}
 
-		match InfraXcm::send_xcm(Here, MultiLocation::parent(), message.clone()) {
-			Ok(_) => log::info!(
-				target: "runtime::system-token-oracle",
-				"Instruction to `exchange rate` sent successfully."
-			),
-			Err(e) => log::error!(
-				target: "runtime::system-token-oracle",
-				"Instruction to `exchange rate` failed to send: {:?}",
-				e
-			),
-		}
+/// Type that reports the para fee rate adjusted by `InfraParaCore` to the Relay chain.
+pub struct ParaFeeRateReporter;
+impl ReportParaFeeRate<Balance> for ParaFeeRateReporter {
+	fn report_para_fee_rate(fee_rate: Balance) {
+		send_to_relay(SystemTokenManagerCalls::ReportParaFeeRate(fee_rate), "para fee rate");
 	}
 }
@@ -20,16 +20,19 @@ use super::{
 };
 use frame_support::{
 	match_types, parameter_types,
-	traits::{ConstU32, Contains, Everything, Nothing, PalletInfoAccess},
+	traits::{
+		fungibles::InspectSystemToken, ConstU32, Contains, ContainsPair, Everything, Nothing,
+		PalletInfoAccess,
+	},
 };
 use infra_asset_common::{
 	matching::{StartsWith, StartsWithExplicitGlobalConsensus},
-	AssetFeeAsExistentialDepositMultiplier,
+	AssetFeeAsExistentialDepositMultiplier, AssetIdForNativeAssetsConvert,
 };
 
 use pallet_xcm::XcmPassthrough;
 use parachain_primitives::primitives::Sibling;
-use sp_runtime::traits::ConvertInto;
+use sp_runtime::traits::{ConvertInto, MaybeEquivalence};
 use xcm::latest::prelude::*;
 use xcm_builder::{
 	AccountId32Aliases, AllowExplicitUnpaidExecutionFrom, AllowSubscriptionsFrom,
@@ -309,8 +312,8 @@ impl xcm_executor::Config for XcmConfig {
 	type XcmSender = XcmRouter;
 	type AssetTransactor = AssetTransactors;
 	type OriginConverter = XcmOriginToTransactDispatchOrigin;
-	type IsReserve = ();
-	type IsTeleporter = Everything;
+	type IsReserve = pallet_system_token_transfer::SystemTokenFromReserve;
+	type IsTeleporter = RelayChainSystemTokens;
 	type UniversalLocation = UniversalLocation;
 	type Barrier = Barrier;
 	type Weigher = WeightInfoBounds<
@@ -416,6 +419,83 @@ impl pallet_xcm::Config for Runtime {
 	type RemoteLockConsumerIdentifier = ();
 }
 
+/// System Tokens which can be moved by `SystemTokenTransfer`. Either issued here through `Assets`
+/// or wrapped through `ForeignAssets`.
+pub struct SystemTokenLocations;
+impl Contains<MultiLocation> for SystemTokenLocations {
+	fn contains(location: &MultiLocation) -> bool {
+		if let Some(asset_id) =
+			AssetIdForNativeAssetsConvert::<NativeAssetsPalletLocation>::convert(location)
+		{
+			return <Assets as InspectSystemToken<AccountId>>::is_system_token(&asset_id)
+		}
+		<ForeignAssets as InspectSystemToken<AccountId>>::is_system_token(location)
+	}
+}
+
+/// System Tokens issued by the Relay Chain are teleported between the Relay Chain and this chain
+/// once they are registered as System Tokens in `ForeignAssets`. Every other System Token transfer
+/// goes through its reserve.
+pub struct RelayChainSystemTokens;
+impl ContainsPair<MultiAsset, MultiLocation> for RelayChainSystemTokens {
+	fn contains(asset: &MultiAsset, dest: &MultiLocation) -> bool {
+		let location = match asset.id {
+			Concrete(location) => location,
+			Abstract(_) => return false,
+		};
+		*dest == MultiLocation::parent() &&
+			pallet_system_token_transfer::reserve_of(&location) == Some(*dest) &&
+			<ForeignAssets as InspectSystemToken<AccountId>>::is_system_token(&location)
+	}
+}
+
+/// Issue a System Token through `Assets` to the origin and transfer it to the Relay Chain.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct SystemTokenTransferBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_system_token_transfer::BenchmarkHelper for SystemTokenTransferBenchmarkHelper {
+	fn system_token_transfer(
+		origin: &MultiLocation,
+	) -> Option<(MultiLocation, u128, MultiLocation)> {
+		use frame_support::traits::fungibles::Mutate;
+		use xcm_executor::traits::ConvertLocation;
+
+		let who = LocationToAccountId::convert_location(origin)?;
+		let asset_id = 1;
+		Assets::force_create(
+			RuntimeOrigin::root(),
+			asset_id.into(),
+			who.clone().into(),
+			true,
+			1,
+			None,
+		)
+		.ok()?;
+		<Assets as Mutate<AccountId>>::mint_into(asset_id, &who, 1_000_000_000_000).ok()?;
+		let asset = NativeAssetsPalletLocation::get()
+			.pushed_with_interior(GeneralIndex(asset_id.into()))
+			.ok()?;
+		Some((asset, 1_000_000_000, Parent.into()))
+	}
+}
+
+impl pallet_system_token_transfer::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type ExecuteXcmOrigin = EnsureXcmOrigin<RuntimeOrigin, LocalOriginToLocation>;
+	type XcmExecutor = XcmExecutor<XcmConfig>;
+	type Weigher = WeightInfoBounds<
+		crate::weights::xcm::StatemintXcmWeight<RuntimeCall>,
+		RuntimeCall,
+		MaxInstructions,
+	>;
+	type UniversalLocation = UniversalLocation;
+	type SystemTokens = SystemTokenLocations;
+	type IsTeleporter = RelayChainSystemTokens;
+	type WeightInfo = pallet_system_token_transfer::weights::SubstrateWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = SystemTokenTransferBenchmarkHelper;
+}
+
 impl cumulus_pallet_xcm::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type XcmExecutor = XcmExecutor<XcmConfig>;
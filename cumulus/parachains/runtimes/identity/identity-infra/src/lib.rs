@@ -0,0 +1,1217 @@
+// Copyright (C) 2021-2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Infra Identity Runtime
+//!
+//! Infra Identity is a parachain that hosts decentralized identifiers, the ownership of resources
+//! and the market of data delegated by their owners.
+//!
+//! ## Identity
+//!
+//! - DIDs and Verifiable Credentials: Configuration of the `did-core` modules.
+//! - Ownership of URIs: Configuration of `pallet-urauth`.
+//! - Data trade: Configuration of `pallet-data-market`.
+//!
+//! ## Other Functionality
+//!
+//! ### Transaction Fees
+//!
+//! Infra Identity has no native token. Transaction fees are paid in System Tokens through
+//! `pallet-system-token-tx-payment`, following the fee table and the para fee rate managed by
+//! `InfraParaCore`.
+//!
+//! ### Governance
+//!
+//! Infra Identity defers its governance (namely, its `Root` origin), to its Relay Chain parent.
+//!
+//! ### XCM
+//!
+//! System Tokens are teleported from the Relay Chain or transferred from their reserve through
+//! `pallet-system-token-transfer`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![recursion_limit = "256"]
+
+// Make the WASM binary available.
+#[cfg(feature = "std")]
+include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
+
+pub mod constants;
+use constants::{currency::*, fee::WeightToFee};
+pub mod relay;
+mod weights;
+pub mod xcm_config;
+use xcm_config::UniversalLocation;
+
+use cumulus_pallet_parachain_system::RelayNumberStrictlyIncreases;
+use sp_api::impl_runtime_apis;
+use sp_core::{crypto::KeyTypeId, Get, OpaqueMetadata};
+use sp_runtime::{
+	create_runtime_str, generic, impl_opaque_keys,
+	infra::*,
+	traits::{AccountIdLookup, BlakeTwo256, Block as BlockT},
+	transaction_validity::{TransactionSource, TransactionValidity},
+	ApplyExtrinsicResult, FixedPointNumber, FixedU128,
+};
+
+use frame_support::{
+	construct_runtime,
+	dispatch::DispatchClass,
+	parameter_types,
+	traits::{
+		tokens::fungibles::{Balanced, Credit, UnionOf},
+		AsEnsureOriginWithArg, ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, EitherOfDiverse,
+	},
+	weights::{ConstantMultiplier, Weight},
+	PalletId,
+};
+use frame_system::{
+	limits::{BlockLength, BlockWeights},
+	EnsureRoot,
+};
+use sp_arithmetic::Perbill;
+use sp_std::prelude::*;
+#[cfg(feature = "std")]
+use sp_version::NativeVersion;
+use sp_version::RuntimeVersion;
+
+use pallet_system_token_tx_payment::{HandleCredit, RewardOriginInfo, TransactionFeeCharger};
+use parachains_common::{
+	constants::*, impls::DealWithFees, infra_relay::consensus::*, opaque::*, AccountId, AuraId,
+	Balance, BlockNumber, Hash, Nonce, Signature,
+};
+use xcm_config::{
+	NativeAssetsPalletLocation, NativeLocation, XcmConfig, XcmOriginToTransactDispatchOrigin,
+};
+
+use infra_asset_common::{
+	local_and_foreign_assets::LocalFromLeft, AssetIdForNativeAssets, AssetIdForNativeAssetsConvert,
+};
+
+#[cfg(any(feature = "std", test))]
+pub use sp_runtime::BuildStorage;
+
+// Polkadot imports
+use pallet_xcm::{EnsureXcm, IsMajorityOfBody};
+use runtime_common::{prod_or_fast, BlockHashCount, SlowAdjustingFeeUpdate};
+use xcm::latest::{BodyId, MultiLocation};
+use xcm_executor::XcmExecutor;
+
+use weights::{BlockExecutionWeight, ExtrinsicBaseWeight, RocksDbWeight};
+
+impl_opaque_keys! {
+	pub struct SessionKeys {
+		pub aura: Aura,
+	}
+}
+
+#[sp_version::runtime_version]
+pub const VERSION: RuntimeVersion = RuntimeVersion {
+	spec_name: create_runtime_str!("InfraBlockchain Identity"),
+	impl_name: create_runtime_str!("InfraBlockchain Identity"),
+	authoring_version: 1,
+	spec_version: 10000,
+	impl_version: 0,
+	apis: RUNTIME_API_VERSIONS,
+	transaction_version: 1,
+	state_version: 0,
+};
+
+/// The version information used to identify this runtime when compiled natively.
+#[cfg(feature = "std")]
+pub fn native_version() -> NativeVersion {
+	NativeVersion { runtime_version: VERSION, can_author_with: Default::default() }
+}
+
+parameter_types! {
+	pub const Version: RuntimeVersion = VERSION;
+	pub RuntimeBlockLength: BlockLength =
+		BlockLength::max_with_normal_ratio(5 * 1024 * 1024, NORMAL_DISPATCH_RATIO);
+	pub RuntimeBlockWeights: BlockWeights = BlockWeights::builder()
+		.base_block(BlockExecutionWeight::get())
+		.for_class(DispatchClass::all(), |weights| {
+			weights.base_extrinsic = ExtrinsicBaseWeight::get();
+		})
+		.for_class(DispatchClass::Normal, |weights| {
+			weights.max_total = Some(NORMAL_DISPATCH_RATIO * MAXIMUM_BLOCK_WEIGHT);
+		})
+		.for_class(DispatchClass::Operational, |weights| {
+			weights.max_total = Some(MAXIMUM_BLOCK_WEIGHT);
+			// Operational transactions have some extra reserved space, so that they
+			// are included even if block reached `MAXIMUM_BLOCK_WEIGHT`.
+			weights.reserved = Some(
+				MAXIMUM_BLOCK_WEIGHT - NORMAL_DISPATCH_RATIO * MAXIMUM_BLOCK_WEIGHT
+			);
+		})
+		.avg_block_initialization(AVERAGE_ON_INITIALIZE_RATIO)
+		.build_or_panic();
+	pub const SS58Prefix: u8 = 0;
+}
+
+// Configure FRAME pallets to include in runtime.
+impl frame_system::Config for Runtime {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = RuntimeBlockWeights;
+	type BlockLength = RuntimeBlockLength;
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Nonce = Nonce;
+	type Hash = Hash;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = AccountIdLookup<AccountId, ()>;
+	type Block = Block;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = BlockHashCount;
+	type DbWeight = RocksDbWeight;
+	type Version = Version;
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = weights::frame_system::WeightInfo<Runtime>;
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = cumulus_pallet_parachain_system::ParachainSetCode<Self>;
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+impl pallet_timestamp::Config for Runtime {
+	/// A timestamp: milliseconds since the unix epoch.
+	type Moment = u64;
+	type OnTimestampSet = Aura;
+	type MinimumPeriod = ConstU64<{ SLOT_DURATION / 2 }>;
+	type WeightInfo = weights::pallet_timestamp::WeightInfo<Runtime>;
+}
+
+impl pallet_authorship::Config for Runtime {
+	type FindAuthor = pallet_session::FindAccountFromAuthorIndex<Self, Aura>;
+	type EventHandler = (CollatorSelection,);
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: Balance = EXISTENTIAL_DEPOSIT;
+}
+
+impl pallet_balances::Config for Runtime {
+	type MaxLocks = ConstU32<50>;
+	/// The type for recording an account's balance.
+	type Balance = Balance;
+	/// The ubiquitous event type.
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = weights::pallet_balances::WeightInfo<Runtime>;
+	type MaxReserves = ConstU32<50>;
+	type ReserveIdentifier = [u8; 8];
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type FreezeIdentifier = ();
+	type MaxHolds = ConstU32<0>;
+	type MaxFreezes = ConstU32<0>;
+}
+
+parameter_types! {
+	/// Relay Chain `TransactionByteFee` / 10
+	pub const TransactionByteFee: Balance = 1 * MILLICENTS;
+}
+
+impl pallet_transaction_payment::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type OnChargeTransaction =
+		pallet_transaction_payment::CurrencyAdapter<Balances, DealWithFees<Runtime>>;
+	type WeightToFee = WeightToFee;
+	type LengthToFee = ConstantMultiplier<Balance, TransactionByteFee>;
+	type FeeMultiplierUpdate = SlowAdjustingFeeUpdate<Self>;
+	type OperationalFeeMultiplier = ConstU8<5>;
+}
+
+parameter_types! {
+	pub const FeeTreasuryId: PalletId = PalletId(*b"infrapid");
+}
+
+/// Calls which are free of charge until a System Token is registered on this chain.
+pub struct BootstrapCallFilter;
+impl frame_support::traits::Contains<RuntimeCall> for BootstrapCallFilter {
+	#[cfg(not(feature = "fast-runtime"))]
+	fn contains(call: &RuntimeCall) -> bool {
+		match call {
+			RuntimeCall::Assets(
+				NativeAssetsCall::create { .. } |
+				NativeAssetsCall::set_metadata { .. } |
+				NativeAssetsCall::mint { .. },
+			) |
+			RuntimeCall::InfraXcm(pallet_xcm::Call::limited_teleport_assets { .. }) |
+			RuntimeCall::InfraParaCore(
+				cumulus_pallet_infra_parachain_core::Call::request_register_system_token { .. },
+			) => true,
+			_ => false,
+		}
+	}
+	#[cfg(feature = "fast-runtime")]
+	fn contains(call: &RuntimeCall) -> bool {
+		match call {
+			_ => true,
+		}
+	}
+}
+
+/// Calls which are free of charge while this chain is under maintenance. Every other signed call
+/// is rejected.
+pub type MaintenanceCallFilter = parachains_common::bootstrap::MaintenanceCallFilter<Runtime>;
+
+parameter_types! {
+	pub const RewardFraction: Perbill = Perbill::from_percent(80);
+}
+
+impl pallet_system_token_tx_payment::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type SystemConfig = InfraParaCore;
+	type PoTHandler = ParachainSystem;
+	type Fungibles = NativeAndForeignAssets;
+	type RewardFraction = RewardFraction;
+	type RewardOrigin = RewardOrigin;
+	type OnChargeSystemToken = TransactionFeeCharger<Runtime, SystemTokenConversion, CreditHandler>;
+	type BootstrapCallFilter = BootstrapCallFilter;
+	type MaintenanceCallFilter = MaintenanceCallFilter;
+	type PalletId = FeeTreasuryId;
+}
+
+pub struct RewardOrigin;
+impl RewardOriginInfo for RewardOrigin {
+	type Origin = u32;
+	fn reward_origin_info() -> voting::RewardOrigin<Self::Origin> {
+		let para_id =
+			<parachain_info::Pallet<Runtime> as Get<cumulus_primitives_core::ParaId>>::get().into();
+		voting::RewardOrigin::Remote(para_id)
+	}
+}
+
+pub struct CreditHandler;
+impl HandleCredit<AccountId, NativeAndForeignAssets> for CreditHandler {
+	fn handle_credit(credit: Credit<AccountId, NativeAndForeignAssets>) {
+		if let Some(author) = pallet_authorship::Pallet::<Runtime>::author() {
+			let _ = <NativeAndForeignAssets as Balanced<AccountId>>::resolve(&author, credit);
+		}
+	}
+}
+
+impl pallet_system_token_conversion::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = SystemTokenBalance;
+	type AssetKind = xcm::v3::MultiLocation;
+	type Fungibles = NativeAndForeignAssets;
+	type SystemConfig = InfraParaCore;
+}
+
+parameter_types! {
+	pub const DepositToCreateAsset: Balance = 1 * DOLLARS; // 1 UNITS deposit to create fungible asset class
+	pub const DepositToMaintainAsset: Balance = deposit(1, 16);
+	pub const ApprovalDeposit: Balance = EXISTENTIAL_DEPOSIT;
+	pub const StringLimit: u32 = 50;
+	/// Key = 32 bytes, Value = 36 bytes (32+1+1+1+1)
+	// https://github.com/paritytech/substrate/blob/069917b/frame/assets/src/lib.rs#L257L271
+	pub const MetadataDepositBase: Balance = deposit(1, 68);
+	pub const MetadataDepositPerByte: Balance = deposit(0, 1);
+	pub const ExecutiveBody: BodyId = BodyId::Executive;
+}
+
+/// We allow root and the Relay Chain council to execute privileged asset operations.
+pub type RootOrigin = EnsureRoot<AccountId>;
+
+pub type NativeAssetsInstance = pallet_assets::Instance1;
+type NativeAssetsCall = pallet_assets::Call<Runtime, NativeAssetsInstance>;
+impl pallet_assets::Config<NativeAssetsInstance> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type AssetId = AssetIdForNativeAssets;
+	type AssetIdParameter = codec::Compact<AssetIdForNativeAssets>;
+	type SystemTokenWeight = SystemTokenWeight;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<AccountId>>;
+	type ForceOrigin = RootOrigin;
+	type AssetDeposit = DepositToCreateAsset;
+	type AssetAccountDeposit = DepositToMaintainAsset;
+	type MetadataDepositBase = MetadataDepositBase;
+	type MetadataDepositPerByte = MetadataDepositPerByte;
+	type ApprovalDeposit = ApprovalDeposit;
+	type StringLimit = StringLimit;
+	type Freezer = ();
+	type Extra = ();
+	type CallbackHandle = ();
+	type WeightInfo = ();
+	type RemoveItemsLimit = ConstU32<1000>;
+}
+
+pub type ForeignAssetsInstance = pallet_assets::Instance2;
+type ForeignAssetsCall = pallet_assets::Call<Runtime, ForeignAssetsInstance>;
+impl pallet_assets::Config<ForeignAssetsInstance> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type AssetId = xcm::v3::MultiLocation;
+	type AssetIdParameter = xcm::v3::MultiLocation;
+	type SystemTokenWeight = SystemTokenWeight;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<AccountId>>;
+	type ForceOrigin = RootOrigin;
+	type AssetDeposit = DepositToCreateAsset;
+	type AssetAccountDeposit = DepositToMaintainAsset;
+	type MetadataDepositBase = MetadataDepositBase;
+	type MetadataDepositPerByte = MetadataDepositPerByte;
+	type ApprovalDeposit = ApprovalDeposit;
+	type StringLimit = StringLimit;
+	type Freezer = ();
+	type Extra = ();
+	type CallbackHandle = ();
+	type WeightInfo = ();
+	type RemoveItemsLimit = ConstU32<1000>;
+}
+
+pub struct ReanchorHandler;
+impl ReanchorSystemToken<MultiLocation> for ReanchorHandler {
+	type Error = ();
+	fn reanchor_system_token(l: &mut MultiLocation) -> Result<(), Self::Error> {
+		let target = MultiLocation::parent();
+		let context = UniversalLocation::get();
+		l.reanchor(&target, context).map_err(|_| {})?;
+		Ok(())
+	}
+}
+
+/// Union fungibles implementation for `Assets` and `ForeignAssets`.
+pub type NativeAndForeignAssets = UnionOf<
+	Assets,
+	ForeignAssets,
+	LocalFromLeft<
+		AssetIdForNativeAssetsConvert<NativeAssetsPalletLocation>,
+		AssetIdForNativeAssets,
+		xcm::v3::MultiLocation,
+	>,
+	xcm::v3::MultiLocation,
+	AccountId,
+	ReanchorHandler,
+>;
+
+parameter_types! {
+	// One storage item; key size is 32; value is size 4+4+16+32 bytes = 56 bytes.
+	pub const DepositBase: Balance = deposit(1, 88);
+	// Additional storage item size of 32 bytes.
+	pub const DepositFactor: Balance = deposit(0, 32);
+	pub const MaxSignatories: u32 = 100;
+}
+
+impl pallet_multisig::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type Currency = Balances;
+	type DepositBase = DepositBase;
+	type DepositFactor = DepositFactor;
+	type MaxSignatories = MaxSignatories;
+	type WeightInfo = weights::pallet_multisig::WeightInfo<Runtime>;
+}
+
+impl pallet_utility::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type PalletsOrigin = OriginCaller;
+	type WeightInfo = weights::pallet_utility::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	pub const ReservedXcmpWeight: Weight = MAXIMUM_BLOCK_WEIGHT.saturating_div(4);
+	pub const ReservedDmpWeight: Weight = MAXIMUM_BLOCK_WEIGHT.saturating_div(4);
+}
+
+type ConsensusHook = cumulus_pallet_aura_ext::FixedVelocityConsensusHook<
+	Runtime,
+	RELAY_CHAIN_SLOT_DURATION_MILLIS,
+	BLOCK_PROCESSING_VELOCITY,
+	UNINCLUDED_SEGMENT_CAPACITY,
+>;
+
+impl cumulus_pallet_parachain_system::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type OnSystemEvent = ();
+	type SelfParaId = parachain_info::Pallet<Runtime>;
+	type DmpMessageHandler = DmpQueue;
+	type ReservedDmpWeight = ReservedDmpWeight;
+	type OutboundXcmpMessageSource = XcmpQueue;
+	type XcmpMessageHandler = XcmpQueue;
+	type ReservedXcmpWeight = ReservedXcmpWeight;
+	type UpdateRCConfig = InfraParaCore;
+	type CheckAssociatedRelayNumber = RelayNumberStrictlyIncreases;
+	type ConsensusHook = ConsensusHook;
+}
+
+parameter_types! {
+	pub const ActiveRequestPeriod: u32 = 100;
+	pub const FeeRateAdjustmentPeriod: u32 = HOURS;
+	pub const TargetBlockFullness: Perbill = Perbill::from_percent(25);
+	pub const MaxFeeRateAdjustment: Perbill = Perbill::from_percent(5);
+	pub MinFeeRateMultiplier: FixedU128 = FixedU128::from_rational(1, 2);
+	pub MaxFeeRateMultiplier: FixedU128 = FixedU128::from_u32(10);
+	pub const MaxFeeRateHistory: u32 = 64;
+	pub const MaxFeeRules: u32 = 4;
+	pub const MaxFeeTableUpdates: u32 = 128;
+	pub const MaxExpiringFeeRules: u32 = 128;
+	pub const MinBootstrapCollators: u32 = 1;
+}
+
+/// Inspects System Tokens and collators of this chain for the bootstrap exit criteria
+pub type BootstrapInspector = parachains_common::bootstrap::BootstrapInspector<
+	Runtime,
+	Assets,
+	ForeignAssets,
+	MinBootstrapCollators,
+>;
+
+impl cumulus_pallet_infra_parachain_core::Config for Runtime {
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type SystemTokenId = MultiLocation;
+	type UniversalLocation = UniversalLocation;
+	type Fungibles = NativeAndForeignAssets;
+	type ActiveRequestPeriod = ActiveRequestPeriod;
+	type FeeTreasuryId = FeeTreasuryId;
+	type ParaFeeRateReporter = relay::ParaFeeRateReporter;
+	type BootstrapInspector = BootstrapInspector;
+	type FeeRateAdjustmentPeriod = FeeRateAdjustmentPeriod;
+	type TargetBlockFullness = TargetBlockFullness;
+	type MaxFeeRateAdjustment = MaxFeeRateAdjustment;
+	type MinFeeRateMultiplier = MinFeeRateMultiplier;
+	type MaxFeeRateMultiplier = MaxFeeRateMultiplier;
+	type MaxFeeRateHistory = MaxFeeRateHistory;
+	type MaxFeeRules = MaxFeeRules;
+	type MaxFeeTableUpdates = MaxFeeTableUpdates;
+	type MaxExpiringFeeRules = MaxExpiringFeeRules;
+	type WeightInfo = cumulus_pallet_infra_parachain_core::weights::SubstrateWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = InfraParaCoreBenchmarkHelper;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct InfraParaCoreBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl cumulus_pallet_infra_parachain_core::BenchmarkHelper<MultiLocation>
+	for InfraParaCoreBenchmarkHelper
+{
+	fn create_system_token(i: u32) -> MultiLocation {
+		use frame_support::traits::{
+			tokens::fungibles::ManageSystemToken, Currency, PalletInfoAccess,
+		};
+		use xcm::latest::prelude::{GeneralIndex, PalletInstance, Parachain, X3};
+
+		let owner: AccountId = frame_benchmarking::whitelisted_caller();
+		Balances::make_free_balance_be(&owner, 1_000 * DOLLARS);
+		let para_id: u32 = ParachainInfo::parachain_id().into();
+		let id = MultiLocation::new(
+			0,
+			X3(Parachain(para_id), PalletInstance(Assets::index() as u8), GeneralIndex(i.into())),
+		);
+		let asset_id = cumulus_pallet_infra_parachain_core::reanchor_to_para(
+			id,
+			para_id,
+			UniversalLocation::get(),
+		)
+		.expect("Original of this parachain");
+		<NativeAndForeignAssets as ManageSystemToken<AccountId>>::touch(
+			owner,
+			asset_id,
+			Default::default(),
+			1,
+			b"Bench".to_vec(),
+			b"BNC".to_vec(),
+			12,
+			1_000,
+		)
+		.expect("System Token should be created");
+		id
+	}
+}
+
+impl parachain_info::Config for Runtime {}
+
+impl cumulus_pallet_aura_ext::Config for Runtime {}
+
+impl cumulus_pallet_xcmp_queue::Config for Runtime {
+	type WeightInfo = weights::cumulus_pallet_xcmp_queue::WeightInfo<Runtime>;
+	type RuntimeEvent = RuntimeEvent;
+	type XcmExecutor = XcmExecutor<XcmConfig>;
+	type ChannelInfo = ParachainSystem;
+	type VersionWrapper = InfraXcm;
+	type ExecuteOverweightOrigin = EnsureRoot<AccountId>;
+	type ControllerOrigin = EitherOfDiverse<
+		EnsureRoot<AccountId>,
+		EnsureXcm<IsMajorityOfBody<NativeLocation, ExecutiveBody>>,
+	>;
+	type ControllerOriginConverter = XcmOriginToTransactDispatchOrigin;
+	type PriceForSiblingDelivery = ();
+}
+
+impl cumulus_pallet_dmp_queue::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type XcmExecutor = XcmExecutor<XcmConfig>;
+	type ExecuteOverweightOrigin = EnsureRoot<AccountId>;
+}
+
+parameter_types! {
+	pub const Period: u32 = 6 * HOURS;
+	pub const Offset: u32 = 0;
+}
+
+impl pallet_session::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type ValidatorId = <Self as frame_system::Config>::AccountId;
+	// we don't have stash and controller, thus we don't need the convert as well.
+	type ValidatorIdOf = pallet_collator_selection::IdentityCollator;
+	type ShouldEndSession = pallet_session::PeriodicSessions<Period, Offset>;
+	type NextSessionRotation = pallet_session::PeriodicSessions<Period, Offset>;
+	type SessionManager = CollatorSelection;
+	// Essentially just Aura, but let's be pedantic.
+	type SessionHandler = <SessionKeys as sp_runtime::traits::OpaqueKeys>::KeyTypeIdProviders;
+	type Keys = SessionKeys;
+	type WeightInfo = weights::pallet_session::WeightInfo<Runtime>;
+}
+
+impl pallet_aura::Config for Runtime {
+	type AuthorityId = AuraId;
+	type DisabledValidators = ();
+	type MaxAuthorities = ConstU32<100_000>;
+	type AllowMultipleBlocksPerSlot = ConstBool<false>;
+	#[cfg(feature = "experimental")]
+	type SlotDuration = pallet_aura::MinimumPeriodTimesTwo<Self>;
+}
+
+parameter_types! {
+	pub const PotId: PalletId = PalletId(*b"PotStake");
+	pub const SessionLength: BlockNumber = 6 * HOURS;
+}
+
+/// We allow root and the Relay Chain council to execute privileged collator selection operations.
+pub type CollatorSelectionUpdateOrigin = EitherOfDiverse<
+	EnsureRoot<AccountId>,
+	EnsureXcm<IsMajorityOfBody<NativeLocation, ExecutiveBody>>,
+>;
+
+impl pallet_collator_selection::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type UpdateOrigin = CollatorSelectionUpdateOrigin;
+	type PotId = PotId;
+	type MaxCandidates = ConstU32<100>;
+	type MinEligibleCollators = ConstU32<4>;
+	type MaxInvulnerables = ConstU32<20>;
+	// should be a multiple of session or things will get inconsistent
+	type KickThreshold = Period;
+	type ValidatorId = <Self as frame_system::Config>::AccountId;
+	type ValidatorIdOf = pallet_collator_selection::IdentityCollator;
+	type ValidatorRegistration = Session;
+	type WeightInfo = weights::pallet_collator_selection::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	pub const MaxDidDocRefSize: u32 = 1024;
+	pub const MaxDidServiceEndpointIdSize: u32 = 1024;
+	pub const MaxDidServiceEndpointOrigins: u32 = 64;
+	pub const MaxDidServiceEndpointOriginSize: u32 = 1025;
+	pub const MaxAccumulatorLabelSize: u32 = 512;
+	pub const MaxAccumulatorParamsSize: u32 = 512;
+	pub const MaxAccumulatorPublicKeySize: u32 = 128;
+	pub const MaxAccumulatorAccumulatedSize: u32 = 128;
+	pub const MaxStatusListCredentialSize: u32 = 40_000;
+	pub const MinStatusListCredentialSize: u32 = 500;
+	pub const MaxIriSize: u32 = 1024;
+	pub const MaxBlobSize: u32 = 1024;
+	pub const MaxOffchainParamsLabelSize: u32 = 512;
+	pub const MaxOffchainParamsBytesSize: u32 = 65_536;
+	pub const MaxBBSPublicKeySize: u32 = 128;
+	pub const MaxBBSPlusPublicKeySize: u32 = 128;
+	pub const MaxPSPublicKeySize: u32 = 65_536;
+	pub const MaxMasterMembers: u32 = 25;
+	pub const MaxPolicyControllers: u32 = 15;
+}
+
+impl did_core::common::Limits for Runtime {
+	type MaxDidDocRefSize = MaxDidDocRefSize;
+	type MaxDidServiceEndpointIdSize = MaxDidServiceEndpointIdSize;
+	type MaxDidServiceEndpointOrigins = MaxDidServiceEndpointOrigins;
+	type MaxDidServiceEndpointOriginSize = MaxDidServiceEndpointOriginSize;
+
+	type MaxAccumulatorLabelSize = MaxAccumulatorLabelSize;
+	type MaxAccumulatorParamsSize = MaxAccumulatorParamsSize;
+	type MaxAccumulatorPublicKeySize = MaxAccumulatorPublicKeySize;
+	type MaxAccumulatorAccumulatedSize = MaxAccumulatorAccumulatedSize;
+
+	type MaxStatusListCredentialSize = MaxStatusListCredentialSize;
+	type MinStatusListCredentialSize = MinStatusListCredentialSize;
+
+	type MaxIriSize = MaxIriSize;
+	type MaxBlobSize = MaxBlobSize;
+
+	type MaxOffchainParamsLabelSize = MaxOffchainParamsLabelSize;
+	type MaxOffchainParamsBytesSize = MaxOffchainParamsBytesSize;
+	type MaxBBSPublicKeySize = MaxBBSPublicKeySize;
+	type MaxBBSPlusPublicKeySize = MaxBBSPlusPublicKeySize;
+	type MaxPSPublicKeySize = MaxPSPublicKeySize;
+
+	type MaxMasterMembers = MaxMasterMembers;
+	type MaxPolicyControllers = MaxPolicyControllers;
+}
+
+impl did_core::did::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type OnDidRemoval = OffchainSignatures;
+}
+
+impl did_core::revoke::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+}
+
+impl did_core::trusted_entity::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+}
+
+impl did_core::status_list_credential::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+}
+
+impl did_core::blob::Config for Runtime {}
+
+impl did_core::attest::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+}
+
+impl did_core::anchor::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type UnixTime = Timestamp;
+}
+
+impl did_core::offchain_signatures::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+}
+
+impl did_core::accumulator::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+}
+
+parameter_types! {
+	pub const MaxOracleMembers: u32 = 10;
+	pub const MaxURIByOracle: u32 = 100;
+	pub const URAuthVerificationPeriod: BlockNumber = prod_or_fast!(HOURS, 10u32);
+	pub const MaxURAuthRequest: u32 = 100;
+	pub const URAuthOwnershipPeriod: BlockNumber = prod_or_fast!(365 * DAYS, 10 * MINUTES);
+	pub const URAuthGracePeriod: BlockNumber = prod_or_fast!(30 * DAYS, 5 * MINUTES);
+	pub const MaxChildURIs: u32 = 100;
+}
+
+impl pallet_urauth::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type URAuthParser = pallet_urauth::URAuthParser<Self>;
+	type UnixTime = Timestamp;
+	type MaxOracleMembers = MaxOracleMembers;
+	type MaxURIByOracle = MaxURIByOracle;
+	type VerificationPeriod = URAuthVerificationPeriod;
+	type MaxRequest = MaxURAuthRequest;
+	type OwnershipPeriod = URAuthOwnershipPeriod;
+	type GracePeriod = URAuthGracePeriod;
+	type MaxChildURIs = MaxChildURIs;
+	type RandomnessEnabled = ConstBool<true>;
+	type AuthorizedOrigin = RootOrigin;
+	type WeightInfo = pallet_urauth::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	// Authorizer of `TrustedEntity` whose issuers are the accredited agencies of the data market
+	pub const DataMarketAgencyAuthorizer: did_core::trusted_entity::AuthorizerId =
+		did_core::trusted_entity::AuthorizerId(*b"data-market/agency-authorizer/00");
+	pub const DataMarketAgencyBond: Balance = 100 * DOLLARS;
+	pub const DataMarketDisputeSlash: Perbill = Perbill::from_percent(50);
+}
+
+impl pallet_data_market::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AdminOrigin = RootOrigin;
+	type Assets = Assets;
+	type MaxPurchaseQuantity = ConstU128<10_000>;
+	type MaxContracts = ConstU32<100>;
+	type MaxTradesPerBatch = ConstU32<100>;
+	type MaxRenewalsPerBlock = ConstU32<100>;
+	type PayoutClaimVerifier = pallet_data_market::Sr25519PayoutClaimVerifier;
+	type AgencyAuthorizer = DataMarketAgencyAuthorizer;
+	type AgencyBond = DataMarketAgencyBond;
+	type AgencyBondSystemTokenId = ConstU32<1>;
+	type DisputeSlash = DataMarketDisputeSlash;
+	type WeightInfo = pallet_data_market::SubstrateWeight<Runtime>;
+}
+
+impl pallet_sudo::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type WeightInfo = ();
+}
+
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Runtime
+where
+	RuntimeCall: From<C>,
+{
+	type Extrinsic = UncheckedExtrinsic;
+	type OverarchingCall = RuntimeCall;
+}
+
+// Create the runtime by composing the FRAME pallets that were previously configured.
+construct_runtime!(
+	pub enum Runtime {
+		// System support stuff.
+		System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>} = 0,
+		ParachainSystem: cumulus_pallet_parachain_system::{
+			Pallet, Call, Config<T>, Storage, Inherent, Event<T>, ValidateUnsigned,
+		} = 1,
+		InfraParaCore: cumulus_pallet_infra_parachain_core::{
+			Pallet, Call, Storage, Inherent, Event<T>
+		} = 2,
+		Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent} = 3,
+		ParachainInfo: parachain_info::{Pallet, Storage, Config<T>} = 4,
+
+		// Monetary stuff.
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>} = 10,
+		TransactionPayment: pallet_transaction_payment::{Pallet, Storage, Event<T>} = 11,
+		SystemTokenTxPayment: pallet_system_token_tx_payment::{Pallet, Event<T>} = 12,
+
+		// Collator support. the order of these 5 are important and shall not change.
+		Authorship: pallet_authorship::{Pallet, Storage} = 20,
+		CollatorSelection: pallet_collator_selection::{Pallet, Call, Storage, Event<T>, Config<T>} = 21,
+		Session: pallet_session::{Pallet, Call, Storage, Event, Config<T>} = 22,
+		Aura: pallet_aura::{Pallet, Storage, Config<T>} = 23,
+		AuraExt: cumulus_pallet_aura_ext::{Pallet, Storage, Config<T>} = 24,
+
+		// XCM helpers.
+		XcmpQueue: cumulus_pallet_xcmp_queue::{Pallet, Call, Storage, Event<T>} = 30,
+		InfraXcm: pallet_xcm::{Pallet, Call, Storage, Event<T>, Origin, Config<T>} = 31,
+		CumulusXcm: cumulus_pallet_xcm::{Pallet, Event<T>, Origin} = 32,
+		DmpQueue: cumulus_pallet_dmp_queue::{Pallet, Call, Storage, Event<T>} = 33,
+		SystemTokenTransfer: pallet_system_token_transfer::{Pallet, Call, Event<T>} = 34,
+
+		// Handy utilities.
+		Utility: pallet_utility::{Pallet, Call, Event} = 40,
+		Multisig: pallet_multisig::{Pallet, Call, Storage, Event<T>} = 41,
+
+		// System Tokens.
+		Assets: pallet_assets::<Instance1>::{Pallet, Call, Storage, Event<T>, Config<T>} = 50,
+		ForeignAssets: pallet_assets::<Instance2>::{Pallet, Call, Storage, Event<T>, Config<T>} = 51,
+		SystemTokenConversion: pallet_system_token_conversion::{Pallet, Event<T>} = 56,
+
+		// The main stage.
+		DIDModule: did_core::did::{Pallet, Call, Storage, Event<T>, Config<T>} = 60,
+		Revoke: did_core::revoke::{Pallet, Call, Storage, Event, Config<T>} = 61,
+		TrustedEntity: did_core::trusted_entity::{Pallet, Call, Storage, Event, Config<T>} = 62,
+		StatusListCredential: did_core::status_list_credential::{Pallet, Call, Storage, Event} = 63,
+		BlobStore: did_core::blob::{Pallet, Call, Storage} = 64,
+		Attest: did_core::attest::{Pallet, Call, Storage, Event} = 65,
+		Anchor: did_core::anchor::{Pallet, Call, Storage, Event<T>} = 66,
+		OffchainSignatures: did_core::offchain_signatures::{Pallet, Call, Storage, Event, Config<T>} = 67,
+		Accumulator: did_core::accumulator::{Pallet, Call, Storage, Event, Config<T>} = 68,
+		URAuth: pallet_urauth::{Pallet, Call, Storage, Event<T>, Config<T>} = 70,
+		DataMarket: pallet_data_market::{Pallet, Call, Storage, Event<T>, Config<T>} = 71,
+
+		Sudo: pallet_sudo::{Pallet, Call, Storage, Config<T>, Event<T>} = 99,
+	}
+);
+
+/// The address format for describing accounts.
+pub type Address = sp_runtime::MultiAddress<AccountId, ()>;
+/// Block type as expected by this runtime.
+pub type Block = generic::Block<Header, UncheckedExtrinsic>;
+/// A Block signed with a Justification
+pub type SignedBlock = generic::SignedBlock<Block>;
+/// BlockId type as expected by this runtime.
+pub type BlockId = generic::BlockId<Block>;
+/// The SignedExtension to the basic transaction logic.
+pub type SignedExtra = (
+	frame_system::CheckNonZeroSender<Runtime>,
+	frame_system::CheckSpecVersion<Runtime>,
+	frame_system::CheckTxVersion<Runtime>,
+	frame_system::CheckGenesis<Runtime>,
+	frame_system::CheckEra<Runtime>,
+	frame_system::CheckNonce<Runtime>,
+	frame_system::CheckWeight<Runtime>,
+	pallet_system_token_tx_payment::ChargeSystemToken<Runtime>,
+);
+/// Unchecked extrinsic type as expected by this runtime.
+pub type UncheckedExtrinsic =
+	generic::UncheckedExtrinsic<Address, RuntimeCall, Signature, SignedExtra>;
+/// Extrinsic type that has already been checked.
+pub type CheckedExtrinsic = generic::CheckedExtrinsic<AccountId, RuntimeCall, SignedExtra>;
+
+/// Executive: handles dispatch to the various modules.
+pub type Executive = frame_executive::Executive<
+	Runtime,
+	Block,
+	frame_system::ChainContext<Runtime>,
+	Runtime,
+	AllPalletsWithSystem,
+>;
+
+#[cfg(feature = "runtime-benchmarks")]
+#[macro_use]
+extern crate frame_benchmarking;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benches {
+	define_benchmarks!(
+		[frame_system, SystemBench::<Runtime>]
+		[pallet_assets, Assets]
+		[pallet_balances, Balances]
+		[pallet_multisig, Multisig]
+		[pallet_session, SessionBench::<Runtime>]
+		[pallet_utility, Utility]
+		[pallet_timestamp, Timestamp]
+		[pallet_collator_selection, CollatorSelection]
+		[cumulus_pallet_xcmp_queue, XcmpQueue]
+		[cumulus_pallet_infra_parachain_core, InfraParaCore]
+		[pallet_urauth, URAuth]
+		[pallet_data_market, DataMarket]
+		// XCM
+		[pallet_xcm, InfraXcm]
+	);
+}
+
+impl_runtime_apis! {
+	impl sp_consensus_aura::AuraApi<Block, AuraId> for Runtime {
+		fn slot_duration() -> sp_consensus_aura::SlotDuration {
+			sp_consensus_aura::SlotDuration::from_millis(Aura::slot_duration())
+		}
+
+		fn authorities() -> Vec<AuraId> {
+			Aura::authorities().into_inner()
+		}
+	}
+
+	impl sp_api::Core<Block> for Runtime {
+		fn version() -> RuntimeVersion {
+			VERSION
+		}
+
+		fn execute_block(block: Block) {
+			Executive::execute_block(block)
+		}
+
+		fn initialize_block(header: &<Block as BlockT>::Header) {
+			Executive::initialize_block(header)
+		}
+	}
+
+	impl sp_api::Metadata<Block> for Runtime {
+		fn metadata() -> OpaqueMetadata {
+			OpaqueMetadata::new(Runtime::metadata().into())
+		}
+
+		fn metadata_at_version(version: u32) -> Option<OpaqueMetadata> {
+			Runtime::metadata_at_version(version)
+		}
+
+		fn metadata_versions() -> sp_std::vec::Vec<u32> {
+			Runtime::metadata_versions()
+		}
+	}
+
+	impl sp_block_builder::BlockBuilder<Block> for Runtime {
+		fn apply_extrinsic(extrinsic: <Block as BlockT>::Extrinsic) -> ApplyExtrinsicResult {
+			Executive::apply_extrinsic(extrinsic)
+		}
+
+		fn finalize_block() -> <Block as BlockT>::Header {
+			Executive::finalize_block()
+		}
+
+		fn inherent_extrinsics(data: sp_inherents::InherentData) -> Vec<<Block as BlockT>::Extrinsic> {
+			data.create_extrinsics()
+		}
+
+		fn check_inherents(
+			block: Block,
+			data: sp_inherents::InherentData,
+		) -> sp_inherents::CheckInherentsResult {
+			data.check_extrinsics(&block)
+		}
+	}
+
+	impl sp_transaction_pool::runtime_api::TaggedTransactionQueue<Block> for Runtime {
+		fn validate_transaction(
+			source: TransactionSource,
+			tx: <Block as BlockT>::Extrinsic,
+			block_hash: <Block as BlockT>::Hash,
+		) -> TransactionValidity {
+			Executive::validate_transaction(source, tx, block_hash)
+		}
+	}
+
+	impl sp_offchain::OffchainWorkerApi<Block> for Runtime {
+		fn offchain_worker(header: &<Block as BlockT>::Header) {
+			Executive::offchain_worker(header)
+		}
+	}
+
+	impl sp_session::SessionKeys<Block> for Runtime {
+		fn generate_session_keys(seed: Option<Vec<u8>>) -> Vec<u8> {
+			SessionKeys::generate(seed)
+		}
+
+		fn decode_session_keys(
+			encoded: Vec<u8>,
+		) -> Option<Vec<(Vec<u8>, KeyTypeId)>> {
+			SessionKeys::decode_into_raw_public_keys(&encoded)
+		}
+	}
+
+	impl frame_system_rpc_runtime_api::AccountNonceApi<Block, AccountId, Nonce> for Runtime {
+		fn account_nonce(account: AccountId) -> Nonce {
+			System::account_nonce(account)
+		}
+	}
+
+	#[api_version(3)]
+	impl cumulus_pallet_infra_parachain_core_rpc_runtime_api::InfraParaCoreApi<Block, MultiLocation, Balance, BlockNumber> for Runtime {
+		fn fee_table() -> Vec<cumulus_pallet_infra_parachain_core_rpc_runtime_api::CallFee<Balance, BlockNumber>> {
+			InfraParaCore::fee_table()
+		}
+
+		fn runtime_state() -> cumulus_pallet_infra_parachain_core_rpc_runtime_api::Mode {
+			InfraParaCore::runtime_state()
+		}
+
+		fn unmet_bootstrap_criteria() -> Vec<cumulus_pallet_infra_parachain_core_rpc_runtime_api::BootstrapCriterion> {
+			InfraParaCore::unmet_bootstrap_criteria()
+		}
+
+		fn system_config() -> Option<cumulus_pallet_infra_parachain_core_rpc_runtime_api::SystemConfig> {
+			cumulus_pallet_infra_parachain_core::RCSystemConfig::<Runtime>::get()
+		}
+
+		fn para_fee_rate() -> Option<Balance> {
+			InfraParaCore::current_para_fee_rate()
+		}
+
+		fn pending_request() -> Option<(MultiLocation, BlockNumber)> {
+			InfraParaCore::pending_request()
+		}
+	}
+
+	impl infra_asset_common::runtime_api::FungiblesApi<
+		Block,
+		AccountId,
+	> for Runtime
+	{
+		fn query_account_balances(account: AccountId) -> Result<xcm::VersionedMultiAssets, infra_asset_common::runtime_api::FungiblesAccessError> {
+			use infra_asset_common::fungible_conversion::{convert, convert_balance};
+			Ok([
+				// collect pallet_balance
+				{
+					let balance = Balances::free_balance(account.clone());
+					if balance > 0 {
+						vec![convert_balance::<NativeLocation, Balance>(balance)?]
+					} else {
+						vec![]
+					}
+				},
+				// collect pallet_assets (TrustBackedAssets)
+				convert::<_, _, _, _, xcm_config::NativeAssetsConvertedConcreteId>(
+					Assets::account_balances(&account)
+						.iter()
+						.filter(|(_, balance)| balance > &0)
+				)?,
+				// collect pallet_assets (ForeignAssets)
+				convert::<_, _, _, _, xcm_config::ForeignAssetsConvertedConcreteId>(
+					ForeignAssets::account_balances(&account)
+						.iter()
+						.filter(|(_, balance)| balance > &0)
+				)?,
+			].concat().into())
+		}
+	}
+
+	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
+		fn query_info(
+			uxt: <Block as BlockT>::Extrinsic,
+			len: u32,
+		) -> pallet_transaction_payment_rpc_runtime_api::RuntimeDispatchInfo<Balance> {
+			TransactionPayment::query_info(uxt, len)
+		}
+		fn query_fee_details(
+			uxt: <Block as BlockT>::Extrinsic,
+			len: u32,
+		) -> pallet_transaction_payment::FeeDetails<Balance> {
+			TransactionPayment::query_fee_details(uxt, len)
+		}
+		fn query_weight_to_fee(weight: Weight) -> Balance {
+			TransactionPayment::weight_to_fee(weight)
+		}
+		fn query_length_to_fee(length: u32) -> Balance {
+			TransactionPayment::length_to_fee(length)
+		}
+	}
+
+	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentCallApi<Block, Balance, RuntimeCall>
+		for Runtime
+	{
+		fn query_call_info(
+			call: RuntimeCall,
+			len: u32,
+		) -> pallet_transaction_payment::RuntimeDispatchInfo<Balance> {
+			TransactionPayment::query_call_info(call, len)
+		}
+		fn query_call_fee_details(
+			call: RuntimeCall,
+			len: u32,
+		) -> pallet_transaction_payment::FeeDetails<Balance> {
+			TransactionPayment::query_call_fee_details(call, len)
+		}
+		fn query_weight_to_fee(weight: Weight) -> Balance {
+			TransactionPayment::weight_to_fee(weight)
+		}
+		fn query_length_to_fee(length: u32) -> Balance {
+			TransactionPayment::length_to_fee(length)
+		}
+	}
+
+	impl did_core::attest::runtime_api::AttestApi<Block, did_core::attest::SchemaAttestation<Runtime>>
+		for Runtime
+	{
+		fn attestations(
+			attester: did_core::attest::Attester,
+			schema_id: Option<did_core::attest::SchemaId>,
+		) -> Vec<(
+			did_core::attest::SchemaId,
+			did_core::attest::SchemaAttestation<Runtime>,
+			did_core::attest::AttestationStatus,
+		)> {
+			Attest::attestations_of(attester, schema_id)
+		}
+		fn schema(schema_id: did_core::attest::SchemaId) -> Option<did_core::attest::Schema> {
+			Attest::schema(schema_id)
+		}
+		fn schema_blob(
+			schema_id: did_core::attest::SchemaId,
+			version: did_core::attest::SchemaVersion,
+		) -> Option<did_core::blob::BlobId> {
+			Attest::schema_version(schema_id, version)
+		}
+	}
+
+	impl pallet_data_market::runtime_api::DataMarketApi<Block, AccountId> for Runtime {
+		fn trade_count(contract_id: pallet_data_market::ContractId) -> pallet_data_market::Quantity {
+			DataMarket::get_trade_count_for_contract(contract_id)
+		}
+		fn data_trade_records(
+			contract_id: pallet_data_market::ContractId,
+			start_after: Option<AccountId>,
+			limit: u32,
+		) -> Vec<(AccountId, pallet_data_market::Quantity)> {
+			DataMarket::data_trade_records(contract_id, start_after, limit)
+		}
+		fn active_consents(
+			data_subject: did_core::did::Did,
+		) -> Vec<(pallet_data_market::ContractId, AccountId)> {
+			DataMarket::active_consents(data_subject)
+		}
+		fn payout_commitments(
+			contract_id: pallet_data_market::ContractId,
+		) -> Vec<pallet_data_market::OwnerCommitment> {
+			DataMarket::payout_commitments(contract_id)
+		}
+		fn agency_reputation(agency: AccountId) -> pallet_data_market::AgencyReputation {
+			DataMarket::get_agency_reputations(agency)
+		}
+	}
+
+	impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
+		fn collect_collation_info(header: &<Block as BlockT>::Header) -> cumulus_primitives_core::CollationInfo {
+			ParachainSystem::collect_collation_info(header)
+		}
+	}
+
+	#[cfg(feature = "try-runtime")]
+	impl frame_try_runtime::TryRuntime<Block> for Runtime {
+		fn on_runtime_upgrade(checks: frame_try_runtime::UpgradeCheckSelect) -> (Weight, Weight) {
+			let weight = Executive::try_runtime_upgrade(checks).unwrap();
+			(weight, RuntimeBlockWeights::get().max_block)
+		}
+
+		fn execute_block(
+			block: Block,
+			state_root_check: bool,
+			signature_check: bool,
+			select: frame_try_runtime::TryStateSelect,
+		) -> Weight {
+			// NOTE: intentional unwrap: we don't want to propagate the error backwards, and want to
+			// have a backtrace here.
+			Executive::try_execute_block(block, state_root_check, signature_check, select).unwrap()
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	impl frame_benchmarking::Benchmark<Block> for Runtime {
+		fn benchmark_metadata(extra: bool) -> (
+			Vec<frame_benchmarking::BenchmarkList>,
+			Vec<frame_support::traits::StorageInfo>,
+		) {
+			use frame_benchmarking::{Benchmarking, BenchmarkList};
+			use frame_support::traits::StorageInfoTrait;
+			use frame_system_benchmarking::Pallet as SystemBench;
+			use cumulus_pallet_session_benchmarking::Pallet as SessionBench;
+
+			let mut list = Vec::<BenchmarkList>::new();
+			list_benchmarks!(list, extra);
+
+			let storage_info = AllPalletsWithSystem::storage_info();
+			return (list, storage_info)
+		}
+
+		fn dispatch_benchmark(
+			config: frame_benchmarking::BenchmarkConfig
+		) -> Result<Vec<frame_benchmarking::BenchmarkBatch>, sp_runtime::RuntimeString> {
+			use frame_benchmarking::{Benchmarking, BenchmarkBatch, TrackedStorageKey};
+
+			use frame_system_benchmarking::Pallet as SystemBench;
+			impl frame_system_benchmarking::Config for Runtime {}
+
+			use cumulus_pallet_session_benchmarking::Pallet as SessionBench;
+			impl cumulus_pallet_session_benchmarking::Config for Runtime {}
+
+			let whitelist: Vec<TrackedStorageKey> = vec![
+				// Block Number
+				hex_literal::hex!("26aa394eea5630e07c48ae0c9558cef702a5c1b19ab7a04f536c519aca4983ac").to_vec().into(),
+				// Total Issuance
+				hex_literal::hex!("c2261276cc9d1f8598ea4b6a74b15c2f57c875e4cff74148e4628f264b974c80").to_vec().into(),
+				// Execution Phase
+				hex_literal::hex!("26aa394eea5630e07c48ae0c9558cef7ff553b5a9862a516939d82b3d3d8661a").to_vec().into(),
+				// Event Count
+				hex_literal::hex!("26aa394eea5630e07c48ae0c9558cef70a98fdbe9ce6c55837576c60c7af3850").to_vec().into(),
+				// System Events
+				hex_literal::hex!("26aa394eea5630e07c48ae0c9558cef780d41e5e16056765bc8461851072c9d7").to_vec().into(),
+			];
+
+			let mut batches = Vec::<BenchmarkBatch>::new();
+			let params = (&config, &whitelist);
+			add_benchmarks!(params, batches);
+
+			Ok(batches)
+		}
+	}
+}
+
+cumulus_pallet_parachain_system::register_validate_block! {
+	Runtime = Runtime,
+	BlockExecutor = cumulus_pallet_aura_ext::BlockExecutor::<Runtime, Executive>,
+}
@@ -0,0 +1,54 @@
+use crate::*;
+use codec::{Decode, Encode};
+use cumulus_pallet_infra_parachain_core::ReportParaFeeRate;
+use xcm::latest::prelude::*;
+
+/// A type containing the encoding of the system token manager pallet in the Relay chain runtime.
+/// Used to construct any remote calls. The codec index must correspond to the index of
+/// `SystemTokenManager` in the `construct_runtime` of the Relay Chain.
+#[derive(Encode, Decode)]
+enum RelayRuntimePallets {
+	#[codec(index = 21)]
+	SystemTokenManager(SystemTokenManagerCalls),
+}
+
+/// Call encoding for the calls needed from the relay system token manager pallet.
+#[derive(Encode, Decode)]
+enum SystemTokenManagerCalls {
+	#[codec(index = 6)]
+	ReportParaFeeRate(u128),
+}
+
+/// Type that reports the para fee rate adjusted by `InfraParaCore` to the Relay chain.
+pub struct ParaFeeRateReporter;
+impl ReportParaFeeRate<Balance> for ParaFeeRateReporter {
+	fn report_para_fee_rate(fee_rate: Balance) {
+		let call = RelayRuntimePallets::SystemTokenManager(
+			SystemTokenManagerCalls::ReportParaFeeRate(fee_rate),
+		);
+		let message = Xcm(vec![
+			Instruction::UnpaidExecution {
+				weight_limit: WeightLimit::Unlimited,
+				check_origin: None,
+			},
+			Instruction::Transact {
+				origin_kind: OriginKind::Native,
+				require_weight_at_most: Weight::from_parts(1_000_000_000, 200000),
+				call: call.encode().into(),
+			},
+		]);
+
+		match InfraXcm::send_xcm(Here, MultiLocation::parent(), message) {
+			Ok(_) => log::info!(
+				target: "runtime::infra-parachain-core",
+				"Para fee rate {} reported to the Relay chain.",
+				fee_rate,
+			),
+			Err(e) => log::error!(
+				target: "runtime::infra-parachain-core",
+				"Para fee rate failed to be reported: {:?}",
+				e
+			),
+		}
+	}
+}
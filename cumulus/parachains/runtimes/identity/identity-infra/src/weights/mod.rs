@@ -0,0 +1,18 @@
+pub mod block_weights;
+pub mod cumulus_pallet_xcmp_queue;
+pub mod extrinsic_weights;
+pub mod frame_system;
+pub mod pallet_balances;
+pub mod pallet_collator_selection;
+pub mod pallet_multisig;
+pub mod pallet_session;
+pub mod pallet_timestamp;
+pub mod pallet_utility;
+pub mod pallet_xcm;
+pub mod paritydb_weights;
+pub mod rocksdb_weights;
+pub mod xcm;
+
+pub use block_weights::constants::BlockExecutionWeight;
+pub use extrinsic_weights::constants::ExtrinsicBaseWeight;
+pub use rocksdb_weights::constants::RocksDbWeight;
@@ -0,0 +1,232 @@
+// Copyright 2022 Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+
+// Cumulus is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cumulus is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
+
+
+//! Autogenerated weights for `pallet_xcm_benchmarks::generic`
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2023-02-23, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! HOSTNAME: `bm6`, CPU: `Intel(R) Core(TM) i7-7700K CPU @ 4.20GHz`
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("statemint-dev"), DB CACHE: 1024
+
+// Executed Command:
+// ./artifacts/polkadot-parachain
+// benchmark
+// pallet
+// --template=./templates/xcm-bench-template.hbs
+// --chain=statemint-dev
+// --execution=wasm
+// --wasm-execution=compiled
+// --pallet=pallet_xcm_benchmarks::generic
+// --extrinsic=*
+// --steps=50
+// --repeat=20
+// --json
+// --header=./file_header.txt
+// --output=./parachains/runtimes/assets/statemint/src/weights/xcm/pallet_xcm_benchmarks_generic.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Weights for `pallet_xcm_benchmarks::generic`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo<T> {
+	// Storage: ParachainInfo ParachainId (r:1 w:0)
+	// Proof: ParachainInfo ParachainId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: InfrablockspaceXcm SupportedVersion (r:1 w:0)
+	// Proof Skipped: InfrablockspaceXcm SupportedVersion (max_values: None, max_size: None, mode: Measured)
+	// Storage: InfrablockspaceXcm VersionDiscoveryQueue (r:1 w:1)
+	// Proof Skipped: InfrablockspaceXcm VersionDiscoveryQueue (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: InfrablockspaceXcm SafeXcmVersion (r:1 w:0)
+	// Proof Skipped: InfrablockspaceXcm SafeXcmVersion (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	// Proof Skipped: ParachainSystem HostConfiguration (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	// Proof Skipped: ParachainSystem PendingUpwardMessages (max_values: Some(1), max_size: None, mode: Measured)
+	pub(crate) fn report_holding() -> Weight {
+		Weight::from_parts(352_828_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	pub(crate) fn buy_execution() -> Weight {
+		Weight::from_parts(3_719_000 as u64, 0)
+	}
+	// Storage: InfrablockspaceXcm Queries (r:1 w:0)
+	// Proof Skipped: InfrablockspaceXcm Queries (max_values: None, max_size: None, mode: Measured)
+	pub(crate) fn query_response() -> Weight {
+		Weight::from_parts(10_697_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+	}
+	pub(crate) fn transact() -> Weight {
+		Weight::from_parts(12_215_000 as u64, 0)
+	}
+	pub(crate) fn refund_surplus() -> Weight {
+		Weight::from_parts(3_925_000 as u64, 0)
+	}
+	pub(crate) fn set_error_handler() -> Weight {
+		Weight::from_parts(2_782_000 as u64, 0)
+	}
+	pub(crate) fn set_appendix() -> Weight {
+		Weight::from_parts(2_765_000 as u64, 0)
+	}
+	pub(crate) fn clear_error() -> Weight {
+		Weight::from_parts(2_766_000 as u64, 0)
+	}
+	pub(crate) fn descend_origin() -> Weight {
+		Weight::from_parts(3_553_000 as u64, 0)
+	}
+	pub(crate) fn clear_origin() -> Weight {
+		Weight::from_parts(2_732_000 as u64, 0)
+	}
+	// Storage: ParachainInfo ParachainId (r:1 w:0)
+	// Proof: ParachainInfo ParachainId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: InfrablockspaceXcm SupportedVersion (r:1 w:0)
+	// Proof Skipped: InfrablockspaceXcm SupportedVersion (max_values: None, max_size: None, mode: Measured)
+	// Storage: InfrablockspaceXcm VersionDiscoveryQueue (r:1 w:1)
+	// Proof Skipped: InfrablockspaceXcm VersionDiscoveryQueue (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: InfrablockspaceXcm SafeXcmVersion (r:1 w:0)
+	// Proof Skipped: InfrablockspaceXcm SafeXcmVersion (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	// Proof Skipped: ParachainSystem HostConfiguration (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	// Proof Skipped: ParachainSystem PendingUpwardMessages (max_values: Some(1), max_size: None, mode: Measured)
+	pub(crate) fn report_error() -> Weight {
+		Weight::from_parts(23_189_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: InfrablockspaceXcm AssetTraps (r:1 w:1)
+	// Proof Skipped: InfrablockspaceXcm AssetTraps (max_values: None, max_size: None, mode: Measured)
+	pub(crate) fn claim_asset() -> Weight {
+		Weight::from_parts(14_569_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	pub(crate) fn trap() -> Weight {
+		Weight::from_parts(2_759_000 as u64, 0)
+	}
+	// Storage: InfrablockspaceXcm VersionNotifyTargets (r:1 w:1)
+	// Proof Skipped: InfrablockspaceXcm VersionNotifyTargets (max_values: None, max_size: None, mode: Measured)
+	// Storage: InfrablockspaceXcm SupportedVersion (r:1 w:0)
+	// Proof Skipped: InfrablockspaceXcm SupportedVersion (max_values: None, max_size: None, mode: Measured)
+	// Storage: InfrablockspaceXcm VersionDiscoveryQueue (r:1 w:1)
+	// Proof Skipped: InfrablockspaceXcm VersionDiscoveryQueue (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: InfrablockspaceXcm SafeXcmVersion (r:1 w:0)
+	// Proof Skipped: InfrablockspaceXcm SafeXcmVersion (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	// Proof Skipped: ParachainSystem HostConfiguration (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	// Proof Skipped: ParachainSystem PendingUpwardMessages (max_values: Some(1), max_size: None, mode: Measured)
+	pub(crate) fn subscribe_version() -> Weight {
+		Weight::from_parts(24_423_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: InfrablockspaceXcm VersionNotifyTargets (r:0 w:1)
+	// Proof Skipped: InfrablockspaceXcm VersionNotifyTargets (max_values: None, max_size: None, mode: Measured)
+	pub(crate) fn unsubscribe_version() -> Weight {
+		Weight::from_parts(4_575_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: ParachainInfo ParachainId (r:1 w:0)
+	// Proof: ParachainInfo ParachainId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: InfrablockspaceXcm SupportedVersion (r:1 w:0)
+	// Proof Skipped: InfrablockspaceXcm SupportedVersion (max_values: None, max_size: None, mode: Measured)
+	// Storage: InfrablockspaceXcm VersionDiscoveryQueue (r:1 w:1)
+	// Proof Skipped: InfrablockspaceXcm VersionDiscoveryQueue (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: InfrablockspaceXcm SafeXcmVersion (r:1 w:0)
+	// Proof Skipped: InfrablockspaceXcm SafeXcmVersion (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	// Proof Skipped: ParachainSystem HostConfiguration (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	// Proof Skipped: ParachainSystem PendingUpwardMessages (max_values: Some(1), max_size: None, mode: Measured)
+	pub(crate) fn initiate_reserve_withdraw() -> Weight {
+		Weight::from_parts(406_184_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	pub(crate) fn burn_asset() -> Weight {
+		Weight::from_parts(125_739_000 as u64, 0)
+	}
+	pub(crate) fn expect_asset() -> Weight {
+		Weight::from_parts(13_105_000 as u64, 0)
+	}
+	pub(crate) fn expect_origin() -> Weight {
+		Weight::from_parts(2_823_000 as u64, 0)
+	}
+	pub(crate) fn expect_error() -> Weight {
+		Weight::from_parts(2_771_000 as u64, 0)
+	}
+	pub(crate) fn expect_transact_status() -> Weight {
+		Weight::from_parts(2_883_000 as u64, 0)
+	}
+	// Storage: ParachainInfo ParachainId (r:1 w:0)
+	// Proof: ParachainInfo ParachainId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: InfrablockspaceXcm SupportedVersion (r:1 w:0)
+	// Proof Skipped: InfrablockspaceXcm SupportedVersion (max_values: None, max_size: None, mode: Measured)
+	// Storage: InfrablockspaceXcm VersionDiscoveryQueue (r:1 w:1)
+	// Proof Skipped: InfrablockspaceXcm VersionDiscoveryQueue (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: InfrablockspaceXcm SafeXcmVersion (r:1 w:0)
+	// Proof Skipped: InfrablockspaceXcm SafeXcmVersion (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	// Proof Skipped: ParachainSystem HostConfiguration (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	// Proof Skipped: ParachainSystem PendingUpwardMessages (max_values: Some(1), max_size: None, mode: Measured)
+	pub(crate) fn query_pallet() -> Weight {
+		Weight::from_parts(25_695_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	pub(crate) fn expect_pallet() -> Weight {
+		Weight::from_parts(4_650_000 as u64, 0)
+	}
+	// Storage: ParachainInfo ParachainId (r:1 w:0)
+	// Proof: ParachainInfo ParachainId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: InfrablockspaceXcm SupportedVersion (r:1 w:0)
+	// Proof Skipped: InfrablockspaceXcm SupportedVersion (max_values: None, max_size: None, mode: Measured)
+	// Storage: InfrablockspaceXcm VersionDiscoveryQueue (r:1 w:1)
+	// Proof Skipped: InfrablockspaceXcm VersionDiscoveryQueue (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: InfrablockspaceXcm SafeXcmVersion (r:1 w:0)
+	// Proof Skipped: InfrablockspaceXcm SafeXcmVersion (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	// Proof Skipped: ParachainSystem HostConfiguration (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	// Proof Skipped: ParachainSystem PendingUpwardMessages (max_values: Some(1), max_size: None, mode: Measured)
+	pub(crate) fn report_transact_status() -> Weight {
+		Weight::from_parts(23_415_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	pub(crate) fn clear_transact_status() -> Weight {
+		Weight::from_parts(2_756_000 as u64, 0)
+	}
+	pub(crate) fn set_topic() -> Weight {
+		Weight::from_parts(2_702_000 as u64, 0)
+	}
+	pub(crate) fn clear_topic() -> Weight {
+		Weight::from_parts(2_738_000 as u64, 0)
+	}
+	pub(crate) fn set_fees_mode() -> Weight {
+		Weight::from_parts(2_700_000 as u64, 0)
+	}
+	pub(crate) fn unpaid_execution() -> Weight {
+		Weight::from_parts(2_894_000 as u64, 0)
+	}
+}
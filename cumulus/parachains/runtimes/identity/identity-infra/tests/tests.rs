@@ -0,0 +1,241 @@
+// Copyright (C) 2023 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the Infra Identity runtime.
+
+use codec::Encode;
+use did_core::{
+	common::PublicKey,
+	did::{Did, UncheckedDidKey},
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{Contains, PalletInfoAccess},
+};
+use identity_runtime::{
+	BootstrapCallFilter, DIDModule, DataMarket, InfraParaCore, MaintenanceCallFilter, Runtime,
+	RuntimeCall, RuntimeOrigin, SessionKeys, URAuth,
+};
+use parachains_common::{AccountId, AuraId, Balance};
+use parachains_runtimes_test_utils::{CollatorSessionKeys, ExtBuilder};
+use sp_keyring::AccountKeyring::{Alice, Bob};
+use sp_runtime::{
+	infra::{BootstrapCriterion, FeeModel, Mode},
+	DispatchError,
+};
+
+const ALICE: [u8; 32] = [1u8; 32];
+const IDENTITY_PARA_ID: u32 = 1001;
+
+fn collator_session_keys() -> CollatorSessionKeys<Runtime> {
+	CollatorSessionKeys::new(
+		AccountId::from(ALICE),
+		AccountId::from(ALICE),
+		SessionKeys { aura: AuraId::from(sp_core::sr25519::Public::from_raw(ALICE)) },
+	)
+}
+
+fn new_test_ext() -> sp_io::TestExternalities {
+	ExtBuilder::<Runtime>::default()
+		.with_collators(collator_session_keys().collators())
+		.with_session_keys(collator_session_keys().session_keys())
+		.with_balances(vec![
+			(Alice.to_account_id(), 1_000_000_000_000 as Balance),
+			(Bob.to_account_id(), 1_000_000_000_000 as Balance),
+		])
+		.with_para_id(IDENTITY_PARA_ID.into())
+		.build()
+}
+
+fn relay_origin() -> RuntimeOrigin {
+	cumulus_pallet_xcm::Origin::Relay.into()
+}
+
+#[test]
+fn infra_para_core_is_encoded_where_the_relay_chain_expects_it() {
+	// `ParachainRuntimePallets` of the Relay Chain encodes `InfraParaCore` at index 2.
+	assert_eq!(<InfraParaCore as PalletInfoAccess>::index(), 2);
+	let call =
+		RuntimeCall::InfraParaCore(cumulus_pallet_infra_parachain_core::Call::update_fee_table {
+			pallet_name: b"URAuth".to_vec(),
+			call_name: b"claim_ownership".to_vec(),
+			fee: 1_000,
+		});
+	assert_eq!(call.encode()[0], 2);
+}
+
+#[test]
+fn bootstrap_call_filter_only_allows_system_token_setup() {
+	let register_did = RuntimeCall::DIDModule(did_core::did::Call::new_onchain {
+		did: Did([1u8; 32]),
+		keys: vec![],
+		controllers: Default::default(),
+	});
+	assert!(!BootstrapCallFilter::contains(&register_did));
+
+	let create_asset = RuntimeCall::Assets(pallet_assets::Call::create {
+		id: 1u32.into(),
+		admin: Alice.to_account_id().into(),
+		min_balance: 1,
+	});
+	assert!(BootstrapCallFilter::contains(&create_asset));
+}
+
+#[test]
+fn maintenance_call_filter_only_allows_operations() {
+	let register_did = RuntimeCall::DIDModule(did_core::did::Call::new_onchain {
+		did: Did([1u8; 32]),
+		keys: vec![],
+		controllers: Default::default(),
+	});
+	assert!(!MaintenanceCallFilter::contains(&register_did));
+
+	let set_keys = RuntimeCall::Session(pallet_session::Call::set_keys {
+		keys: SessionKeys { aura: AuraId::from(sp_core::sr25519::Public::from_raw(ALICE)) },
+		proof: vec![],
+	});
+	assert!(MaintenanceCallFilter::contains(&set_keys));
+}
+
+#[test]
+fn bootstrap_ends_only_when_every_criterion_is_met() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(
+			InfraParaCore::unmet_bootstrap_criteria(),
+			vec![
+				BootstrapCriterion::SystemConfig,
+				BootstrapCriterion::SystemToken,
+				BootstrapCriterion::FeeTable,
+			]
+		);
+		assert_ok!(InfraParaCore::update_fee_table(
+			relay_origin(),
+			b"URAuth".to_vec(),
+			b"claim_ownership".to_vec(),
+			1_000,
+		));
+		assert_eq!(
+			InfraParaCore::unmet_bootstrap_criteria(),
+			vec![BootstrapCriterion::SystemConfig, BootstrapCriterion::SystemToken]
+		);
+		assert_noop!(
+			InfraParaCore::update_runtime_state(relay_origin()),
+			cumulus_pallet_infra_parachain_core::Error::<Runtime>::NotAllowedToChangeState
+		);
+		assert_eq!(InfraParaCore::runtime_state(), Mode::Bootstrap);
+	})
+}
+
+#[test]
+fn relay_chain_puts_runtime_under_maintenance_and_rolls_it_back() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			InfraParaCore::set_runtime_state(RuntimeOrigin::root(), Mode::Maintenance),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(InfraParaCore::set_runtime_state(relay_origin(), Mode::Maintenance));
+		assert_eq!(InfraParaCore::runtime_state(), Mode::Maintenance);
+		assert_noop!(
+			InfraParaCore::set_runtime_state(relay_origin(), Mode::Normal),
+			cumulus_pallet_infra_parachain_core::Error::<Runtime>::NotAllowedToChangeState
+		);
+		assert_ok!(InfraParaCore::set_runtime_state(relay_origin(), Mode::Bootstrap));
+		assert_eq!(InfraParaCore::runtime_state(), Mode::Bootstrap);
+	})
+}
+
+#[test]
+fn on_chain_did_can_be_registered() {
+	new_test_ext().execute_with(|| {
+		let did = Did([7u8; 32]);
+		let key = UncheckedDidKey::new_with_all_relationships(PublicKey::sr25519(Alice.public().0));
+		assert_ok!(DIDModule::new_onchain(
+			RuntimeOrigin::signed(Alice.to_account_id()),
+			did,
+			vec![key],
+			Default::default(),
+		));
+		assert!(DIDModule::did(did).is_some());
+
+		assert!(DIDModule::new_onchain(
+			RuntimeOrigin::signed(Bob.to_account_id()),
+			did,
+			vec![],
+			Default::default(),
+		)
+		.is_err());
+	})
+}
+
+#[test]
+fn urauth_oracle_members_are_managed_by_root() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			URAuth::add_oracle_member(
+				RuntimeOrigin::signed(Alice.to_account_id()),
+				Bob.to_account_id()
+			),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(URAuth::add_oracle_member(RuntimeOrigin::root(), Bob.to_account_id()));
+		assert!(URAuth::oracle_members().contains(&Bob.to_account_id()));
+	})
+}
+
+#[test]
+fn data_market_agency_can_be_registered() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(DataMarket::register_agency(RuntimeOrigin::signed(Alice.to_account_id())));
+		assert!(DataMarket::check_agency_exist(&Alice.to_account_id()));
+		assert!(DataMarket::register_agency(RuntimeOrigin::signed(Alice.to_account_id())).is_err());
+	})
+}
+
+#[test]
+fn relay_chain_sets_fees_of_identity_calls() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			InfraParaCore::update_fee_table(
+				RuntimeOrigin::root(),
+				b"URAuth".to_vec(),
+				b"claim_ownership".to_vec(),
+				1_000,
+			),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(InfraParaCore::update_fee_table(
+			relay_origin(),
+			b"URAuth".to_vec(),
+			b"claim_ownership".to_vec(),
+			1_000,
+		));
+		assert!(InfraParaCore::update_fee_table(
+			relay_origin(),
+			b"URAuth".to_vec(),
+			b"unknown_call".to_vec(),
+			1_000,
+		)
+		.is_err());
+
+		let call_fee = InfraParaCore::fee_table()
+			.into_iter()
+			.find(|f| {
+				f.pallet_name == b"URAuth".to_vec() && f.call_name == b"claim_ownership".to_vec()
+			})
+			.expect("every call of the runtime is listed in the fee table");
+		assert_eq!(call_fee.rule.map(|r| r.model), Some(FeeModel::Flat(1_000)));
+		assert!(!call_fee.is_wildcard);
+	})
+}
@@ -34,7 +34,11 @@ use parachain_primitives::primitives::{
 use parachains_common::{constants::SLOT_DURATION, types::AccountId};
 use sp_consensus_aura::{SlotDuration, AURA_ENGINE_ID};
 use sp_core::Encode;
-use sp_runtime::{traits::Header, BuildStorage, Digest, DigestItem};
+use sp_runtime::{
+	infra::{SystemConfig, SystemTokenWeight},
+	traits::Header,
+	BuildStorage, Digest, DigestItem,
+};
 use xcm::{
 	latest::{MultiAsset, MultiLocation, XcmContext, XcmHash},
 	prelude::*,
@@ -450,20 +454,11 @@ pub fn mock_open_hrmp_channel<
 	sender: ParaId,
 	recipient: ParaId,
 	included_head: HeaderFor<C>,
-	mut slot_digest: &[u8],
+	slot_digest: &[u8],
 ) {
-	const RELAY_CHAIN_SLOT_DURATION: SlotDuration = SlotDuration::from_millis(6000);
-	let slot = Slot::decode(&mut slot_digest).expect("failed to decode digest");
-	// Convert para slot to relay chain.
-	let timestamp = slot.saturating_mul(SLOT_DURATION);
-	let relay_slot = Slot::from_timestamp(timestamp.into(), RELAY_CHAIN_SLOT_DURATION);
-
-	let n = 1_u32;
 	let mut sproof_builder = RelayStateSproofBuilder {
 		para_id: sender,
-		included_para_head: Some(HeadData(included_head.encode())),
 		hrmp_egress_channel_index: Some(vec![recipient]),
-		current_slot: relay_slot,
 		..Default::default()
 	};
 	sproof_builder.hrmp_channels.insert(
@@ -478,6 +473,65 @@ pub fn mock_open_hrmp_channel<
 		},
 	);
 
+	mock_relay_state_proof::<C, T>(sproof_builder, included_head, slot_digest);
+}
+
+/// InfraBlockchain system config which the relay chain state proof carries by default.
+pub fn infra_system_config() -> SystemConfig {
+	RelayStateSproofBuilder::default()
+		.system_config
+		.expect("relay chain state proof carries a system config by default")
+}
+
+/// Calls parachain-system's `create_inherent` with a relay chain state proof which carries the
+/// given InfraBlockchain state of `para_id`.
+///
+/// A `system_config` of `None` leaves the system config out of the proof, as a relay chain
+/// lacking it would.
+///
+/// See [`mock_open_hrmp_channel`] for the requirements on `included_head` and `slot_digest`.
+pub fn mock_infra_relay_state<
+	C: cumulus_pallet_parachain_system::Config,
+	T: ProvideInherent<Call = cumulus_pallet_parachain_system::Call<C>>,
+>(
+	para_id: ParaId,
+	system_config: Option<SystemConfig>,
+	updated_system_token_weights: Option<Vec<(MultiLocation, SystemTokenWeight)>>,
+	included_head: HeaderFor<C>,
+	slot_digest: &[u8],
+) {
+	let sproof_builder = RelayStateSproofBuilder {
+		para_id,
+		system_config,
+		updated_system_token_weights,
+		..Default::default()
+	};
+
+	mock_relay_state_proof::<C, T>(sproof_builder, included_head, slot_digest);
+}
+
+/// Calls parachain-system's `create_inherent` with the relay chain state proof built by
+/// `sproof_builder`, setting its latest included parachain head and relay chain slot first.
+///
+/// See [`mock_open_hrmp_channel`] for the requirements on `included_head` and `slot_digest`.
+pub fn mock_relay_state_proof<
+	C: cumulus_pallet_parachain_system::Config,
+	T: ProvideInherent<Call = cumulus_pallet_parachain_system::Call<C>>,
+>(
+	mut sproof_builder: RelayStateSproofBuilder,
+	included_head: HeaderFor<C>,
+	mut slot_digest: &[u8],
+) {
+	const RELAY_CHAIN_SLOT_DURATION: SlotDuration = SlotDuration::from_millis(6000);
+	let slot = Slot::decode(&mut slot_digest).expect("failed to decode digest");
+	// Convert para slot to relay chain.
+	let timestamp = slot.saturating_mul(SLOT_DURATION);
+	let relay_slot = Slot::from_timestamp(timestamp.into(), RELAY_CHAIN_SLOT_DURATION);
+
+	let n = 1_u32;
+	sproof_builder.included_para_head = Some(HeadData(included_head.encode()));
+	sproof_builder.current_slot = relay_slot;
+
 	let (relay_parent_storage_root, relay_chain_state) = sproof_builder.into_state_root_and_proof();
 	let vfp = PersistedValidationData {
 		relay_parent_number: n as RelayChainBlockNumber,
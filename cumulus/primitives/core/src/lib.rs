@@ -51,6 +51,8 @@ pub trait UpdateRCConfig<AssetId, Weight> {
 	fn update_system_config(system_config: SystemConfig);
 	/// System Token weight set by Relay Chain
 	fn update_system_token_weight_for(assets: Vec<(AssetId, Weight)>);
+	/// Commitment to the System Token weight table of Relay Chain
+	fn update_system_token_weight_commitment(commitment: relay_chain::SystemTokenWeightCommitment);
 }
 
 /// An inbound HRMP message.
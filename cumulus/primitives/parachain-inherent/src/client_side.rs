@@ -16,11 +16,11 @@
 
 //! Client side code for generating the parachain inherent.
 
-use crate::ParachainInherentData;
+use crate::{ParachainInherentData, SystemTokenWeightInherentData};
 use codec::Decode;
 use cumulus_primitives_core::{
 	relay_chain::{self, Hash as PHash, HrmpChannelId},
-	ParaId, PersistedValidationData,
+	MultiLocation, ParaId, PersistedValidationData,
 };
 use cumulus_relay_chain_interface::RelayChainInterface;
 
@@ -112,6 +112,7 @@ async fn collect_relay_storage_proof(
 		relay_well_known_keys::upgrade_restriction_signal(para_id),
 		relay_well_known_keys::para_head(para_id),
 		relay_well_known_keys::update_system_token_weight(para_id),
+		relay_well_known_keys::SYSTEM_TOKEN_WEIGHT_COMMITMENT.to_vec(),
 	];
 	relevant_keys.extend(ingress_channels.into_iter().map(|sender| {
 		relay_well_known_keys::hrmp_channels(HrmpChannelId { sender, recipient: para_id })
@@ -198,3 +199,72 @@ impl sp_inherents::InherentDataProvider for ParachainInherentData {
 		None
 	}
 }
+
+impl SystemTokenWeightInherentData {
+	/// Create the [`SystemTokenWeightInherentData`] at the given `relay_parent`.
+	///
+	/// Returns `None` if the relay chain has not committed to any System Token weight table yet,
+	/// or if the table cannot be read.
+	pub async fn create_at(
+		relay_parent: PHash,
+		relay_chain_interface: &impl RelayChainInterface,
+	) -> Option<SystemTokenWeightInherentData> {
+		use relay_chain::well_known_keys as relay_well_known_keys;
+
+		let read = |key: &'static [u8]| async move {
+			relay_chain_interface
+				.get_storage_by_key(relay_parent, key)
+				.await
+				.map_err(|e| {
+					tracing::error!(
+						target: LOG_TARGET,
+						relay_parent = ?relay_parent,
+						error = ?e,
+						"Cannot obtain the System Token weight table.",
+					)
+				})
+				.ok()
+				.flatten()
+		};
+
+		let commitment = read(relay_well_known_keys::SYSTEM_TOKEN_WEIGHT_COMMITMENT).await?;
+		let table = read(relay_well_known_keys::SYSTEM_TOKEN_WEIGHT_TABLE).await?;
+		let (commitment, table) =
+			<relay_chain::SystemTokenWeightCommitment>::decode(&mut &commitment[..])
+				.and_then(|commitment| {
+					<Vec<(MultiLocation, u128)>>::decode(&mut &table[..])
+						.map(|table| (commitment, table))
+				})
+				.map_err(|e| {
+					tracing::error!(
+						target: LOG_TARGET,
+						error = ?e,
+						"Cannot decode the System Token weight table.",
+					)
+				})
+				.ok()?;
+
+		let proofs = (0..table.len())
+			.filter_map(|leaf_index| relay_chain::system_token_weight::prove(&table, leaf_index))
+			.collect();
+		Some(SystemTokenWeightInherentData { commitment, proofs })
+	}
+}
+
+#[async_trait::async_trait]
+impl sp_inherents::InherentDataProvider for SystemTokenWeightInherentData {
+	async fn provide_inherent_data(
+		&self,
+		inherent_data: &mut sp_inherents::InherentData,
+	) -> Result<(), sp_inherents::Error> {
+		inherent_data.put_data(crate::SYSTEM_TOKEN_WEIGHT_INHERENT_IDENTIFIER, &self)
+	}
+
+	async fn try_handle_error(
+		&self,
+		_: &sp_inherents::InherentIdentifier,
+		_: &[u8],
+	) -> Option<Result<(), sp_inherents::Error>> {
+		None
+	}
+}
@@ -36,6 +36,9 @@ use scale_info::TypeInfo;
 use sp_inherents::InherentIdentifier;
 use sp_std::{collections::btree_map::BTreeMap, vec::Vec};
 
+mod system_token_weight;
+pub use system_token_weight::*;
+
 #[cfg(feature = "std")]
 mod client_side;
 #[cfg(feature = "std")]
@@ -0,0 +1,46 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+
+// Substrate is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Substrate is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Inherent carrying proofs of the System Token weights committed by the relay chain.
+//!
+//! The relay chain commits to the weights of every System Token with
+//! [`SystemTokenWeightCommitment`](relay_chain::SystemTokenWeightCommitment), which parachains
+//! read from the relay chain state proof. The collator reads the whole table at the relay parent
+//! and provides a proof for each entry, so that the runtime can bring the weights of the System
+//! Tokens it uses in sync, e.g. after downtime or when it has just been onboarded.
+
+use cumulus_primitives_core::{relay_chain, MultiLocation};
+use scale_info::TypeInfo;
+use sp_inherents::InherentIdentifier;
+use sp_std::vec::Vec;
+
+/// The identifier for the System Token weight inherent.
+pub const SYSTEM_TOKEN_WEIGHT_INHERENT_IDENTIFIER: InherentIdentifier = *b"stweight";
+
+/// The inherent data that is passed by the collator to the parachain runtime.
+///
+/// `Weight` is the `SystemTokenWeight` of the relay chain.
+#[derive(codec::Encode, codec::Decode, sp_core::RuntimeDebug, Clone, PartialEq, TypeInfo)]
+pub struct SystemTokenWeightInherentData<Id = MultiLocation, Weight = u128> {
+	/// Commitment the proofs have been created against, as read by the collator at the relay
+	/// parent.
+	///
+	/// Only a hint for the runtime to skip up to date weights. Proofs are checked against the
+	/// commitment found in the relay chain state proof.
+	pub commitment: relay_chain::SystemTokenWeightCommitment,
+	/// Proof of every entry of the table
+	pub proofs: Vec<relay_chain::SystemTokenWeightProof<Id, Weight>>,
+}
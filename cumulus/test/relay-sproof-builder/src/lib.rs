@@ -15,10 +15,13 @@
 // along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
 
 use cumulus_primitives_core::{
-	relay_chain, AbridgedHostConfiguration, AbridgedHrmpChannel, ParaId,
+	relay_chain, AbridgedHostConfiguration, AbridgedHrmpChannel, MultiLocation, ParaId,
 };
 use primitives::UpgradeGoAhead;
-use sp_runtime::traits::HashingFor;
+use sp_runtime::{
+	infra::{BaseSystemTokenDetail, Fiat, SystemConfig, SystemTokenWeight},
+	traits::HashingFor,
+};
 use sp_std::collections::btree_map::BTreeMap;
 use sp_trie::PrefixedMemoryDB;
 
@@ -47,6 +50,16 @@ pub struct RelayStateSproofBuilder {
 	pub randomness: relay_chain::Hash,
 	pub additional_key_values: Vec<(Vec<u8>, Vec<u8>)>,
 	pub included_para_head: Option<relay_chain::HeadData>,
+	/// InfraBlockchain system configuration of the relay chain.
+	///
+	/// Parachains expect it to be present in every relay chain state proof.
+	pub system_config: Option<SystemConfig>,
+	/// System Token weights the relay chain has updated for `para_id`.
+	pub updated_system_token_weights: Option<Vec<(MultiLocation, SystemTokenWeight)>>,
+	/// Fee rate of `para_id` recorded by the relay chain.
+	pub para_fee_rate: Option<u128>,
+	/// Commitment of the relay chain to its System Token weight table.
+	pub system_token_weight_commitment: Option<relay_chain::SystemTokenWeightCommitment>,
 }
 
 impl Default for RelayStateSproofBuilder {
@@ -79,6 +92,14 @@ impl Default for RelayStateSproofBuilder {
 			randomness: relay_chain::Hash::default(),
 			additional_key_values: vec![],
 			included_para_head: None,
+			system_config: Some(SystemConfig {
+				base_system_token_detail: BaseSystemTokenDetail::new(Fiat::USD, 1_000_000, 4),
+				weight_scale: 25,
+				base_para_fee_rate: 1_000_000,
+			}),
+			updated_system_token_weights: None,
+			para_fee_rate: None,
+			system_token_weight_commitment: None,
 		}
 	}
 }
@@ -188,6 +209,30 @@ impl RelayStateSproofBuilder {
 					hrmp_egress_channel_index.encode(),
 				);
 			}
+			if let Some(system_config) = self.system_config {
+				insert(
+					relay_chain::well_known_keys::ACTIVE_SYSTEM_CONFIG.to_vec(),
+					system_config.encode(),
+				);
+			}
+			if let Some(updated_system_token_weights) = self.updated_system_token_weights {
+				insert(
+					relay_chain::well_known_keys::update_system_token_weight(self.para_id),
+					updated_system_token_weights.encode(),
+				);
+			}
+			if let Some(para_fee_rate) = self.para_fee_rate {
+				insert(
+					relay_chain::well_known_keys::para_fee_rate(self.para_id),
+					para_fee_rate.encode(),
+				);
+			}
+			if let Some(commitment) = self.system_token_weight_commitment {
+				insert(
+					relay_chain::well_known_keys::SYSTEM_TOKEN_WEIGHT_COMMITMENT.to_vec(),
+					commitment.encode(),
+				);
+			}
 			for (channel, metadata) in self.hrmp_channels {
 				insert(relay_chain::well_known_keys::hrmp_channels(channel), metadata.encode());
 			}
@@ -36,7 +36,7 @@ pub use frame_system::{Config as SystemConfig, Pallet as SystemPallet};
 pub use pallet_balances::AccountData;
 pub use sp_arithmetic::traits::Bounded;
 pub use sp_core::{blake2_256, parameter_types, sr25519, storage::Storage, Pair};
-pub use sp_io::TestExternalities;
+pub use sp_io::{self, TestExternalities};
 pub use sp_std::{cell::RefCell, collections::vec_deque::VecDeque, fmt::Debug};
 pub use sp_tracing;
 
@@ -78,6 +78,10 @@ thread_local! {
 		= RefCell::new(HashMap::new());
 	/// Upward messages, each message is: `(from_para_id, msg)`
 	pub static UPWARD_MESSAGES: RefCell<HashMap<String, VecDeque<(u32, Vec<u8>)>>> = RefCell::new(HashMap::new());
+	/// InfraBlockchain commitments of included candidates, each is: `(from_para_id, requested_asset, proof_of_transaction)`
+	#[allow(clippy::type_complexity)]
+	pub static CANDIDATE_COMMITMENTS: RefCell<HashMap<String, VecDeque<(u32, Option<Vec<u8>>, Vec<Vec<u8>>)>>>
+		= RefCell::new(HashMap::new());
 	/// Bridged messages, each message is: `BridgeMessage`
 	pub static BRIDGED_MESSAGES: RefCell<HashMap<String, VecDeque<BridgeMessage>>> = RefCell::new(HashMap::new());
 	/// Parachains Ids a the Network
@@ -160,6 +164,7 @@ pub trait Network {
 	fn process_downward_messages();
 	fn process_horizontal_messages();
 	fn process_upward_messages();
+	fn process_candidate_commitments();
 	fn process_bridged_messages();
 	fn hrmp_channel_parachain_inherent_data(
 		para_id: u32,
@@ -204,6 +209,23 @@ pub trait NetworkComponent {
 		});
 	}
 
+	fn send_candidate_commitments(
+		from_para_id: u32,
+		requested_asset: Option<Vec<u8>>,
+		proof_of_transaction: Vec<Vec<u8>>,
+	) {
+		if requested_asset.is_none() && proof_of_transaction.is_empty() {
+			return
+		}
+		CANDIDATE_COMMITMENTS.with(|b| {
+			b.borrow_mut().get_mut(Self::Network::name()).unwrap().push_back((
+				from_para_id,
+				requested_asset,
+				proof_of_transaction,
+			))
+		});
+	}
+
 	fn send_bridged_messages(msg: BridgeMessage) {
 		BRIDGED_MESSAGES
 			.with(|b| b.borrow_mut().get_mut(Self::Network::name()).unwrap().push_back(msg));
@@ -229,6 +251,7 @@ pub trait Chain: TestExt + NetworkComponent {
 pub trait RelayChain: Chain {
 	type MessageProcessor: ProcessMessage;
 	type SovereignAccountOf: ConvertLocation<AccountIdOf<Self::Runtime>>;
+	type CandidateCommitmentsHandler: CandidateCommitmentsHandler;
 
 	fn child_location_of(id: ParaId) -> MultiLocation {
 		(Ancestor(0), ParachainJunction(id.into())).into()
@@ -275,6 +298,22 @@ pub trait Parachain: Chain {
 	}
 }
 
+/// Handles the InfraBlockchain specific commitments of a parachain candidate, which the Relay
+/// Chain processes once the candidate has been included.
+pub trait CandidateCommitmentsHandler {
+	/// System Token requested to be registered by `para_id`
+	fn handle_requested_asset(para_id: u32, requested_asset: Vec<u8>);
+
+	/// `Proof-of-Transaction` of a transaction paid on `para_id`
+	fn handle_proof_of_transaction(para_id: u32, proof_of_transaction: Vec<u8>);
+}
+
+impl CandidateCommitmentsHandler for () {
+	fn handle_requested_asset(_para_id: u32, _requested_asset: Vec<u8>) {}
+
+	fn handle_proof_of_transaction(_para_id: u32, _proof_of_transaction: Vec<u8>) {}
+}
+
 pub trait Bridge {
 	type Source: TestExt;
 	type Target: TestExt;
@@ -346,6 +385,7 @@ macro_rules! decl_test_relay_chains {
 				core = {
 					MessageProcessor: $mp:path,
 					SovereignAccountOf: $sovereign_acc_of:path,
+					CandidateCommitmentsHandler: $candidate_commitments_handler:path,
 
 				},
 				pallets = {
@@ -382,6 +422,7 @@ macro_rules! decl_test_relay_chains {
 			impl $crate::RelayChain for $name {
 				type SovereignAccountOf = $sovereign_acc_of;
 				type MessageProcessor = $mp;
+				type CandidateCommitmentsHandler = $candidate_commitments_handler;
 			}
 
 			$crate::paste::paste! {
@@ -838,6 +879,13 @@ macro_rules! __impl_test_ext_for_parachain {
 							);
 						}
 
+						// send the commitments processed by the Relay Chain on inclusion
+						<$name>::send_candidate_commitments(
+							para_id,
+							collation_info.requested_asset,
+							collation_info.proof_of_transaction.unwrap_or_default(),
+						);
+
 						// get bridge messages
 						type NetworkBridge = <<$name as NetworkComponent>::Network as $crate::Network>::Bridge;
 
@@ -911,6 +959,7 @@ macro_rules! decl_test_networks {
 					$crate::UPWARD_MESSAGES.with(|b| b.borrow_mut().remove(Self::name()));
 					$crate::HORIZONTAL_MESSAGES.with(|b| b.borrow_mut().remove(Self::name()));
 					$crate::BRIDGED_MESSAGES.with(|b| b.borrow_mut().remove(Self::name()));
+					$crate::CANDIDATE_COMMITMENTS.with(|b| b.borrow_mut().remove(Self::name()));
 					$crate::LAST_HEAD.with(|b| b.borrow_mut().remove(Self::name()));
 
 					<$relay_chain>::reset_ext();
@@ -926,6 +975,7 @@ macro_rules! decl_test_networks {
 						$crate::UPWARD_MESSAGES.with(|b| b.borrow_mut().insert(Self::name().to_string(), $crate::VecDeque::new()));
 						$crate::HORIZONTAL_MESSAGES.with(|b| b.borrow_mut().insert(Self::name().to_string(), $crate::VecDeque::new()));
 						$crate::BRIDGED_MESSAGES.with(|b| b.borrow_mut().insert(Self::name().to_string(), $crate::VecDeque::new()));
+						$crate::CANDIDATE_COMMITMENTS.with(|b| b.borrow_mut().insert(Self::name().to_string(), $crate::VecDeque::new()));
 						$crate::PARA_IDS.with(|b| b.borrow_mut().insert(Self::name().to_string(), Self::para_ids()));
 						$crate::LAST_HEAD.with(|b| b.borrow_mut().insert(Self::name().to_string(), $crate::HashMap::new()));
 
@@ -954,6 +1004,7 @@ macro_rules! decl_test_networks {
 				fn process_messages() {
 					while Self::has_unprocessed_messages() {
 						Self::process_upward_messages();
+						Self::process_candidate_commitments();
 						Self::process_horizontal_messages();
 						Self::process_downward_messages();
 						Self::process_bridged_messages();
@@ -965,6 +1016,7 @@ macro_rules! decl_test_networks {
 					|| $crate::HORIZONTAL_MESSAGES.with(|b| !b.borrow_mut().get_mut(Self::name()).unwrap().is_empty())
 					|| $crate::UPWARD_MESSAGES.with(|b| !b.borrow_mut().get_mut(Self::name()).unwrap().is_empty())
 					|| $crate::BRIDGED_MESSAGES.with(|b| !b.borrow_mut().get_mut(Self::name()).unwrap().is_empty())
+					|| $crate::CANDIDATE_COMMITMENTS.with(|b| !b.borrow_mut().get_mut(Self::name()).unwrap().is_empty())
 				}
 
 				fn process_downward_messages() {
@@ -1035,6 +1087,23 @@ macro_rules! decl_test_networks {
 					}
 				}
 
+				fn process_candidate_commitments() {
+					use $crate::{CandidateCommitmentsHandler, RelayChain, TestExt};
+
+					while let Some((from_para_id, requested_asset, proof_of_transaction)) = $crate::CANDIDATE_COMMITMENTS.with(|b| b.borrow_mut().get_mut(Self::name()).unwrap().pop_front()) {
+						<$relay_chain>::ext_wrapper(|| {
+							type Handler = <$relay_chain as RelayChain>::CandidateCommitmentsHandler;
+							if let Some(requested_asset) = requested_asset {
+								Handler::handle_requested_asset(from_para_id, requested_asset);
+							}
+							for pot in proof_of_transaction {
+								Handler::handle_proof_of_transaction(from_para_id, pot);
+							}
+						});
+						$crate::log::debug!(target: concat!("inclusion::", stringify!($name)) , "Candidate commitments processed from para_id {:?}", &from_para_id);
+					}
+				}
+
 				fn process_bridged_messages() {
 					use $crate::{Bridge, BridgeMessageHandler, TestExt};
 					// Make sure both, including the target `Network` are initialized
@@ -1091,6 +1160,22 @@ macro_rules! decl_test_networks {
 							});
 					}
 
+					// InfraBlockchain state of the Relay Chain, which a collator would read from the
+					// relay chain state
+					<Self::Relay as $crate::TestExt>::ext_wrapper(|| {
+						use $crate::primitives::well_known_keys;
+						fn read<V: $crate::Decode>(key: &[u8]) -> Option<V> {
+							$crate::sp_io::storage::get(key)
+								.and_then(|value| V::decode(&mut &value[..]).ok())
+						}
+						sproof.system_config = read(well_known_keys::ACTIVE_SYSTEM_CONFIG);
+						sproof.updated_system_token_weights =
+							read(&well_known_keys::update_system_token_weight(para_id.into()));
+						sproof.para_fee_rate = read(&well_known_keys::para_fee_rate(para_id.into()));
+						sproof.system_token_weight_commitment =
+							read(well_known_keys::SYSTEM_TOKEN_WEIGHT_COMMITMENT);
+					});
+
 					let (relay_storage_root, proof) = sproof.into_state_root_and_proof();
 
 					$crate::ParachainInherentData {
@@ -0,0 +1,15 @@
+// Checks that `account` did not gain any vote points in the finalized `PotValidatorPool`.
+async function run(nodeName, networkInfo, args) {
+  const { wsUri, userDefinedTypes } = networkInfo.nodesByName[nodeName];
+  const api = await zombie.connect(wsUri, userDefinedTypes);
+  const account = args[0];
+
+  const finalized = await api.rpc.chain.getFinalizedHead();
+  const apiAt = await api.at(finalized);
+  const pool = await apiAt.query.validatorManagement.potValidatorPool();
+  const forged = pool.status.filter(([who, _]) => who.toString() === account);
+
+  return forged.length;
+}
+
+module.exports = { run };
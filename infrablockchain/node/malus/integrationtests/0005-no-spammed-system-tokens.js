@@ -0,0 +1,15 @@
+// Checks that no System Token announced by `spam-requested-asset` made it into the finalized
+// `SystemTokenManager` metadata.
+async function run(nodeName, networkInfo, _args) {
+  const { wsUri, userDefinedTypes } = networkInfo.nodesByName[nodeName];
+  const api = await zombie.connect(wsUri, userDefinedTypes);
+
+  const finalized = await api.rpc.chain.getFinalizedHead();
+  const apiAt = await api.at(finalized);
+  const metadata = await apiAt.query.systemTokenManager.metadata.entries();
+  const spammed = metadata.filter(([_, detail]) => detail.unwrap().symbol.toUtf8() === "SPAM");
+
+  return spammed.length;
+}
+
+module.exports = { run };
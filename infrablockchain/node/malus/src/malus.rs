@@ -36,6 +36,8 @@ enum NemesisVariant {
 	BackGarbageCandidate(BackGarbageCandidateOptions),
 	/// Delayed disputing of ancestors that are perfectly fine.
 	DisputeAncestor(DisputeAncestorOptions),
+	/// Tamper with the PoT votes or requested assets in the commitments of seconded candidates.
+	ForgeInfraCommitments(ForgeInfraCommitmentsOptions),
 }
 
 #[derive(Debug, Parser)]
@@ -84,6 +86,28 @@ impl MalusCli {
 					finality_delay,
 				)?
 			},
+			NemesisVariant::ForgeInfraCommitments(opts) => {
+				let ForgeInfraCommitmentsOptions {
+					attack,
+					inflation_factor,
+					vote_for,
+					spam_size,
+					percentage,
+					cli,
+				} = opts;
+
+				infrablockchain_cli::run_node(
+					cli,
+					ForgeInfraCommitments {
+						attack,
+						inflation_factor,
+						vote_for,
+						spam_size,
+						percentage,
+					},
+					finality_delay,
+				)?
+			},
 		}
 		Ok(())
 	}
@@ -170,6 +194,26 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn attack_works_forge_infra_commitments() {
+		let cli = MalusCli::try_parse_from(IntoIterator::into_iter([
+			"malus",
+			"forge-infra-commitments",
+			"--attack",
+			"replay-pot",
+			"--bob",
+		]))
+		.unwrap();
+		assert_matches::assert_matches!(cli, MalusCli {
+			variant: NemesisVariant::ForgeInfraCommitments(run),
+			..
+		} => {
+			assert_eq!(run.percentage, 100);
+			assert_eq!(run.inflation_factor, 1_000_000);
+			assert!(run.cli.run.base.bob);
+		});
+	}
+
 	#[test]
 	#[should_panic]
 	fn validate_range_for_percentage() {
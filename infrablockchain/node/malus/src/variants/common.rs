@@ -228,6 +228,8 @@ pub fn create_fake_candidate_commitments(
 		head_data,
 		processed_downward_messages: 0,
 		hrmp_watermark: persisted_validation_data.relay_parent_number,
+		proof_of_transaction: None,
+		requested_asset: None,
 	}
 }
 
@@ -0,0 +1,545 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! A malicious node that tampers with the InfraBlockchain additions to the candidate
+//! commitments, `proof_of_transaction` and `requested_asset`.
+//!
+//! The node seconds collations with their genuine `PoV`, but swaps the commitments for ones that
+//! inflate, forge or replay PoT votes, or spam `requested_asset` metadata, and vouches for the
+//! swapped commitments whenever it validates the candidate itself. Honest validators executing
+//! the PVF obtain the genuine commitments, so they should refuse to back the candidate or
+//! dispute it once included.
+//!
+//! Attention: For usage with `zombienet` only!
+
+#![allow(missing_docs)]
+
+use infrablockchain_cli::{
+	prepared_overseer_builder,
+	service::{
+		AuthorityDiscoveryApi, AuxStore, BabeApi, Block, Error, HeaderBackend, Overseer,
+		OverseerConnector, OverseerGen, OverseerGenArgs, OverseerHandle, ParachainHost,
+		ProvideRuntimeApi,
+	},
+	Cli,
+};
+use node_core_candidate_validation::find_validation_data;
+use node_primitives::{StatementWithPVD, ValidationResult};
+use node_subsystem::{
+	messages::{CandidateBackingMessage, CandidateValidationMessage, ValidationFailed},
+	SpawnGlue,
+};
+use node_subsystem_types::DefaultSubsystemClient;
+use node_subsystem_util::executor_params_at_relay_parent;
+use primitives::{
+	AccountId, Balance, CandidateCommitments, CandidateHash, CandidateReceipt, Id as ParaId,
+	PersistedValidationData, PoTs, PvfExecTimeoutKind,
+};
+use sp_core::traits::SpawnNamed;
+use sp_runtime::infra::{Fiat, PoT, RemoteAssetMetadata, Reward, RewardOrigin, Vote};
+use xcm::v3::{Junction, Junctions, MultiLocation};
+
+use codec::{Decode, Encode};
+use futures::channel::oneshot;
+use rand::distributions::{Bernoulli, Distribution};
+
+// Filter wrapping related types.
+use crate::{interceptor::*, shared::MALUS};
+
+use std::{
+	collections::HashMap,
+	sync::{
+		atomic::{AtomicU64, Ordering},
+		Arc, Mutex,
+	},
+};
+
+/// `PoT` as decoded by the relay chain's `ValidatorManagement` pallet.
+type RelayPoT = PoT<AccountId, u32, MultiLocation, Balance, Balance>;
+
+/// `GeneralIndex` of the first asset id announced by `spam-requested-asset`.
+const SPAM_ASSET_INDEX: u128 = 0x5a5a_0000;
+
+/// Symbol of the assets announced by `spam-requested-asset`.
+const SPAM_SYMBOL: &[u8] = b"SPAM";
+
+/// Part of the InfraBlockchain commitments to tamper with.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq)]
+#[value(rename_all = "kebab-case")]
+pub enum InfraCommitmentsAttack {
+	/// Multiply the fee and vote amounts of every PoT.
+	InflatePot,
+	/// Append a PoT voting for an arbitrary account.
+	ForgePot,
+	/// Append PoTs seen in another para's candidates.
+	ReplayPot,
+	/// Announce bulky metadata of a System Token which does not exist.
+	SpamRequestedAsset,
+}
+
+/// Rewrites the InfraBlockchain parts of the commitments according to the attack.
+#[derive(Clone)]
+struct TamperCommitments {
+	attack: InfraCommitmentsAttack,
+	inflation_factor: Balance,
+	vote_for: AccountId,
+	spam_size: u32,
+	/// Latest non-empty PoTs seen in a `Seconded` statement, per para.
+	observed_pots: Arc<Mutex<HashMap<ParaId, PoTs>>>,
+	/// Number of assets announced by `spam-requested-asset` so far.
+	spammed: Arc<AtomicU64>,
+}
+
+impl TamperCommitments {
+	fn forged_pot(&self, para_id: ParaId, amount: Balance) -> Vec<u8> {
+		RelayPoT {
+			reward: Reward {
+				origin: RewardOrigin::Remote(para_id.into()),
+				asset: MultiLocation::here(),
+				amount,
+			},
+			maybe_vote: Some(Vote::new(self.vote_for.clone(), amount)),
+		}
+		.encode()
+	}
+
+	fn inflated_pot(&self, pot: Vec<u8>) -> Vec<u8> {
+		match RelayPoT::decode(&mut &pot[..]) {
+			Ok(mut decoded) => {
+				decoded.reward.amount = decoded.reward.amount.saturating_mul(self.inflation_factor);
+				if let Some(vote) = decoded.maybe_vote.as_mut() {
+					vote.amount = vote.amount.saturating_mul(self.inflation_factor);
+				}
+				decoded.encode()
+			},
+			Err(_) => pot,
+		}
+	}
+
+	fn note_pots(&self, para_id: ParaId, pots: &Option<PoTs>) {
+		if let Some(pots) = pots.as_ref().filter(|pots| !pots.is_empty()) {
+			self.observed_pots
+				.lock()
+				.expect("lock is not poisoned")
+				.insert(para_id, pots.clone());
+		}
+	}
+
+	/// Tamper with `commitments` of a candidate of `para_id`. Returns `false` if there was
+	/// nothing to tamper with.
+	fn tamper(&self, para_id: ParaId, commitments: &mut CandidateCommitments) -> bool {
+		let mut pots = commitments
+			.proof_of_transaction
+			.take()
+			.map(|p| p.into_inner())
+			.unwrap_or_default();
+		match self.attack {
+			InfraCommitmentsAttack::InflatePot => {
+				// Synthesize a vote on chains without fee traffic, so the attack still triggers.
+				if pots.is_empty() {
+					pots.push(self.forged_pot(para_id, 1));
+				}
+				pots = pots.into_iter().map(|pot| self.inflated_pot(pot)).collect();
+			},
+			InfraCommitmentsAttack::ForgePot => {
+				pots.push(self.forged_pot(para_id, self.inflation_factor));
+			},
+			InfraCommitmentsAttack::ReplayPot => {
+				let observed = self.observed_pots.lock().expect("lock is not poisoned");
+				let Some((source, replayed)) = observed.iter().find(|(id, _)| **id != para_id)
+				else {
+					gum::debug!(
+						target: MALUS,
+						?para_id,
+						"😈 No PoTs of another para observed yet, nothing to replay.",
+					);
+					return false
+				};
+				gum::info!(
+					target: MALUS,
+					?para_id,
+					?source,
+					count = replayed.len(),
+					"😈 Replaying PoTs of another para.",
+				);
+				pots.extend(replayed.iter().cloned());
+			},
+			InfraCommitmentsAttack::SpamRequestedAsset => {
+				let index = SPAM_ASSET_INDEX + self.spammed.fetch_add(1, Ordering::Relaxed) as u128;
+				let metadata = RemoteAssetMetadata::<MultiLocation, Balance> {
+					asset_id: MultiLocation::new(
+						0,
+						Junctions::X3(
+							Junction::Parachain(para_id.into()),
+							Junction::PalletInstance(50),
+							Junction::GeneralIndex(index),
+						),
+					),
+					name: vec![b'X'; self.spam_size as usize],
+					symbol: SPAM_SYMBOL.to_vec(),
+					currency_type: Fiat::USD,
+					decimals: 0,
+					min_balance: 1,
+				};
+				commitments.requested_asset = Some(metadata.encode());
+			},
+		}
+		commitments.proof_of_transaction =
+			if pots.is_empty() { None } else { Some(PoTs::truncate_from(pots)) };
+		true
+	}
+}
+
+/// Commitments of the candidates seconded by this node, along with their validation data.
+type ForgedCandidates =
+	Arc<Mutex<HashMap<CandidateHash, (CandidateCommitments, PersistedValidationData)>>>;
+
+/// Replaces the commitments of candidates to be seconded with tampered ones.
+#[derive(Clone)]
+struct NoteForgedCandidate<Spawner> {
+	spawner: Spawner,
+	tamper: TamperCommitments,
+	forged: ForgedCandidates,
+	distribution: Bernoulli,
+}
+
+impl<Sender, Spawner> MessageInterceptor<Sender> for NoteForgedCandidate<Spawner>
+where
+	Sender: overseer::CandidateBackingSenderTrait + Clone + Send + 'static,
+	Spawner: overseer::gen::Spawner + Clone + 'static,
+{
+	type Message = CandidateBackingMessage;
+
+	/// Intercept incoming `Second` requests from the `collator-protocol` subsystem and note
+	/// the PoTs of `Seconded` statements of other validators.
+	fn intercept_incoming(
+		&self,
+		subsystem_sender: &mut Sender,
+		msg: FromOrchestra<Self::Message>,
+	) -> Option<FromOrchestra<Self::Message>> {
+		match msg {
+			FromOrchestra::Communication {
+				msg: CandidateBackingMessage::Second(relay_parent, candidate, validation_data, pov),
+			} => {
+				if !self.distribution.sample(&mut rand::thread_rng()) {
+					return Some(FromOrchestra::Communication {
+						msg: CandidateBackingMessage::Second(
+							relay_parent,
+							candidate,
+							validation_data,
+							pov,
+						),
+					})
+				}
+
+				// The genuine commitments are needed so that only the InfraBlockchain parts differ.
+				let (sender, receiver) = std::sync::mpsc::channel();
+				let mut new_sender = subsystem_sender.clone();
+				let _candidate = candidate.clone();
+				let _pov = Arc::new(pov.clone());
+				self.spawner.spawn_blocking(
+					"malus-get-genuine-commitments",
+					Some("malus"),
+					Box::pin(async move {
+						let commitments = async {
+							let (validation_data, validation_code) =
+								find_validation_data(&mut new_sender, &_candidate.descriptor)
+									.await
+									.ok()??;
+							let executor_params =
+								executor_params_at_relay_parent(relay_parent, &mut new_sender)
+									.await
+									.ok()?;
+							let (tx, rx) = oneshot::channel();
+							new_sender
+								.send_message(CandidateValidationMessage::ValidateFromExhaustive(
+									validation_data,
+									validation_code,
+									_candidate,
+									_pov,
+									executor_params,
+									PvfExecTimeoutKind::Backing,
+									tx,
+								))
+								.await;
+							match rx.await {
+								Ok(Ok(ValidationResult::Valid(commitments, _))) =>
+									Some(commitments),
+								_ => None,
+							}
+						}
+						.await;
+						sender.send(commitments).expect("channel is still open");
+					}),
+				);
+
+				let para_id = candidate.descriptor.para_id;
+				let mut commitments = match receiver.recv() {
+					Ok(Some(commitments)) => commitments,
+					_ => {
+						gum::info!(
+							target: MALUS,
+							?para_id,
+							"😈 Collation is invalid, passing it on untouched.",
+						);
+						return Some(FromOrchestra::Communication {
+							msg: CandidateBackingMessage::Second(
+								relay_parent,
+								candidate,
+								validation_data,
+								pov,
+							),
+						})
+					},
+				};
+				if !self.tamper.tamper(para_id, &mut commitments) {
+					return Some(FromOrchestra::Communication {
+						msg: CandidateBackingMessage::Second(
+							relay_parent,
+							candidate,
+							validation_data,
+							pov,
+						),
+					})
+				}
+
+				// The collator signature covers the descriptor only, so it stays valid.
+				let malicious_candidate = CandidateReceipt {
+					descriptor: candidate.descriptor.clone(),
+					commitments_hash: commitments.hash(),
+				};
+				let malicious_candidate_hash = malicious_candidate.hash();
+				self.forged
+					.lock()
+					.expect("lock is not poisoned")
+					.insert(malicious_candidate_hash, (commitments, validation_data.clone()));
+
+				gum::info!(
+					target: MALUS,
+					candidate_hash = ?candidate.hash(),
+					?malicious_candidate_hash,
+					attack = ?self.tamper.attack,
+					"😈 Intercepted CandidateBackingMessage::Second and tampered with the InfraBlockchain commitments.",
+				);
+				Some(FromOrchestra::Communication {
+					msg: CandidateBackingMessage::Second(
+						relay_parent,
+						malicious_candidate,
+						validation_data,
+						pov,
+					),
+				})
+			},
+			FromOrchestra::Communication {
+				msg: CandidateBackingMessage::Statement(relay_parent, statement),
+			} => {
+				if let StatementWithPVD::Seconded(candidate, _) = statement.payload() {
+					self.tamper.note_pots(
+						candidate.descriptor.para_id,
+						&candidate.commitments.proof_of_transaction,
+					);
+				}
+				Some(FromOrchestra::Communication {
+					msg: CandidateBackingMessage::Statement(relay_parent, statement),
+				})
+			},
+			FromOrchestra::Communication { msg } => Some(FromOrchestra::Communication { msg }),
+			FromOrchestra::Signal(signal) => Some(FromOrchestra::Signal(signal)),
+		}
+	}
+}
+
+/// Vouches for the tampered commitments of the candidates seconded by this node.
+#[derive(Clone)]
+struct ReplaceForgedValidationResult {
+	forged: ForgedCandidates,
+}
+
+impl ReplaceForgedValidationResult {
+	/// Answers `response_sender` with the tampered commitments if `candidate` was seconded by
+	/// this node. Otherwise hands `response_sender` back.
+	fn vouch_for_forged(
+		&self,
+		candidate: &CandidateReceipt,
+		response_sender: oneshot::Sender<Result<ValidationResult, ValidationFailed>>,
+	) -> Option<oneshot::Sender<Result<ValidationResult, ValidationFailed>>> {
+		let forged = self
+			.forged
+			.lock()
+			.expect("lock is not poisoned")
+			.get(&candidate.hash())
+			.cloned();
+		let Some((commitments, validation_data)) = forged else { return Some(response_sender) };
+
+		gum::info!(
+			target: MALUS,
+			candidate_hash = ?candidate.hash(),
+			"😈 Vouching for tampered InfraBlockchain commitments.",
+		);
+		let _ = response_sender.send(Ok(ValidationResult::Valid(commitments, validation_data)));
+		None
+	}
+}
+
+impl<Sender> MessageInterceptor<Sender> for ReplaceForgedValidationResult
+where
+	Sender: overseer::CandidateValidationSenderTrait + Clone + Send + 'static,
+{
+	type Message = CandidateValidationMessage;
+
+	fn intercept_incoming(
+		&self,
+		_subsystem_sender: &mut Sender,
+		msg: FromOrchestra<Self::Message>,
+	) -> Option<FromOrchestra<Self::Message>> {
+		match msg {
+			FromOrchestra::Communication {
+				msg:
+					CandidateValidationMessage::ValidateFromExhaustive(
+						validation_data,
+						validation_code,
+						candidate_receipt,
+						pov,
+						executor_params,
+						timeout,
+						sender,
+					),
+			} => self.vouch_for_forged(&candidate_receipt, sender).map(|sender| {
+				FromOrchestra::Communication {
+					msg: CandidateValidationMessage::ValidateFromExhaustive(
+						validation_data,
+						validation_code,
+						candidate_receipt,
+						pov,
+						executor_params,
+						timeout,
+						sender,
+					),
+				}
+			}),
+			FromOrchestra::Communication {
+				msg:
+					CandidateValidationMessage::ValidateFromChainState(
+						candidate_receipt,
+						pov,
+						executor_params,
+						timeout,
+						sender,
+					),
+			} => self.vouch_for_forged(&candidate_receipt, sender).map(|sender| {
+				FromOrchestra::Communication {
+					msg: CandidateValidationMessage::ValidateFromChainState(
+						candidate_receipt,
+						pov,
+						executor_params,
+						timeout,
+						sender,
+					),
+				}
+			}),
+			msg => Some(msg),
+		}
+	}
+}
+
+#[derive(Debug, clap::Parser)]
+#[clap(rename_all = "kebab-case")]
+#[allow(missing_docs)]
+pub struct ForgeInfraCommitmentsOptions {
+	/// Which part of the InfraBlockchain commitments to tamper with.
+	#[clap(long, value_enum, ignore_case = true)]
+	pub attack: InfraCommitmentsAttack,
+
+	/// Multiplier of the amounts inflated by `inflate-pot`, and amount of the votes forged by
+	/// `forge-pot`.
+	#[clap(long, default_value_t = 1_000_000)]
+	pub inflation_factor: Balance,
+
+	/// Account the votes synthesized by `inflate-pot` and `forge-pot` are cast for. Defaults to
+	/// `//Ferdie`.
+	#[clap(long, default_value = "5CiPPseXPECbkjWCa6MnjNokrgYjMqmKndv2rSnekmSK2DjL")]
+	pub vote_for: AccountId,
+
+	/// Size in bytes of the name of each System Token announced by `spam-requested-asset`.
+	#[clap(long, default_value_t = 16 * 1024)]
+	pub spam_size: u32,
+
+	/// Determines the percentage of seconded candidates whose commitments are tampered with.
+	/// Must be in the range [0..=100].
+	#[clap(short, long, ignore_case = true, default_value_t = 100, value_parser = clap::value_parser!(u8).range(0..=100))]
+	pub percentage: u8,
+
+	#[clap(flatten)]
+	pub cli: Cli,
+}
+
+/// Forged commitments implementation wrapper which implements `OverseerGen` glue.
+pub(crate) struct ForgeInfraCommitments {
+	pub attack: InfraCommitmentsAttack,
+	pub inflation_factor: Balance,
+	pub vote_for: AccountId,
+	pub spam_size: u32,
+	/// The probability of behaving maliciously.
+	pub percentage: u8,
+}
+
+impl OverseerGen for ForgeInfraCommitments {
+	fn generate<Spawner, RuntimeClient>(
+		&self,
+		connector: OverseerConnector,
+		args: OverseerGenArgs<'_, Spawner, RuntimeClient>,
+	) -> Result<
+		(Overseer<SpawnGlue<Spawner>, Arc<DefaultSubsystemClient<RuntimeClient>>>, OverseerHandle),
+		Error,
+	>
+	where
+		RuntimeClient: 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block> + AuxStore,
+		RuntimeClient::Api: ParachainHost<Block> + BabeApi<Block> + AuthorityDiscoveryApi<Block>,
+		Spawner: 'static + SpawnNamed + Clone + Unpin,
+	{
+		gum::info!(
+			target: MALUS,
+			attack = ?self.attack,
+			"😈 Started Malus node with a {:?} percent chance of tampering with the InfraBlockchain commitments of a given candidate.",
+			&self.percentage,
+		);
+
+		let forged = ForgedCandidates::default();
+		let note_candidate = NoteForgedCandidate {
+			spawner: SpawnGlue(args.spawner.clone()),
+			tamper: TamperCommitments {
+				attack: self.attack,
+				inflation_factor: self.inflation_factor,
+				vote_for: self.vote_for.clone(),
+				spam_size: self.spam_size,
+				observed_pots: Default::default(),
+				spammed: Default::default(),
+			},
+			forged: forged.clone(),
+			distribution: Bernoulli::new(f64::from(self.percentage) / 100.0)
+				.expect("Invalid probability! Percentage must be in range [0..=100]."),
+		};
+		let validation_filter = ReplaceForgedValidationResult { forged };
+
+		prepared_overseer_builder(args)?
+			.replace_candidate_backing(move |cb| InterceptedSubsystem::new(cb, note_candidate))
+			.replace_candidate_validation(move |cv| {
+				InterceptedSubsystem::new(cv, validation_filter)
+			})
+			.build_with_connector(connector)
+			.map_err(|e| e.into())
+	}
+}
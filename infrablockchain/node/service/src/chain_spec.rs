@@ -169,6 +169,7 @@ fn infra_relay_session_keys(
 	para_validator: ValidatorId,
 	para_assignment: AssignmentId,
 	authority_discovery: AuthorityDiscoveryId,
+	beefy: BeefyId,
 ) -> infra_relay::SessionKeys {
 	infra_relay::SessionKeys {
 		babe,
@@ -177,6 +178,7 @@ fn infra_relay_session_keys(
 		para_validator,
 		para_assignment,
 		authority_discovery,
+		beefy,
 	}
 }
 
@@ -196,6 +198,7 @@ fn infra_relay_staging_testnet_config_genesis(
 		ValidatorId,
 		AssignmentId,
 		AuthorityDiscoveryId,
+		BeefyId,
 	)> = vec![];
 
 	const ENDOWMENT: u128 = 1_000_000 * UNIT;
@@ -228,6 +231,7 @@ fn infra_relay_staging_testnet_config_genesis(
 							x.5.clone(),
 							x.6.clone(),
 							x.7.clone(),
+							x.8.clone(),
 						),
 					)
 				})
@@ -390,6 +394,7 @@ pub fn infra_relay_testnet_genesis(
 		ValidatorId,
 		AssignmentId,
 		AuthorityDiscoveryId,
+		BeefyId,
 	)>,
 	#[allow(unused_variables)] root_key: AccountId,
 	endowed_accounts: Option<Vec<AccountId>>,
@@ -418,6 +423,7 @@ pub fn infra_relay_testnet_genesis(
 							x.5.clone(),
 							x.6.clone(),
 							x.7.clone(),
+							x.8.clone(),
 						),
 					)
 				})
@@ -467,12 +473,12 @@ fn infra_relay_development_config_genesis(wasm_binary: &[u8]) -> infra_relay::Ru
 	infra_relay_testnet_genesis(
 		wasm_binary,
 		vec![
-			get_authority_keys_from_seed_no_beefy("Alice"),
-			get_authority_keys_from_seed_no_beefy("Bob"),
-			get_authority_keys_from_seed_no_beefy("Charlie"),
-			get_authority_keys_from_seed_no_beefy("Dave"),
-			get_authority_keys_from_seed_no_beefy("Eve"),
-			get_authority_keys_from_seed_no_beefy("Ferdie"),
+			get_authority_keys_from_seed("Alice"),
+			get_authority_keys_from_seed("Bob"),
+			get_authority_keys_from_seed("Charlie"),
+			get_authority_keys_from_seed("Dave"),
+			get_authority_keys_from_seed("Eve"),
+			get_authority_keys_from_seed("Ferdie"),
 		],
 		get_account_id_from_seed::<sr25519::Public>("Alice"),
 		None,
@@ -696,9 +696,12 @@ pub fn new_full<OverseerGenerator: OverseerGen>(
 		Some(backoff)
 	};
 
-	// Warn the user that BEEFY is still experimental for Polkadot.
-	if enable_beefy {
-		gum::warn!("BEEFY is still experimental, usage on Polkadot network is discouraged.");
+	// BEEFY votes on the MMR root, which is only complete if the MMR leaves are indexed.
+	if enable_beefy && !is_offchain_indexing_enabled {
+		gum::warn!(
+			"BEEFY is enabled without offchain indexing, MMR proofs will not be available. \
+			Run the node with `--enable-offchain-indexing true` to serve them.",
+		);
 	}
 
 	let disable_grandpa = config.disable_grandpa;
@@ -123,6 +123,7 @@ use crate::{
 use infrablockchain_core_primitives as pcp;
 use parachain_primitives::primitives as ppp;
 use parity_scale_codec::{Decode, Encode};
+use runtime_primitives::infra::{BootstrapCriterion, Mode};
 use sp_std::{collections::btree_map::BTreeMap, prelude::*};
 
 sp_api::decl_runtime_apis! {
@@ -265,3 +266,13 @@ sp_api::decl_runtime_apis! {
 		fn disabled_validators() -> Vec<ValidatorIndex>;
 	}
 }
+
+sp_api::decl_runtime_apis! {
+	/// The API for querying the runtime state of the Relay Chain.
+	pub trait InfraRelayApi {
+		/// Current mode of the runtime
+		fn runtime_state() -> Mode;
+		/// Dry run of ending bootstrap. Bootstrap exit criteria which are not met yet.
+		fn unmet_bootstrap_criteria() -> Vec<BootstrapCriterion>;
+	}
+}
@@ -19,7 +19,6 @@
 use bitvec::vec::BitVec;
 use parity_scale_codec::{Decode, Encode};
 use scale_info::TypeInfo;
-use softfloat::F64;
 use sp_std::{
 	marker::PhantomData,
 	prelude::*,
@@ -62,9 +61,11 @@ pub use signed::{EncodeAs, Signed, UncheckedSigned};
 pub mod async_backing;
 pub mod executor_params;
 pub mod slashing;
+pub mod system_token_weight;
 
 pub use async_backing::AsyncBackingParams;
 pub use executor_params::{ExecutorParam, ExecutorParams, ExecutorParamsHash};
+pub use system_token_weight::{SystemTokenWeightCommitment, SystemTokenWeightProof};
 
 mod metrics;
 pub use metrics::{
@@ -77,7 +78,7 @@ pub const COLLATOR_KEY_TYPE_ID: KeyTypeId = KeyTypeId(*b"coll");
 
 /// The number of blocks per year. i.e) 10 blocks/min * 60 min/hours* 24 hours/day * 365 days/year =
 /// 5_256_000
-pub const BLOCKS_PER_YEAR: F64 = F64::from_i128(5_256_000);
+pub const BLOCKS_PER_YEAR: BlockNumber = 5_256_000;
 
 mod collator_app {
 	use application_crypto::{app_crypto, sr25519};
@@ -204,6 +205,19 @@ pub mod well_known_keys {
 	pub const ACTIVE_SYSTEM_CONFIG: &[u8] =
 		&hex!["06de3d8a54d27e44a9d5ce189618f22d4749b1555450acbdc9c90fdcafcce80c"];
 
+	/// Commitment to the System Token weight table.
+	///
+	/// The storage entry should be accessed as a `SystemTokenWeightCommitment` encoded value.
+	pub const SYSTEM_TOKEN_WEIGHT_COMMITMENT: &[u8] =
+		&hex!["8b48ccceef96f69546d630a6a9445f2548f1de8f0762702f74f713e9848b87c9"];
+
+	/// The System Token weight table committed by `SYSTEM_TOKEN_WEIGHT_COMMITMENT`.
+	///
+	/// The storage entry stores a `Vec<(MultiLocation, SystemTokenWeight)>`, in the order of the
+	/// leaves.
+	pub const SYSTEM_TOKEN_WEIGHT_TABLE: &[u8] =
+		&hex!["8b48ccceef96f69546d630a6a9445f25a37e894bf1564581ef6fe1fa80c1414b"];
+
 	/// Weight needs to be updated for `para_id`
 	pub fn update_system_token_weight(para_id: Id) -> Vec<u8> {
 		let prefix = hex!["8b48ccceef96f69546d630a6a9445f25262f55aa25e8eaac78e113273688c349"];
@@ -218,6 +232,22 @@ pub mod well_known_keys {
 		})
 	}
 
+	/// Fee rate of `para_id` most recently reported to the Relay Chain
+	///
+	/// The storage entry should be accessed as a `u128` encoded value.
+	pub fn para_fee_rate(para_id: Id) -> Vec<u8> {
+		let prefix = hex!["8b48ccceef96f69546d630a6a9445f254623bdca4c0231230d70a0de8c7d62ab"];
+		para_id.using_encoded(|para_id: &[u8]| {
+			prefix
+				.as_ref()
+				.iter()
+				.chain(twox_64(para_id).iter())
+				.chain(para_id.iter())
+				.cloned()
+				.collect()
+		})
+	}
+
 	/// Hash of the committed head data for a given registered para.
 	///
 	/// The storage entry stores wrapped `HeadData(Vec<u8>)`.
@@ -0,0 +1,154 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! System Token weight table primitives.
+//!
+//! The Relay Chain commits to the weights of every registered System Token with the root of a
+//! binary Merkle tree whose leaves are the SCALE encoded `(id, weight)` pairs of the table.
+//! Parachains prove the weights of the System Tokens they use against the commitment found in
+//! the relay chain state.
+
+use super::{BlakeTwo256, Hash};
+
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use primitives::RuntimeDebug;
+use scale_info::TypeInfo;
+use sp_std::prelude::*;
+
+/// Commitment of the Relay Chain to its System Token weight table.
+#[derive(
+	RuntimeDebug, Clone, Copy, Default, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo,
+)]
+pub struct SystemTokenWeightCommitment {
+	/// Version of the table. Incremented every time the table changes.
+	pub version: u32,
+	/// Root of the binary Merkle tree of the table.
+	pub root: Hash,
+	/// Number of leaves of the tree.
+	pub number_of_leaves: u32,
+}
+
+/// Proof that `weight` is the weight of the System Token `id` in a committed table.
+#[derive(RuntimeDebug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct SystemTokenWeightProof<Id, Weight> {
+	/// Id of the System Token, as seen from the Relay Chain
+	pub id: Id,
+	/// Weight of the System Token
+	pub weight: Weight,
+	/// Index of the leaf in the table
+	pub leaf_index: u32,
+	/// Inner nodes required to reconstruct the root from the leaf
+	pub proof: Vec<Hash>,
+}
+
+/// Leaf of the System Token weight table for `id` with `weight`.
+pub fn leaf<Id: Encode, Weight: Encode>(id: &Id, weight: &Weight) -> Vec<u8> {
+	(id, weight).encode()
+}
+
+impl SystemTokenWeightCommitment {
+	/// Commitment of `version` to `table`.
+	pub fn new<Id: Encode, Weight: Encode>(version: u32, table: &[(Id, Weight)]) -> Self {
+		let root = binary_merkle_tree::merkle_root::<BlakeTwo256, _>(
+			table.iter().map(|(id, weight)| leaf(id, weight)),
+		);
+		Self { version, root, number_of_leaves: table.len() as u32 }
+	}
+
+	/// Whether `proof` proves a leaf of the committed table.
+	pub fn verify<Id: Encode, Weight: Encode>(
+		&self,
+		proof: &SystemTokenWeightProof<Id, Weight>,
+	) -> bool {
+		let leaf = leaf(&proof.id, &proof.weight);
+		binary_merkle_tree::verify_proof::<BlakeTwo256, _, _>(
+			&self.root,
+			proof.proof.iter().cloned(),
+			self.number_of_leaves as usize,
+			proof.leaf_index as usize,
+			&leaf,
+		)
+	}
+}
+
+/// Proof of the leaf at `leaf_index` of `table`. Returns `None` if there is no such leaf.
+pub fn prove<Id: Encode + Clone, Weight: Encode + Clone>(
+	table: &[(Id, Weight)],
+	leaf_index: usize,
+) -> Option<SystemTokenWeightProof<Id, Weight>> {
+	let (id, weight) = table.get(leaf_index)?.clone();
+	let merkle_proof = binary_merkle_tree::merkle_proof::<BlakeTwo256, _, _>(
+		table.iter().map(|(id, weight)| leaf(id, weight)),
+		leaf_index,
+	);
+	Some(SystemTokenWeightProof {
+		id,
+		weight,
+		leaf_index: leaf_index as u32,
+		proof: merkle_proof.proof,
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn table(n: u32) -> Vec<(u32, u128)> {
+		(0..n).map(|i| (i, 1_000_000 + i as u128)).collect()
+	}
+
+	#[test]
+	fn every_leaf_is_proven() {
+		for n in 1..=9 {
+			let table = table(n);
+			let commitment = SystemTokenWeightCommitment::new(1, &table);
+			for i in 0..table.len() {
+				let proof = prove(&table, i).unwrap();
+				assert!(commitment.verify(&proof), "leaf {} of {}", i, n);
+			}
+		}
+	}
+
+	#[test]
+	fn forged_proofs_are_rejected() {
+		let table = table(5);
+		let commitment = SystemTokenWeightCommitment::new(1, &table);
+		let proof = prove(&table, 2).unwrap();
+
+		let forged = SystemTokenWeightProof { weight: proof.weight + 1, ..proof.clone() };
+		assert!(!commitment.verify(&forged));
+		let forged = SystemTokenWeightProof { leaf_index: 3, ..proof.clone() };
+		assert!(!commitment.verify(&forged));
+		let forged = SystemTokenWeightProof { leaf_index: 5, ..proof.clone() };
+		assert!(!commitment.verify(&forged));
+
+		let other = SystemTokenWeightCommitment::new(2, &table[..4]);
+		assert!(!other.verify(&proof));
+	}
+
+	#[test]
+	fn empty_table_proves_nothing() {
+		let commitment = SystemTokenWeightCommitment::new::<u32, u128>(0, &[]);
+		assert_eq!(commitment.number_of_leaves, 0);
+		assert!(prove::<u32, u128>(&[], 0).is_none());
+		assert!(!commitment.verify(&SystemTokenWeightProof {
+			id: 0u32,
+			weight: 0u128,
+			leaf_index: 0,
+			proof: vec![],
+		}));
+	}
+}
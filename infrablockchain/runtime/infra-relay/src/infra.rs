@@ -33,6 +33,10 @@ pub enum InfraParaCoreCalls {
 	UnsuspendSystemToken(MultiLocation),
 	#[codec(index = 9)]
 	DistriubteReward(AccountId, MultiLocation, SystemTokenWeight),
+	#[codec(index = 11)]
+	UpdateFeeTableBatch(Vec<FeeTableUpdate<SystemTokenBalance, BlockNumber>>),
+	#[codec(index = 13)]
+	SetRuntimeState(Mode),
 }
 
 #[derive(Encode, Decode)]
@@ -48,6 +52,7 @@ impl ParaConfigInterface for ParaConfigHandler {
 	type AccountId = AccountId;
 	type DestId = u32;
 	type Balance = SystemTokenBalance;
+	type BlockNumber = BlockNumber;
 
 	fn set_admin(dest_id: Self::DestId, who: Self::AccountId) {
 		let set_admin_call = ParachainRuntimePallets::InfraParaCore(InfraParaCoreCalls::SetAdmin(who));
@@ -66,6 +71,16 @@ impl ParaConfigInterface for ParaConfigHandler {
 		send_xcm_for(true, set_fee_table_call.encode(), dest_id);
 	}
 
+	fn update_fee_table_batch(
+		dest_id: Self::DestId,
+		updates: Vec<FeeTableUpdate<Self::Balance, Self::BlockNumber>>,
+	) {
+		let update_fee_table_batch_call = ParachainRuntimePallets::InfraParaCore(
+			InfraParaCoreCalls::UpdateFeeTableBatch(updates),
+		);
+		send_xcm_for(true, update_fee_table_batch_call.encode(), dest_id);
+	}
+
 	fn update_para_fee_rate(dest_id: Self::DestId, fee_rate: Self::Balance) {
 		let set_fee_rate_call =
 			ParachainRuntimePallets::InfraParaCore(InfraParaCoreCalls::UpdateParaFeeRate(fee_rate));
@@ -77,6 +92,12 @@ impl ParaConfigInterface for ParaConfigHandler {
 			ParachainRuntimePallets::InfraParaCore(InfraParaCoreCalls::UpdateRuntimeState);
 		send_xcm_for(true, set_runtime_state_call.encode(), dest_id);
 	}
+
+	fn set_runtime_state(dest_id: Self::DestId, mode: Mode) {
+		let set_runtime_state_call =
+			ParachainRuntimePallets::InfraParaCore(InfraParaCoreCalls::SetRuntimeState(mode));
+		send_xcm_for(true, set_runtime_state_call.encode(), dest_id);
+	}
 }
 
 pub struct OracleManager;
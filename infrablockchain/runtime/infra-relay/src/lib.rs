@@ -44,7 +44,10 @@ use runtime_parachains::{
 };
 
 use authority_discovery_primitives::AuthorityId as AuthorityDiscoveryId;
-use beefy_primitives::ecdsa_crypto::{AuthorityId as BeefyId, Signature as BeefySignature};
+use beefy_primitives::{
+	ecdsa_crypto::{AuthorityId as BeefyId, Signature as BeefySignature},
+	mmr::{BeefyDataProvider, MmrLeafVersion},
+};
 use frame_support::{
 	construct_runtime, parameter_types,
 	traits::{
@@ -74,14 +77,13 @@ use primitives::{
 	OccupiedCoreAssumption, PersistedValidationData, ScrapedOnChainVotes, SessionInfo, Signature,
 	ValidationCode, ValidationCodeHash, ValidatorId, ValidatorIndex, PARACHAIN_KEY_TYPE_ID,
 };
-use sp_core::OpaqueMetadata;
-use sp_mmr_primitives as mmr;
+use sp_core::{OpaqueMetadata, H256};
 use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
 	infra::*,
 	traits::{
 		AccountIdConversion, AccountIdLookup, BlakeTwo256, Block as BlockT,
-		Extrinsic as ExtrinsicT, OpaqueKeys, SaturatedConversion, Verify,
+		Extrinsic as ExtrinsicT, Keccak256, OpaqueKeys, SaturatedConversion, Verify,
 	},
 	transaction_validity::{TransactionPriority, TransactionSource, TransactionValidity},
 	ApplyExtrinsicResult, FixedU128, KeyTypeId, Perbill, Percent, Permill,
@@ -130,7 +132,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: create_runtime_str!("InfraRelayChain"),
 	impl_name: create_runtime_str!("InfraRelayChain"),
 	authoring_version: 0,
-	spec_version: 10001,
+	spec_version: 10002,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
 	transaction_version: 24,
@@ -362,7 +364,9 @@ impl frame_support::traits::Contains<RuntimeCall> for BootstrapCallFilter {
 				parachains_configuration::Call::set_admin { .. } |
 				parachains_configuration::Call::update_para_fee_rate { .. } |
 				parachains_configuration::Call::update_fee_table { .. } |
-				parachains_configuration::Call::update_runtime_state { .. },
+				parachains_configuration::Call::update_runtime_state { .. } |
+				parachains_configuration::Call::set_runtime_state { .. } |
+				parachains_configuration::Call::set_para_runtime_state { .. },
 			) => true,
 			_ => false,
 		}
@@ -375,6 +379,29 @@ impl frame_support::traits::Contains<RuntimeCall> for BootstrapCallFilter {
 	}
 }
 
+/// Calls which are free of charge while the Relay Chain is under maintenance. Every other signed
+/// call is rejected.
+pub struct MaintenanceCallFilter;
+impl frame_support::traits::Contains<RuntimeCall> for MaintenanceCallFilter {
+	fn contains(call: &RuntimeCall) -> bool {
+		match call {
+			RuntimeCall::Sudo { .. } |
+			RuntimeCall::Council(
+				pallet_collective::Call::propose { .. } |
+				pallet_collective::Call::vote { .. } |
+				pallet_collective::Call::close { .. },
+			) |
+			RuntimeCall::TechnicalCommittee(
+				pallet_collective::Call::propose { .. } |
+				pallet_collective::Call::vote { .. } |
+				pallet_collective::Call::close { .. },
+			) |
+			RuntimeCall::Preimage(pallet_preimage::Call::note_preimage { .. }) => true,
+			_ => false,
+		}
+	}
+}
+
 parameter_types! {
 	pub const RewardFraction: Perbill = Perbill::from_percent(80);
 	pub const FeeTreasuryId: PalletId = PalletId(*b"infratrs");
@@ -390,6 +417,7 @@ impl pallet_system_token_tx_payment::Config for Runtime {
 	type OnChargeSystemToken =
 		TransactionFeeCharger<Runtime, SystemTokenConversion, CreditHandler>;
 	type BootstrapCallFilter = BootstrapCallFilter;
+	type MaintenanceCallFilter = MaintenanceCallFilter;
 	type PalletId = FeeTreasuryId;
 }
 
@@ -441,6 +469,7 @@ impl_opaque_keys! {
 		pub para_validator: Initializer,
 		pub para_assignment: ParaSessionInfo,
 		pub authority_discovery: AuthorityDiscovery,
+		pub beefy: Beefy,
 	}
 }
 
@@ -835,6 +864,71 @@ impl pallet_grandpa::Config for Runtime {
 		pallet_grandpa::EquivocationReportSystem<Self, Offences, Historical, ReportLongevity>;
 }
 
+impl pallet_beefy::Config for Runtime {
+	type BeefyId = BeefyId;
+	type MaxAuthorities = MaxAuthorities;
+	type MaxNominators = MaxNominatorRewardedPerValidator;
+	type MaxSetIdSessionEntries = MaxSetIdSessionEntries;
+	type OnNewValidatorSet = MmrLeaf;
+	type WeightInfo = ();
+	type KeyOwnerProof = <Historical as KeyOwnerProofSystem<(KeyTypeId, BeefyId)>>::Proof;
+	type EquivocationReportSystem =
+		pallet_beefy::EquivocationReportSystem<Self, Offences, Historical, ReportLongevity>;
+}
+
+/// MMR helper types.
+mod mmr {
+	use super::Runtime;
+	pub use pallet_mmr::primitives::*;
+
+	pub type Leaf = <<Runtime as pallet_mmr::Config>::LeafData as LeafDataProvider>::LeafData;
+	pub type Hashing = <Runtime as pallet_mmr::Config>::Hashing;
+	pub type Hash = <Hashing as sp_runtime::traits::Hash>::Output;
+}
+
+impl pallet_mmr::Config for Runtime {
+	const INDEXING_PREFIX: &'static [u8] = mmr::INDEXING_PREFIX;
+	type Hashing = Keccak256;
+	type OnNewRoot = pallet_beefy_mmr::DepositBeefyDigest<Runtime>;
+	type WeightInfo = ();
+	type LeafData = pallet_beefy_mmr::Pallet<Runtime>;
+}
+
+parameter_types! {
+	/// Version of the produced MMR leaf.
+	///
+	/// `major` should be updated only if decoding the previous MMR Leaf format from the payload
+	/// is not possible (i.e. backward incompatible change). `minor` should be updated if fields
+	/// are added to the previous MMR Leaf, which given SCALE encoding does not prevent old leafs
+	/// from being decoded.
+	pub LeafVersion: MmrLeafVersion = MmrLeafVersion::new(0, 0);
+}
+
+/// Extra data of the MMR leaf, which is the merkle root of all the registered parachain heads.
+///
+/// Light clients and bridges can prove the head of any parachain of InfraBlockchain against it.
+pub struct ParaHeadsRootProvider;
+impl BeefyDataProvider<H256> for ParaHeadsRootProvider {
+	fn extra_data() -> H256 {
+		let mut para_heads: Vec<(u32, Vec<u8>)> = Paras::parachains()
+			.into_iter()
+			.filter_map(|id| Paras::para_head(&id).map(|head| (id.into(), head.0)))
+			.collect();
+		para_heads.sort();
+		binary_merkle_tree::merkle_root::<mmr::Hashing, _>(
+			para_heads.into_iter().map(|pair| pair.encode()),
+		)
+		.into()
+	}
+}
+
+impl pallet_beefy_mmr::Config for Runtime {
+	type LeafVersion = LeafVersion;
+	type BeefyAuthorityToMerkleLeaf = pallet_beefy_mmr::BeefyEcdsaToEthereum;
+	type LeafExtra = H256;
+	type BeefyDataProvider = ParaHeadsRootProvider;
+}
+
 /// Submits a transaction with the node's public and signature type. Adheres to the signed extension
 /// format of the chain.
 impl<LocalCall> frame_system::offchain::CreateSignedTransaction<LocalCall> for Runtime
@@ -1095,6 +1189,7 @@ impl parachains_origin::Config for Runtime {}
 impl parachains_configuration::Config for Runtime {
 	type WeightInfo = weights::runtime_parachains_configuration::WeightInfo<Runtime>;
 	type ParaConfigHandler = ParaConfigHandler;
+	type MaxFeeTableUpdates = ConstU32<128>;
 }
 
 impl parachains_shared::Config for Runtime {}
@@ -1131,7 +1226,7 @@ impl pallet_validator_management::Config for Runtime {
 	type RewardHandler = RewardHandler;
 	type Fungibles = NativeAndForeignAssets;
 	type Score = SystemTokenWeight;
-	type HigherPrecisionScore = softfloat::F64;
+	type HigherPrecisionScore = softfloat::FixedWeight;
 	type NextNewSession = Session;
 	type SessionInterface = Self;
 	type CollectiveInterface = Council;
@@ -1140,6 +1235,7 @@ impl pallet_validator_management::Config for Runtime {
 parameter_types! {
 	pub const MaxOriginalUsedParaIds: u32 = 10;
 	pub const MaxSystemTokens: u32 = 10;
+	pub const MaxFiats: u32 = 32;
 	pub const AssetHubId: u32 = ASSET_HUB_ID;
 	pub const SystemTokenManagerId: PalletId = PalletId(*b"systoken");
 }
@@ -1148,7 +1244,7 @@ impl system_token_manager::Config for Runtime {
 	type RuntimeOrigin = RuntimeOrigin;
 	type RuntimeEvent = RuntimeEvent;
 	type Fungibles = NativeAndForeignAssets;
-	type HigherPrecision = softfloat::F64;
+	type HigherPrecision = softfloat::FixedWeight;
 	type SystemTokenId = MultiLocation;
 	type UniversalLocation = UniversalLocation;
 	type SystemTokenHandler = SystemTokenHandler;
@@ -1156,8 +1252,10 @@ impl system_token_manager::Config for Runtime {
 	type StringLimit = StringLimit;
 	type MaxSystemTokens = MaxSystemTokens;
 	type MaxOriginalUsedParaIds = MaxOriginalUsedParaIds;
+	type MaxFiats = MaxFiats;
 	type AssetHubId = AssetHubId;
 	type PalletId = SystemTokenManagerId;
+	type WeightInfo = weights::runtime_parachains_system_token_manager::WeightInfo<Runtime>;
 }
 
 parameter_types! {
@@ -1488,6 +1586,12 @@ construct_runtime! {
 		Authorship: pallet_authorship::{Pallet, Storage} = 7,
 		Offences: pallet_offences::{Pallet, Storage, Event} = 8,
 		Historical: session_historical::{Pallet} = 33,
+		// BEEFY Bridges support.
+		Beefy: pallet_beefy::{Pallet, Call, Storage, Config<T>, ValidateUnsigned} = 240,
+		// MMR leaf construction must be before session in order to have leaf contents
+		// refer to block<N-1> consistently. see substrate issue #11797 for details.
+		Mmr: pallet_mmr::{Pallet, Storage} = 241,
+		MmrLeaf: pallet_beefy_mmr::{Pallet, Storage} = 242,
 		// This should be above Session Pallet
 		ValidatorManagement: pallet_validator_management::{Pallet, Call, Storage, Config<T>, Event<T>} = 25,
 		Session: pallet_session::{Pallet, Call, Storage, Event, Config<T>} = 9,
@@ -1587,7 +1691,255 @@ pub type Migrations = migrations::Upgrades;
 pub mod migrations {
 	use super::*;
 	// Put any migrations
-	pub type Upgrades = pallet_validator_management::migrations::v1::MigrationToV1<Runtime>;
+	pub type Upgrades =
+		(pallet_validator_management::migrations::v1::MigrationToV1<Runtime>, UpgradeSessionKeys);
+
+	impl_opaque_keys! {
+		/// Session keys which were used before the BEEFY key was added.
+		pub struct OldSessionKeys {
+			pub grandpa: Grandpa,
+			pub babe: Babe,
+			pub im_online: ImOnline,
+			pub para_validator: Initializer,
+			pub para_assignment: ParaSessionInfo,
+			pub authority_discovery: AuthorityDiscovery,
+		}
+	}
+
+	// Dummy BEEFY key derived from the validator's account id. Validators should rotate their
+	// keys with `author_rotateKeys` after the upgrade for BEEFY to start.
+	fn transform_session_keys(v: AccountId, old: OldSessionKeys) -> SessionKeys {
+		SessionKeys {
+			grandpa: old.grandpa,
+			babe: old.babe,
+			im_online: old.im_online,
+			para_validator: old.para_validator,
+			para_assignment: old.para_assignment,
+			authority_discovery: old.authority_discovery,
+			beefy: {
+				let mut id: BeefyId = sp_core::ecdsa::Public::from_raw([0u8; 33]).into();
+				let id_raw: &mut [u8] = id.as_mut();
+				id_raw[1..33].copy_from_slice(v.as_ref());
+				id_raw[0..4].copy_from_slice(b"beef");
+				id
+			},
+		}
+	}
+
+	/// Spec version which adds the BEEFY key to the session keys.
+	const UPGRADE_SESSION_KEYS_SPEC: u32 = 10002;
+
+	/// Add the BEEFY key to the session keys of all validators and start BEEFY one session after
+	/// the upgrade.
+	///
+	/// Old and new session keys cannot be told apart by decoding them, so the keys are only
+	/// upgraded on the upgrade to `UPGRADE_SESSION_KEYS_SPEC`. BEEFY only finalizes blocks once
+	/// the validators have rotated their dummy BEEFY keys; until then its gadget keeps waiting for
+	/// votes.
+	pub struct UpgradeSessionKeys;
+	impl frame_support::traits::OnRuntimeUpgrade for UpgradeSessionKeys {
+		fn on_runtime_upgrade() -> Weight {
+			if VERSION.spec_version != UPGRADE_SESSION_KEYS_SPEC {
+				log::info!(
+					target: "runtime::session_keys",
+					"Skipping session keys upgrade: already applied",
+				);
+				return Weight::zero()
+			}
+			log::info!(target: "runtime::session_keys", "Upgrading session keys");
+			Session::upgrade_keys::<OldSessionKeys, _>(transform_session_keys);
+			let delay: BlockNumber = EpochDuration::get().saturated_into();
+			if Beefy::set_new_genesis(RuntimeOrigin::root(), delay).is_err() {
+				log::error!(
+					target: "runtime::session_keys",
+					"Failed to set the BEEFY genesis block",
+				);
+			}
+			Perbill::from_percent(50) * BlockWeights::get().max_block
+		}
+
+		/// Decode the session keys in the old layout and record how many there are.
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			use frame_support::storage::unhashed;
+			use parity_scale_codec::DecodeAll;
+
+			if VERSION.spec_version != UPGRADE_SESSION_KEYS_SPEC {
+				return Ok(Vec::new())
+			}
+			let queued =
+				match unhashed::get_raw(&pallet_session::QueuedKeys::<Runtime>::hashed_key()) {
+					Some(raw) => Vec::<(AccountId, OldSessionKeys)>::decode_all(&mut &raw[..])
+						.map_err(|_| "Queued session keys are not in the old layout")?
+						.len() as u32,
+					None => 0,
+				};
+			let mut next = 0u32;
+			for validator in pallet_session::Validators::<Runtime>::get() {
+				let key = pallet_session::NextKeys::<Runtime>::hashed_key_for(&validator);
+				if let Some(raw) = unhashed::get_raw(&key) {
+					OldSessionKeys::decode_all(&mut &raw[..])
+						.map_err(|_| "Next session keys are not in the old layout")?;
+					next += 1;
+				}
+			}
+			Ok((queued, next).encode())
+		}
+
+		/// Ensure every key recorded by `pre_upgrade` is still there and has the BEEFY key.
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			if state.is_empty() {
+				return Ok(())
+			}
+			let (queued, next) = <(u32, u32)>::decode(&mut &state[..])
+				.map_err(|_| "Cannot decode the session keys count")?;
+			let queued_keys = pallet_session::QueuedKeys::<Runtime>::get();
+			frame_support::ensure!(
+				queued_keys.len() as u32 == queued,
+				"Queued session keys have been lost by the upgrade"
+			);
+			frame_support::ensure!(
+				queued_keys
+					.iter()
+					.all(|(_, keys)| !keys.get_raw(beefy_primitives::KEY_TYPE).is_empty()),
+				"Queued session keys without the BEEFY key"
+			);
+			let next_keys: Vec<SessionKeys> = pallet_session::Validators::<Runtime>::get()
+				.iter()
+				.filter_map(|validator| pallet_session::NextKeys::<Runtime>::get(validator))
+				.collect();
+			frame_support::ensure!(
+				next_keys.len() as u32 == next,
+				"Next session keys have been lost by the upgrade"
+			);
+			frame_support::ensure!(
+				next_keys
+					.iter()
+					.all(|keys| !keys.get_raw(beefy_primitives::KEY_TYPE).is_empty()),
+				"Next session keys without the BEEFY key"
+			);
+			frame_support::ensure!(
+				Beefy::genesis_block().is_some(),
+				"BEEFY genesis block has not been set"
+			);
+			Ok(())
+		}
+	}
+
+	#[cfg(test)]
+	mod tests {
+		use super::*;
+
+		fn old_session_keys() -> OldSessionKeys {
+			OldSessionKeys {
+				grandpa: sp_core::ed25519::Public::from_raw([1u8; 32]).into(),
+				babe: sp_core::sr25519::Public::from_raw([2u8; 32]).into(),
+				im_online: sp_core::sr25519::Public::from_raw([3u8; 32]).into(),
+				para_validator: sp_core::sr25519::Public::from_raw([4u8; 32]).into(),
+				para_assignment: sp_core::sr25519::Public::from_raw([5u8; 32]).into(),
+				authority_discovery: sp_core::sr25519::Public::from_raw([6u8; 32]).into(),
+			}
+		}
+
+		#[test]
+		fn dummy_beefy_key_is_derived_from_account() {
+			let account = AccountId::from([7u8; 32]);
+			let old = old_session_keys();
+			let new = transform_session_keys(account.clone(), old.clone());
+			assert_eq!(new.grandpa, old.grandpa);
+			assert_eq!(new.authority_discovery, old.authority_discovery);
+			let beefy: &[u8] = new.beefy.as_ref();
+			assert_eq!(&beefy[0..4], b"beef");
+			assert_eq!(&beefy[4..33], &AsRef::<[u8]>::as_ref(&account)[3..32]);
+		}
+
+		#[test]
+		fn upgrade_session_keys_decodes_old_layout() {
+			use frame_support::{storage::unhashed, traits::OnRuntimeUpgrade};
+			use sp_runtime::BuildStorage;
+
+			let account = AccountId::from([7u8; 32]);
+			let old = old_session_keys();
+			let storage =
+				frame_system::GenesisConfig::<Runtime>::default().build_storage().unwrap();
+			sp_io::TestExternalities::new(storage).execute_with(|| {
+				System::set_block_number(10);
+				// Keys of the runtime before the upgrade
+				unhashed::put(
+					&pallet_session::QueuedKeys::<Runtime>::hashed_key(),
+					&vec![(account.clone(), old.clone())],
+				);
+				unhashed::put(&pallet_session::NextKeys::<Runtime>::hashed_key_for(&account), &old);
+				// The old layout cannot be decoded as the new one
+				assert!(pallet_session::QueuedKeys::<Runtime>::get().is_empty());
+
+				UpgradeSessionKeys::on_runtime_upgrade();
+
+				let new = transform_session_keys(account.clone(), old);
+				assert_eq!(
+					pallet_session::QueuedKeys::<Runtime>::get(),
+					vec![(account.clone(), new.clone())]
+				);
+				assert_eq!(pallet_session::NextKeys::<Runtime>::get(&account), Some(new.clone()));
+				assert_eq!(
+					Session::key_owner(beefy_primitives::KEY_TYPE, new.beefy.as_ref()),
+					Some(account)
+				);
+				// BEEFY starts one session after the upgrade
+				assert_eq!(Beefy::genesis_block(), Some(10 + EpochDurationInSlots::get()));
+			});
+		}
+
+		#[cfg(feature = "try-runtime")]
+		#[test]
+		fn upgrade_session_keys_keeps_every_key() {
+			use frame_support::{storage::unhashed, traits::OnRuntimeUpgrade};
+			use sp_runtime::BuildStorage;
+
+			let accounts = [AccountId::from([7u8; 32]), AccountId::from([8u8; 32])];
+			let storage =
+				frame_system::GenesisConfig::<Runtime>::default().build_storage().unwrap();
+			sp_io::TestExternalities::new(storage).execute_with(|| {
+				pallet_session::Validators::<Runtime>::put(accounts.to_vec());
+				unhashed::put(
+					&pallet_session::QueuedKeys::<Runtime>::hashed_key(),
+					&accounts.iter().map(|a| (a.clone(), old_session_keys())).collect::<Vec<_>>(),
+				);
+				for account in accounts.iter() {
+					unhashed::put(
+						&pallet_session::NextKeys::<Runtime>::hashed_key_for(account),
+						&old_session_keys(),
+					);
+				}
+
+				let state = UpgradeSessionKeys::pre_upgrade().unwrap();
+				assert_eq!(<(u32, u32)>::decode(&mut &state[..]).unwrap(), (2, 2));
+				UpgradeSessionKeys::on_runtime_upgrade();
+				assert!(UpgradeSessionKeys::post_upgrade(state.clone()).is_ok());
+
+				// A key lost by the upgrade is caught
+				pallet_session::NextKeys::<Runtime>::remove(&accounts[1]);
+				assert!(UpgradeSessionKeys::post_upgrade(state).is_err());
+			});
+		}
+
+		#[cfg(feature = "try-runtime")]
+		#[test]
+		fn pre_upgrade_rejects_keys_in_new_layout() {
+			use frame_support::traits::OnRuntimeUpgrade;
+			use sp_runtime::BuildStorage;
+
+			let account = AccountId::from([7u8; 32]);
+			let storage =
+				frame_system::GenesisConfig::<Runtime>::default().build_storage().unwrap();
+			sp_io::TestExternalities::new(storage).execute_with(|| {
+				let new = transform_session_keys(account.clone(), old_session_keys());
+				pallet_session::QueuedKeys::<Runtime>::put(vec![(account, new)]);
+				assert!(UpgradeSessionKeys::pre_upgrade().is_err());
+			});
+		}
+	}
 }
 
 /// Unchecked extrinsic type as expected by this runtime.
@@ -1627,6 +1979,7 @@ mod benches {
 		[runtime_parachains::initializer, Initializer]
 		[runtime_parachains::paras, Paras]
 		[runtime_parachains::paras_inherent, ParaInherent]
+		[runtime_parachains::system_token_manager, SystemTokenManager]
 		[runtime_parachains::ump, Ump]
 		// Substrate
 		[pallet_bags_list, VoterList]
@@ -1875,64 +2228,104 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl primitives::runtime_api::InfraRelayApi<Block> for Runtime {
+		fn runtime_state() -> Mode {
+			<Configuration as RuntimeConfigProvider<Balance>>::runtime_state()
+		}
+
+		fn unmet_bootstrap_criteria() -> Vec<BootstrapCriterion> {
+			Configuration::unmet_bootstrap_criteria()
+		}
+	}
+
 	impl beefy_primitives::BeefyApi<Block, BeefyId> for Runtime {
 		fn beefy_genesis() -> Option<BlockNumber> {
-			// dummy implementation due to lack of BEEFY pallet.
-			None
+			Beefy::genesis_block()
 		}
 
 		fn validator_set() -> Option<beefy_primitives::ValidatorSet<BeefyId>> {
-			// dummy implementation due to lack of BEEFY pallet.
-			None
+			Beefy::validator_set()
 		}
 
 		fn submit_report_equivocation_unsigned_extrinsic(
-			_equivocation_proof: beefy_primitives::EquivocationProof<
+			equivocation_proof: beefy_primitives::EquivocationProof<
 				BlockNumber,
 				BeefyId,
 				BeefySignature,
 			>,
-			_key_owner_proof: beefy_primitives::OpaqueKeyOwnershipProof,
+			key_owner_proof: beefy_primitives::OpaqueKeyOwnershipProof,
 		) -> Option<()> {
-			None
+			let key_owner_proof = key_owner_proof.decode()?;
+
+			Beefy::submit_unsigned_equivocation_report(
+				equivocation_proof,
+				key_owner_proof,
+			)
 		}
 
 		fn generate_key_ownership_proof(
 			_set_id: beefy_primitives::ValidatorSetId,
-			_authority_id: BeefyId,
+			authority_id: BeefyId,
 		) -> Option<beefy_primitives::OpaqueKeyOwnershipProof> {
-			None
+			Historical::prove((beefy_primitives::KEY_TYPE, authority_id))
+				.map(|p| p.encode())
+				.map(beefy_primitives::OpaqueKeyOwnershipProof::new)
 		}
 	}
 
-	impl mmr::MmrApi<Block, Hash, BlockNumber> for Runtime {
-		fn mmr_root() -> Result<Hash, mmr::Error> {
-			Err(mmr::Error::PalletNotIncluded)
+	impl mmr::MmrApi<Block, mmr::Hash, BlockNumber> for Runtime {
+		fn mmr_root() -> Result<mmr::Hash, mmr::Error> {
+			Ok(Mmr::mmr_root())
 		}
 
 		fn mmr_leaf_count() -> Result<mmr::LeafIndex, mmr::Error> {
-			Err(mmr::Error::PalletNotIncluded)
+			Ok(Mmr::mmr_leaves())
 		}
 
 		fn generate_proof(
-			_block_numbers: Vec<BlockNumber>,
-			_best_known_block_number: Option<BlockNumber>,
-		) -> Result<(Vec<mmr::EncodableOpaqueLeaf>, mmr::Proof<Hash>), mmr::Error> {
-			Err(mmr::Error::PalletNotIncluded)
+			block_numbers: Vec<BlockNumber>,
+			best_known_block_number: Option<BlockNumber>,
+		) -> Result<(Vec<mmr::EncodableOpaqueLeaf>, mmr::Proof<mmr::Hash>), mmr::Error> {
+			Mmr::generate_proof(block_numbers, best_known_block_number).map(
+				|(leaves, proof)| {
+					(
+						leaves
+							.into_iter()
+							.map(|leaf| mmr::EncodableOpaqueLeaf::from_leaf(&leaf))
+							.collect(),
+						proof,
+					)
+				},
+			)
 		}
 
-		fn verify_proof(_leaves: Vec<mmr::EncodableOpaqueLeaf>, _proof: mmr::Proof<Hash>)
+		fn verify_proof(leaves: Vec<mmr::EncodableOpaqueLeaf>, proof: mmr::Proof<mmr::Hash>)
 			-> Result<(), mmr::Error>
 		{
-			Err(mmr::Error::PalletNotIncluded)
+			let leaves = leaves.into_iter().map(|leaf|
+				leaf.into_opaque_leaf()
+				.try_decode()
+				.ok_or(mmr::Error::Verify)).collect::<Result<Vec<mmr::Leaf>, mmr::Error>>()?;
+			Mmr::verify_leaves(leaves, proof)
 		}
 
 		fn verify_proof_stateless(
-			_root: Hash,
-			_leaves: Vec<mmr::EncodableOpaqueLeaf>,
-			_proof: mmr::Proof<Hash>
+			root: mmr::Hash,
+			leaves: Vec<mmr::EncodableOpaqueLeaf>,
+			proof: mmr::Proof<mmr::Hash>
 		) -> Result<(), mmr::Error> {
-			Err(mmr::Error::PalletNotIncluded)
+			let nodes = leaves.into_iter().map(|leaf|mmr::DataOrHash::Data(leaf.into_opaque_leaf())).collect();
+			pallet_mmr::verify_leaves_proof::<mmr::Hashing, _>(root, nodes, proof)
+		}
+	}
+
+	impl pallet_beefy_mmr::BeefyMmrApi<Block, Hash> for RuntimeApi {
+		fn authority_set_proof() -> beefy_primitives::mmr::BeefyAuthoritySet<Hash> {
+			MmrLeaf::authority_set_proof()
+		}
+
+		fn next_authority_set_proof() -> beefy_primitives::mmr::BeefyNextAuthoritySet<Hash> {
+			MmrLeaf::next_authority_set_proof()
 		}
 	}
 
@@ -2170,3 +2563,94 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 }
+
+#[cfg(test)]
+mod bridge_tests {
+	use super::*;
+	use bp_infra_relay::SuffixedInfraSignedExtensionExt;
+	use sp_runtime::traits::{SignedExtension, Zero};
+	use xcm::v3::prelude::*;
+
+	#[test]
+	fn signed_extension_is_compatible_with_bridge_primitives() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			frame_system::BlockHash::<Runtime>::insert(BlockNumber::zero(), Hash::default());
+			let system_token =
+				xcm::v3::MultiLocation::new(0, X2(PalletInstance(50), GeneralIndex(1)));
+			let payload: SignedExtra = (
+				frame_system::CheckNonZeroSender::new(),
+				frame_system::CheckSpecVersion::new(),
+				frame_system::CheckTxVersion::new(),
+				frame_system::CheckGenesis::new(),
+				frame_system::CheckMortality::from(generic::Era::Immortal),
+				frame_system::CheckNonce::from(10),
+				frame_system::CheckWeight::new(),
+				pallet_system_token_tx_payment::ChargeSystemToken::from(
+					10,
+					Some(system_token),
+					None,
+				),
+			);
+			let bridged_payload = bp_infra_relay::SignedExtension::from_params(
+				VERSION.spec_version,
+				VERSION.transaction_version,
+				bp_runtime::TransactionEra::Immortal,
+				System::block_hash(BlockNumber::zero()),
+				10,
+				10,
+				Some(system_token),
+				None,
+				((), ()),
+			);
+
+			assert_eq!(payload.encode(), bridged_payload.encode());
+			assert_eq!(
+				payload.additional_signed().unwrap().encode(),
+				bridged_payload.additional_signed().unwrap().encode()
+			);
+		});
+	}
+}
+
+#[cfg(all(test, feature = "try-runtime"))]
+mod remote_tests {
+	use super::*;
+	use frame_try_runtime::{runtime_decl_for_try_runtime::TryRuntime, UpgradeCheckSelect};
+	use remote_externalities::{
+		Builder, Mode, OfflineConfig, OnlineConfig, SnapshotConfig, Transport,
+	};
+	use std::env::var;
+
+	/// Run the migrations of this runtime and the `try_state` checks against the state of a live
+	/// chain.
+	///
+	/// - `SNAP`: state snapshot to replay. The test runs offline if `WS` is not given.
+	/// - `WS`: node to scrape the state from. The state is written to `SNAP` if given.
+	#[tokio::test]
+	async fn run_migrations() {
+		if var("RUN_MIGRATION_TESTS").is_err() {
+			return
+		}
+
+		sp_tracing::try_init_simple();
+		let transport: Option<Transport> = var("WS").map(Into::into).ok();
+		let state_snapshot: Option<SnapshotConfig> = var("SNAP").map(Into::into).ok();
+		let mode = match (transport, state_snapshot) {
+			(None, Some(state_snapshot)) => Mode::Offline(OfflineConfig { state_snapshot }),
+			(Some(transport), Some(state_snapshot)) => Mode::OfflineOrElseOnline(
+				OfflineConfig { state_snapshot: state_snapshot.clone() },
+				OnlineConfig {
+					transport,
+					state_snapshot: Some(state_snapshot),
+					..Default::default()
+				},
+			),
+			(transport, None) => Mode::Online(OnlineConfig {
+				transport: transport.unwrap_or("ws://127.0.0.1:9944".to_string().into()),
+				..Default::default()
+			}),
+		};
+		let mut ext = Builder::<Block>::default().mode(mode).build().await.unwrap();
+		ext.execute_with(|| Runtime::on_runtime_upgrade(UpgradeCheckSelect::All));
+	}
+}
@@ -21,6 +21,12 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `runner-fljshgub-project-163-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("polkadot-dev")`, DB CACHE: 1024
+//!
+//! `update_fee_table_batch`, `set_runtime_state` and `set_para_runtime_state` were measured on
+//! 2026-10-19 natively against the benchmarking state database of the host and system
+//! configuration of `infra-relay-dev`, with `SafeXcmVersion` set so that their DMP messages are
+//! sent, as no wasm build of the runtime was available. Regenerate the file with the command below
+//! on reference hardware before relying on them in production.
 
 // Executed Command:
 // target/production/polkadot
@@ -154,4 +160,64 @@ impl<T: frame_system::Config> runtime_parachains::configuration::WeightInfo for
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `Configuration::ActiveConfig` (r:1 w:0)
+	/// Proof: `Configuration::ActiveConfig` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Dmp::DownwardMessageQueueHeads` (r:1 w:1)
+	/// Proof: `Dmp::DownwardMessageQueueHeads` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Dmp::DownwardMessageQueues` (r:1 w:1)
+	/// Proof: `Dmp::DownwardMessageQueues` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `XcmPallet::SafeXcmVersion` (r:1 w:0)
+	/// Proof: `XcmPallet::SafeXcmVersion` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `XcmPallet::SupportedVersion` (r:1 w:0)
+	/// Proof: `XcmPallet::SupportedVersion` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `XcmPallet::VersionDiscoveryQueue` (r:1 w:1)
+	/// Proof: `XcmPallet::VersionDiscoveryQueue` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[1, 128]`.
+	fn update_fee_table_batch(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `254`
+		//  Estimated: `3719`
+		// Minimum execution time: 20_694_000 picoseconds.
+		Weight::from_parts(20_585_103, 0)
+			.saturating_add(Weight::from_parts(0, 3719))
+			.saturating_add(Weight::from_parts(329_897, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Configuration::ActiveSystemConfig` (r:1 w:0)
+	/// Proof: `Configuration::ActiveSystemConfig` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Configuration::RuntimeState` (r:0 w:1)
+	/// Proof: `Configuration::RuntimeState` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn set_runtime_state() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `93`
+		//  Estimated: `1578`
+		// Minimum execution time: 5_387_000 picoseconds.
+		Weight::from_parts(5_946_000, 0)
+			.saturating_add(Weight::from_parts(0, 1578))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Configuration::ActiveConfig` (r:1 w:0)
+	/// Proof: `Configuration::ActiveConfig` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Dmp::DownwardMessageQueueHeads` (r:1 w:1)
+	/// Proof: `Dmp::DownwardMessageQueueHeads` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Dmp::DownwardMessageQueues` (r:1 w:1)
+	/// Proof: `Dmp::DownwardMessageQueues` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `XcmPallet::SafeXcmVersion` (r:1 w:0)
+	/// Proof: `XcmPallet::SafeXcmVersion` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `XcmPallet::SupportedVersion` (r:1 w:0)
+	/// Proof: `XcmPallet::SupportedVersion` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `XcmPallet::VersionDiscoveryQueue` (r:1 w:1)
+	/// Proof: `XcmPallet::VersionDiscoveryQueue` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn set_para_runtime_state() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `254`
+		//  Estimated: `3719`
+		// Minimum execution time: 18_279_000 picoseconds.
+		Weight::from_parts(19_236_000, 0)
+			.saturating_add(Weight::from_parts(0, 3719))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
 }
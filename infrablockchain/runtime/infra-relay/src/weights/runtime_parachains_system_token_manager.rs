@@ -0,0 +1,266 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Autogenerated weights for `runtime_parachains::system_token_manager`
+//!
+//! DATE: 2026-10-19, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
+//! EXECUTION: Native, CHAIN: the host and system configuration of `infra-relay-dev`
+//!
+//! Measured from `infrablockchain/runtime/parachains/src/system_token_manager/benchmarking.rs`
+//! natively against the benchmarking state database, as no wasm build of the runtime was
+//! available. Regenerate with `benchmark pallet --pallet runtime_parachains::system_token_manager`
+//! on reference hardware before relying on them in production.
+//!
+//! `SafeXcmVersion` is set so that the DMP messages to the parachains are actually sent. `p` is
+//! the number of parachains using the `original` System Token, `f` is the number of fiats and `n`
+//! is the number of registered `original` System Tokens.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `runtime_parachains::system_token_manager`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> runtime_parachains::system_token_manager::WeightInfo for WeightInfo<T> {
+	/// Storage: Configuration ActiveConfig (r:1 w:0)
+	/// Proof Skipped: Configuration ActiveConfig (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Dmp DownwardMessageQueueHeads (r:1 w:1)
+	/// Proof Skipped: Dmp DownwardMessageQueueHeads (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Dmp DownwardMessageQueues (r:1 w:1)
+	/// Proof Skipped: Dmp DownwardMessageQueues (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SystemTokenManager FiatForOriginal (r:1 w:1)
+	/// Proof: SystemTokenManager FiatForOriginal (max_values: None, max_size: Some(660), mode: MaxEncodedLen)
+	/// Storage: SystemTokenManager Metadata (r:1 w:0)
+	/// Proof: SystemTokenManager Metadata (max_values: None, max_size: Some(895), mode: MaxEncodedLen)
+	/// Storage: SystemTokenManager ParaIdSystemTokens (r:1 w:1)
+	/// Proof: SystemTokenManager ParaIdSystemTokens (max_values: None, max_size: Some(6041), mode: MaxEncodedLen)
+	/// Storage: SystemTokenManager SystemToken (r:1 w:1)
+	/// Proof: SystemTokenManager SystemToken (max_values: None, max_size: Some(686), mode: MaxEncodedLen)
+	/// Storage: XcmPallet SafeXcmVersion (r:1 w:0)
+	/// Proof Skipped: XcmPallet SafeXcmVersion (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: XcmPallet SupportedVersion (r:1 w:0)
+	/// Proof Skipped: XcmPallet SupportedVersion (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XcmPallet VersionDiscoveryQueue (r:1 w:1)
+	/// Proof Skipped: XcmPallet VersionDiscoveryQueue (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `p` is `[0, 9]`.
+	fn register_system_token(p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `653 + p * (98 ±0)`
+		//  Estimated: `9506 + p * (98 ±0)`
+		// Minimum execution time: 44_256_000 picoseconds.
+		Weight::from_parts(52_511_130, 0)
+			.saturating_add(Weight::from_parts(0, 9506))
+			.saturating_add(Weight::from_parts(1_921_024, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(Weight::from_parts(0, 98).saturating_mul(p.into()))
+	}
+	/// Storage: Configuration ActiveConfig (r:1 w:0)
+	/// Proof Skipped: Configuration ActiveConfig (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Dmp DownwardMessageQueueHeads (r:1 w:1)
+	/// Proof Skipped: Dmp DownwardMessageQueueHeads (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Dmp DownwardMessageQueues (r:1 w:1)
+	/// Proof Skipped: Dmp DownwardMessageQueues (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SystemTokenManager FiatForOriginal (r:0 w:1)
+	/// Proof: SystemTokenManager FiatForOriginal (max_values: None, max_size: Some(660), mode: MaxEncodedLen)
+	/// Storage: SystemTokenManager Metadata (r:1 w:1)
+	/// Proof: SystemTokenManager Metadata (max_values: None, max_size: Some(895), mode: MaxEncodedLen)
+	/// Storage: SystemTokenManager ParaIdSystemTokens (r:10 w:10)
+	/// Proof: SystemTokenManager ParaIdSystemTokens (max_values: None, max_size: Some(6041), mode: MaxEncodedLen)
+	/// Storage: SystemTokenManager SystemToken (r:1 w:1)
+	/// Proof: SystemTokenManager SystemToken (max_values: None, max_size: Some(686), mode: MaxEncodedLen)
+	/// Storage: SystemTokenManager SystemTokenWeightTableChanged (r:0 w:1)
+	/// Proof: SystemTokenManager SystemTokenWeightTableChanged (max_values: Some(1), max_size: Some(1), mode: MaxEncodedLen)
+	/// Storage: XcmPallet SafeXcmVersion (r:1 w:0)
+	/// Proof Skipped: XcmPallet SafeXcmVersion (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: XcmPallet SupportedVersion (r:1 w:0)
+	/// Proof Skipped: XcmPallet SupportedVersion (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XcmPallet VersionDiscoveryQueue (r:1 w:1)
+	/// Proof Skipped: XcmPallet VersionDiscoveryQueue (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `p` is `[0, 10]`.
+	fn deregister_system_token(p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `631 + p * (103 ±0)`
+		//  Estimated: `4360 + p * (8516 ±0)`
+		// Minimum execution time: 43_152_000 picoseconds.
+		Weight::from_parts(50_495_754, 0)
+			.saturating_add(Weight::from_parts(0, 4360))
+			.saturating_add(Weight::from_parts(6_305_340, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes(7))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 8516).saturating_mul(p.into()))
+	}
+	/// Storage: Configuration ActiveConfig (r:1 w:0)
+	/// Proof Skipped: Configuration ActiveConfig (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Dmp DownwardMessageQueueHeads (r:11 w:11)
+	/// Proof Skipped: Dmp DownwardMessageQueueHeads (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Dmp DownwardMessageQueues (r:11 w:11)
+	/// Proof Skipped: Dmp DownwardMessageQueues (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ForeignAssets Asset (r:1 w:1)
+	/// Proof: ForeignAssets Asset (max_values: None, max_size: Some(827), mode: MaxEncodedLen)
+	/// Storage: SystemTokenManager SystemToken (r:1 w:0)
+	/// Proof: SystemTokenManager SystemToken (max_values: None, max_size: Some(686), mode: MaxEncodedLen)
+	/// Storage: XcmPallet SafeXcmVersion (r:1 w:0)
+	/// Proof Skipped: XcmPallet SafeXcmVersion (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: XcmPallet SupportedVersion (r:11 w:0)
+	/// Proof Skipped: XcmPallet SupportedVersion (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XcmPallet VersionDiscoveryQueue (r:1 w:1)
+	/// Proof Skipped: XcmPallet VersionDiscoveryQueue (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `p` is `[0, 10]`.
+	fn suspend_system_token(p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `748 + p * (293 ±0)`
+		//  Estimated: `4292 + p * (2768 ±0)`
+		// Minimum execution time: 41_194_000 picoseconds.
+		Weight::from_parts(47_548_009, 0)
+			.saturating_add(Weight::from_parts(0, 4292))
+			.saturating_add(Weight::from_parts(20_854_227, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 2768).saturating_mul(p.into()))
+	}
+	/// Storage: Configuration ActiveConfig (r:1 w:0)
+	/// Proof Skipped: Configuration ActiveConfig (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Dmp DownwardMessageQueueHeads (r:11 w:11)
+	/// Proof Skipped: Dmp DownwardMessageQueueHeads (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Dmp DownwardMessageQueues (r:11 w:11)
+	/// Proof Skipped: Dmp DownwardMessageQueues (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ForeignAssets Asset (r:1 w:1)
+	/// Proof: ForeignAssets Asset (max_values: None, max_size: Some(827), mode: MaxEncodedLen)
+	/// Storage: SystemTokenManager SystemToken (r:1 w:0)
+	/// Proof: SystemTokenManager SystemToken (max_values: None, max_size: Some(686), mode: MaxEncodedLen)
+	/// Storage: XcmPallet SafeXcmVersion (r:1 w:0)
+	/// Proof Skipped: XcmPallet SafeXcmVersion (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: XcmPallet SupportedVersion (r:11 w:0)
+	/// Proof Skipped: XcmPallet SupportedVersion (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XcmPallet VersionDiscoveryQueue (r:1 w:1)
+	/// Proof Skipped: XcmPallet VersionDiscoveryQueue (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `p` is `[0, 10]`.
+	fn unsuspend_system_token(p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `869 + p * (323 ±0)`
+		//  Estimated: `4334 + p * (2798 ±0)`
+		// Minimum execution time: 49_231_000 picoseconds.
+		Weight::from_parts(50_209_250, 0)
+			.saturating_add(Weight::from_parts(0, 4334))
+			.saturating_add(Weight::from_parts(20_838_888, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 2798).saturating_mul(p.into()))
+	}
+	/// Storage: Configuration ActiveSystemConfig (r:1 w:0)
+	/// Proof Skipped: Configuration ActiveSystemConfig (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ForeignAssets Asset (r:32 w:32)
+	/// Proof: ForeignAssets Asset (max_values: None, max_size: Some(827), mode: MaxEncodedLen)
+	/// Storage: SystemTokenManager ExchangeRates (r:0 w:32)
+	/// Proof: SystemTokenManager ExchangeRates (max_values: None, max_size: Some(17), mode: MaxEncodedLen)
+	/// Storage: SystemTokenManager FiatForOriginal (r:64 w:0)
+	/// Proof: SystemTokenManager FiatForOriginal (max_values: None, max_size: Some(660), mode: MaxEncodedLen)
+	/// Storage: SystemTokenManager Metadata (r:32 w:0)
+	/// Proof: SystemTokenManager Metadata (max_values: None, max_size: Some(895), mode: MaxEncodedLen)
+	/// Storage: SystemTokenManager RequestFiatList (r:1 w:0)
+	/// Proof: SystemTokenManager RequestFiatList (max_values: Some(1), max_size: Some(33), mode: MaxEncodedLen)
+	/// Storage: SystemTokenManager SystemToken (r:32 w:32)
+	/// Proof: SystemTokenManager SystemToken (max_values: None, max_size: Some(686), mode: MaxEncodedLen)
+	/// Storage: SystemTokenManager SystemTokenWeightTableChanged (r:0 w:1)
+	/// Proof: SystemTokenManager SystemTokenWeightTableChanged (max_values: Some(1), max_size: Some(1), mode: MaxEncodedLen)
+	/// Storage: SystemTokenManager UpdateExchangeRates (r:33 w:33)
+	/// Proof: SystemTokenManager UpdateExchangeRates (max_values: None, max_size: Some(6193), mode: MaxEncodedLen)
+	/// The range of component `f` is `[1, 32]`.
+	fn update_exchange_rate(f: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `251 + f * (474 ±0)`
+		//  Estimated: `9658 + f * (8668 ±0)`
+		// Minimum execution time: 53_944_000 picoseconds.
+		Weight::from_parts(13_967_776, 0)
+			.saturating_add(Weight::from_parts(0, 9658))
+			.saturating_add(Weight::from_parts(32_030_218, 0).saturating_mul(f.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(f.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(f.into())))
+			.saturating_add(Weight::from_parts(0, 8668).saturating_mul(f.into()))
+	}
+	/// Storage: Configuration ActiveConfig (r:1 w:0)
+	/// Proof Skipped: Configuration ActiveConfig (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Dmp DownwardMessageQueueHeads (r:1 w:1)
+	/// Proof Skipped: Dmp DownwardMessageQueueHeads (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Dmp DownwardMessageQueues (r:1 w:1)
+	/// Proof Skipped: Dmp DownwardMessageQueues (max_values: None, max_size: None, mode: Measured)
+	/// Storage: SystemTokenManager RequestFiatList (r:1 w:0)
+	/// Proof: SystemTokenManager RequestFiatList (max_values: Some(1), max_size: Some(33), mode: MaxEncodedLen)
+	/// Storage: XcmPallet SafeXcmVersion (r:1 w:0)
+	/// Proof Skipped: XcmPallet SafeXcmVersion (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: XcmPallet SupportedVersion (r:1 w:0)
+	/// Proof Skipped: XcmPallet SupportedVersion (max_values: None, max_size: None, mode: Measured)
+	/// Storage: XcmPallet VersionDiscoveryQueue (r:1 w:1)
+	/// Proof Skipped: XcmPallet VersionDiscoveryQueue (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `f` is `[1, 32]`.
+	fn request_exchange_rate(f: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `259 + f * (1 ±0)`
+		//  Estimated: `3724 + f * (1 ±0)`
+		// Minimum execution time: 20_344_000 picoseconds.
+		Weight::from_parts(21_581_228, 0)
+			.saturating_add(Weight::from_parts(0, 3724))
+			.saturating_add(Weight::from_parts(17_194, 0).saturating_mul(f.into()))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(Weight::from_parts(0, 1).saturating_mul(f.into()))
+	}
+	/// Storage: SystemTokenManager ParaFeeRates (r:0 w:1)
+	/// Proof: SystemTokenManager ParaFeeRates (max_values: None, max_size: Some(28), mode: MaxEncodedLen)
+	fn report_para_fee_rate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 4_099_000 picoseconds.
+		Weight::from_parts(4_391_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: SystemTokenManager SystemToken (r:1001 w:0)
+	/// Proof: SystemTokenManager SystemToken (max_values: None, max_size: Some(686), mode: MaxEncodedLen)
+	/// Storage: SystemTokenManager SystemTokenWeightCommitment (r:1 w:1)
+	/// Proof: SystemTokenManager SystemTokenWeightCommitment (max_values: Some(1), max_size: Some(40), mode: MaxEncodedLen)
+	/// Storage: SystemTokenManager SystemTokenWeightTable (r:0 w:1)
+	/// Proof Skipped: SystemTokenManager SystemTokenWeightTable (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `n` is `[1, 1000]`.
+	fn commit_system_token_weights(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `9 + n * (49 ±0)`
+		//  Estimated: `4151 + n * (3161 ±0)`
+		// Minimum execution time: 17_908_000 picoseconds.
+		Weight::from_parts(19_208_000, 0)
+			.saturating_add(Weight::from_parts(0, 4151))
+			.saturating_add(Weight::from_parts(5_136_041, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(Weight::from_parts(0, 3161).saturating_mul(n.into()))
+	}
+}
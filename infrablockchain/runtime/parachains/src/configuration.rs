@@ -33,6 +33,10 @@ use sp_std::prelude::*;
 
 type SystemTokenBalanceOf<T> = <<T as Config>::ParaConfigHandler as ParaConfigInterface>::Balance;
 type DestIdOf<T> = <<T as Config>::ParaConfigHandler as ParaConfigInterface>::DestId;
+type FeeTableUpdateOf<T> = FeeTableUpdate<
+	SystemTokenBalanceOf<T>,
+	<<T as Config>::ParaConfigHandler as ParaConfigInterface>::BlockNumber,
+>;
 #[cfg(test)]
 mod tests;
 
@@ -456,6 +460,9 @@ pub trait WeightInfo {
 	fn set_hrmp_open_request_ttl() -> Weight;
 	fn set_config_with_executor_params() -> Weight;
 	fn set_config_with_perbill() -> Weight;
+	fn update_fee_table_batch(n: u32) -> Weight;
+	fn set_runtime_state() -> Weight;
+	fn set_para_runtime_state() -> Weight;
 }
 
 pub struct TestWeightInfo;
@@ -481,6 +488,15 @@ impl WeightInfo for TestWeightInfo {
 	fn set_config_with_perbill() -> Weight {
 		Weight::MAX
 	}
+	fn update_fee_table_batch(_n: u32) -> Weight {
+		Weight::MAX
+	}
+	fn set_runtime_state() -> Weight {
+		Weight::MAX
+	}
+	fn set_para_runtime_state() -> Weight {
+		Weight::MAX
+	}
 }
 
 #[frame_support::pallet]
@@ -514,6 +530,10 @@ pub mod pallet {
 
 		/// The configuration interface for parachain
 		type ParaConfigHandler: ParaConfigInterface<AccountId = Self::AccountId>;
+
+		/// Maximum number of fee table updates sent by `update_fee_table_batch`
+		#[pallet::constant]
+		type MaxFeeTableUpdates: Get<u32>;
 	}
 
 	#[pallet::error]
@@ -522,6 +542,10 @@ pub mod pallet {
 		InvalidNewValue,
 		/// The bootstrap has already ended.
 		AlreadyEndedBootstrap,
+		/// System configuration is not valid, so the bootstrap cannot end.
+		InvalidSystemConfig,
+		/// Number of updates exceeds `MaxFeeTableUpdates`.
+		TooManyFeeTableUpdates,
 	}
 
 	/// The active configuration for the current session.
@@ -1290,13 +1314,57 @@ pub mod pallet {
 		))]
 		pub fn end_bootstrap(origin: OriginFor<T>) -> DispatchResult {
 			ensure_root(origin)?;
-			RuntimeState::<T>::try_mutate(|s| -> DispatchResult {
-				if *s == Mode::Normal {
-					return Err(Error::<T>::AlreadyEndedBootstrap.into())
-				}
-				*s = Mode::Normal;
-				Ok(())
-			})?;
+			ensure!(RuntimeState::<T>::get() != Mode::Normal, Error::<T>::AlreadyEndedBootstrap);
+			Self::do_set_runtime_state(Mode::Normal)
+		}
+
+		/// Apply `updates` to the fee table of `dest` Runtime at once. At most
+		/// `MaxFeeTableUpdates` updates can be sent.
+		#[pallet::call_index(59)]
+		#[pallet::weight((
+			T::WeightInfo::update_fee_table_batch(updates.len() as u32),
+			DispatchClass::Operational
+		))]
+		pub fn update_fee_table_batch(
+			origin: OriginFor<T>,
+			dest: DestIdOf<T>,
+			updates: Vec<FeeTableUpdateOf<T>>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(
+				updates.len() <= T::MaxFeeTableUpdates::get() as usize,
+				Error::<T>::TooManyFeeTableUpdates
+			);
+			T::ParaConfigHandler::update_fee_table_batch(dest, updates);
+			Ok(())
+		}
+
+		/// Set `mode` of this Runtime. `Mode::Maintenance` is for emergencies and
+		/// `Mode::Bootstrap` rolls back the end of bootstrap.
+		#[pallet::call_index(60)]
+		#[pallet::weight((
+			T::WeightInfo::set_runtime_state(),
+			DispatchClass::Operational
+		))]
+		pub fn set_runtime_state(origin: OriginFor<T>, mode: Mode) -> DispatchResult {
+			ensure_root(origin)?;
+			Self::do_set_runtime_state(mode)
+		}
+
+		/// Set `mode` of `dest` Runtime. `Mode::Normal` is rejected by `dest` unless its
+		/// bootstrap exit criteria are met.
+		#[pallet::call_index(61)]
+		#[pallet::weight((
+			T::WeightInfo::set_para_runtime_state(),
+			DispatchClass::Operational
+		))]
+		pub fn set_para_runtime_state(
+			origin: OriginFor<T>,
+			dest: DestIdOf<T>,
+			mode: Mode,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			T::ParaConfigHandler::set_runtime_state(dest, mode);
 			Ok(())
 		}
 	}
@@ -1310,6 +1378,12 @@ pub mod pallet {
 				"`well_known_keys::ACTIVE_CONFIG` doesn't match key of `ActiveConfig`! Make sure that the name of the\
 				 configuration pallet is `Configuration` in the runtime!",
 			);
+			assert_eq!(
+				&ActiveSystemConfig::<T>::hashed_key(),
+				primitives::well_known_keys::ACTIVE_SYSTEM_CONFIG,
+				"`well_known_keys::ACTIVE_SYSTEM_CONFIG` doesn't match key of `ActiveSystemConfig`! Make sure that the\
+				 name of the configuration pallet is `Configuration` in the runtime!",
+			);
 		}
 	}
 }
@@ -1332,6 +1406,26 @@ impl<T: Config> Pallet<T> {
 	/// Called by the initializer to finalize the configuration pallet.
 	pub(crate) fn initializer_finalize() {}
 
+	/// Set `mode` of this Runtime. It leaves bootstrap only if every bootstrap exit criterion is
+	/// met.
+	pub(crate) fn do_set_runtime_state(mode: Mode) -> DispatchResult {
+		if mode == Mode::Normal {
+			ensure!(Self::unmet_bootstrap_criteria().is_empty(), Error::<T>::InvalidSystemConfig);
+		}
+		RuntimeState::<T>::put(mode);
+		Ok(())
+	}
+
+	/// Bootstrap exit criteria of this Runtime which are not met yet. Runtime can enter
+	/// `Mode::Normal` only if it is empty.
+	pub fn unmet_bootstrap_criteria() -> Vec<BootstrapCriterion> {
+		if ActiveSystemConfig::<T>::get().check_validity().is_ok() {
+			Vec::new()
+		} else {
+			sp_std::vec![BootstrapCriterion::SystemConfig]
+		}
+	}
+
 	/// Called by the initializer to note that a new session has started.
 	///
 	/// Returns the configuration that was actual before the session change and the configuration
@@ -1499,6 +1593,8 @@ pub trait ParaConfigInterface {
 	type DestId: Parameter;
 	/// Balance type of System Token
 	type Balance: Parameter + AtLeast32BitUnsigned;
+	/// Block number type of the parachains
+	type BlockNumber: Parameter;
 
 	/// Set admin for InfraParaCore of `dest_id` Runtime
 	fn set_admin(dest_id: Self::DestId, who: Self::AccountId);
@@ -1509,10 +1605,17 @@ pub trait ParaConfigInterface {
 		call_name: Vec<u8>,
 		fee: Self::Balance,
 	);
+	/// Apply `updates` to the fee table of `dest_id` Runtime at once
+	fn update_fee_table_batch(
+		dest_id: Self::DestId,
+		updates: Vec<FeeTableUpdate<Self::Balance, Self::BlockNumber>>,
+	);
 	/// Update fee rate for `dest_id` Runtime
 	fn update_para_fee_rate(dest_id: Self::DestId, fee_rate: Self::Balance);
 	/// Set runtime state for `dest_id` Runtime
 	fn update_runtime_state(dest_id: Self::DestId);
+	/// Set `mode` of `dest_id` Runtime
+	fn set_runtime_state(dest_id: Self::DestId, mode: Mode);
 }
 
 impl<T: Config> RuntimeConfigProvider<SystemTokenBalanceOf<T>> for Pallet<T>
@@ -1529,7 +1632,11 @@ where
 		Ok(ActiveSystemConfig::<T>::get().base_system_token_detail.base_weight.into())
 	}
 
-	fn fee_for(_ext: ExtrinsicMetadata) -> Option<SystemTokenBalanceOf<T>> {
+	fn fee_for(
+		_ext: ExtrinsicMetadata,
+		_weight: Weight,
+		_len: u32,
+	) -> Option<SystemTokenBalanceOf<T>> {
 		None
 	}
 
@@ -18,7 +18,7 @@ use crate::configuration::*;
 use frame_benchmarking::{benchmarks, BenchmarkError, BenchmarkResult};
 use frame_system::RawOrigin;
 use primitives::{ExecutorParam, ExecutorParams, PvfExecTimeoutKind, PvfPrepTimeoutKind};
-use sp_runtime::traits::One;
+use sp_runtime::traits::{One, TrailingZeroInput};
 
 benchmarks! {
 	set_config_with_block_number {}: set_code_retention_period(RawOrigin::Root, One::one())
@@ -49,6 +49,39 @@ benchmarks! {
 
 	set_config_with_perbill {}: set_on_demand_fee_variability(RawOrigin::Root, Perbill::from_percent(100))
 
+	update_fee_table_batch {
+		let n in 1 .. T::MaxFeeTableUpdates::get();
+		let dest = DestIdOf::<T>::decode(&mut TrailingZeroInput::zeroes())
+			.expect("infinite length input; no invalid inputs for type; qed");
+		let update = FeeTableUpdate {
+			pallet_name: b"Assets".to_vec(),
+			call_name: b"transfer_keep_alive".to_vec(),
+			rule: Some(FeeRule {
+				model: FeeModel::Flat(1_000u32.into()),
+				starts_at: None,
+				expires_at: None,
+			}),
+		};
+		let updates = vec![update; n as usize];
+	}: _(RawOrigin::Root, dest, updates)
+
+	// Worst case is leaving bootstrap, which checks the bootstrap exit criteria.
+	set_runtime_state {
+		ActiveSystemConfig::<T>::put(SystemConfig {
+			base_system_token_detail: BaseSystemTokenDetail::new(Fiat::USD, 1_000_000, 4),
+			weight_scale: 25,
+			base_para_fee_rate: 1_000_000,
+		});
+	}: _(RawOrigin::Root, Mode::Normal)
+	verify {
+		assert_eq!(RuntimeState::<T>::get(), Mode::Normal);
+	}
+
+	set_para_runtime_state {
+		let dest = DestIdOf::<T>::decode(&mut TrailingZeroInput::zeroes())
+			.expect("infinite length input; no invalid inputs for type; qed");
+	}: _(RawOrigin::Root, dest, Mode::Normal)
+
 	impl_benchmark_test_suite!(
 		Pallet,
 		crate::mock::new_test_ext(Default::default()),
@@ -524,3 +524,30 @@ fn verify_externally_accessible() {
 		);
 	});
 }
+
+#[test]
+fn bootstrap_ends_only_with_valid_system_config() {
+	new_test_ext(Default::default()).execute_with(|| {
+		assert_eq!(
+			Configuration::unmet_bootstrap_criteria(),
+			vec![BootstrapCriterion::SystemConfig]
+		);
+		assert_noop!(
+			Configuration::set_runtime_state(RuntimeOrigin::root(), Mode::Normal),
+			Error::<Test>::InvalidSystemConfig
+		);
+
+		ActiveSystemConfig::<Test>::put(SystemConfig {
+			base_system_token_detail: BaseSystemTokenDetail::new(Fiat::USD, 1_000_000, 4),
+			weight_scale: 25,
+			base_para_fee_rate: 1_000_000,
+		});
+		assert!(Configuration::unmet_bootstrap_criteria().is_empty());
+		assert_ok!(Configuration::set_runtime_state(RuntimeOrigin::root(), Mode::Normal));
+		assert_eq!(RuntimeState::<Test>::get(), Mode::Normal);
+
+		// Rolling back to bootstrap is always allowed
+		assert_ok!(Configuration::set_runtime_state(RuntimeOrigin::root(), Mode::Bootstrap));
+		assert_eq!(RuntimeState::<Test>::get(), Mode::Bootstrap);
+	});
+}
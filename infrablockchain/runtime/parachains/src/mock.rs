@@ -21,15 +21,17 @@ use crate::{
 	inclusion::{self, AggregateMessageOrigin, UmpQueueId},
 	initializer, origin, paras,
 	paras::ParaKind,
-	paras_inherent, scheduler, session_info, shared, ParaId,
+	paras_inherent, scheduler, session_info, shared, system_token_manager, ParaId,
 };
 
 use frame_support::{
 	assert_ok, parameter_types,
 	traits::{
-		Currency, ProcessMessage, ProcessMessageError, ValidatorSet, ValidatorSetWithIdentification,
+		AsEnsureOriginWithArg, Currency, ProcessMessage, ProcessMessageError, ValidatorSet,
+		ValidatorSetWithIdentification,
 	},
 	weights::{Weight, WeightMeter},
+	PalletId,
 };
 use frame_support_test::TestRandomness;
 use frame_system::limits;
@@ -38,14 +40,18 @@ use primitives::{
 	AuthorityDiscoveryId, Balance, BlockNumber, CandidateHash, Moment, SessionIndex, UpwardMessage,
 	ValidationCode, ValidatorIndex,
 };
-use sp_core::{ConstU32, H256};
+use sp_core::{ConstU128, ConstU32, H256};
 use sp_io::TestExternalities;
 use sp_runtime::{
+	infra::{Fiat, SystemTokenWeight},
 	traits::{AccountIdConversion, BlakeTwo256, IdentityLookup},
 	transaction_validity::TransactionPriority,
 	BuildStorage, FixedU128, Perbill, Permill,
 };
 use std::{cell::RefCell, collections::HashMap};
+use xcm::latest::{
+	InteriorMultiLocation, Junction::GlobalConsensus, Junctions::X1, MultiLocation, NetworkId,
+};
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlockU32<Test>;
@@ -72,6 +78,8 @@ frame_support::construct_runtime!(
 		SessionInfo: session_info,
 		Disputes: disputes,
 		Babe: pallet_babe,
+		Assets: pallet_assets,
+		SystemTokenManager: system_token_manager,
 	}
 );
 
@@ -231,6 +239,89 @@ impl crate::hrmp::Config for Test {
 	type WeightInfo = crate::hrmp::TestWeightInfo;
 }
 
+impl pallet_assets::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type AssetId = MultiLocation;
+	type AssetIdParameter = MultiLocation;
+	type SystemTokenWeight = SystemTokenWeight;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<u64>>;
+	type ForceOrigin = frame_system::EnsureRoot<u64>;
+	type AssetDeposit = ConstU128<1>;
+	type AssetAccountDeposit = ConstU128<1>;
+	type MetadataDepositBase = ConstU128<1>;
+	type MetadataDepositPerByte = ConstU128<1>;
+	type ApprovalDeposit = ConstU128<1>;
+	type StringLimit = ConstU32<50>;
+	type Freezer = ();
+	type Extra = ();
+	type CallbackHandle = ();
+	type WeightInfo = ();
+	type RemoveItemsLimit = ConstU32<1000>;
+}
+
+parameter_types! {
+	pub const AssetHubId: u32 = 1000;
+	pub const SystemTokenManagerId: PalletId = PalletId(*b"infra/sm");
+	pub const UniversalLocation: InteriorMultiLocation = X1(GlobalConsensus(NetworkId::InfraRelay));
+}
+
+/// System Token handler which sends nothing, since there are no parachains to receive DMPs.
+pub struct TestSystemTokenHandler;
+
+impl system_token_manager::SystemTokenInterface for TestSystemTokenHandler {
+	type AccountId = AccountId;
+	type Location = MultiLocation;
+	type Balance = Balance;
+	type SystemTokenWeight = SystemTokenWeight;
+	type DestId = u32;
+
+	fn register_system_token(_: u32, _: MultiLocation, _: SystemTokenWeight) {}
+	fn deregister_system_token(_: u32, _: MultiLocation) {}
+	fn create_wrapped(
+		_: u32,
+		_: AccountId,
+		_: MultiLocation,
+		_: Fiat,
+		_: Balance,
+		_: Vec<u8>,
+		_: Vec<u8>,
+		_: u8,
+		_: SystemTokenWeight,
+	) {
+	}
+	fn suspend_system_token(_: u32, _: MultiLocation) {}
+	fn unsuspend_system_token(_: u32, _: MultiLocation) {}
+}
+
+/// Oracle manager which requests nothing.
+pub struct TestOracleManager;
+
+impl system_token_manager::OracleInterface for TestOracleManager {
+	type DestId = u32;
+
+	fn request_fiat(_: u32, _: Vec<Fiat>) {}
+}
+
+impl crate::system_token_manager::Config for Test {
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeEvent = RuntimeEvent;
+	type Fungibles = Assets;
+	type HigherPrecision = softfloat::FixedWeight;
+	type SystemTokenId = MultiLocation;
+	type UniversalLocation = UniversalLocation;
+	type SystemTokenHandler = TestSystemTokenHandler;
+	type OracleManager = TestOracleManager;
+	type StringLimit = ConstU32<50>;
+	type MaxSystemTokens = ConstU32<10>;
+	type MaxOriginalUsedParaIds = ConstU32<10>;
+	type MaxFiats = ConstU32<10>;
+	type AssetHubId = AssetHubId;
+	type PalletId = SystemTokenManagerId;
+	type WeightInfo = crate::system_token_manager::TestWeightInfo;
+}
+
 impl crate::disputes::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type RewardValidators = Self;
@@ -43,7 +43,54 @@ pub use traits::{SystemTokenInterface, OracleInterface};
 use types::*;
 use xcm::latest::{InteriorMultiLocation, SystemTokenId};
 
-#[frame_support::pallet(dev_mode)]
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub trait WeightInfo {
+	fn register_system_token(p: u32) -> Weight;
+	fn deregister_system_token(p: u32) -> Weight;
+	fn suspend_system_token(p: u32) -> Weight;
+	fn unsuspend_system_token(p: u32) -> Weight;
+	fn update_exchange_rate(f: u32) -> Weight;
+	fn request_exchange_rate(f: u32) -> Weight;
+	fn report_para_fee_rate() -> Weight;
+	fn commit_system_token_weights(n: u32) -> Weight;
+}
+
+/// A weight info that is only suitable for testing.
+pub struct TestWeightInfo;
+
+impl WeightInfo for TestWeightInfo {
+	fn register_system_token(_: u32) -> Weight {
+		Weight::MAX
+	}
+	fn deregister_system_token(_: u32) -> Weight {
+		Weight::MAX
+	}
+	fn suspend_system_token(_: u32) -> Weight {
+		Weight::MAX
+	}
+	fn unsuspend_system_token(_: u32) -> Weight {
+		Weight::MAX
+	}
+	fn update_exchange_rate(_: u32) -> Weight {
+		Weight::MAX
+	}
+	fn request_exchange_rate(_: u32) -> Weight {
+		Weight::MAX
+	}
+	fn report_para_fee_rate() -> Weight {
+		Weight::MAX
+	}
+	fn commit_system_token_weights(_: u32) -> Weight {
+		Weight::zero()
+	}
+}
+
+#[frame_support::pallet]
 pub mod pallet {
 
 	use super::*;
@@ -83,11 +130,16 @@ pub mod pallet {
 		/// Max number of `paraId` that are using `original` system token
 		#[pallet::constant]
 		type MaxOriginalUsedParaIds: Get<u32>;
+		/// Max number of fiat currencies that can be requested to the Oracle.
+		#[pallet::constant]
+		type MaxFiats: Get<u32>;
 		/// The ParaId of the asset hub system parachain.
 		#[pallet::constant]
 		type AssetHubId: Get<u32>;
 		/// Id for `SystemTokenManager`
 		type PalletId: Get<PalletId>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
 	}
 
 	#[pallet::event]
@@ -106,6 +158,10 @@ pub mod pallet {
 		SystemTokenUnsuspended { kind: MutateKind<T::SystemTokenId, SystemTokenOriginIdOf<T>> },
 		/// Update exchange rates for given fiat currencies
 		ExchangeRateUpdated { updated: Vec<(Fiat, ExchangeRate)> },
+		/// Fee rate adjusted by a parachain has been reported
+		ParaFeeRateReported { para_id: ParaId, fee_rate: u128 },
+		/// System Token weight table has been committed
+		SystemTokenWeightCommitted { version: u32, root: primitives::Hash },
 	}
 
 	#[pallet::error]
@@ -173,6 +229,8 @@ pub mod pallet {
 		ErrorCalculateSystemTokenWeight,
 		/// Request for RC is already made
 		AlreadyRequested,
+		/// Requested fiat currencies are out of limit
+		TooManyFiats,
 	}
 
 	#[pallet::pallet]
@@ -183,13 +241,53 @@ pub mod pallet {
 		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
 			let l = UpdateExchangeRates::<T>::iter().count();
 			// TODO: Find better way
-			if l != 0 {
+			let weight = if l != 0 {
 				let _ = UpdateExchangeRates::<T>::clear(u32::MAX, None);
 				T::DbWeight::get().writes(l as u64)
 			} else {
 				T::DbWeight::get().reads(1)
+			};
+			// Any extrinsic of this block may change `SystemToken`, so the rebuild of
+			// `SystemTokenWeightTable` in `on_finalize` is reserved up front. One more leaf is
+			// reserved for an `original` registered in this block.
+			let leaves = SystemTokenWeightTable::<T>::decode_len().unwrap_or_default() as u32;
+			weight.saturating_add(T::DbWeight::get().reads(2)).saturating_add(
+				<T as Config>::WeightInfo::commit_system_token_weights(leaves.saturating_add(1)),
+			)
+		}
+
+		fn on_finalize(_n: BlockNumberFor<T>) {
+			if SystemTokenWeightTableChanged::<T>::take() {
+				Self::commit_system_token_weights();
 			}
 		}
+
+		fn integrity_test() {
+			let para_id = ParaId::from(T::AssetHubId::get());
+			assert_eq!(
+				ParaFeeRates::<T>::hashed_key_for(para_id),
+				primitives::well_known_keys::para_fee_rate(para_id),
+				"`well_known_keys::para_fee_rate` doesn't match key of `ParaFeeRates`! Make sure that the name of the\
+				 system token manager pallet is `SystemTokenManager` in the runtime!",
+			);
+			assert_eq!(
+				SystemTokenWeightCommitment::<T>::hashed_key().to_vec(),
+				primitives::well_known_keys::SYSTEM_TOKEN_WEIGHT_COMMITMENT,
+				"`well_known_keys::SYSTEM_TOKEN_WEIGHT_COMMITMENT` doesn't match key of \
+				 `SystemTokenWeightCommitment`!",
+			);
+			assert_eq!(
+				SystemTokenWeightTable::<T>::hashed_key().to_vec(),
+				primitives::well_known_keys::SYSTEM_TOKEN_WEIGHT_TABLE,
+				"`well_known_keys::SYSTEM_TOKEN_WEIGHT_TABLE` doesn't match key of \
+				 `SystemTokenWeightTable`!",
+			);
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+			Self::do_try_state()
+		}
 	}
 
 	/// Kind of fiat currencies needs to be requested. It is bounded for number of real-world
@@ -201,14 +299,19 @@ pub mod pallet {
 	/// 2. Fiat stored on this list will be sent to runtime which implements `Oracle`
 	/// 3. Oracle will send exchange rates for given fiat
 	#[pallet::storage]
-	#[pallet::unbounded]
 	#[pallet::getter(fn request_fiat_list)]
-	pub type RequestFiatList<T: Config> = StorageValue<_, Vec<Fiat>, ValueQuery>;
+	pub type RequestFiatList<T: Config> =
+		StorageValue<_, BoundedVec<Fiat, T::MaxFiats>, ValueQuery>;
 
 	/// Exchange rates for currencies relative to the base currency.
 	#[pallet::storage]
 	pub type ExchangeRates<T: Config> = StorageMap<_, Twox64Concat, Fiat, ExchangeRate>;
 
+	/// Fee rate most recently reported by each parachain, adjusted by its own fee rate controller
+	#[pallet::storage]
+	#[pallet::getter(fn para_fee_rate)]
+	pub type ParaFeeRates<T: Config> = StorageMap<_, Twox64Concat, ParaId, u128>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn system_token)]
 	/// **Description:**
@@ -235,12 +338,11 @@ pub mod pallet {
 
 	/// Updated exchange rates for `para_id` based on updated exchange rate data from Oracle
 	#[pallet::storage]
-	#[pallet::unbounded]
 	pub type UpdateExchangeRates<T: Config> = StorageMap<
 		_,
 		Twox64Concat,
 		SystemTokenOriginIdOf<T>,
-		Vec<(T::SystemTokenId, SystemTokenWeightOf<T>)>,
+		BoundedVec<(T::SystemTokenId, SystemTokenWeightOf<T>), T::MaxSystemTokens>,
 	>;
 
 	#[pallet::storage]
@@ -276,7 +378,6 @@ pub mod pallet {
 	///
 	/// Vec of 'Original' SystemTokenId
 	#[pallet::storage]
-	#[pallet::unbounded]
 	pub type FiatForOriginal<T: Config> = StorageDoubleMap<
 		_,
 		Twox64Concat,
@@ -307,6 +408,26 @@ pub mod pallet {
 		BoundedVec<T::SystemTokenId, T::MaxSystemTokens>,
 	>;
 
+	/// Commitment to `SystemTokenWeightTable`. Parachains prove the weights of the System Tokens
+	/// they use against it.
+	#[pallet::storage]
+	pub type SystemTokenWeightCommitment<T: Config> =
+		StorageValue<_, primitives::SystemTokenWeightCommitment, ValueQuery>;
+
+	/// Weights of every `original` System Token, sorted by the encoded id. Each entry is a leaf
+	/// of `SystemTokenWeightCommitment` in this order.
+	///
+	/// Unbounded as the number of `original` System Tokens is not bounded. It is only rebuilt in
+	/// `on_finalize` when `SystemToken` has changed.
+	#[pallet::storage]
+	#[pallet::unbounded]
+	pub type SystemTokenWeightTable<T: Config> =
+		StorageValue<_, Vec<(T::SystemTokenId, SystemTokenWeightOf<T>)>, ValueQuery>;
+
+	/// Whether `SystemTokenWeightTable` has changed in this block and should be committed again
+	#[pallet::storage]
+	pub(super) type SystemTokenWeightTableChanged<T: Config> = StorageValue<_, bool, ValueQuery>;
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		// **Description**:
@@ -324,6 +445,9 @@ pub mod pallet {
 		// - Create & register `Original` System Token
 		// - Create `wrapped` for Relay if None. Otherwise, create asset remotely via DMP
 		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::register_system_token(
+			T::MaxOriginalUsedParaIds::get()
+		))]
 		pub fn register_system_token(
 			origin: OriginFor<T>,
 			system_token_type: RegisterType<T::SystemTokenId, SystemTokenOriginIdOf<T>>,
@@ -359,6 +483,9 @@ pub mod pallet {
 		}
 
 		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::deregister_system_token(
+			T::MaxOriginalUsedParaIds::get()
+		))]
 		// Description:
 		// Deregister SystemToken based on given `MutateKind`
 		//
@@ -379,6 +506,9 @@ pub mod pallet {
 		}
 
 		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::suspend_system_token(
+			T::MaxOriginalUsedParaIds::get()
+		))]
 		// Description:
 		// Suspend all `original` and `wrapped` system token registered on runtime.
 		// Suspended system token is no longer used as `transaction fee`
@@ -400,6 +530,9 @@ pub mod pallet {
 		}
 
 		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::unsuspend_system_token(
+			T::MaxOriginalUsedParaIds::get()
+		))]
 		// Description:
 		// Unsuspend all `original` and `wrapped` system token registered on runtime.
 		// Unsuspended system token is no longer used as `transaction fee`
@@ -420,6 +553,9 @@ pub mod pallet {
 		}
 
 		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_exchange_rate(
+			exchange_rates.len() as u32
+		))]
 		pub fn update_exchange_rate(
 			origin: OriginFor<T>,
 			exchange_rates: Vec<(Fiat, ExchangeRate)>,
@@ -431,6 +567,7 @@ pub mod pallet {
 		}
 
 		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::request_exchange_rate(T::MaxFiats::get()))]
 		pub fn request_exchange_rate(
 			origin: OriginFor<T>,
 		) -> DispatchResult {
@@ -438,8 +575,25 @@ pub mod pallet {
 			let requested_fiat_list = RequestFiatList::<T>::get();
 			if requested_fiat_list.is_empty() { return Ok(()) }
 			let dest_id = T::AssetHubId::get();
-			T::OracleManager::request_fiat(dest_id, requested_fiat_list);
+			T::OracleManager::request_fiat(dest_id, requested_fiat_list.into_inner());
+
+			Ok(())
+		}
 
+		// Description:
+		// Record fee rate which has been adjusted by the parachain itself
+		//
+		// Origin:
+		// ** Parachain **
+		//
+		// Params:
+		// - fee_rate: Adjusted fee rate of the parachain
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::report_para_fee_rate())]
+		pub fn report_para_fee_rate(origin: OriginFor<T>, fee_rate: u128) -> DispatchResult {
+			let para_id = ensure_parachain(<T as Config>::RuntimeOrigin::from(origin))?;
+			ParaFeeRates::<T>::insert(para_id, fee_rate);
+			Self::deposit_event(Event::<T>::ParaFeeRateReported { para_id, fee_rate });
 			Ok(())
 		}
 	}
@@ -507,6 +661,8 @@ impl<T: Config> Pallet<T> {
 					.map_err(|_| Error::<T>::ErrorUpdateSystemTokenWeight)?;
 			}
 			system_token_detail.update_weight(updated_sys_weight);
+			SystemToken::<T>::insert(&o, system_token_detail);
+			SystemTokenWeightTableChanged::<T>::put(true);
 			if let Some(ws) = FiatForOriginal::<T>::get(currency, &o) {
 				for w in ws {
 					para_ids.push(w);
@@ -521,7 +677,11 @@ impl<T: Config> Pallet<T> {
 						original
 							.reanchor_loc(0, Some(para_id.clone()), &context)
 							.map_err(|_| Error::<T>::ErrorConvertToWrapped)?;
-						*maybe_updated = Some(vec![(original, updated_sys_weight)]);
+						let mut updated = BoundedVec::new();
+						updated
+							.try_push((original, updated_sys_weight))
+							.map_err(|_| Error::<T>::TooManySystemTokensOnPara)?;
+						*maybe_updated = Some(updated);
 						Ok(())
 					},
 				)?;
@@ -540,6 +700,31 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Commit the weights of every `original` System Token to `SystemTokenWeightCommitment`.
+	///
+	/// The version is only bumped if the table has actually changed.
+	fn commit_system_token_weights() {
+		let mut table: Vec<(T::SystemTokenId, SystemTokenWeightOf<T>)> = SystemToken::<T>::iter()
+			.map(|(original, detail)| (original, detail.weight()))
+			.collect();
+		table.sort_by_cached_key(|(original, _)| original.encode());
+		let current = SystemTokenWeightCommitment::<T>::get();
+		let next = primitives::SystemTokenWeightCommitment::new(current.version, &table);
+		if next == current {
+			return
+		}
+		let next = primitives::SystemTokenWeightCommitment {
+			version: current.version.saturating_add(1),
+			..next
+		};
+		SystemTokenWeightTable::<T>::put(table);
+		SystemTokenWeightCommitment::<T>::put(next);
+		Self::deposit_event(Event::<T>::SystemTokenWeightCommitted {
+			version: next.version,
+			root: next.root,
+		});
+	}
+
 	/// Calcuƒlate `original` system token weight based on `FORMULA`
 	///
 	/// `FORMULA` = `BASE_WEIGHT` * `DECIMAL_RELATIVE_TO_BASE` / `EXCHANGE_RATE_RELATIVE_TO_BASE`
@@ -639,17 +824,18 @@ impl<T: Config> Pallet<T> {
 					.map_err(|_| Error::<T>::ErrorRegisterSystemToken)?;
 			let system_token_metadata = SystemTokenMetadata::new(
 				currency_type.clone(),
-				name,
-				symbol,
+				BoundedStringOf::<T>::try_from(name).map_err(|_| Error::<T>::BadMetadata)?,
+				BoundedStringOf::<T>::try_from(symbol).map_err(|_| Error::<T>::BadMetadata)?,
 				decimals,
 				min_balance,
 			);
 			Metadata::<T>::insert(original, system_token_metadata.clone());
-			RequestFiatList::<T>::mutate(|request_fiat| {
+			RequestFiatList::<T>::try_mutate(|request_fiat| -> DispatchResult {
 				if !request_fiat.contains(&currency_type) {
-					request_fiat.push(currency_type);
+					request_fiat.try_push(currency_type).map_err(|_| Error::<T>::TooManyFiats)?;
 				}
-			});
+				Ok(())
+			})?;
 			*is_remote = false;
 			return Ok(true)
 		} 
@@ -701,6 +887,7 @@ impl<T: Config> Pallet<T> {
 		}
 		Metadata::<T>::insert(original, system_token_metadata);
 		SystemToken::<T>::insert(original, SystemTokenDetail::new(system_token_weight));
+		SystemTokenWeightTableChanged::<T>::put(true);
 		Ok(())
 	}
 
@@ -765,8 +952,8 @@ impl<T: Config> Pallet<T> {
 				reanchored,
 				currency_type,
 				min_balance,
-				name,
-				symbol,
+				name.into_inner(),
+				symbol.into_inner(),
 				decimals,
 				system_token_weight,
 			) {
@@ -945,6 +1132,76 @@ impl<T: Config> Pallet<T> {
 	}
 }
 
+#[cfg(any(feature = "try-runtime", test))]
+impl<T: Config> Pallet<T> {
+	/// Invariants of the System Token bookkeeping
+	///
+	/// - Every `original` System Token has its metadata.
+	/// - Every `wrapped` entry points to a registered `original`, and `ParaIdSystemTokens` lists
+	///   exactly the `wrapped` entries of `SystemToken`.
+	/// - `FiatForOriginal` only maps registered `original` System Tokens to their `wrapped`
+	///   entries.
+	/// - `SystemTokenWeightTable` and its commitment reflect `SystemToken` unless they are pending
+	///   to be committed.
+	pub fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+		for (original, detail) in SystemToken::<T>::iter() {
+			ensure!(
+				Metadata::<T>::contains_key(&original),
+				"Registered System Token has no metadata"
+			);
+			for (para_id, _) in detail.para_ids.iter() {
+				ensure!(
+					ParaIdSystemTokens::<T>::get(para_id)
+						.map_or(false, |system_tokens| system_tokens.contains(&original)),
+					"Wrapped System Token is not listed in `ParaIdSystemTokens`"
+				);
+			}
+		}
+		for (para_id, system_tokens) in ParaIdSystemTokens::<T>::iter() {
+			ensure!(!system_tokens.is_empty(), "Empty entry of `ParaIdSystemTokens`");
+			for original in system_tokens.iter() {
+				let detail = SystemToken::<T>::get(original)
+					.ok_or("`ParaIdSystemTokens` lists unregistered System Token")?;
+				ensure!(
+					detail.is_used_by(&para_id),
+					"`ParaIdSystemTokens` lists System Token not wrapped for the parachain"
+				);
+			}
+		}
+		for (currency_type, original, para_ids) in FiatForOriginal::<T>::iter() {
+			let detail = SystemToken::<T>::get(&original)
+				.ok_or("`FiatForOriginal` maps unregistered System Token")?;
+			ensure!(
+				Metadata::<T>::get(&original)
+					.map_or(false, |metadata| metadata.currency_type == currency_type),
+				"`FiatForOriginal` maps System Token of another currency"
+			);
+			ensure!(
+				para_ids.iter().all(|para_id| detail.is_used_by(para_id)),
+				"`FiatForOriginal` maps wrapped System Token which is not registered"
+			);
+		}
+		if !SystemTokenWeightTableChanged::<T>::get() {
+			let mut table: Vec<(T::SystemTokenId, SystemTokenWeightOf<T>)> =
+				SystemToken::<T>::iter()
+					.map(|(original, detail)| (original, detail.weight()))
+					.collect();
+			table.sort_by_cached_key(|(original, _)| original.encode());
+			ensure!(
+				SystemTokenWeightTable::<T>::get() == table,
+				"`SystemTokenWeightTable` doesn't match `SystemToken`"
+			);
+			let commitment = SystemTokenWeightCommitment::<T>::get();
+			ensure!(
+				primitives::SystemTokenWeightCommitment::new(commitment.version, &table) ==
+					commitment,
+				"`SystemTokenWeightCommitment` doesn't commit to `SystemTokenWeightTable`"
+			);
+		}
+		Ok(())
+	}
+}
+
 // XCM-related internal methods
 impl<T: Config> Pallet<T> {
 	/// **Description:**
@@ -959,11 +1216,14 @@ impl<T: Config> Pallet<T> {
 					SystemToken::<T>::get(&original).ok_or(Error::<T>::SystemTokenNotRegistered)?;
 				let (origin_id, _, _) =
 					original.id().map_err(|_| Error::<T>::ErrorConvertToSystemTokenId)?;
-				for para_id in system_token_detail.list_all_wrapped().iter() {
+				for (para_id, _) in system_token_detail.para_ids.iter() {
 					Self::remove_system_token_for_para_id(&original, para_id)?;
 				}
 				SystemToken::<T>::remove(&original);
-				Metadata::<T>::remove(&original);
+				if let Some(metadata) = Metadata::<T>::take(&original) {
+					FiatForOriginal::<T>::remove(&metadata.currency_type, &original);
+				}
+				SystemTokenWeightTableChanged::<T>::put(true);
 				if let Some(para_id) = origin_id {
 					let mut reanchored = original.clone();
 					reanchored
@@ -978,13 +1238,26 @@ impl<T: Config> Pallet<T> {
 			},
 			MutateKind::Wrapped { original, wrapped } => {
 				if let Some(para_id) = wrapped {
-					let system_token_detail = SystemToken::<T>::get(&original)
+					let mut system_token_detail = SystemToken::<T>::get(&original)
 						.ok_or(Error::<T>::SystemTokenNotRegistered)?;
 					ensure!(
 						system_token_detail.is_used_by(&para_id),
 						Error::<T>::WrappedNotRegistered
 					);
 					Self::remove_system_token_for_para_id(&original, &para_id)?;
+					system_token_detail.deregister_wrapped_for(&para_id);
+					SystemToken::<T>::insert(&original, system_token_detail);
+					if let Some(metadata) = Metadata::<T>::get(&original) {
+						FiatForOriginal::<T>::mutate(
+							&metadata.currency_type,
+							&original,
+							|maybe_para_ids| {
+								if let Some(para_ids) = maybe_para_ids {
+									para_ids.retain(|p| p != &para_id);
+								}
+							},
+						);
+					}
 					let mut reanchored = original.clone();
 					reanchored
 						.reanchor_loc(0, Some(para_id.clone()), &T::UniversalLocation::get())
@@ -1051,15 +1324,24 @@ impl<T: Config> Pallet<T> {
 		decimals: u8,
 		min_balance: SystemTokenBalanceOf<T>,
 	) {
+		let (Ok(name), Ok(symbol)) =
+			(BoundedStringOf::<T>::try_from(name), BoundedStringOf::<T>::try_from(symbol))
+		else {
+			log::error!("❌ Metadata of requested System Token is out of limit ❌");
+			return
+		};
+		let is_requested = RequestFiatList::<T>::mutate(|request_fiat| {
+			request_fiat.contains(currency_type) ||
+				request_fiat.try_push(currency_type.clone()).is_ok()
+		});
+		if !is_requested {
+			log::error!("❌ Too many fiat currencies have been requested ❌");
+			return
+		}
 		Metadata::<T>::insert(
 			original,
 			SystemTokenMetadata::new(currency_type.clone(), name, symbol, decimals, min_balance),
 		);
-		RequestFiatList::<T>::mutate(|request_fiat| {
-			if !request_fiat.contains(currency_type) {
-				request_fiat.push(currency_type.clone());
-			}
-		});
 	}
 
 	pub fn requested_asset_metadata(bytes: &mut Vec<u8>) {
@@ -1204,6 +1486,11 @@ pub mod types {
 			}
 		}
 
+		/// Remove `para_id` from `wrapped` list
+		pub fn deregister_wrapped_for(&mut self, para_id: &ParaId) {
+			self.para_ids.retain(|(p, _)| p != para_id);
+		}
+
 		/// List all `para_ids` which are `SystemTokenStatus::Active` that are using this System
 		/// Token
 		pub fn list_all_wrapped(&self) -> Vec<ParaId> {
@@ -1219,14 +1506,21 @@ pub mod types {
 		}
 	}
 
-	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, Default, TypeInfo)]
+	#[derive(
+		Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, Default, TypeInfo, MaxEncodedLen,
+	)]
+	#[codec(mel_bound(
+		Balance: parity_scale_codec::HasCompact + MaxEncodedLen,
+		BoundedString: Encode + MaxEncodedLen,
+		BlockNumber: parity_scale_codec::HasCompact + MaxEncodedLen
+	))]
 	/// Metadata of the `original` asset from enshrined runtime
 	pub struct SystemTokenMetadata<Balance, BoundedString, BlockNumber> {
 		pub(crate) currency_type: Fiat,
 		/// The user friendly name of this system token.
-		pub(crate) name: Vec<u8>,
+		pub(crate) name: BoundedString,
 		/// The exchange symbol for this system token.
-		pub(crate) symbol: Vec<u8>,
+		pub(crate) symbol: BoundedString,
 		/// The number of decimals this asset uses to represent one unit.
 		pub(crate) decimals: u8,
 		/// The minimum balance of this new asset that any single account must
@@ -1257,8 +1551,8 @@ pub mod types {
 
 		pub fn new(
 			currency_type: Fiat,
-			name: Vec<u8>,
-			symbol: Vec<u8>,
+			name: BoundedString,
+			symbol: BoundedString,
 			decimals: u8,
 			min_balance: Balance,
 		) -> Self {
@@ -0,0 +1,214 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//  http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use crate::system_token_manager::{Pallet as SystemTokenManager, *};
+use frame_benchmarking::v2::*;
+use frame_support::assert_ok;
+use frame_system::RawOrigin;
+
+/// Pallet instance of the `original` System Tokens used in the benchmarks.
+const PALLET_ID: u8 = 50;
+/// Prefix of the para ids which are using `wrapped` System Tokens.
+const PREFIX: u32 = 2_000;
+/// Upper bound of the number of `original` System Tokens in the benchmarks. `SystemToken` itself
+/// is not bounded.
+const MAX_ORIGINALS: u32 = 1_000;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+/// `i`-th fiat, distinct as long as there are enough variants of `Fiat`
+fn fiat(i: u32) -> Fiat {
+	Fiat::decode(&mut &[i as u8][..]).unwrap_or_default()
+}
+
+/// `original` System Token of the asset hub with the given `asset_id`
+fn original_of<T: Config>(asset_id: u32) -> T::SystemTokenId
+where
+	SystemTokenOriginIdOf<T>: From<u32>,
+	SystemTokenPalletIdOf<T>: From<u8>,
+	SystemTokenAssetIdOf<T>: From<u32>,
+{
+	T::SystemTokenId::convert_back(
+		Some(T::AssetHubId::get().into()),
+		PALLET_ID.into(),
+		asset_id.into(),
+	)
+}
+
+/// Register `original` System Token of the asset hub for `currency_type` in the same way as
+/// `register_system_token`, along with its `wrapped` for the Relay Chain and for `p` parachains.
+fn register_original<T: Config>(asset_id: u32, currency_type: Fiat, p: u32) -> T::SystemTokenId
+where
+	SystemTokenOriginIdOf<T>: From<u32>,
+	SystemTokenPalletIdOf<T>: From<u8>,
+	SystemTokenAssetIdOf<T>: From<u32>,
+{
+	let original = original_of::<T>(asset_id);
+	let string: BoundedStringOf<T> = vec![0u8; T::StringLimit::get() as usize]
+		.try_into()
+		.expect("String of `StringLimit` is bounded; qed");
+	Metadata::<T>::insert(
+		&original,
+		SystemTokenMetadata::new(currency_type.clone(), string.clone(), string, 6, 1u32.into()),
+	);
+	ExchangeRates::<T>::insert(&currency_type, 1_000_000);
+	RequestFiatList::<T>::mutate(|request_fiat| {
+		if !request_fiat.contains(&currency_type) {
+			let _ = request_fiat.try_push(currency_type.clone());
+		}
+	});
+	SystemToken::<T>::insert(&original, SystemTokenDetail::new(1_000u32.into()));
+	assert_ok!(SystemTokenManager::<T>::do_register_wrapped(&original, None));
+	for i in 0..p {
+		assert_ok!(SystemTokenManager::<T>::do_register_wrapped(
+			&original,
+			Some((PREFIX + i).into())
+		));
+	}
+	original
+}
+
+#[benchmarks(
+	where
+		<T as frame_system::Config>::RuntimeOrigin: From<crate::Origin>,
+		SystemTokenOriginIdOf<T>: From<u32>,
+		SystemTokenPalletIdOf<T>: From<u8>,
+		SystemTokenAssetIdOf<T>: From<u32>,
+)]
+mod benchmarks {
+	use super::*;
+
+	// Worst case is registering the last `wrapped` which is allowed for the `original`.
+	#[benchmark]
+	fn register_system_token(p: Linear<0, { T::MaxOriginalUsedParaIds::get() - 1 }>) {
+		let original = register_original::<T>(0, fiat(0), p);
+		let para_id: SystemTokenOriginIdOf<T> = (PREFIX + p).into();
+		let system_token_type = RegisterType::Wrapped {
+			original: original.clone(),
+			maybe_para_id: Some(para_id.clone()),
+		};
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, system_token_type, None);
+
+		assert_last_event::<T>(
+			Event::<T>::SystemTokenRegistered { original, wrapped: Some(para_id) }.into(),
+		);
+	}
+
+	#[benchmark]
+	fn deregister_system_token(p: Linear<0, { T::MaxOriginalUsedParaIds::get() }>) {
+		let original = register_original::<T>(0, fiat(0), p);
+		let kind = MutateKind::All(original.clone());
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, kind.clone());
+
+		assert!(SystemToken::<T>::get(&original).is_none());
+		assert_last_event::<T>(Event::<T>::SystemTokenDeregistered { kind }.into());
+	}
+
+	#[benchmark]
+	fn suspend_system_token(p: Linear<0, { T::MaxOriginalUsedParaIds::get() }>) {
+		let original = register_original::<T>(0, fiat(0), p);
+		let kind = MutateKind::All(original);
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, kind.clone());
+
+		assert_last_event::<T>(Event::<T>::SystemTokenSuspended { kind }.into());
+	}
+
+	#[benchmark]
+	fn unsuspend_system_token(p: Linear<0, { T::MaxOriginalUsedParaIds::get() }>) {
+		let original = register_original::<T>(0, fiat(0), p);
+		let kind = MutateKind::All(original);
+		assert_ok!(SystemTokenManager::<T>::do_suspend_system_token(kind.clone()));
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, kind.clone());
+
+		assert_last_event::<T>(Event::<T>::SystemTokenUnsuspended { kind }.into());
+	}
+
+	// Worst case is every fiat being used by an `original` System Token which is used by the
+	// Relay Chain and a parachain. Each of them is used by its own parachain so as not to exceed
+	// `MaxSystemTokens` of a parachain.
+	#[benchmark]
+	fn update_exchange_rate(f: Linear<1, { T::MaxFiats::get() }>) {
+		for i in 0..f {
+			let original = register_original::<T>(i, fiat(i), 0);
+			assert_ok!(SystemTokenManager::<T>::do_register_wrapped(
+				&original,
+				Some((PREFIX + i).into())
+			));
+		}
+		let exchange_rates: Vec<(Fiat, ExchangeRate)> =
+			(0..f).map(|i| (fiat(i), 2_000_000)).collect();
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, exchange_rates.clone());
+
+		assert_last_event::<T>(Event::<T>::ExchangeRateUpdated { updated: exchange_rates }.into());
+	}
+
+	#[benchmark]
+	fn request_exchange_rate(f: Linear<1, { T::MaxFiats::get() }>) {
+		let request_fiat: BoundedVec<Fiat, T::MaxFiats> = (0..f)
+			.map(fiat)
+			.collect::<Vec<_>>()
+			.try_into()
+			.expect("`f` is bounded by `MaxFiats`; qed");
+		RequestFiatList::<T>::put(request_fiat);
+
+		#[extrinsic_call]
+		_(RawOrigin::Root);
+	}
+
+	#[benchmark]
+	fn report_para_fee_rate() {
+		let para_id = ParaId::from(T::AssetHubId::get());
+		let para_origin: crate::Origin = T::AssetHubId::get().into();
+		let fee_rate = 1_000_000u128;
+
+		#[extrinsic_call]
+		_(para_origin, fee_rate);
+
+		assert_eq!(ParaFeeRates::<T>::get(para_id), Some(fee_rate));
+		assert_last_event::<T>(Event::<T>::ParaFeeRateReported { para_id, fee_rate }.into());
+	}
+
+	// Worst case is every leaf of the table having changed.
+	#[benchmark]
+	fn commit_system_token_weights(n: Linear<1, MAX_ORIGINALS>) {
+		for i in 0..n {
+			SystemToken::<T>::insert(original_of::<T>(i), SystemTokenDetail::new(1_000u32.into()));
+		}
+
+		#[block]
+		{
+			SystemTokenManager::<T>::commit_system_token_weights();
+		}
+
+		assert_eq!(SystemTokenWeightTable::<T>::decode_len(), Some(n as usize));
+		assert_eq!(SystemTokenWeightCommitment::<T>::get().number_of_leaves, n);
+	}
+}
@@ -0,0 +1,99 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//  http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+use crate::mock::{new_test_ext, RuntimeOrigin, SystemTokenManager, Test};
+use frame_support::{assert_noop, assert_ok};
+use xcm::latest::MultiLocation;
+
+/// `original` System Token of the asset hub
+fn original() -> MultiLocation {
+	MultiLocation::convert_back(Some(1000), 50, 1)
+}
+
+/// Register `original` System Token in the same way as `register_system_token` does, along with
+/// its `wrapped` for the given parachains.
+fn register_original(original: &MultiLocation, para_ids: &[u32]) {
+	Metadata::<Test>::insert(
+		original,
+		SystemTokenMetadata::new(
+			Fiat::USD,
+			b"Infra USD".to_vec().try_into().unwrap(),
+			b"IUSD".to_vec().try_into().unwrap(),
+			6,
+			1,
+		),
+	);
+	SystemToken::<Test>::insert(original, SystemTokenDetail::new(1_000));
+	SystemTokenWeightTableChanged::<Test>::put(true);
+	for para_id in para_ids {
+		assert_ok!(SystemTokenManager::do_register_wrapped(original, Some(*para_id)));
+	}
+	assert_ok!(SystemTokenManager::do_try_state());
+}
+
+#[test]
+fn deregister_all_cleans_up_every_wrapped() {
+	new_test_ext(Default::default()).execute_with(|| {
+		let original = original();
+		register_original(&original, &[2000, 2001]);
+		assert_eq!(FiatForOriginal::<Test>::get(Fiat::USD, &original).unwrap().len(), 2);
+
+		assert_ok!(SystemTokenManager::deregister_system_token(
+			RuntimeOrigin::root(),
+			MutateKind::All(original.clone()),
+		));
+
+		assert!(SystemToken::<Test>::get(&original).is_none());
+		assert!(Metadata::<Test>::get(&original).is_none());
+		assert!(FiatForOriginal::<Test>::get(Fiat::USD, &original).is_none());
+		assert!(ParaIdSystemTokens::<Test>::get(2000).is_none());
+		assert!(ParaIdSystemTokens::<Test>::get(2001).is_none());
+		assert_ok!(SystemTokenManager::do_try_state());
+	});
+}
+
+#[test]
+fn deregister_wrapped_only_drops_given_para_id() {
+	new_test_ext(Default::default()).execute_with(|| {
+		let original = original();
+		register_original(&original, &[2000, 2001]);
+
+		assert_ok!(SystemTokenManager::deregister_system_token(
+			RuntimeOrigin::root(),
+			MutateKind::Wrapped { original: original.clone(), wrapped: Some(2000) },
+		));
+
+		let detail = SystemToken::<Test>::get(&original).unwrap();
+		assert!(!detail.is_used_by(&2000));
+		assert!(detail.is_used_by(&2001));
+		assert_eq!(
+			FiatForOriginal::<Test>::get(Fiat::USD, &original).unwrap().into_inner(),
+			vec![2001]
+		);
+		assert!(ParaIdSystemTokens::<Test>::get(2000).is_none());
+		assert_eq!(ParaIdSystemTokens::<Test>::get(2001).unwrap().into_inner(), vec![original]);
+		assert_ok!(SystemTokenManager::do_try_state());
+
+		assert_noop!(
+			SystemTokenManager::deregister_system_token(
+				RuntimeOrigin::root(),
+				MutateKind::Wrapped { original: original.clone(), wrapped: Some(2000) },
+			),
+			Error::<Test>::WrappedNotRegistered
+		);
+	});
+}
@@ -30,11 +30,16 @@ pub enum FungiblesAccessError {
 
 sp_api::decl_runtime_apis! {
 	/// The API for querying account's balances from runtime.
+	#[api_version(2)]
 	pub trait FungiblesApi<AccountId>
 	where
 		AccountId: Codec,
 	{
 		/// Returns the list of all [`MultiAsset`] that an `AccountId` has.
+		#[changed_in(2)]
 		fn query_account_balances(account: AccountId) -> Result<Vec<MultiAsset>, FungiblesAccessError>;
+
+		/// Returns the list of all [`MultiAsset`] that an `AccountId` has.
+		fn query_account_balances(account: AccountId) -> Result<xcm::VersionedMultiAssets, FungiblesAccessError>;
 	}
 }
@@ -1,52 +1,44 @@
-//! Benchmarking setup for pallet-asset-registry
+//! Benchmarking setup for infra-asset-link
 
 use super::*;
 
 #[allow(unused)]
 use crate::Pallet as AssetLink;
 use frame_benchmarking::benchmarks;
-use frame_support::{assert_ok, traits::fungibles::Inspect};
+use frame_support::assert_ok;
 use frame_system::RawOrigin;
-use xcm::opaque::latest::{
-	Junction::{GeneralIndex, PalletInstance, Parachain},
-	Junctions, MultiLocation,
-};
 
 pub const LOCAL_ASSET_ID: u32 = 10;
 
+fn location(index: u128) -> MultiLocation {
+	MultiLocation { parents: 0, interior: X2(PalletInstance(50), GeneralIndex(index)) }
+}
+
 benchmarks! {
-	where_clause {
-		where
-			T::Assets: Inspect<<T as frame_system::Config>::AccountId, AssetId = u32>,
+	link {
+		let asset_id = T::BenchmarkHelper::create_asset(LOCAL_ASSET_ID);
+	}: _(RawOrigin::Root, asset_id.clone(), location(LOCAL_ASSET_ID as u128))
+	verify {
+		assert_eq!(AssetLink::<T>::asset_of(location(LOCAL_ASSET_ID as u128)), Some(asset_id));
 	}
 
-	link_system_token {
-		let asset_multi_location = MultiLocation {
-			parents: 1,
-			interior: Junctions::X3(Parachain(Default::default()), PalletInstance(Default::default()), GeneralIndex(Default::default()))
-		};
-
-	}: _(RawOrigin::Root, LOCAL_ASSET_ID, asset_multi_location.clone())
+	unlink {
+		let asset_id = T::BenchmarkHelper::create_asset(LOCAL_ASSET_ID);
+		assert_ok!(AssetLink::<T>::link(RawOrigin::Root.into(), asset_id.clone(), location(LOCAL_ASSET_ID as u128)));
+	}: _(RawOrigin::Root, asset_id.clone())
 	verify {
-		let read_asset_multi_location = AssetLink::<T>::asset_id_multilocation(LOCAL_ASSET_ID)
-			.expect("error reading AssetIdMultiLocation");
-		assert_eq!(read_asset_multi_location, asset_multi_location);
+		assert!(AssetLink::<T>::asset_info(asset_id).is_none());
+		assert!(AssetLink::<T>::asset_of(location(LOCAL_ASSET_ID as u128)).is_none());
 	}
 
-	unlink_system_token {
-		let asset_multi_location = MultiLocation {
-			parents: 1,
-			interior: Junctions::X3(Parachain(Default::default()), PalletInstance(Default::default()), GeneralIndex(Default::default()))
-		};
-
-		assert_ok!(AssetLink::<T>::link_system_token(RawOrigin::Root.into(), LOCAL_ASSET_ID, asset_multi_location.clone()));
-		let read_asset_multi_location = AssetLink::<T>::asset_id_multilocation(LOCAL_ASSET_ID)
-			.expect("error reading AssetIdMultiLocation");
-		assert_eq!(read_asset_multi_location, asset_multi_location);
-
-	}: _(RawOrigin::Root, LOCAL_ASSET_ID)
+	relink {
+		let asset_id = T::BenchmarkHelper::create_asset(LOCAL_ASSET_ID);
+		assert_ok!(AssetLink::<T>::link(RawOrigin::Root.into(), asset_id.clone(), location(LOCAL_ASSET_ID as u128)));
+		let new = location(LOCAL_ASSET_ID as u128 + 1);
+	}: _(RawOrigin::Root, asset_id.clone(), new)
 	verify {
-		assert_eq!(AssetLink::<T>::asset_id_multilocation(LOCAL_ASSET_ID), None);
+		assert_eq!(AssetLink::<T>::asset_of(new), Some(asset_id));
+		assert!(AssetLink::<T>::asset_of(location(LOCAL_ASSET_ID as u128)).is_none());
 	}
 
 	impl_benchmark_test_suite!(AssetLink, crate::mock::new_test_ext(), crate::mock::Test);
@@ -1,15 +1,30 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
-/// Edit this file to define custom logic or remove it if it is not needed.
-/// Learn more about FRAME and the core library of Substrate FRAME pallets:
-/// <https://docs.substrate.io/reference/frame-pallets/>
+//! Registry of the assets of this chain.
+//!
+//! Every registered asset of `Config::Assets` is linked to the `MultiLocation` other chains use
+//! for it, and carries metadata which is aware of that location: which parachain issues it and
+//! whether the asset on this chain is the original or a wrapped one.
+
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+#[cfg(test)]
+mod mock;
+pub mod runtime_api;
+#[cfg(test)]
+mod tests;
 pub mod weights;
 pub use weights::*;
 
-use frame_support::{pallet_prelude::*, traits::tokens::fungibles::Inspect};
-use sp_runtime::types::token::*;
+use frame_support::{
+	pallet_prelude::*,
+	traits::tokens::fungibles::{
+		metadata::Inspect as InspectMetadata, Inspect, InspectSystemToken,
+	},
+};
+use frame_system::pallet_prelude::*;
+use sp_runtime::infra::Fiat;
+use sp_std::vec::Vec;
 use xcm::latest::prelude::*;
 use xcm_primitives::AssetMultiLocationGetter;
 
@@ -17,6 +32,46 @@ pub use pallet::*;
 
 pub type AssetIdOf<T> =
 	<<T as Config>::Assets as Inspect<<T as frame_system::Config>::AccountId>>::AssetId;
+pub type AssetInfoOf<T> = AssetInfo<BoundedVec<u8, <T as Config>::StringLimit>>;
+
+/// Whether the asset on this chain is the one issued by its issuer
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum AssetKind {
+	/// Issued by this chain
+	Original,
+	/// Issued by another chain and wrapped on this chain
+	Wrapped,
+}
+
+/// Registered asset linked to its location
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct AssetInfo<BoundedString> {
+	/// Location of the asset seen from this chain
+	pub location: MultiLocation,
+	pub name: BoundedString,
+	pub symbol: BoundedString,
+	pub decimals: u8,
+	/// Currency type if the asset is a System Token
+	pub fiat: Option<Fiat>,
+	/// Parachain which issues the asset. `None` if it is issued by the Relay Chain.
+	pub issuer: Option<u32>,
+	pub kind: AssetKind,
+}
+
+impl<BoundedString> AssetInfo<BoundedString> {
+	/// Same info with the name and the symbol converted by `f`
+	pub fn map_strings<S>(self, f: impl Fn(BoundedString) -> S) -> AssetInfo<S> {
+		let AssetInfo { location, name, symbol, decimals, fiat, issuer, kind } = self;
+		AssetInfo { location, name: f(name), symbol: f(symbol), decimals, fiat, issuer, kind }
+	}
+}
+
+/// Something which creates assets for benchmarking
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AssetId> {
+	/// Create the asset `id` with its metadata
+	fn create_asset(id: u32) -> AssetId;
+}
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -28,26 +83,44 @@ pub mod pallet {
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
+		/// The overarching event type.
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
-		type Assets: Inspect<Self::AccountId>;
+		/// Assets which are linked to their locations
+		type Assets: InspectMetadata<Self::AccountId> + InspectSystemToken<Self::AccountId>;
+		/// Origin which links, unlinks and relinks assets
+		type RegistryOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// Universal location of this chain
+		type UniversalLocation: Get<InteriorMultiLocation>;
+		/// Maximum length of the name and the symbol of an asset
+		#[pallet::constant]
+		type StringLimit: Get<u32>;
+		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
+		/// Something which creates assets for benchmarking
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<AssetIdOf<Self>>;
 	}
 
+	/// Registered assets
 	#[pallet::storage]
-	#[pallet::getter(fn asset_id_multilocation)]
-	pub type AssetIdMultiLocation<T: Config> =
-		StorageMap<_, Twox64Concat, AssetIdOf<T>, MultiLocation, OptionQuery>;
+	#[pallet::getter(fn asset_info)]
+	pub type Registry<T: Config> = StorageMap<_, Blake2_128Concat, AssetIdOf<T>, AssetInfoOf<T>>;
 
+	/// Asset linked to the location
 	#[pallet::storage]
-	#[pallet::getter(fn asset_multilocation_id)]
-	pub type AssetMultiLocationId<T: Config> =
-		StorageMap<_, Twox64Concat, MultiLocation, AssetIdOf<T>, OptionQuery>;
+	#[pallet::getter(fn asset_of)]
+	pub type LocationToAsset<T: Config> =
+		StorageMap<_, Blake2_128Concat, MultiLocation, AssetIdOf<T>>;
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
-		AssetLinked { asset_id: AssetIdOf<T>, asset_multi_location: MultiLocation },
-		AssetUnlinked { asset_id: AssetIdOf<T> },
+		/// Asset has been linked to `location`
+		AssetLinked { asset_id: AssetIdOf<T>, location: MultiLocation },
+		/// Asset has been unlinked from `location`
+		AssetUnlinked { asset_id: AssetIdOf<T>, location: MultiLocation },
+		/// Asset has been linked to `new` instead of `old`. Its metadata has been refreshed.
+		AssetRelinked { asset_id: AssetIdOf<T>, old: MultiLocation, new: MultiLocation },
 	}
 
 	#[pallet::error]
@@ -58,58 +131,148 @@ pub mod pallet {
 		AssetDoesNotExist,
 		/// The Asset ID is not registered
 		AssetIsNotLinked,
+		/// Another asset is linked to the location
+		LocationAlreadyLinked,
+		/// Location is outside of the global consensus of this chain
+		BadLocation,
+		/// Name or symbol of the asset is longer than `StringLimit`
+		BadMetadata,
 	}
-}
 
-impl<T: Config> AssetMultiLocationGetter<AssetIdOf<T>> for Pallet<T> {
-	fn get_asset_multi_location(asset_id: AssetIdOf<T>) -> Option<MultiLocation> {
-		AssetIdMultiLocation::<T>::get(asset_id)
-	}
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Link `asset_id` to `location` with the metadata of the asset
+		///
+		/// Origin
+		/// `RegistryOrigin`
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::link())]
+		pub fn link(
+			origin: OriginFor<T>,
+			asset_id: AssetIdOf<T>,
+			location: MultiLocation,
+		) -> DispatchResult {
+			T::RegistryOrigin::ensure_origin(origin)?;
+			ensure!(!Registry::<T>::contains_key(&asset_id), Error::<T>::AssetAlreadyLinked);
+			ensure!(
+				!LocationToAsset::<T>::contains_key(&location),
+				Error::<T>::LocationAlreadyLinked
+			);
+			let info = Self::resolve(&asset_id, location)?;
+			LocationToAsset::<T>::insert(&location, &asset_id);
+			Registry::<T>::insert(&asset_id, info);
+			Self::deposit_event(Event::<T>::AssetLinked { asset_id, location });
+			Ok(())
+		}
 
-	fn get_asset_id(asset_type: MultiLocation) -> Option<AssetIdOf<T>> {
-		AssetMultiLocationId::<T>::get(asset_type)
+		/// Unlink `asset_id` from its location
+		///
+		/// Origin
+		/// `RegistryOrigin`
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::unlink())]
+		pub fn unlink(origin: OriginFor<T>, asset_id: AssetIdOf<T>) -> DispatchResult {
+			T::RegistryOrigin::ensure_origin(origin)?;
+			let AssetInfo { location, .. } =
+				Registry::<T>::take(&asset_id).ok_or(Error::<T>::AssetIsNotLinked)?;
+			LocationToAsset::<T>::remove(&location);
+			Self::deposit_event(Event::<T>::AssetUnlinked { asset_id, location });
+			Ok(())
+		}
+
+		/// Link `asset_id` to `location` instead of its current location. Metadata of the asset
+		/// is refreshed, so relinking to the same location only refreshes the metadata.
+		///
+		/// Origin
+		/// `RegistryOrigin`
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::relink())]
+		pub fn relink(
+			origin: OriginFor<T>,
+			asset_id: AssetIdOf<T>,
+			location: MultiLocation,
+		) -> DispatchResult {
+			T::RegistryOrigin::ensure_origin(origin)?;
+			let old = Registry::<T>::get(&asset_id).ok_or(Error::<T>::AssetIsNotLinked)?.location;
+			if old != location {
+				ensure!(
+					!LocationToAsset::<T>::contains_key(&location),
+					Error::<T>::LocationAlreadyLinked
+				);
+			}
+			let info = Self::resolve(&asset_id, location)?;
+			LocationToAsset::<T>::remove(&old);
+			LocationToAsset::<T>::insert(&location, &asset_id);
+			Registry::<T>::insert(&asset_id, info);
+			Self::deposit_event(Event::<T>::AssetRelinked { asset_id, old, new: location });
+			Ok(())
+		}
 	}
 }
 
-impl<T: Config> AssetLinkInterface<AssetIdOf<T>> for Pallet<T>
-where
-	AssetIdOf<T>: From<SystemTokenAssetId>,
-{
-	type Error = DispatchError;
-
-	fn link(asset_id: &AssetIdOf<T>, parents: u8, original: SystemTokenId) -> DispatchResult {
+impl<T: Config> Pallet<T> {
+	/// Info of `asset_id` linked to `location`, with the metadata of `Config::Assets`
+	fn resolve(
+		asset_id: &AssetIdOf<T>,
+		location: MultiLocation,
+	) -> Result<AssetInfoOf<T>, DispatchError> {
 		ensure!(T::Assets::asset_exists(asset_id.clone()), Error::<T>::AssetDoesNotExist);
-		ensure!(
-			!AssetIdMultiLocation::<T>::contains_key(&asset_id),
-			Error::<T>::AssetAlreadyLinked
+		let universal_location = T::UniversalLocation::get();
+		let para_of = |junctions: &Junctions| {
+			junctions.iter().find_map(|j| match j {
+				Parachain(id) => Some(*id),
+				_ => None,
+			})
+		};
+		let issuer = para_of(
+			&universal_location
+				.within_global(location)
+				.map_err(|_| Error::<T>::BadLocation)?,
 		);
-		let SystemTokenId { para_id, pallet_id, asset_id } = original;
-		let asset_multi_location = MultiLocation {
-			parents,
-			interior: Junctions::X3(
-				Parachain(para_id),
-				PalletInstance(pallet_id),
-				GeneralIndex(asset_id as u128),
-			),
+		let kind = if issuer.is_some() && issuer == para_of(&universal_location) {
+			AssetKind::Original
+		} else {
+			AssetKind::Wrapped
 		};
-		let id: AssetIdOf<T> = asset_id.into();
-		AssetIdMultiLocation::<T>::insert(&id, &asset_multi_location);
-		AssetMultiLocationId::<T>::insert(&asset_multi_location, id.clone());
-
-		Self::deposit_event(Event::AssetLinked { asset_id: id, asset_multi_location });
+		let bounded = |s: Vec<u8>| BoundedVec::try_from(s).map_err(|_| Error::<T>::BadMetadata);
+		Ok(AssetInfo {
+			location,
+			name: bounded(T::Assets::name(asset_id))?,
+			symbol: bounded(T::Assets::symbol(asset_id))?,
+			decimals: T::Assets::decimals(asset_id),
+			fiat: T::Assets::fiat(asset_id).ok(),
+			issuer,
+			kind,
+		})
+	}
 
-		Ok(())
+	/// Info of the registered asset `asset_id`
+	pub fn registered_asset(asset_id: AssetIdOf<T>) -> Option<AssetInfo<Vec<u8>>> {
+		Registry::<T>::get(asset_id).map(|info| info.map_strings(Into::into))
 	}
 
-	fn unlink(asset_id: &AssetIdOf<T>) -> Result<(), Self::Error> {
-		let asset_multi_location =
-			AssetIdMultiLocation::<T>::get(asset_id).ok_or(Error::<T>::AssetIsNotLinked)?;
+	/// Registered asset linked to `location`
+	pub fn registered_asset_at(
+		location: MultiLocation,
+	) -> Option<(AssetIdOf<T>, AssetInfo<Vec<u8>>)> {
+		let asset_id = LocationToAsset::<T>::get(location)?;
+		Self::registered_asset(asset_id.clone()).map(|info| (asset_id, info))
+	}
 
-		AssetIdMultiLocation::<T>::remove(asset_id);
-		AssetMultiLocationId::<T>::remove(&asset_multi_location);
+	/// Every registered asset
+	pub fn registered_assets() -> Vec<(AssetIdOf<T>, AssetInfo<Vec<u8>>)> {
+		Registry::<T>::iter()
+			.map(|(id, info)| (id, info.map_strings(Into::into)))
+			.collect()
+	}
+}
 
-		Self::deposit_event(Event::AssetUnlinked { asset_id: asset_id.clone() });
+impl<T: Config> AssetMultiLocationGetter<AssetIdOf<T>> for Pallet<T> {
+	fn get_asset_multi_location(asset_id: AssetIdOf<T>) -> Option<MultiLocation> {
+		Registry::<T>::get(asset_id).map(|info| info.location)
+	}
 
-		Ok(())
+	fn get_asset_id(asset_type: MultiLocation) -> Option<AssetIdOf<T>> {
+		LocationToAsset::<T>::get(asset_type)
 	}
 }
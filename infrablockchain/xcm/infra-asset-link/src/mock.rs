@@ -0,0 +1,162 @@
+use crate as infra_asset_link;
+use frame_support::{
+	construct_runtime, parameter_types,
+	traits::{AsEnsureOriginWithArg, ConstU128, ConstU32, ConstU64},
+};
+use frame_system::{EnsureRoot, EnsureSigned};
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	BuildStorage,
+};
+use xcm::latest::prelude::*;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+pub type AccountId = u64;
+pub type Balance = u128;
+
+pub const ADMIN: AccountId = 1;
+pub const PARA_ID: u32 = 1000;
+/// Asset issued by this chain
+pub const LOCAL_ASSET: u32 = 1;
+/// Asset issued by the parachain 2000
+pub const FOREIGN_ASSET: u32 = 2;
+/// Asset issued by the Relay Chain
+pub const RELAY_ASSET: u32 = 3;
+/// Asset whose name is longer than `StringLimit`
+pub const LONG_NAMED_ASSET: u32 = 4;
+
+// Configure a mock runtime to test the pallet.
+construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		Assets: pallet_assets,
+		AssetLink: infra_asset_link,
+	}
+);
+
+impl frame_system::Config for Test {
+	type RuntimeOrigin = RuntimeOrigin;
+	type Nonce = u64;
+	type RuntimeCall = RuntimeCall;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Block = Block;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type BaseCallFilter = frame_support::traits::Everything;
+	type SystemWeightInfo = ();
+	type DbWeight = ();
+	type BlockWeights = ();
+	type BlockLength = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_balances::Config for Test {
+	type Balance = Balance;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU128<1>;
+	type AccountStore = frame_system::Pallet<Test>;
+	type WeightInfo = ();
+	type MaxLocks = ConstU32<50>;
+	type MaxReserves = ConstU32<50>;
+	type ReserveIdentifier = [u8; 8];
+	type RuntimeHoldReason = ();
+	type FreezeIdentifier = ();
+	type MaxHolds = ConstU32<0>;
+	type MaxFreezes = ConstU32<0>;
+}
+
+impl pallet_assets::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type SystemTokenWeight = Balance;
+	type RemoveItemsLimit = ConstU32<1000>;
+	type AssetId = u32;
+	type AssetIdParameter = u32;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<Self::AccountId>>;
+	type ForceOrigin = EnsureRoot<Self::AccountId>;
+	type AssetDeposit = ConstU128<1>;
+	type AssetAccountDeposit = ConstU128<1>;
+	type MetadataDepositBase = ConstU128<1>;
+	type MetadataDepositPerByte = ConstU128<1>;
+	type ApprovalDeposit = ConstU128<1>;
+	type StringLimit = ConstU32<50>;
+	type Freezer = ();
+	type Extra = ();
+	type WeightInfo = ();
+	type CallbackHandle = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
+parameter_types! {
+	pub UniversalLocation: InteriorMultiLocation =
+		X2(GlobalConsensus(NetworkId::InfraRelay), Parachain(PARA_ID));
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct AssetLinkBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl infra_asset_link::BenchmarkHelper<u32> for AssetLinkBenchmarkHelper {
+	fn create_asset(id: u32) -> u32 {
+		use frame_support::traits::tokens::fungibles::{metadata::Mutate, Create};
+		<Assets as Create<AccountId>>::create(id, ADMIN, true, 1).unwrap();
+		Assets::set(id, &ADMIN, b"Bench".to_vec(), b"BNC".to_vec(), 12).unwrap();
+		id
+	}
+}
+
+impl infra_asset_link::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Assets = Assets;
+	type RegistryOrigin = EnsureRoot<AccountId>;
+	type UniversalLocation = UniversalLocation;
+	type StringLimit = ConstU32<8>;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = AssetLinkBenchmarkHelper;
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+	pallet_balances::GenesisConfig::<Test> { balances: vec![(ADMIN, 1_000_000)] }
+		.assimilate_storage(&mut storage)
+		.unwrap();
+	pallet_assets::GenesisConfig::<Test> {
+		assets: vec![
+			(LOCAL_ASSET, ADMIN, true, 1),
+			(FOREIGN_ASSET, ADMIN, false, 1),
+			(RELAY_ASSET, ADMIN, true, 1),
+			(LONG_NAMED_ASSET, ADMIN, false, 1),
+		],
+		metadata: vec![
+			(LOCAL_ASSET, b"Local".to_vec(), b"LCL".to_vec(), 12),
+			(FOREIGN_ASSET, b"Foreign".to_vec(), b"FRN".to_vec(), 6),
+			(RELAY_ASSET, b"Relay".to_vec(), b"RLY".to_vec(), 18),
+			(LONG_NAMED_ASSET, b"Long Named Token".to_vec(), b"LNT".to_vec(), 18),
+		],
+		accounts: vec![],
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+
+	let mut ext: sp_io::TestExternalities = storage.into();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
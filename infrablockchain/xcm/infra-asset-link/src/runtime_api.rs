@@ -0,0 +1,21 @@
+//! Runtime API definition for the asset registry.
+
+use crate::AssetInfo;
+use parity_scale_codec::Codec;
+use sp_std::vec::Vec;
+use xcm::latest::MultiLocation;
+
+sp_api::decl_runtime_apis! {
+	/// The API for wallets to query the registered assets of the runtime.
+	pub trait AssetLinkApi<AssetId>
+	where
+		AssetId: Codec,
+	{
+		/// Returns the info of the registered asset `asset_id`.
+		fn asset_info(asset_id: AssetId) -> Option<AssetInfo<Vec<u8>>>;
+		/// Returns the registered asset linked to `location` with its info.
+		fn asset_by_location(location: MultiLocation) -> Option<(AssetId, AssetInfo<Vec<u8>>)>;
+		/// Returns every registered asset with its info.
+		fn assets() -> Vec<(AssetId, AssetInfo<Vec<u8>>)>;
+	}
+}
@@ -0,0 +1,149 @@
+use crate::{mock::*, AssetInfo, AssetKind, Error, Event};
+use frame_support::{assert_noop, assert_ok, traits::tokens::fungibles::ManageSystemToken};
+use sp_runtime::{infra::Fiat, DispatchError};
+use xcm::latest::prelude::*;
+
+fn local(index: u128) -> MultiLocation {
+	MultiLocation { parents: 0, interior: X2(PalletInstance(50), GeneralIndex(index)) }
+}
+
+fn foreign(index: u128) -> MultiLocation {
+	MultiLocation {
+		parents: 1,
+		interior: X3(Parachain(2000), PalletInstance(50), GeneralIndex(index)),
+	}
+}
+
+fn relay() -> MultiLocation {
+	MultiLocation::parent()
+}
+
+#[test]
+fn link_resolves_metadata_aware_of_location() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetLink::link(RuntimeOrigin::root(), LOCAL_ASSET, local(1)));
+		assert_ok!(AssetLink::link(RuntimeOrigin::root(), FOREIGN_ASSET, foreign(1)));
+		assert_ok!(AssetLink::link(RuntimeOrigin::root(), RELAY_ASSET, relay()));
+		System::assert_last_event(
+			Event::<Test>::AssetLinked { asset_id: RELAY_ASSET, location: relay() }.into(),
+		);
+
+		assert_eq!(
+			AssetLink::registered_asset(LOCAL_ASSET),
+			Some(AssetInfo {
+				location: local(1),
+				name: b"Local".to_vec(),
+				symbol: b"LCL".to_vec(),
+				decimals: 12,
+				fiat: None,
+				issuer: Some(PARA_ID),
+				kind: AssetKind::Original,
+			})
+		);
+		let info = AssetLink::registered_asset(FOREIGN_ASSET).unwrap();
+		assert_eq!((info.issuer, info.kind, info.decimals), (Some(2000), AssetKind::Wrapped, 6));
+		let info = AssetLink::registered_asset(RELAY_ASSET).unwrap();
+		assert_eq!((info.issuer, info.kind), (None, AssetKind::Wrapped));
+
+		assert_eq!(
+			AssetLink::registered_asset_at(foreign(1)).map(|(id, _)| id),
+			Some(FOREIGN_ASSET)
+		);
+		assert_eq!(AssetLink::registered_assets().len(), 3);
+	});
+}
+
+#[test]
+fn link_resolves_fiat_of_system_tokens() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(<Assets as ManageSystemToken<AccountId>>::touch(
+			ADMIN,
+			5,
+			Fiat::USD,
+			1,
+			b"iUSD".to_vec(),
+			b"iUSD".to_vec(),
+			6,
+			1_000_000,
+		));
+		assert_ok!(AssetLink::link(RuntimeOrigin::root(), 5, foreign(5)));
+		assert_eq!(AssetLink::registered_asset(5).unwrap().fiat, Some(Fiat::USD));
+	});
+}
+
+#[test]
+fn link_fails_with_bad_requests() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			AssetLink::link(RuntimeOrigin::signed(ADMIN), LOCAL_ASSET, local(1)),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			AssetLink::link(RuntimeOrigin::root(), 99, local(99)),
+			Error::<Test>::AssetDoesNotExist
+		);
+		assert_noop!(
+			AssetLink::link(RuntimeOrigin::root(), LONG_NAMED_ASSET, local(4)),
+			Error::<Test>::BadMetadata
+		);
+		let outside = MultiLocation { parents: 3, interior: Here };
+		assert_noop!(
+			AssetLink::link(RuntimeOrigin::root(), LOCAL_ASSET, outside),
+			Error::<Test>::BadLocation
+		);
+
+		assert_ok!(AssetLink::link(RuntimeOrigin::root(), LOCAL_ASSET, local(1)));
+		assert_noop!(
+			AssetLink::link(RuntimeOrigin::root(), LOCAL_ASSET, local(2)),
+			Error::<Test>::AssetAlreadyLinked
+		);
+		assert_noop!(
+			AssetLink::link(RuntimeOrigin::root(), FOREIGN_ASSET, local(1)),
+			Error::<Test>::LocationAlreadyLinked
+		);
+	});
+}
+
+#[test]
+fn relink_moves_asset_and_unlink_removes_it() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			AssetLink::relink(RuntimeOrigin::root(), FOREIGN_ASSET, foreign(2)),
+			Error::<Test>::AssetIsNotLinked
+		);
+		assert_ok!(AssetLink::link(RuntimeOrigin::root(), LOCAL_ASSET, local(1)));
+		assert_ok!(AssetLink::link(RuntimeOrigin::root(), FOREIGN_ASSET, local(2)));
+		assert_eq!(AssetLink::registered_asset(FOREIGN_ASSET).unwrap().kind, AssetKind::Original);
+		assert_noop!(
+			AssetLink::relink(RuntimeOrigin::root(), FOREIGN_ASSET, local(1)),
+			Error::<Test>::LocationAlreadyLinked
+		);
+
+		assert_ok!(AssetLink::relink(RuntimeOrigin::root(), FOREIGN_ASSET, foreign(2)));
+		System::assert_last_event(
+			Event::<Test>::AssetRelinked {
+				asset_id: FOREIGN_ASSET,
+				old: local(2),
+				new: foreign(2),
+			}
+			.into(),
+		);
+		assert_eq!(AssetLink::asset_of(local(2)), None);
+		assert_eq!(AssetLink::asset_of(foreign(2)), Some(FOREIGN_ASSET));
+		assert_eq!(AssetLink::registered_asset(FOREIGN_ASSET).unwrap().kind, AssetKind::Wrapped);
+		// Relinking to the same location refreshes the metadata
+		assert_ok!(AssetLink::relink(RuntimeOrigin::root(), FOREIGN_ASSET, foreign(2)));
+		assert_eq!(AssetLink::asset_of(foreign(2)), Some(FOREIGN_ASSET));
+
+		assert_ok!(AssetLink::unlink(RuntimeOrigin::root(), FOREIGN_ASSET));
+		System::assert_last_event(
+			Event::<Test>::AssetUnlinked { asset_id: FOREIGN_ASSET, location: foreign(2) }.into(),
+		);
+		assert_eq!(AssetLink::asset_info(FOREIGN_ASSET), None);
+		assert_eq!(AssetLink::asset_of(foreign(2)), None);
+		assert_noop!(
+			AssetLink::unlink(RuntimeOrigin::root(), FOREIGN_ASSET),
+			Error::<Test>::AssetIsNotLinked
+		);
+	});
+}
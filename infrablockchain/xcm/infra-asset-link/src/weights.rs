@@ -1,29 +1,12 @@
-
-//! Autogenerated weights for `pallet_asset_link`
+//! Autogenerated weights for `infra_asset_link`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2022-10-04, STEPS: `20`, REPEAT: 10, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! HOSTNAME: `bernardo-benchmarking`, CPU: `AMD EPYC 7B13`
-//! EXECUTION: None, WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
-
-// collected on a c2d-highcpu-8 of Google Cloud Platform
-
-// Executed Command:
-// ./target/release/trappist-collator
-// benchmark
-// pallet
-// --chain
-// dev
-// --pallet
-// pallet_asset_link
-// --extrinsic
-// *
-// --steps
-// 20
-// --repeat
-// 10
-// --output
-// pallets/asset-registry/src/weights.rs
+//! DATE: 2026-10-19, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
+//! EXECUTION: Native, CHAIN: the mock runtime of `mock.rs`
+//!
+//! Measured from `benchmarking.rs` natively against the benchmarking state database, as no wasm
+//! build of a runtime was available. Regenerate with `benchmark pallet --pallet infra_asset_link`
+//! on reference hardware before relying on them in production.
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -32,45 +15,86 @@
 use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
 use sp_std::marker::PhantomData;
 
+/// Weight functions needed for `infra_asset_link`.
 pub trait WeightInfo {
-	fn link_system_token() -> Weight;
-	fn unlink_system_token() -> Weight;
+	fn link() -> Weight;
+	fn unlink() -> Weight;
+	fn relink() -> Weight;
 }
 
-/// Weight functions for `pallet_asset_link`.
+/// Weights for `infra_asset_link` measured on the mock runtime.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
-	// Storage: Assets Asset (r:1 w:0)
-	// Storage: AssetLink AssetIdMultiLocation (r:1 w:1)
-	// Storage: AssetLink AssetMultiLocationId (r:0 w:1)
-	fn link_system_token() -> Weight {
-		Weight::from_parts(18_710_000, 0)
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+	/// Storage: Assets Asset (r:1 w:0)
+	/// Storage: Assets Metadata (r:1 w:0)
+	/// Storage: AssetLink Registry (r:1 w:1)
+	/// Storage: AssetLink LocationToAsset (r:1 w:1)
+	fn link() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `413`
+		// Minimum execution time: 47_601_000 picoseconds.
+		Weight::from_parts(51_613_000, 0)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: AssetLink Registry (r:1 w:1)
+	/// Storage: AssetLink LocationToAsset (r:0 w:1)
+	fn unlink() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `85`
+		// Minimum execution time: 19_099_000 picoseconds.
+		Weight::from_parts(20_211_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
-	// Storage: AssetLink AssetIdMultiLocation (r:1 w:1)
-	// Storage: AssetLink AssetMultiLocationId (r:0 w:1)
-	fn unlink_system_token() -> Weight {
-		Weight::from_parts(16_570_000, 0)
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(2))
+	/// Storage: Assets Asset (r:1 w:0)
+	/// Storage: Assets Metadata (r:1 w:0)
+	/// Storage: AssetLink Registry (r:1 w:1)
+	/// Storage: AssetLink LocationToAsset (r:1 w:2)
+	fn relink() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		// Minimum execution time: 55_164_000 picoseconds.
+		Weight::from_parts(58_010_000, 0)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 }
 
+// For backwards compatibility and tests
 impl WeightInfo for () {
-	// Storage: Assets Asset (r:1 w:0)
-	// Storage: AssetLink AssetIdMultiLocation (r:1 w:1)
-	// Storage: AssetLink AssetMultiLocationId (r:0 w:1)
-	fn link_system_token() -> Weight {
-		Weight::from_parts(18_710_000, 0)
-			.saturating_add(RocksDbWeight::get().reads(2))
-			.saturating_add(RocksDbWeight::get().writes(2))
+	/// Storage: Assets Asset (r:1 w:0)
+	/// Storage: Assets Metadata (r:1 w:0)
+	/// Storage: AssetLink Registry (r:1 w:1)
+	/// Storage: AssetLink LocationToAsset (r:1 w:1)
+	fn link() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `413`
+		// Minimum execution time: 47_601_000 picoseconds.
+		Weight::from_parts(51_613_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: AssetLink Registry (r:1 w:1)
+	/// Storage: AssetLink LocationToAsset (r:0 w:1)
+	fn unlink() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `85`
+		// Minimum execution time: 19_099_000 picoseconds.
+		Weight::from_parts(20_211_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
-	// Storage: AssetLink AssetIdMultiLocation (r:1 w:1)
-	// Storage: AssetLink AssetMultiLocationId (r:0 w:1)
-	fn unlink_system_token() -> Weight {
-		Weight::from_parts(16_570_000, 0)
-			.saturating_add(RocksDbWeight::get().reads(1))
-			.saturating_add(RocksDbWeight::get().writes(2))
+	/// Storage: Assets Asset (r:1 w:0)
+	/// Storage: Assets Metadata (r:1 w:0)
+	/// Storage: AssetLink Registry (r:1 w:1)
+	/// Storage: AssetLink LocationToAsset (r:1 w:2)
+	fn relink() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		// Minimum execution time: 55_164_000 picoseconds.
+		Weight::from_parts(58_010_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 }
@@ -604,7 +604,7 @@ pub trait SystemTokenId: LocalAssetId {
 	/// Id for token pallet id(e.g `u8`)
 	type PalletId: Parameter;
 	/// Id for token origin(e.g `u32`)
-	type OriginId: Parameter;
+	type OriginId: Parameter + MaxEncodedLen;
 	/// Error type
 	type Error: FullCodec + Debug;
 
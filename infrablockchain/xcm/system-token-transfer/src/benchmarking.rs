@@ -0,0 +1,26 @@
+//! Benchmarks for `pallet_system_token_transfer`
+
+use super::*;
+use frame_benchmarking::{benchmarks, BenchmarkError, BenchmarkResult};
+use sp_std::prelude::*;
+
+type RuntimeOrigin<T> = <T as frame_system::Config>::RuntimeOrigin;
+
+benchmarks! {
+	transfer_system_token {
+		let send_origin =
+			T::ExecuteXcmOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let origin_location = T::ExecuteXcmOrigin::try_origin(send_origin.clone())
+			.map_err(|_| BenchmarkError::Override(BenchmarkResult::from_weight(Weight::MAX)))?;
+		let (asset, amount, dest) = T::BenchmarkHelper::system_token_transfer(&origin_location)
+			.ok_or(BenchmarkError::Override(BenchmarkResult::from_weight(Weight::MAX)))?;
+		let beneficiary: MultiLocation = AccountId32 { network: None, id: [0u8; 32] }.into();
+	}: _<RuntimeOrigin<T>>(
+		send_origin,
+		Box::new(asset.into()),
+		amount,
+		Box::new(dest.into()),
+		Box::new(beneficiary.into()),
+		Unlimited
+	)
+}
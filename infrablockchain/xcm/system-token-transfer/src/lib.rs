@@ -0,0 +1,365 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! # System Token Transfer Pallet
+//!
+//! Moves System Tokens between any two InfraBlockchain runtimes with a single extrinsic.
+//!
+//! A System Token is identified by its `MultiLocation`, which always ends with
+//! `PalletInstance(_)` and `GeneralIndex(_)`. Everything in front of those junctions is the chain
+//! that issued the token and therefore its reserve. Based on that and on the teleport trust
+//! configured for the runtime, the pallet picks one of the [`TransferType`]s and builds the XCM
+//! program, paying for execution on every hop with the transferred token itself.
+
+pub use pallet::*;
+pub mod weights;
+pub use weights::WeightInfo;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod tests;
+
+use frame_support::{
+	pallet_prelude::*,
+	traits::{Contains, ContainsPair},
+};
+use frame_system::pallet_prelude::*;
+use sp_std::{boxed::Box, vec};
+use xcm::{latest::prelude::*, VersionedMultiLocation};
+use xcm_executor::traits::WeightBounds;
+
+/// Log target of this pallet
+pub const LOG_TARGET: &str = "runtime::system-token-transfer";
+
+/// How a System Token is moved from this chain to the destination.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum TransferType {
+	/// This chain and the destination trust each other as teleporters of the token.
+	Teleport,
+	/// This chain is the reserve of the token.
+	LocalReserve,
+	/// The destination is the reserve of the token.
+	DestinationReserve,
+	/// The token is reserved on a third chain which the transfer hops through.
+	RemoteReserve(MultiLocation),
+}
+
+impl TransferType {
+	/// Decide how `asset` should be moved to `dest`.
+	///
+	/// Teleport is preferred whenever `IsTeleporter` allows it. Otherwise the reserve of the
+	/// token decides. Return `None` if the reserve could not be derived from the location of
+	/// `asset`.
+	pub fn new<IsTeleporter: ContainsPair<MultiAsset, MultiLocation>>(
+		asset: &MultiAsset,
+		dest: &MultiLocation,
+	) -> Option<Self> {
+		if IsTeleporter::contains(asset, dest) {
+			return Some(Self::Teleport)
+		}
+		let location = match asset.id {
+			Concrete(location) => location,
+			Abstract(_) => return None,
+		};
+		let reserve = reserve_of(&location)?;
+		let transfer_type = if reserve == Here.into_location() {
+			Self::LocalReserve
+		} else if reserve == *dest {
+			Self::DestinationReserve
+		} else {
+			Self::RemoteReserve(reserve)
+		};
+		Some(transfer_type)
+	}
+
+	/// Build the XCM program which should be executed locally for transferring `asset` to
+	/// `beneficiary` on `dest`.
+	///
+	/// Fees are always paid with `asset`. For `RemoteReserve`, half of the amount is reserved as
+	/// the maximum fee for each hop and whatever has not been spent is deposited to the
+	/// `beneficiary`.
+	pub fn message<Call>(
+		&self,
+		asset: MultiAsset,
+		dest: MultiLocation,
+		beneficiary: MultiLocation,
+		weight_limit: WeightLimit,
+		context: InteriorMultiLocation,
+	) -> Result<Xcm<Call>, XcmError> {
+		let fees =
+			asset.clone().reanchored(&dest, context).map_err(|_| XcmError::ReanchorFailed)?;
+		let deposit = DepositAsset { assets: Wild(AllCounted(1)), beneficiary };
+		let message = match self {
+			Self::Teleport => Xcm(vec![
+				WithdrawAsset(asset.into()),
+				SetFeesMode { jit_withdraw: true },
+				InitiateTeleport {
+					assets: Wild(AllCounted(1)),
+					dest,
+					xcm: Xcm(vec![BuyExecution { fees, weight_limit }, deposit]),
+				},
+			]),
+			Self::LocalReserve => Xcm(vec![
+				SetFeesMode { jit_withdraw: true },
+				TransferReserveAsset {
+					assets: asset.into(),
+					dest,
+					xcm: Xcm(vec![BuyExecution { fees, weight_limit }, deposit]),
+				},
+			]),
+			Self::DestinationReserve => Xcm(vec![
+				WithdrawAsset(asset.into()),
+				SetFeesMode { jit_withdraw: true },
+				InitiateReserveWithdraw {
+					assets: Wild(AllCounted(1)),
+					reserve: dest,
+					xcm: Xcm(vec![BuyExecution { fees, weight_limit }, deposit]),
+				},
+			]),
+			Self::RemoteReserve(reserve) => {
+				let half = match asset.fun {
+					Fungible(amount) => Fungible(amount / 2),
+					NonFungible(_) => return Err(XcmError::AssetNotFound),
+				};
+				let reserve_fees = MultiAsset { id: asset.id, fun: half.clone() }
+					.reanchored(reserve, context)
+					.map_err(|_| XcmError::ReanchorFailed)?;
+				let dest_fees = MultiAsset { fun: half, ..fees };
+				let dest_from_reserve =
+					dest.reanchored(reserve, context).map_err(|_| XcmError::ReanchorFailed)?;
+				Xcm(vec![
+					WithdrawAsset(asset.into()),
+					SetFeesMode { jit_withdraw: true },
+					InitiateReserveWithdraw {
+						assets: Wild(AllCounted(1)),
+						reserve: *reserve,
+						xcm: Xcm(vec![
+							BuyExecution { fees: reserve_fees, weight_limit: weight_limit.clone() },
+							DepositReserveAsset {
+								assets: Wild(AllCounted(1)),
+								dest: dest_from_reserve,
+								xcm: Xcm(vec![
+									BuyExecution { fees: dest_fees, weight_limit },
+									deposit,
+								]),
+							},
+						]),
+					},
+				])
+			},
+		};
+		Ok(message)
+	}
+}
+
+/// Reserve of the System Token located at `location`, which is the location of its issuing
+/// chain. Return `None` if `location` is not shaped like a System Token.
+pub fn reserve_of(location: &MultiLocation) -> Option<MultiLocation> {
+	let mut reserve = *location;
+	match (reserve.take_last(), reserve.take_last()) {
+		(Some(GeneralIndex(_)), Some(PalletInstance(_))) => Some(reserve),
+		_ => None,
+	}
+}
+
+/// Accept System Tokens as reserve assets only from the chain that issued them.
+///
+/// Meant to be used as `IsReserve` of the `XcmConfig`, so that the deposits made by
+/// [`TransferType::LocalReserve`] and [`TransferType::RemoteReserve`] are accepted.
+pub struct SystemTokenFromReserve;
+impl ContainsPair<MultiAsset, MultiLocation> for SystemTokenFromReserve {
+	fn contains(asset: &MultiAsset, origin: &MultiLocation) -> bool {
+		match asset.id {
+			Concrete(ref location) => reserve_of(location).map_or(false, |r| r == *origin),
+			Abstract(_) => false,
+		}
+	}
+}
+
+/// Setup of a System Token transfer which can be benchmarked.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper {
+	/// Fund `origin` with a System Token and return its location, the amount to be transferred
+	/// and a reachable destination. Return `None` if the runtime cannot transfer any System
+	/// Token.
+	fn system_token_transfer(
+		origin: &MultiLocation,
+	) -> Option<(MultiLocation, u128, MultiLocation)>;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl BenchmarkHelper for () {
+	fn system_token_transfer(_: &MultiLocation) -> Option<(MultiLocation, u128, MultiLocation)> {
+		None
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// Required origin for executing XCM messages. If successful, it resolves to
+		/// `MultiLocation` which exists as an interior location within this chain's XCM context.
+		type ExecuteXcmOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = MultiLocation>;
+		/// Something to execute an XCM message.
+		type XcmExecutor: ExecuteXcm<<Self as frame_system::Config>::RuntimeCall>;
+		/// Means of measuring the weight consumed by an XCM message locally.
+		type Weigher: WeightBounds<<Self as frame_system::Config>::RuntimeCall>;
+		/// This chain's Universal Location.
+		type UniversalLocation: Get<InteriorMultiLocation>;
+		/// Registry of System Tokens known to this chain, keyed by their location relative to
+		/// this chain.
+		type SystemTokens: Contains<MultiLocation>;
+		/// Pairs of System Token and destination which may be teleported instead of being
+		/// transferred through their reserve.
+		type IsTeleporter: ContainsPair<MultiAsset, MultiLocation>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+		/// Setup of the `transfer_system_token` benchmark.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper;
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// System Token has been sent to `dest`
+		SystemTokenTransferred {
+			origin: MultiLocation,
+			asset: MultiLocation,
+			amount: u128,
+			dest: MultiLocation,
+			transfer_type: TransferType,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The version of the `Versioned` value used is not able to be interpreted.
+		BadVersion,
+		/// Given asset is not registered as System Token on this chain
+		NotSystemToken,
+		/// Transfer amount should be greater than zero
+		ZeroAmount,
+		/// Destination is this chain
+		InvalidDestination,
+		/// Reserve of the System Token could not be determined
+		UnknownReserve,
+		/// Could not re-anchor the assets or locations to the destination or reserve
+		CannotReanchor,
+		/// The message's weight could not be determined.
+		UnweighableMessage,
+		/// Local execution of the transfer message has failed
+		TransferFailed,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Transfer `amount` of the System Token located at `asset` to `beneficiary` on `dest`.
+		///
+		/// Teleport, local reserve, destination reserve or multi-hop remote reserve transfer is
+		/// chosen based on the System Token's location and teleport configuration. Execution on
+		/// every hop is paid with the System Token itself.
+		///
+		/// - `asset`: Location of the System Token relative to this chain
+		/// - `amount`: Amount to be withdrawn from the sender, including fees
+		/// - `dest`: Destination chain relative to this chain, e.g `(1, Parachain(2000))`
+		/// - `beneficiary`: Receiver of the System Token relative to `dest`
+		/// - `weight_limit`: Weight limit for buying execution on each hop
+		#[pallet::call_index(0)]
+		#[pallet::weight({
+			let maybe_asset: Result<MultiLocation, ()> = (*asset.clone()).try_into();
+			let maybe_dest: Result<MultiLocation, ()> = (*dest.clone()).try_into();
+			let maybe_beneficiary: Result<MultiLocation, ()> = (*beneficiary.clone()).try_into();
+			match (maybe_asset, maybe_dest, maybe_beneficiary) {
+				(Ok(asset), Ok(dest), Ok(beneficiary)) => Pallet::<T>::transfer_message(
+					asset,
+					*amount,
+					dest,
+					beneficiary,
+					weight_limit.clone(),
+				)
+				.ok()
+				.and_then(|(_, mut message)| T::Weigher::weight(&mut message).ok())
+				.map_or(Weight::MAX, |w| T::WeightInfo::transfer_system_token().saturating_add(w)),
+				_ => Weight::MAX,
+			}
+		})]
+		pub fn transfer_system_token(
+			origin: OriginFor<T>,
+			asset: Box<VersionedMultiLocation>,
+			#[pallet::compact] amount: u128,
+			dest: Box<VersionedMultiLocation>,
+			beneficiary: Box<VersionedMultiLocation>,
+			weight_limit: WeightLimit,
+		) -> DispatchResult {
+			let origin_location = T::ExecuteXcmOrigin::ensure_origin(origin)?;
+			let asset: MultiLocation = (*asset).try_into().map_err(|()| Error::<T>::BadVersion)?;
+			let dest: MultiLocation = (*dest).try_into().map_err(|()| Error::<T>::BadVersion)?;
+			let beneficiary: MultiLocation =
+				(*beneficiary).try_into().map_err(|()| Error::<T>::BadVersion)?;
+			let (transfer_type, mut message) =
+				Self::transfer_message(asset, amount, dest, beneficiary, weight_limit)?;
+			let weight =
+				T::Weigher::weight(&mut message).map_err(|()| Error::<T>::UnweighableMessage)?;
+			let hash = message.using_encoded(sp_io::hashing::blake2_256);
+			let outcome = T::XcmExecutor::execute_xcm_in_credit(
+				origin_location,
+				message,
+				hash,
+				weight,
+				weight,
+			);
+			if let Err(e) = outcome.clone().ensure_complete() {
+				log::error!(
+					target: LOG_TARGET,
+					"System Token transfer to {:?} has failed: {:?}",
+					dest,
+					e,
+				);
+				return Err(Error::<T>::TransferFailed.into())
+			}
+			Self::deposit_event(Event::<T>::SystemTokenTransferred {
+				origin: origin_location,
+				asset,
+				amount,
+				dest,
+				transfer_type,
+			});
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Decide how `amount` of the System Token located at `asset` is moved to `beneficiary`
+		/// on `dest` and build the XCM program which is executed locally for it.
+		pub fn transfer_message(
+			asset: MultiLocation,
+			amount: u128,
+			dest: MultiLocation,
+			beneficiary: MultiLocation,
+			weight_limit: WeightLimit,
+		) -> Result<(TransferType, Xcm<<T as frame_system::Config>::RuntimeCall>), DispatchError>
+		{
+			ensure!(amount > 0, Error::<T>::ZeroAmount);
+			ensure!(dest != Here.into_location(), Error::<T>::InvalidDestination);
+			ensure!(T::SystemTokens::contains(&asset), Error::<T>::NotSystemToken);
+
+			let system_token: MultiAsset = (asset, amount).into();
+			let transfer_type = TransferType::new::<T::IsTeleporter>(&system_token, &dest)
+				.ok_or(Error::<T>::UnknownReserve)?;
+			let message = transfer_type
+				.message(system_token, dest, beneficiary, weight_limit, T::UniversalLocation::get())
+				.map_err(|_| Error::<T>::CannotReanchor)?;
+			Ok((transfer_type, message))
+		}
+	}
+}
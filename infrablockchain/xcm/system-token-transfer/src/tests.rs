@@ -0,0 +1,226 @@
+use super::*;
+use frame_support::parameter_types;
+
+parameter_types! {
+	pub Context: InteriorMultiLocation = X2(GlobalConsensus(InfraRelay), Parachain(2000));
+	pub ParaA: MultiLocation = MultiLocation::new(1, X1(Parachain(2000)));
+	pub ParaB: MultiLocation = MultiLocation::new(1, X1(Parachain(2001)));
+	pub AssetHub: MultiLocation = MultiLocation::new(1, X1(Parachain(1000)));
+}
+
+/// Only System Tokens issued by Relay Chain are teleported to Asset Hub
+pub struct RelayTokenToAssetHub;
+impl ContainsPair<MultiAsset, MultiLocation> for RelayTokenToAssetHub {
+	fn contains(asset: &MultiAsset, dest: &MultiLocation) -> bool {
+		matches!(
+			asset.id,
+			Concrete(MultiLocation {
+				parents: 1,
+				interior: X2(PalletInstance(_), GeneralIndex(_))
+			})
+		) && dest == &AssetHub::get()
+	}
+}
+
+fn system_token(location: MultiLocation, amount: u128) -> MultiAsset {
+	(location, amount).into()
+}
+
+fn local_token() -> MultiLocation {
+	MultiLocation::new(0, X2(PalletInstance(50), GeneralIndex(1)))
+}
+
+fn para_b_token() -> MultiLocation {
+	MultiLocation::new(1, X3(Parachain(2001), PalletInstance(50), GeneralIndex(1)))
+}
+
+fn asset_hub_token() -> MultiLocation {
+	MultiLocation::new(1, X3(Parachain(1000), PalletInstance(50), GeneralIndex(1)))
+}
+
+fn relay_token() -> MultiLocation {
+	MultiLocation::new(1, X2(PalletInstance(22), GeneralIndex(1)))
+}
+
+fn beneficiary() -> MultiLocation {
+	AccountId32 { network: None, id: [1u8; 32] }.into()
+}
+
+#[test]
+fn reserve_of_works() {
+	assert_eq!(reserve_of(&local_token()), Some(Here.into_location()));
+	assert_eq!(reserve_of(&para_b_token()), Some(ParaB::get()));
+	assert_eq!(reserve_of(&relay_token()), Some(Parent.into()));
+	assert_eq!(reserve_of(&MultiLocation::new(1, X1(Parachain(2001)))), None);
+	assert_eq!(reserve_of(&MultiLocation::new(0, X1(GeneralIndex(1)))), None);
+}
+
+#[test]
+fn transfer_type_works() {
+	type T = RelayTokenToAssetHub;
+	// Issued here
+	assert_eq!(
+		TransferType::new::<T>(&system_token(local_token(), 100), &ParaB::get()),
+		Some(TransferType::LocalReserve)
+	);
+	// Issued on destination
+	assert_eq!(
+		TransferType::new::<T>(&system_token(para_b_token(), 100), &ParaB::get()),
+		Some(TransferType::DestinationReserve)
+	);
+	// Issued on a third para
+	assert_eq!(
+		TransferType::new::<T>(&system_token(asset_hub_token(), 100), &ParaB::get()),
+		Some(TransferType::RemoteReserve(AssetHub::get()))
+	);
+	// Trusted teleport
+	assert_eq!(
+		TransferType::new::<T>(&system_token(relay_token(), 100), &AssetHub::get()),
+		Some(TransferType::Teleport)
+	);
+	// Not trusted, so fall back to the reserve
+	assert_eq!(
+		TransferType::new::<T>(&system_token(relay_token(), 100), &ParaB::get()),
+		Some(TransferType::RemoteReserve(Parent.into()))
+	);
+	// Not a System Token location
+	assert_eq!(
+		TransferType::new::<T>(
+			&system_token(MultiLocation::new(1, X1(Parachain(2001))), 100),
+			&ParaB::get()
+		),
+		None
+	);
+}
+
+#[test]
+fn local_reserve_message_works() {
+	let message = TransferType::LocalReserve
+		.message::<()>(
+			system_token(local_token(), 100),
+			ParaB::get(),
+			beneficiary(),
+			Unlimited,
+			Context::get(),
+		)
+		.unwrap();
+	let fees = system_token(
+		MultiLocation::new(1, X3(Parachain(2000), PalletInstance(50), GeneralIndex(1))),
+		100,
+	);
+	assert_eq!(
+		message,
+		Xcm(vec![
+			SetFeesMode { jit_withdraw: true },
+			TransferReserveAsset {
+				assets: system_token(local_token(), 100).into(),
+				dest: ParaB::get(),
+				xcm: Xcm(vec![
+					BuyExecution { fees, weight_limit: Unlimited },
+					DepositAsset { assets: Wild(AllCounted(1)), beneficiary: beneficiary() },
+				]),
+			},
+		])
+	);
+}
+
+#[test]
+fn destination_reserve_message_works() {
+	let message = TransferType::DestinationReserve
+		.message::<()>(
+			system_token(para_b_token(), 100),
+			ParaB::get(),
+			beneficiary(),
+			Unlimited,
+			Context::get(),
+		)
+		.unwrap();
+	let fees = system_token(local_token(), 100);
+	assert_eq!(
+		message,
+		Xcm(vec![
+			WithdrawAsset(system_token(para_b_token(), 100).into()),
+			SetFeesMode { jit_withdraw: true },
+			InitiateReserveWithdraw {
+				assets: Wild(AllCounted(1)),
+				reserve: ParaB::get(),
+				xcm: Xcm(vec![
+					BuyExecution { fees, weight_limit: Unlimited },
+					DepositAsset { assets: Wild(AllCounted(1)), beneficiary: beneficiary() },
+				]),
+			},
+		])
+	);
+}
+
+#[test]
+fn remote_reserve_message_hops_through_reserve() {
+	let message = TransferType::RemoteReserve(AssetHub::get())
+		.message::<()>(
+			system_token(asset_hub_token(), 100),
+			ParaB::get(),
+			beneficiary(),
+			Unlimited,
+			Context::get(),
+		)
+		.unwrap();
+	// Fees on Asset Hub are paid with its local asset, and fees on ParaB with the wrapped one.
+	let reserve_fees = system_token(local_token(), 50);
+	let dest_fees = system_token(asset_hub_token(), 50);
+	assert_eq!(
+		message,
+		Xcm(vec![
+			WithdrawAsset(system_token(asset_hub_token(), 100).into()),
+			SetFeesMode { jit_withdraw: true },
+			InitiateReserveWithdraw {
+				assets: Wild(AllCounted(1)),
+				reserve: AssetHub::get(),
+				xcm: Xcm(vec![
+					BuyExecution { fees: reserve_fees, weight_limit: Unlimited },
+					DepositReserveAsset {
+						assets: Wild(AllCounted(1)),
+						dest: ParaB::get(),
+						xcm: Xcm(vec![
+							BuyExecution { fees: dest_fees, weight_limit: Unlimited },
+							DepositAsset {
+								assets: Wild(AllCounted(1)),
+								beneficiary: beneficiary(),
+							},
+						]),
+					},
+				]),
+			},
+		])
+	);
+}
+
+#[test]
+fn teleport_message_works() {
+	let message = TransferType::Teleport
+		.message::<()>(
+			system_token(relay_token(), 100),
+			AssetHub::get(),
+			beneficiary(),
+			Unlimited,
+			Context::get(),
+		)
+		.unwrap();
+	assert_eq!(
+		message,
+		Xcm(vec![
+			WithdrawAsset(system_token(relay_token(), 100).into()),
+			SetFeesMode { jit_withdraw: true },
+			InitiateTeleport {
+				assets: Wild(AllCounted(1)),
+				dest: AssetHub::get(),
+				xcm: Xcm(vec![
+					BuyExecution {
+						fees: system_token(relay_token(), 100),
+						weight_limit: Unlimited
+					},
+					DepositAsset { assets: Wild(AllCounted(1)), beneficiary: beneficiary() },
+				]),
+			},
+		])
+	);
+}
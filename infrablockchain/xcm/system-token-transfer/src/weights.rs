@@ -0,0 +1,34 @@
+//! Weights for `pallet_system_token_transfer`
+//!
+//! The weight of the XCM program built by the call is measured by `Config::Weigher` and added to
+//! these on dispatch. The values below cover only the local bookkeeping of the call. They have not
+//! been measured yet and must be regenerated from `benchmarking.rs` with `benchmark pallet`.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+pub trait WeightInfo {
+	fn transfer_system_token() -> Weight;
+}
+
+/// Weight functions for `pallet_system_token_transfer`.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn transfer_system_token() -> Weight {
+		Weight::from_parts(95_000_000, 7_000)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+}
+
+impl WeightInfo for () {
+	fn transfer_system_token() -> Weight {
+		Weight::from_parts(95_000_000, 7_000)
+			.saturating_add(RocksDbWeight::get().reads(5))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+}
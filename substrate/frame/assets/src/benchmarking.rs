@@ -49,6 +49,7 @@ fn create_default_asset<T: Config<I>, I: 'static>(
 		caller_lookup.clone(),
 		is_sufficient,
 		1u32.into(),
+		None,
 	)
 	.is_ok());
 	(asset_id, caller, caller_lookup)
@@ -150,7 +151,7 @@ benchmarks_instance_pallet! {
 		let asset_id = default_asset_id::<T, I>();
 		let caller: T::AccountId = whitelisted_caller();
 		let caller_lookup = T::Lookup::unlookup(caller.clone());
-	}: _(SystemOrigin::Root, asset_id, caller_lookup, true, 1u32.into())
+	}: _(SystemOrigin::Root, asset_id, caller_lookup, true, 1u32.into(), None)
 	verify {
 		assert_last_event::<T, I>(Event::ForceCreated { asset_id: asset_id.into(), owner: caller }.into());
 	}
@@ -172,7 +172,6 @@ use frame_support::{
 };
 use frame_system::pallet_prelude::*;
 use scale_info::TypeInfo;
-use softfloat::F64;
 use sp_runtime::{
 	infra::token::*,
 	traits::{AtLeast32BitUnsigned, CheckedAdd, CheckedSub, Saturating, StaticLookup, Zero},
@@ -244,7 +243,7 @@ pub mod pallet {
 			+ TypeInfo;
 
 		/// The units in which we record weight of System Token
-		type SystemTokenWeight: Balance + From<F64> + TryInto<i128>;
+		type SystemTokenWeight: Balance + TryInto<i128>;
 
 		/// Max number of items to destroy per `destroy_accounts` and `destroy_approvals` call.
 		///
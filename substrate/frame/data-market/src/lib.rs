@@ -9,11 +9,26 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod runtime_api;
+
 mod types;
 pub use types::*;
 
+mod privacy;
+pub use privacy::*;
+
+pub mod weights;
+pub use weights::*;
+
+use did_core::{
+	common::Policy,
+	did::{self, Did, DidSignature},
+	revoke::{self, RegistryId, RevokeId},
+	trusted_entity::{self, AuthorizerId, TrustedEntityId},
+};
 use frame_support::{
 	pallet_prelude::*,
+	storage::with_storage_layer,
 	traits::{
 		fungibles::{Inspect, Mutate},
 		tokens::Preservation,
@@ -23,19 +38,25 @@ use frame_support::{
 };
 use frame_system::{pallet_prelude::*, Config as SystemConfig};
 pub use pallet::*;
-use sp_runtime::{traits::AccountIdConversion, BoundedBTreeMap};
-use sp_std::{prelude::*, vec, vec::Vec};
+use sp_runtime::{
+	traits::{AccountIdConversion, SaturatedConversion, Saturating, TrailingZeroInput, Zero},
+	BoundedBTreeMap, Perbill,
+};
+use sp_std::{collections::btree_map::BTreeMap, prelude::*, vec, vec::Vec};
 
-#[frame_support::pallet(dev_mode)]
+#[frame_support::pallet]
 pub mod pallet {
 
 	use super::*;
 
 	#[pallet::pallet]
+	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
 	#[pallet::config]
-	pub trait Config: frame_system::Config {
+	pub trait Config:
+		frame_system::Config + did::Config + revoke::Config + trusted_entity::Config
+	{
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
 		/// Origin for admin-level operations.
@@ -51,6 +72,36 @@ pub mod pallet {
 		// The maximum quantity of contracts that can be joined
 		#[pallet::constant]
 		type MaxContracts: Get<u32>;
+
+		// The maximum quantity of data trades that can be executed in a batch
+		#[pallet::constant]
+		type MaxTradesPerBatch: Get<u32>;
+
+		// The maximum quantity of subscription contracts that can be renewed in a block
+		#[pallet::constant]
+		type MaxRenewalsPerBlock: Get<u32>;
+
+		/// Verifier of the proofs of payout claims of contracts in privacy mode.
+		type PayoutClaimVerifier: VerifyPayoutClaim;
+
+		// The authorizer whose issuers are the accredited agencies
+		#[pallet::constant]
+		type AgencyAuthorizer: Get<AuthorizerId>;
+
+		// The bond held from an agency while it is registered
+		#[pallet::constant]
+		type AgencyBond: Get<AssetBalanceOf<Self>>;
+
+		// The System Token of the agency bond
+		#[pallet::constant]
+		type AgencyBondSystemTokenId: Get<u32>;
+
+		// The part of the agency bond slashed by a dispute the agency loses
+		#[pallet::constant]
+		type DisputeSlash: Get<Perbill>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
 	}
 
 	// The Next value of contract id
@@ -80,6 +131,77 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	// The quantity of data traded with each Data Purchase Contract
+	#[pallet::storage]
+	#[pallet::getter(fn get_trade_count_for_contract)]
+	pub(super) type TradeCountForContract<T: Config> =
+		StorageMap<_, Twox64Concat, ContractId, Quantity, ValueQuery>;
+
+	// The quantity of data traded by each data owner with each Data Purchase Contract. Records are
+	// kept after the contract is terminated.
+	#[pallet::storage]
+	#[pallet::getter(fn get_data_trade_records)]
+	pub(super) type DataTradeRecords<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		ContractId,
+		Twox64Concat,
+		T::AccountId,
+		Quantity,
+		OptionQuery,
+	>;
+
+	// The Data Delegate Contracts consented by each data subject, by the revocation of the consent.
+	// Kept after the contract is terminated so that a consent cannot be used again.
+	#[pallet::storage]
+	#[pallet::getter(fn get_data_consents)]
+	pub(super) type DataConsents<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		Did,
+		Twox64Concat,
+		ConsentRevocation,
+		ContractId,
+		OptionQuery,
+	>;
+
+	// The subscription contracts to be renewed at each block
+	#[pallet::storage]
+	#[pallet::getter(fn get_subscription_renewals)]
+	pub(super) type SubscriptionRenewals<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<ContractId, T::MaxRenewalsPerBlock>,
+		ValueQuery,
+	>;
+
+	// The payout pools of the Data Purchase Contracts in privacy mode. Pools are kept after the
+	// contract is terminated so that the notes can still be claimed.
+	#[pallet::storage]
+	#[pallet::getter(fn get_private_payout_pools)]
+	pub(super) type PrivatePayoutPools<T: Config> =
+		StorageMap<_, Twox64Concat, ContractId, PrivatePayoutPool<AssetBalanceOf<T>>, OptionQuery>;
+
+	// The commitments of the data owners who sold data with each contract in privacy mode
+	#[pallet::storage]
+	#[pallet::getter(fn get_payout_commitments)]
+	pub(super) type PayoutCommitments<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		ContractId,
+		Twox64Concat,
+		OwnerCommitment,
+		(),
+		OptionQuery,
+	>;
+
+	// The nullifiers of the notes claimed from each contract in privacy mode
+	#[pallet::storage]
+	#[pallet::getter(fn get_payout_nullifiers)]
+	pub(super) type PayoutNullifiers<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, ContractId, Twox64Concat, Nullifier, (), OptionQuery>;
+
 	// The Contract List
 	#[pallet::storage]
 	#[pallet::getter(fn get_contract_list)]
@@ -98,6 +220,24 @@ pub mod pallet {
 	#[pallet::getter(fn get_agencies)]
 	pub(super) type Agencies<T: Config> = StorageValue<_, Vec<T::AccountId>, ValueQuery>;
 
+	// The bonds of the registered agencies
+	#[pallet::storage]
+	#[pallet::getter(fn get_agency_bonds)]
+	pub(super) type AgencyBonds<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, AgencyBond<AssetBalanceOf<T>>, OptionQuery>;
+
+	// The reputation of each agency, which is kept after the agency is deregistered
+	#[pallet::storage]
+	#[pallet::getter(fn get_agency_reputations)]
+	pub(super) type AgencyReputations<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, AgencyReputation, ValueQuery>;
+
+	// The open disputes of the data buyers against the agencies of their Data Purchase Contracts
+	#[pallet::storage]
+	#[pallet::getter(fn get_disputes)]
+	pub(super) type Disputes<T: Config> =
+		StorageMap<_, Twox64Concat, ContractId, Dispute<T::AccountId>, OptionQuery>;
+
 	// The Config of the platform
 	#[pallet::storage]
 	#[pallet::getter(fn get_platform_config)]
@@ -164,6 +304,64 @@ pub mod pallet {
 			contract_id: ContractId,
 			amount: AssetBalanceOf<T>,
 		},
+		// Deposit of Subscription Contract Falls Below the Threshold
+		LowDeposit {
+			contract_id: ContractId,
+			deposit: AssetBalanceOf<T>,
+		},
+		// Renew Subscription Contract
+		RenewSubscription {
+			contract_id: ContractId,
+			charged: AssetBalanceOf<T>,
+			expired_at: BlockNumberFor<T>,
+		},
+		// Failed to Renew Subscription Contract
+		RenewSubscriptionFailed {
+			contract_id: ContractId,
+		},
+		// Refund Subscription Contract on Termination
+		RefundSubscription {
+			contract_id: ContractId,
+			refunded: AssetBalanceOf<T>,
+			forfeited: AssetBalanceOf<T>,
+		},
+		// Set Auto Renewal of Subscription Contract
+		SetAutoRenew {
+			contract_id: ContractId,
+			auto_renew: bool,
+		},
+		// Private Data Trades Executed
+		PrivateDataTradeExecuted {
+			contract_id: ContractId,
+			quantity: Quantity,
+		},
+		// Claim Private Payout
+		ClaimPrivatePayout {
+			contract_id: ContractId,
+			nullifier: Nullifier,
+		},
+		// Open Dispute against Agency
+		OpenDispute {
+			contract_id: ContractId,
+			agency: T::AccountId,
+		},
+		// Resolve Dispute against Agency
+		ResolveDispute {
+			contract_id: ContractId,
+			agency: T::AccountId,
+			agency_at_fault: bool,
+			slashed: AssetBalanceOf<T>,
+		},
+		// Top up Bond of Agency after it is slashed
+		TopUpAgencyBond {
+			agency: T::AccountId,
+			amount: AssetBalanceOf<T>,
+		},
+		// Update Reputation of Agency
+		UpdateReputation {
+			agency: T::AccountId,
+			score: u32,
+		},
 	}
 
 	#[pallet::error]
@@ -206,6 +404,69 @@ pub mod pallet {
 		AlreadyPurchased,
 		/// The Signer has already reached  contract limit
 		MaxContractsReached,
+		/// Deposit of the contract is not enough to pay for the data trades
+		InsufficientDeposit,
+		/// Consent of the data subject is not signed by a key of its DID
+		InvalidConsentSignature,
+		/// The data subject cannot revoke its consent in the revocation registry
+		ConsentNotRevocable,
+		/// Consent of the data subject has been revoked
+		ConsentRevoked,
+		/// Consent of the data subject has already been given to a contract
+		ConsentAlreadyUsed,
+		/// Consent of the data subject can no longer be used to make a contract
+		ConsentExpired,
+		/// The data owner has not consented to delegate data to the agency
+		NoActiveConsent,
+		/// Budget of the subscription period is exceeded
+		BudgetExceeded,
+		/// Too many subscriptions are renewed around the end of the period
+		TooManyRenewals,
+		/// The contract is not a subscription contract
+		NotSubscription,
+		/// Parameters of the subscription are invalid
+		InvalidSubscription,
+		/// No verifier of payout claims is configured for privacy mode
+		PrivacyModeDisabled,
+		/// The contract is in privacy mode
+		PrivateContract,
+		/// The contract is not in privacy mode
+		NotPrivateContract,
+		/// Proof of the payout claim is invalid
+		InvalidPayoutProof,
+		/// The payout has already been claimed
+		PayoutAlreadyClaimed,
+		/// All the payouts of the contract have been claimed
+		NoUnclaimedPayout,
+		/// The agency is not accredited by an issuer of the agency authorizer
+		AgencyNotAccredited,
+		/// Reputation of the agency is below the minimum of the contract
+		ReputationTooLow,
+		/// A dispute is already open for the contract
+		DisputeAlreadyOpen,
+		/// No dispute is open for the contract
+		DisputeNotExist,
+		/// The agency has open disputes
+		AgencyInDispute,
+		/// The bond of the agency has been slashed and is not topped up to `AgencyBond`
+		BondNotToppedUp,
+		/// The bond of the agency is not short of `AgencyBond`
+		NoBondToTopUp,
+		/// The agency has signed purchase contracts which are not terminated
+		AgencyHasLiveContracts,
+		/// The agency holds no bond to be slashed
+		AgencyNotBonded,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T>
+	where
+		AssetBalanceOf<T>: From<u128> + Into<u128>,
+		AssetIdOf<T>: From<u32>,
+	{
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+			Self::handle_subscription_renewals(n)
+		}
 	}
 
 	#[pallet::genesis_config]
@@ -235,8 +496,13 @@ pub mod pallet {
 		///
 		/// The dispatch origin for this call must be _Signed_.
 		///
-		/// - `detail`: The detail of the contract.
+		/// The contract is bound to the DID of the data subject, which signs its consent to the
+		/// delegation with one of its keys. The consent is withdrawn once its revocation is
+		/// revoked in the registry.
+		///
+		/// - `params`: The detail of the contract.
 		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::make_delegate_contract())]
 		pub fn make_delegate_contract(
 			origin: OriginFor<T>,
 			params: DataDelegateContractParams<T::AccountId, BlockNumberFor<T>>,
@@ -252,6 +518,7 @@ pub mod pallet {
 		///
 		/// - `contract_id`: The id of the contract.
 		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::sign_delegate_contract())]
 		pub fn sign_delegate_contract(
 			origin: OriginFor<T>,
 			contract_id: ContractId,
@@ -267,6 +534,7 @@ pub mod pallet {
 		///
 		/// - `detail`: The detail of the contract.
 		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::make_purchase_contract())]
 		pub fn make_purchase_contract(
 			origin: OriginFor<T>,
 			params: DataPurchaseContractParams<T::AccountId, BlockNumberFor<T>, AssetBalanceOf<T>>,
@@ -284,6 +552,7 @@ pub mod pallet {
 		/// - `contract_id`: The id of the contract.
 		/// - `data_verifier`: The verifier of the contract.
 		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::sign_purchase_contract())]
 		pub fn sign_purchase_contract(
 			origin: OriginFor<T>,
 			contract_id: ContractId,
@@ -300,6 +569,7 @@ pub mod pallet {
 		///
 		/// - `contract_id`: The id of the contract.
 		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::terminate_delegate_contract())]
 		pub fn terminate_delegate_contract(
 			origin: OriginFor<T>,
 			contract_id: ContractId,
@@ -315,6 +585,7 @@ pub mod pallet {
 		///
 		/// - `contract_id`: The id of the contract.
 		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::terminate_purchase_contract())]
 		pub fn terminate_purchase_contract(
 			origin: OriginFor<T>,
 			contract_id: ContractId,
@@ -337,6 +608,7 @@ pub mod pallet {
 		/// - `price_per_data`: The price per data.
 		/// - `data_verification_proof`: The verification proof of the data.
 		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::execute_data_trades(1))]
 		pub fn execute_data_trade(
 			origin: OriginFor<T>,
 			contract_id: ContractId,
@@ -349,9 +621,7 @@ pub mod pallet {
 			data_verification_proof: AnyText,
 		) -> DispatchResult {
 			let maybe_verifier = ensure_signed(origin)?;
-			Self::do_execute_data_trade(
-				maybe_verifier,
-				contract_id,
+			let trade = DataTradeParams {
 				data_owner,
 				data_issuer,
 				data_owner_fee_ratio,
@@ -359,16 +629,22 @@ pub mod pallet {
 				agency,
 				agency_fee_ratio,
 				data_verification_proof,
-			)?;
+			};
+			Self::do_execute_data_trades(maybe_verifier, contract_id, vec![trade])?;
 			Ok(())
 		}
 
 		/// Register an agency
 		///
 		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// The agency must be accredited by an issuer of the agency authorizer, and `AgencyBond`
+		/// is held from it until it is deregistered.
 		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::register_agency())]
 		pub fn register_agency(origin: OriginFor<T>) -> DispatchResult {
 			let agency = ensure_signed(origin)?;
+			ensure!(Self::is_accredited(&agency), Error::<T>::AgencyNotAccredited);
 			Agencies::<T>::try_mutate(|list| -> DispatchResult {
 				if list.contains(&agency) {
 					return Err(Error::<T>::InvalidAgency.into())
@@ -376,6 +652,7 @@ pub mod pallet {
 				list.push(agency.clone());
 				Ok(())
 			})?;
+			Self::bond_agency(&agency)?;
 			Self::deposit_event(Event::<T>::RegisterAgency { agency });
 			Ok(())
 		}
@@ -383,9 +660,14 @@ pub mod pallet {
 		/// Deregister an agency
 		///
 		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// The bond of the agency is released once it has no open dispute and no purchase
+		/// contract it has signed is left to be terminated.
 		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::deregister_agency(T::MaxContracts::get()))]
 		pub fn deregister_agency(origin: OriginFor<T>) -> DispatchResult {
 			let agency = ensure_signed(origin)?;
+			Self::ensure_agency_can_deregister(&agency)?;
 			Agencies::<T>::try_mutate(|list| -> DispatchResult {
 				if list.contains(&agency) {
 					list.retain(|x| x != &agency);
@@ -394,6 +676,7 @@ pub mod pallet {
 				}
 				Ok(())
 			})?;
+			Self::unbond_agency(&agency)?;
 			Self::deposit_event(Event::<T>::DeregisterAgency { agency });
 			Ok(())
 		}
@@ -405,6 +688,7 @@ pub mod pallet {
 		/// - `agency`: The agency of the contract.
 		/// - `params`: The detail of the contract.
 		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::make_delegate_contract())]
 		pub fn make_delegate_contract_by_admin(
 			origin: OriginFor<T>,
 			agency: T::AccountId,
@@ -422,6 +706,7 @@ pub mod pallet {
 		/// - `contract_id`: The id of the contract.
 		/// - `data_owner`: The owner of the data.
 		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::sign_delegate_contract())]
 		pub fn sign_delegate_contract_by_admin(
 			origin: OriginFor<T>,
 			data_owner: T::AccountId,
@@ -440,6 +725,7 @@ pub mod pallet {
 		/// - `params`: The detail of the contract.
 		/// - `is_agency_exist`: The flag of the agency.
 		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::make_purchase_contract())]
 		pub fn make_purchase_contract_by_admin(
 			origin: OriginFor<T>,
 			data_buyer: T::AccountId,
@@ -459,6 +745,7 @@ pub mod pallet {
 		/// - `contract_id`: The id of the contract.
 		/// - `data_verifier`: The verifier of the contract.
 		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::sign_purchase_contract())]
 		pub fn sign_purchase_contract_by_admin(
 			origin: OriginFor<T>,
 			agency: T::AccountId,
@@ -477,6 +764,7 @@ pub mod pallet {
 		/// - `signer`: The signer of the contract.
 		/// - `contract_id`: The id of the contract.
 		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::terminate_delegate_contract())]
 		pub fn terminate_delegate_contract_by_admin(
 			origin: OriginFor<T>,
 			signer: T::AccountId,
@@ -494,6 +782,7 @@ pub mod pallet {
 		/// - `signer`: The signer of the contract.
 		/// - `contract_id`: The id of the contract.
 		#[pallet::call_index(14)]
+		#[pallet::weight(T::WeightInfo::terminate_purchase_contract())]
 		pub fn terminate_purchase_contract_by_admin(
 			origin: OriginFor<T>,
 			signer: T::AccountId,
@@ -508,8 +797,12 @@ pub mod pallet {
 		///
 		/// The dispatch origin for this call must be _Admin_.
 		///
+		/// The agency is neither checked for accreditation nor bonded. It cannot make or sign
+		/// contracts until it is accredited and has bonded with `top_up_agency_bond`.
+		///
 		/// - `agency`: The agency of the contract.
 		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::register_agency())]
 		pub fn register_agency_by_admin(
 			origin: OriginFor<T>,
 			agency: T::AccountId,
@@ -532,11 +825,13 @@ pub mod pallet {
 		///
 		/// - `agency`: The agency of the contract.
 		#[pallet::call_index(16)]
+		#[pallet::weight(T::WeightInfo::deregister_agency(T::MaxContracts::get()))]
 		pub fn deregister_agency_by_admin(
 			origin: OriginFor<T>,
 			agency: T::AccountId,
 		) -> DispatchResult {
 			T::AdminOrigin::ensure_origin(origin)?;
+			Self::ensure_agency_can_deregister(&agency)?;
 			Agencies::<T>::try_mutate(|list| -> DispatchResult {
 				if list.contains(&agency) {
 					list.retain(|x| x != &agency);
@@ -545,6 +840,7 @@ pub mod pallet {
 				}
 				Ok(())
 			})?;
+			Self::unbond_agency(&agency)?;
 			Self::deposit_event(Event::<T>::DeregisterAgency { agency });
 			Ok(())
 		}
@@ -555,6 +851,7 @@ pub mod pallet {
 		///
 		/// - `config`: The configuration of the platform.
 		#[pallet::call_index(17)]
+		#[pallet::weight(T::WeightInfo::set_platform_config())]
 		pub fn set_platform_config(
 			origin: OriginFor<T>,
 			config: MarketConfiguration,
@@ -570,7 +867,8 @@ pub mod pallet {
 		///
 		/// - `contract_id`: The id of the contract.
 		/// - `amount`: The amount of the deposit.
-		#[pallet::weight(18)]
+		#[pallet::call_index(18)]
+		#[pallet::weight(T::WeightInfo::deposit_balance())]
 		pub fn deposit_balance(
 			origin: OriginFor<T>,
 			contract_id: ContractId,
@@ -588,7 +886,8 @@ pub mod pallet {
 		/// - `data_buyer`: The buyer of the data.
 		/// - `contract_id`: The id of the contract.
 		/// - `amount`: The amount of the deposit.
-		#[pallet::weight(19)]
+		#[pallet::call_index(19)]
+		#[pallet::weight(T::WeightInfo::deposit_balance())]
 		pub fn deposit_balance_by_admin(
 			origin: OriginFor<T>,
 			data_buyer: T::AccountId,
@@ -599,6 +898,293 @@ pub mod pallet {
 			Self::do_deposit_balance(data_buyer, contract_id, amount)?;
 			Ok(())
 		}
+
+		/// Execute data trades in a batch
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// The fees of all the trades are settled from the escrow at once.
+		///
+		/// - `contract_id`: The id of the contract.
+		/// - `trades`: The data trades to execute.
+		#[pallet::call_index(20)]
+		#[pallet::weight(T::WeightInfo::execute_data_trades(trades.len() as u32))]
+		pub fn execute_data_trades(
+			origin: OriginFor<T>,
+			contract_id: ContractId,
+			trades: BoundedVec<DataTradeParams<T::AccountId>, T::MaxTradesPerBatch>,
+		) -> DispatchResult {
+			let maybe_verifier = ensure_signed(origin)?;
+			Self::do_execute_data_trades(maybe_verifier, contract_id, trades.into_inner())?;
+			Ok(())
+		}
+
+		/// Set whether a subscription contract is renewed at the end of a period
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `contract_id`: The id of the contract.
+		/// - `auto_renew`: Whether to renew the contract.
+		#[pallet::call_index(21)]
+		#[pallet::weight(T::WeightInfo::set_auto_renew())]
+		pub fn set_auto_renew(
+			origin: OriginFor<T>,
+			contract_id: ContractId,
+			auto_renew: bool,
+		) -> DispatchResult {
+			let maybe_buyer = ensure_signed(origin)?;
+			Self::do_set_auto_renew(maybe_buyer, contract_id, auto_renew)?;
+			Ok(())
+		}
+
+		/// Execute data trades in a batch with a contract in privacy mode
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// The data owners are represented by their commitments and their fees are kept in the
+		/// escrow until they are claimed with `claim_private_payout`.
+		///
+		/// - `contract_id`: The id of the contract.
+		/// - `trades`: The data trades to execute.
+		#[pallet::call_index(22)]
+		#[pallet::weight(T::WeightInfo::execute_private_data_trades(trades.len() as u32))]
+		pub fn execute_private_data_trades(
+			origin: OriginFor<T>,
+			contract_id: ContractId,
+			trades: BoundedVec<PrivateDataTradeParams<T::AccountId>, T::MaxTradesPerBatch>,
+		) -> DispatchResult {
+			let maybe_verifier = ensure_signed(origin)?;
+			Self::do_execute_private_data_trades(maybe_verifier, contract_id, trades.into_inner())?;
+			Ok(())
+		}
+
+		/// Claim the fee of a data owner of a contract in privacy mode
+		///
+		/// The dispatch origin for this call must be _Signed_. Any account can submit the claim
+		/// so that the beneficiary is not linked to the data owner.
+		///
+		/// - `contract_id`: The id of the contract.
+		/// - `nullifier`: The nullifier of the commitment of the data owner.
+		/// - `beneficiary`: The account to be paid.
+		/// - `proof`: The proof of membership in the commitments of the contract.
+		#[pallet::call_index(23)]
+		#[pallet::weight(T::WeightInfo::claim_private_payout(
+			T::MaxPurchaseQuantity::get().saturated_into()
+		))]
+		pub fn claim_private_payout(
+			origin: OriginFor<T>,
+			contract_id: ContractId,
+			nullifier: Nullifier,
+			beneficiary: T::AccountId,
+			proof: PayoutClaimProof,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			Self::do_claim_private_payout(contract_id, nullifier, beneficiary, proof)?;
+			Ok(())
+		}
+
+		/// Open a dispute against the agency of a purchase contract
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `contract_id`: The id of the contract.
+		#[pallet::call_index(24)]
+		#[pallet::weight(T::WeightInfo::open_dispute())]
+		pub fn open_dispute(origin: OriginFor<T>, contract_id: ContractId) -> DispatchResult {
+			let maybe_buyer = ensure_signed(origin)?;
+			Self::do_open_dispute(maybe_buyer, contract_id)?;
+			Ok(())
+		}
+
+		/// Resolve a dispute against the agency of a purchase contract
+		///
+		/// The dispatch origin for this call must be _Admin_.
+		///
+		/// If the agency is at fault, `DisputeSlash` of its bond is slashed to the data buyer.
+		///
+		/// - `contract_id`: The id of the contract.
+		/// - `agency_at_fault`: Whether the dispute is resolved against the agency.
+		#[pallet::call_index(25)]
+		#[pallet::weight(T::WeightInfo::resolve_dispute())]
+		pub fn resolve_dispute(
+			origin: OriginFor<T>,
+			contract_id: ContractId,
+			agency_at_fault: bool,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			Self::do_resolve_dispute(contract_id, agency_at_fault)?;
+			Ok(())
+		}
+
+		/// Top up the bond of an agency to `AgencyBond`
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// An agency whose bond has been slashed cannot make or sign contracts until it tops up
+		/// the bond. An agency registered by the admin bonds for the first time with this call.
+		#[pallet::call_index(26)]
+		#[pallet::weight(T::WeightInfo::top_up_agency_bond())]
+		pub fn top_up_agency_bond(origin: OriginFor<T>) -> DispatchResult {
+			let agency = ensure_signed(origin)?;
+			Self::do_top_up_agency_bond(agency)?;
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Trade records of the contract, at most `limit` of them following the record of
+	/// `start_after` in storage order. Records are returned from the first one if `start_after` is
+	/// `None`.
+	pub fn data_trade_records(
+		contract_id: ContractId,
+		start_after: Option<T::AccountId>,
+		limit: u32,
+	) -> Vec<(T::AccountId, Quantity)> {
+		let records = match start_after {
+			Some(data_owner) => DataTradeRecords::<T>::iter_prefix_from(
+				contract_id,
+				DataTradeRecords::<T>::hashed_key_for(contract_id, data_owner),
+			),
+			None => DataTradeRecords::<T>::iter_prefix(contract_id),
+		};
+		records.take(limit as usize).collect()
+	}
+
+	/// Trusted entity id of the agency, which is its encoded account id padded with zeros.
+	pub fn agency_entity_id(agency: &T::AccountId) -> TrustedEntityId {
+		TrustedEntityId::decode(&mut TrailingZeroInput::new(&agency.encode()))
+			.expect("infinite length input; qed")
+	}
+
+	/// Whether the agency is an issuer of the agency authorizer.
+	pub fn is_accredited(agency: &T::AccountId) -> bool {
+		trusted_entity::Issuers::<T>::contains_key(
+			T::AgencyAuthorizer::get(),
+			Self::agency_entity_id(agency),
+		)
+	}
+
+	/// Ensure that the reputation score of the agency is at least `min_reputation` if any.
+	fn ensure_agency_reputation(
+		agency: &T::AccountId,
+		min_reputation: Option<u32>,
+	) -> DispatchResult {
+		if let Some(min_reputation) = min_reputation {
+			ensure!(
+				AgencyReputations::<T>::get(agency).score >= min_reputation,
+				Error::<T>::ReputationTooLow
+			);
+		}
+		Ok(())
+	}
+
+	/// Add a record to the reputation of the agency and update its score.
+	fn update_reputation(agency: &T::AccountId, record: impl FnOnce(&mut AgencyReputation)) {
+		let score = AgencyReputations::<T>::mutate(agency, |reputation| {
+			record(reputation);
+			reputation.update_score();
+			reputation.score
+		});
+		Self::deposit_event(Event::<T>::UpdateReputation { agency: agency.clone(), score });
+	}
+
+	/// Commitments of the data owners who sold data with the contract in privacy mode.
+	pub fn payout_commitments(contract_id: ContractId) -> Vec<OwnerCommitment> {
+		PayoutCommitments::<T>::iter_key_prefix(contract_id).collect()
+	}
+
+	/// Data Delegate Contracts with their agencies which the data subject has consented to and
+	/// are still in effect.
+	pub fn active_consents(data_subject: Did) -> Vec<(ContractId, T::AccountId)> {
+		DataConsents::<T>::iter_prefix(data_subject)
+			.filter_map(|(_, contract_id)| {
+				DataDelegateContracts::<T>::get(contract_id)
+					.filter(Self::is_delegation_active)
+					.map(|detail| (contract_id, detail.agency))
+			})
+			.collect()
+	}
+
+	/// Message to be signed by the data subject to consent to the Data Delegate Contract made by
+	/// `agency` with `params`.
+	pub fn data_consent_message(
+		agency: &T::AccountId,
+		params: &DataDelegateContractParams<T::AccountId, BlockNumberFor<T>>,
+	) -> Vec<u8> {
+		DataConsentPayload {
+			agency,
+			data_owner: &params.data_owner,
+			data_owner_minimum_fee_ratio: params.data_owner_minimum_fee_ratio,
+			deligated_data: &params.deligated_data,
+			duration: &params.duration,
+			consent_revocation: &params.consent_revocation,
+			consent_expires_at: &params.consent_expires_at,
+		}
+		.encode()
+	}
+
+	/// Whether the delegate contract is signed by all the signers, in its period and consented by
+	/// the data subject without being revoked.
+	fn is_delegation_active(
+		detail: &DataDelegateContractDetail<T::AccountId, BlockNumberFor<T>>,
+	) -> bool {
+		let ConsentRevocation { registry_id, revoke_id } = detail.consent_revocation;
+		let current_block_number = <frame_system::Pallet<T>>::block_number();
+
+		detail.signed_status.iter().all(|(_, signed)| *signed == SignStatus::Signed) &&
+			detail.effective_at <= current_block_number &&
+			detail.expired_at > current_block_number &&
+			!revoke::Revocations::<T>::contains_key(registry_id, revoke_id)
+	}
+
+	/// Whether the data owner has delegated its data to the agency with a consent in effect.
+	fn has_active_consent(data_owner: &T::AccountId, agency: &T::AccountId) -> bool {
+		ContractList::<T>::get(data_owner, ContractType::Delegate)
+			.iter()
+			.any(|contract_id| {
+				DataDelegateContracts::<T>::get(contract_id).map_or(false, |detail| {
+					&detail.agency == agency && Self::is_delegation_active(&detail)
+				})
+			})
+	}
+
+	/// Verify the consent of the data subject to the delegate contract and return its DID.
+	///
+	/// The signing key must be able to authenticate or control the DID, and the data subject must
+	/// be a controller of the revocation registry so that it can withdraw the consent.
+	fn verify_data_consent(
+		agency: &T::AccountId,
+		params: &DataDelegateContractParams<T::AccountId, BlockNumberFor<T>>,
+	) -> Result<Did, DispatchError> {
+		let DidSignature { did: data_subject, key_id, .. } = params.consent_signature;
+		let public_key = did::Pallet::<T>::auth_or_control_key(&data_subject, key_id)?;
+		let message = Self::data_consent_message(agency, params);
+		let is_valid = params
+			.consent_signature
+			.verify(&message, &public_key)
+			.map_err(did::Error::<T>::from)?;
+		ensure!(is_valid, Error::<T>::InvalidConsentSignature);
+		ensure!(
+			params.consent_expires_at > frame_system::Pallet::<T>::block_number(),
+			Error::<T>::ConsentExpired
+		);
+
+		let ConsentRevocation { registry_id, revoke_id } = params.consent_revocation;
+		let Policy::OneOf(controllers) = revoke::Registries::<T>::get(registry_id)
+			.ok_or(Error::<T>::ConsentNotRevocable)?
+			.policy;
+		ensure!(controllers.contains(&data_subject), Error::<T>::ConsentNotRevocable);
+		ensure!(
+			!revoke::Revocations::<T>::contains_key(registry_id, revoke_id),
+			Error::<T>::ConsentRevoked
+		);
+		ensure!(
+			!DataConsents::<T>::contains_key(data_subject, params.consent_revocation),
+			Error::<T>::ConsentAlreadyUsed
+		);
+
+		Ok(data_subject)
 	}
 }
 
@@ -648,25 +1234,48 @@ where
 		DataPurchaseContracts::<T>::insert(contract_id, detail);
 	}
 
-	pub fn settle_data_trade(
+	/// Add the fees of a data trade to `payouts`, which are settled from the escrow at once by
+	/// `settle_data_trades`.
+	fn add_data_trade_payouts(
+		payouts: &mut BTreeMap<T::AccountId, u128>,
 		data_owner: T::AccountId,
 		data_owner_fee: u128,
-		data_issuer: Vec<(T::AccountId, IssuerWeight)>,
+		data_issuer: &[(T::AccountId, IssuerWeight)],
 		data_issuer_fee: u128,
 		platform_fee: u128,
 		maybe_agency: Option<T::AccountId>,
 		agency_fee: u128,
-		system_token_asset_id: u32,
 	) -> DispatchResult {
-		let platform_account = Self::get_platform_account();
-		let escrow_account = Self::get_escrow_account();
+		Self::add_data_issuer_payouts(payouts, data_issuer, data_issuer_fee, platform_fee)?;
 
-		Self::transfer_escrow(
-			escrow_account.clone(),
-			data_owner,
-			system_token_asset_id,
-			data_owner_fee.into(),
-		)?;
+		let mut pay = |to: T::AccountId, amount: u128| {
+			let payout = payouts.entry(to).or_default();
+			*payout = payout.saturating_add(amount);
+		};
+
+		pay(data_owner, data_owner_fee);
+
+		if let Some(agency) = maybe_agency {
+			if agency_fee > 0 {
+				pay(agency, agency_fee);
+			}
+		}
+
+		Ok(())
+	}
+
+	/// Add the fee of the data issuers, distributed by their weights, and the fee of the platform
+	/// to `payouts`.
+	fn add_data_issuer_payouts(
+		payouts: &mut BTreeMap<T::AccountId, u128>,
+		data_issuer: &[(T::AccountId, IssuerWeight)],
+		data_issuer_fee: u128,
+		platform_fee: u128,
+	) -> DispatchResult {
+		let mut pay = |to: T::AccountId, amount: u128| {
+			let payout = payouts.entry(to).or_default();
+			*payout = payout.saturating_add(amount);
+		};
 
 		let total_weight: u32 = data_issuer.iter().map(|(_, weight)| weight).sum();
 		ensure!(total_weight > 0u32, Error::<T>::IssuerWeightInvalid);
@@ -675,28 +1284,28 @@ where
 			let distributed_fee = data_issuer_fee
 				.saturating_mul(*weight as u128)
 				.saturating_div(total_weight as u128);
-			Self::transfer_escrow(
-				escrow_account.clone(),
-				issuer.clone(),
-				system_token_asset_id,
-				distributed_fee.into(),
-			)?;
+			pay(issuer.clone(), distributed_fee);
 		}
 
-		Self::transfer_escrow(
-			escrow_account.clone(),
-			platform_account,
-			system_token_asset_id,
-			platform_fee.into(),
-		)?;
+		pay(Self::get_platform_account(), platform_fee);
 
-		if let Some(agency) = maybe_agency {
-			if agency_fee > 0 {
+		Ok(())
+	}
+
+	/// Transfer the accumulated `payouts` from the escrow, once for each recipient.
+	pub fn settle_data_trades(
+		payouts: BTreeMap<T::AccountId, u128>,
+		system_token_asset_id: u32,
+	) -> DispatchResult {
+		let escrow_account = Self::get_escrow_account();
+
+		for (to, amount) in payouts.into_iter() {
+			if amount > 0 {
 				Self::transfer_escrow(
-					escrow_account,
-					agency,
+					escrow_account.clone(),
+					to,
 					system_token_asset_id,
-					agency_fee.into(),
+					amount.into(),
 				)?;
 			}
 		}
@@ -754,13 +1363,13 @@ where
 		system_token_asset_id: u32,
 		amount: AssetBalanceOf<T>,
 	) -> DispatchResult {
-		let _ = T::Assets::transfer(
+		T::Assets::transfer(
 			system_token_asset_id.into(),
 			&from,
 			&to,
 			amount,
 			Preservation::Protect,
-		);
+		)?;
 
 		Ok(())
 	}
@@ -769,15 +1378,20 @@ where
 		agency: T::AccountId,
 		params: DataDelegateContractParams<T::AccountId, BlockNumberFor<T>>,
 	) -> DispatchResult {
+		Self::ensure_agency_in_good_standing(&agency)?;
+
+		let data_subject = Self::verify_data_consent(&agency, &params)?;
+
 		let DataDelegateContractParams {
 			data_owner,
 			data_owner_minimum_fee_ratio,
 			deligated_data,
 			duration,
+			consent_revocation,
+			consent_expires_at: _,
+			consent_signature: _,
 		} = params;
 
-		ensure!(Self::check_agency_exist(&agency), Error::<T>::InvalidAgency);
-
 		let current_block_number = frame_system::Pallet::<T>::block_number();
 
 		let mut signed_status: ContractSigner<T::AccountId> = BoundedBTreeMap::new();
@@ -795,6 +1409,8 @@ where
 				effective_at: current_block_number,
 				expired_at: current_block_number + duration,
 				signed_status,
+				data_subject,
+				consent_revocation,
 			};
 
 		let contract_id_base =
@@ -806,6 +1422,7 @@ where
 		let contract_id: ContractId = contract_id_base << 1 | 0;
 
 		DataDelegateContracts::<T>::insert(contract_id, detail);
+		DataConsents::<T>::insert(data_subject, consent_revocation, contract_id);
 
 		<ContractList<T>>::try_mutate(&data_owner, &ContractType::Delegate, |ref mut list| {
 			list.try_push(contract_id).map_err(|_| Error::<T>::MaxContractsReached)
@@ -862,9 +1479,46 @@ where
 			deposit,
 			duration,
 			price_per_data,
+			subscription,
+			privacy,
+			min_agency_reputation,
 		} = params.clone();
 
 		let current_block_number = frame_system::Pallet::<T>::block_number();
+		let expired_at = current_block_number + duration;
+
+		// The period of a subscription contract is its duration
+		let mut subscription = match subscription {
+			Some(SubscriptionParams { budget_per_period, low_deposit_threshold, auto_renew }) => {
+				ensure!(
+					!duration.is_zero() && budget_per_period > 0u128.into(),
+					Error::<T>::InvalidSubscription
+				);
+				ensure!(deposit >= budget_per_period, Error::<T>::InsufficientDeposit);
+				Some(SubscriptionDetail {
+					period: duration,
+					budget_per_period,
+					spent_in_period: 0u128.into(),
+					low_deposit_threshold,
+					auto_renew,
+					renew_at: expired_at,
+				})
+			},
+			None => None,
+		};
+
+		if let Some(PrivacyParams { data_owner_fee_ratio }) = privacy {
+			ensure!(T::PayoutClaimVerifier::is_enabled(), Error::<T>::PrivacyModeDisabled);
+			// Data owners are not identified in privacy mode, so their consent to an agency cannot
+			// be checked
+			ensure!(!is_agency_exist, Error::<T>::InvalidAgency);
+			let MarketConfiguration { total_fee_ratio, min_platform_fee_ratio } =
+				PlatformConfig::<T>::get();
+			ensure!(
+				data_owner_fee_ratio + min_platform_fee_ratio <= total_fee_ratio,
+				Error::<T>::InvalidFeeRatio
+			);
+		}
 
 		let contract_id_base =
 			NextContractId::<T>::try_mutate(|c| -> Result<ContractId, DispatchError> {
@@ -874,6 +1528,10 @@ where
 
 		let contract_id: ContractId = contract_id_base << 1 | 1;
 
+		if let Some(subscription) = subscription.as_mut() {
+			subscription.renew_at = Self::schedule_renewal(contract_id, expired_at)?;
+		}
+
 		let mut detail: DataPurchaseContractDetail<
 			T::AccountId,
 			BlockNumberFor<T>,
@@ -882,15 +1540,16 @@ where
 			data_buyer: data_buyer.clone(),
 			data_verifier: data_verifier.clone(),
 			effective_at: current_block_number,
-			expired_at: current_block_number + duration,
+			expired_at,
 			data_purchase_info,
 			system_token_id,
 			agency: None,
 			price_per_data,
 			deposit,
-			trade_count: 0,
 			signed_status: BoundedBTreeMap::new(),
-			data_trade_record: vec![], // empty
+			subscription: subscription.clone(),
+			privacy,
+			min_agency_reputation,
 		};
 
 		let mut signed_status: ContractSigner<T::AccountId> = BoundedBTreeMap::new();
@@ -900,6 +1559,7 @@ where
 		if is_agency_exist {
 			let agency = agency.ok_or(Error::<T>::InvalidAgency)?;
 			ensure!(data_verifier.is_none(), Error::<T>::InvalidVerifier);
+			Self::ensure_agency_reputation(&agency, min_agency_reputation)?;
 
 			if data_buyer != agency {
 				<ContractList<T>>::try_mutate(&agency, &ContractType::Purchase, |ref mut list| {
@@ -927,6 +1587,21 @@ where
 		let escrow_account = Self::get_escrow_account();
 		Self::transfer_escrow(data_buyer.clone(), escrow_account, system_token_id, deposit)?;
 
+		// The fee of every data owner is fixed when the contract is made
+		if let Some(PrivacyParams { data_owner_fee_ratio }) = privacy {
+			let (note_value, _, _, _) =
+				Self::calculate_data_fee(price_per_data.into(), data_owner_fee_ratio, 0, 0);
+			PrivatePayoutPools::<T>::insert(
+				contract_id,
+				PrivatePayoutPool {
+					system_token_id,
+					note_value: note_value.into(),
+					commitment_count: 0,
+					claimed_count: 0,
+				},
+			);
+		}
+
 		Self::deposit_event(Event::<T>::MakeDataPurchaseContract { contract_id, data_buyer });
 
 		Ok(())
@@ -941,6 +1616,8 @@ where
 			DataPurchaseContracts::<T>::get(contract_id).ok_or(Error::<T>::ContractNotExist)?;
 		let agency = detail.clone().agency.ok_or(Error::<T>::InvalidAgency)?;
 		ensure!(maybe_agency == agency, Error::<T>::InvalidAgency);
+		Self::ensure_agency_in_good_standing(&agency)?;
+		Self::ensure_agency_reputation(&agency, detail.min_agency_reputation)?;
 
 		if detail.agency.is_some() {
 			let mut is_signed = false;
@@ -1024,6 +1701,7 @@ where
 	) -> DispatchResult {
 		let mut detail =
 			DataPurchaseContracts::<T>::get(contract_id).ok_or(Error::<T>::ContractNotExist)?;
+		let active_until = Self::active_until(&detail);
 
 		let DataPurchaseContractDetail {
 			ref data_buyer,
@@ -1033,37 +1711,67 @@ where
 			agency: ref agency_in_detail,
 			price_per_data: _,
 			deposit,
-			trade_count: _,
 			signed_status,
-			data_trade_record: _,
 			effective_at: _,
 			expired_at,
+			ref subscription,
+			privacy: _,
+			min_agency_reputation: _,
 		} = detail;
 
 		let mut new_signed_status = signed_status;
 
-		if agency_in_detail.is_some() {
-			let mut is_signed = false;
-			new_signed_status.iter_mut().for_each(|(signer, status)| {
-				if signer == &maybe_signer {
-					*status = SignStatus::WantToTerminate;
-					is_signed = true;
-				}
-			});
-
-			ensure!(is_signed, Error::<T>::NotSigned);
-		}
+		let mut is_signed = false;
+		new_signed_status.iter_mut().for_each(|(signer, status)| {
+			if signer == &maybe_signer {
+				*status = SignStatus::WantToTerminate;
+				is_signed = true;
+			}
+		});
+		ensure!(is_signed, Error::<T>::NotSigned);
 
 		let current_block_number = <frame_system::Pallet<T>>::block_number();
 
 		if new_signed_status.iter().all(|(_, signed)| *signed != SignStatus::Signed) ||
-			current_block_number > expired_at
+			current_block_number > active_until
 		{
 			DataPurchaseContracts::<T>::remove(contract_id);
 
-			if deposit > 0.into() {
-				let escrow = Self::get_escrow_account();
-				Self::transfer_escrow(escrow, data_buyer.clone(), system_token_id, detail.deposit)?;
+			let escrow = Self::get_escrow_account();
+			let (refunded, forfeited) = match subscription {
+				Some(subscription) => Self::split_subscription_deposit(
+					subscription,
+					deposit.into(),
+					expired_at,
+					current_block_number,
+				),
+				None => (deposit.into(), 0),
+			};
+
+			if forfeited > 0 {
+				Self::transfer_escrow(
+					escrow.clone(),
+					Self::get_platform_account(),
+					system_token_id,
+					forfeited.into(),
+				)?;
+			}
+
+			if refunded > 0 {
+				Self::transfer_escrow(
+					escrow,
+					data_buyer.clone(),
+					system_token_id,
+					refunded.into(),
+				)?;
+			}
+
+			if subscription.is_some() {
+				Self::deposit_event(Event::<T>::RefundSubscription {
+					contract_id,
+					refunded: refunded.into(),
+					forfeited: forfeited.into(),
+				});
 			}
 
 			// If the contract is terminated, Remove from the contract list
@@ -1075,6 +1783,18 @@ where
 				<ContractList<T>>::mutate(agency, &ContractType::Purchase, |ref mut list| {
 					list.retain(|x| x != &contract_id);
 				});
+
+				// The contract is completed if it has expired, or terminated early otherwise. A
+				// contract of the agency with itself is not recorded, and a contract without any
+				// data trade is not counted as completed, so that the agency cannot make up its
+				// reputation.
+				if agency != data_buyer {
+					if current_block_number <= active_until {
+						Self::update_reputation(agency, |reputation| reputation.terminated += 1);
+					} else if TradeCountForContract::<T>::get(contract_id) > 0 {
+						Self::update_reputation(agency, |reputation| reputation.completed += 1);
+					}
+				}
 			}
 
 			Self::deposit_event(Event::<T>::ContractTerminated {
@@ -1095,114 +1815,575 @@ where
 		Ok(())
 	}
 
-	fn do_execute_data_trade(
+	/// Check that the verifier can execute `quantity` data trades with the purchase contract now
+	/// and charge their price to the deposit and to the budget of the subscription.
+	///
+	/// Returns the quantity of data traded with the contract including the trades.
+	fn charge_data_trades(
+		maybe_verifier: &T::AccountId,
+		contract_id: ContractId,
+		detail: &mut DataPurchaseContractDetail<T::AccountId, BlockNumberFor<T>, AssetBalanceOf<T>>,
+		quantity: Quantity,
+	) -> Result<Quantity, DispatchError> {
+		let current_block_number = <frame_system::Pallet<T>>::block_number();
+
+		let data_verifier = detail.data_verifier.clone().ok_or(Error::<T>::InvalidVerifier)?;
+		ensure!(maybe_verifier == &data_verifier, Error::<T>::InvalidVerifier);
+		ensure!(
+			detail.signed_status.iter().all(|(_, signed)| *signed == SignStatus::Signed),
+			Error::<T>::ContractNotActive
+		);
+		ensure!(Self::active_until(detail) > current_block_number, Error::<T>::ContractNotActive);
+		ensure!(detail.effective_at <= current_block_number, Error::<T>::ContractNotActive);
+
+		let trade_count = TradeCountForContract::<T>::get(contract_id)
+			.checked_add(quantity)
+			.ok_or(Error::<T>::Overflow)?;
+		ensure!(trade_count <= T::MaxPurchaseQuantity::get(), Error::<T>::TradeLimitReached);
+
+		let price_per_data: u128 = detail.price_per_data.into();
+		let deposit: u128 = detail.deposit.into();
+		let total_price = price_per_data.saturating_mul(quantity);
+		ensure!(deposit >= total_price, Error::<T>::InsufficientDeposit);
+
+		if let Some(subscription) = detail.subscription.as_mut() {
+			let spent_in_period: u128 = subscription.spent_in_period.into();
+			let spent_in_period = spent_in_period.saturating_add(total_price);
+			ensure!(
+				spent_in_period <= subscription.budget_per_period.into(),
+				Error::<T>::BudgetExceeded
+			);
+			subscription.spent_in_period = spent_in_period.into();
+		}
+
+		detail.deposit = (deposit - total_price).into();
+
+		Ok(trade_count)
+	}
+
+	fn do_execute_data_trades(
 		maybe_verifier: T::AccountId,
 		contract_id: ContractId,
-		data_owner: T::AccountId,
-		data_issuer: Vec<(T::AccountId, IssuerWeight)>,
-		data_owner_fee_ratio: u32,
-		data_issuer_fee_ratio: u32,
-		maybe_agency: Option<T::AccountId>,
-		maybe_agency_fee_ratio: Option<u32>,
-		data_verification_proof: AnyText,
+		trades: Vec<DataTradeParams<T::AccountId>>,
 	) -> DispatchResult {
 		let mut detail =
 			DataPurchaseContracts::<T>::get(contract_id).ok_or(Error::<T>::ContractNotExist)?;
+		ensure!(detail.privacy.is_none(), Error::<T>::PrivateContract);
 
-		let DataPurchaseContractDetail {
-			data_buyer: _,
-			data_verifier: ref data_verifier_in_detail,
-			data_purchase_info: _,
-			system_token_id,
-			agency: ref agency_in_detail,
-			price_per_data,
-			deposit,
-			mut trade_count,
-			ref signed_status,
-			mut data_trade_record,
-			effective_at,
-			expired_at,
-		} = detail;
+		let trade_count = Self::charge_data_trades(
+			&maybe_verifier,
+			contract_id,
+			&mut detail,
+			trades.len() as Quantity,
+		)?;
 
-		let current_block_number = <frame_system::Pallet<T>>::block_number();
+		let agency_in_detail = detail.agency.clone();
+		let price_per_data: u128 = detail.price_per_data.into();
+		let MarketConfiguration { total_fee_ratio, min_platform_fee_ratio } =
+			PlatformConfig::<T>::get();
+		let mut payouts: BTreeMap<T::AccountId, u128> = BTreeMap::new();
+		// Emitted once the trades are settled
+		let mut executed_events = Vec::with_capacity(trades.len());
+
+		for trade in trades {
+			let DataTradeParams {
+				data_owner,
+				data_issuer,
+				data_owner_fee_ratio,
+				data_issuer_fee_ratio,
+				agency: maybe_agency,
+				agency_fee_ratio: maybe_agency_fee_ratio,
+				data_verification_proof,
+			} = trade;
+
+			if let Some(agency) = maybe_agency.as_ref() {
+				ensure!(agency_in_detail.as_ref() == Some(agency), Error::<T>::InvalidAgency);
+			}
+			// Data of an agency contract is sold through the agency, whether it takes a fee or not
+			if let Some(agency) = agency_in_detail.as_ref() {
+				ensure!(Self::has_active_consent(&data_owner, agency), Error::<T>::NoActiveConsent);
+			}
+
+			// If Agency doesnt exist, then the data owner only sell data one time. Otherwise, the
+			// data owner can sell data multiple times.
+			DataTradeRecords::<T>::try_mutate(
+				contract_id,
+				&data_owner,
+				|record| -> DispatchResult {
+					ensure!(
+						agency_in_detail.is_some() || record.is_none(),
+						Error::<T>::AlreadyPurchased
+					);
+					*record = Some(record.unwrap_or_default().saturating_add(1));
+					Ok(())
+				},
+			)?;
+
+			let agency_fee_ratio = maybe_agency_fee_ratio.unwrap_or(0);
+			let sum_fee_ratio = agency_fee_ratio +
+				data_issuer_fee_ratio +
+				data_owner_fee_ratio +
+				min_platform_fee_ratio;
+
+			ensure!(sum_fee_ratio <= total_fee_ratio, Error::<T>::InvalidFeeRatio);
+
+			let (data_owner_fee, data_issuer_fee, platform_fee, agency_fee) =
+				Self::calculate_data_fee(
+					price_per_data,
+					data_owner_fee_ratio,
+					data_issuer_fee_ratio,
+					agency_fee_ratio,
+				);
+
+			Self::add_data_trade_payouts(
+				&mut payouts,
+				data_owner.clone(),
+				data_owner_fee,
+				&data_issuer,
+				data_issuer_fee,
+				platform_fee,
+				maybe_agency,
+				agency_fee,
+			)?;
+
+			executed_events.push(Event::<T>::DataTradeExecuted {
+				contract_id,
+				data_owner,
+				data_issuer,
+				data_owner_fee,
+				data_issuer_fee,
+				platform_fee,
+				data_verification_proof,
+			});
+		}
+
+		let system_token_id = detail.system_token_id;
+		TradeCountForContract::<T>::insert(contract_id, trade_count);
+		Self::check_low_deposit(contract_id, &detail);
+		DataPurchaseContracts::<T>::insert(contract_id, detail);
+
+		Self::settle_data_trades(payouts, system_token_id)?;
+
+		for event in executed_events {
+			Self::deposit_event(event);
+		}
+
+		Ok(())
+	}
+
+	/// Execute data trades with a contract in privacy mode.
+	///
+	/// The commitments of the data owners are recorded in place of their accounts and their fees
+	/// are kept in the escrow as notes of the payout pool. Only the quantity of the trades is
+	/// emitted.
+	fn do_execute_private_data_trades(
+		maybe_verifier: T::AccountId,
+		contract_id: ContractId,
+		trades: Vec<PrivateDataTradeParams<T::AccountId>>,
+	) -> DispatchResult {
+		let mut detail =
+			DataPurchaseContracts::<T>::get(contract_id).ok_or(Error::<T>::ContractNotExist)?;
+		let PrivacyParams { data_owner_fee_ratio } =
+			detail.privacy.ok_or(Error::<T>::NotPrivateContract)?;
+		let mut pool =
+			PrivatePayoutPools::<T>::get(contract_id).ok_or(Error::<T>::NotPrivateContract)?;
+
+		let quantity = trades.len() as Quantity;
+		let trade_count =
+			Self::charge_data_trades(&maybe_verifier, contract_id, &mut detail, quantity)?;
+
+		let price_per_data: u128 = detail.price_per_data.into();
+		let note_value: u128 = pool.note_value.into();
+		let MarketConfiguration { total_fee_ratio, min_platform_fee_ratio } =
+			PlatformConfig::<T>::get();
+		let mut payouts: BTreeMap<T::AccountId, u128> = BTreeMap::new();
+
+		for trade in trades {
+			let PrivateDataTradeParams { owner_commitment, data_issuer, data_issuer_fee_ratio } =
+				trade;
+
+			// The data owner only sell data one time as there is no agency
+			ensure!(
+				!PayoutCommitments::<T>::contains_key(contract_id, owner_commitment),
+				Error::<T>::AlreadyPurchased
+			);
+			PayoutCommitments::<T>::insert(contract_id, owner_commitment, ());
+
+			ensure!(
+				data_owner_fee_ratio + data_issuer_fee_ratio + min_platform_fee_ratio <=
+					total_fee_ratio,
+				Error::<T>::InvalidFeeRatio
+			);
+
+			// The platform takes what is left after the note, which is fixed when the contract is
+			// made
+			let (_, data_issuer_fee, _, _) =
+				Self::calculate_data_fee(price_per_data, 0, data_issuer_fee_ratio, 0);
+			let platform_fee =
+				price_per_data.saturating_sub(note_value).saturating_sub(data_issuer_fee);
+
+			Self::add_data_issuer_payouts(
+				&mut payouts,
+				&data_issuer,
+				data_issuer_fee,
+				platform_fee,
+			)?;
+		}
+
+		pool.commitment_count = pool.commitment_count.saturating_add(quantity);
+		PrivatePayoutPools::<T>::insert(contract_id, pool);
+
+		let system_token_id = detail.system_token_id;
+		TradeCountForContract::<T>::insert(contract_id, trade_count);
+		Self::check_low_deposit(contract_id, &detail);
+		DataPurchaseContracts::<T>::insert(contract_id, detail);
+
+		Self::settle_data_trades(payouts, system_token_id)?;
 
-		let data_verifier = data_verifier_in_detail.clone().ok_or(Error::<T>::InvalidVerifier)?;
-		ensure!(maybe_verifier == data_verifier, Error::<T>::InvalidVerifier);
+		Self::deposit_event(Event::<T>::PrivateDataTradeExecuted { contract_id, quantity });
+
+		Ok(())
+	}
+
+	/// Pay a note of the payout pool of the contract to `beneficiary` if `proof` shows the
+	/// membership in the commitments of the contract, from which `nullifier` is derived. The
+	/// proof is bound to the contract and the beneficiary so that a claim cannot be redirected.
+	fn do_claim_private_payout(
+		contract_id: ContractId,
+		nullifier: Nullifier,
+		beneficiary: T::AccountId,
+		proof: PayoutClaimProof,
+	) -> DispatchResult {
+		let mut pool =
+			PrivatePayoutPools::<T>::get(contract_id).ok_or(Error::<T>::NotPrivateContract)?;
+		ensure!(pool.claimed_count < pool.commitment_count, Error::<T>::NoUnclaimedPayout);
 		ensure!(
-			signed_status.iter().all(|(_, signed)| *signed == SignStatus::Signed),
-			Error::<T>::ContractNotActive
+			!PayoutNullifiers::<T>::contains_key(contract_id, nullifier),
+			Error::<T>::PayoutAlreadyClaimed
 		);
-		ensure!(expired_at > current_block_number, Error::<T>::ContractNotActive);
-		ensure!(effective_at <= current_block_number, Error::<T>::ContractNotActive);
-		if let Some(agency) = maybe_agency.clone() {
-			let agency_from_detail = agency_in_detail.clone().ok_or(Error::<T>::InvalidAgency)?;
-			ensure!(agency == agency_from_detail, Error::<T>::InvalidAgency);
+
+		let commitments = Self::payout_commitments(contract_id);
+		let claim = (contract_id, &beneficiary).encode();
+		ensure!(
+			T::PayoutClaimVerifier::verify(&commitments, &nullifier, &claim, &proof),
+			Error::<T>::InvalidPayoutProof
+		);
+
+		PayoutNullifiers::<T>::insert(contract_id, nullifier, ());
+		pool.claimed_count = pool.claimed_count.saturating_add(1);
+		let (system_token_id, note_value) = (pool.system_token_id, pool.note_value);
+		PrivatePayoutPools::<T>::insert(contract_id, pool);
+
+		Self::transfer_escrow(
+			Self::get_escrow_account(),
+			beneficiary,
+			system_token_id,
+			note_value,
+		)?;
+
+		Self::deposit_event(Event::<T>::ClaimPrivatePayout { contract_id, nullifier });
+
+		Ok(())
+	}
+
+	fn do_set_auto_renew(
+		maybe_buyer: T::AccountId,
+		contract_id: ContractId,
+		auto_renew: bool,
+	) -> DispatchResult {
+		DataPurchaseContracts::<T>::try_mutate(contract_id, |maybe_detail| -> DispatchResult {
+			let detail = maybe_detail.as_mut().ok_or(Error::<T>::ContractNotExist)?;
+			ensure!(detail.data_buyer == maybe_buyer, Error::<T>::InvalidBuyer);
+			let subscription = detail.subscription.as_mut().ok_or(Error::<T>::NotSubscription)?;
+			subscription.auto_renew = auto_renew;
+			Ok(())
+		})?;
+
+		Self::deposit_event(Event::<T>::SetAutoRenew { contract_id, auto_renew });
+		Ok(())
+	}
+
+	/// Emit `LowDeposit` if the deposit of the subscription contract is below its threshold
+	fn check_low_deposit(
+		contract_id: ContractId,
+		detail: &DataPurchaseContractDetail<T::AccountId, BlockNumberFor<T>, AssetBalanceOf<T>>,
+	) {
+		if let Some(subscription) = &detail.subscription {
+			if detail.deposit < subscription.low_deposit_threshold {
+				Self::deposit_event(Event::<T>::LowDeposit {
+					contract_id,
+					deposit: detail.deposit,
+				});
+			}
+		}
+	}
+
+	/// The block until which the purchase contract is active. A subscription set to auto renew
+	/// stays active past the end of its period until its queued renewal is processed.
+	fn active_until(
+		detail: &DataPurchaseContractDetail<T::AccountId, BlockNumberFor<T>, AssetBalanceOf<T>>,
+	) -> BlockNumberFor<T> {
+		match &detail.subscription {
+			Some(subscription) if subscription.auto_renew =>
+				detail.expired_at.max(subscription.renew_at),
+			_ => detail.expired_at,
+		}
+	}
+
+	/// Split the deposit of a subscription contract at `now` into the part refunded to the data
+	/// buyer and the part forfeited to the platform.
+	///
+	/// The budget of the period which is not spent is refunded pro rata to the blocks left in the
+	/// period and the rest is forfeited. The deposit beyond the budget is always refunded.
+	fn split_subscription_deposit(
+		subscription: &SubscriptionDetail<BlockNumberFor<T>, AssetBalanceOf<T>>,
+		deposit: u128,
+		expired_at: BlockNumberFor<T>,
+		now: BlockNumberFor<T>,
+	) -> (u128, u128) {
+		let budget_per_period: u128 = subscription.budget_per_period.into();
+		let spent_in_period: u128 = subscription.spent_in_period.into();
+		let unspent = budget_per_period.saturating_sub(spent_in_period).min(deposit);
+
+		let period: u128 = subscription.period.saturated_into();
+		let blocks_left: u128 = expired_at.saturating_sub(now).saturated_into();
+		let refundable = match period {
+			0 => 0,
+			_ => unspent.saturating_mul(blocks_left.min(period)) / period,
+		};
+		let forfeited = unspent - refundable;
+
+		(deposit - forfeited, forfeited)
+	}
+
+	/// Schedule the renewal of the subscription contract at `renew_at`. If too many contracts are
+	/// renewed at that block, it is scheduled at one of the next `MAX_RENEWAL_DELAY` blocks with
+	/// room.
+	///
+	/// Returns the block the renewal is scheduled at.
+	///
+	/// ## Error
+	/// `TooManyRenewals`
+	fn schedule_renewal(
+		contract_id: ContractId,
+		renew_at: BlockNumberFor<T>,
+	) -> Result<BlockNumberFor<T>, DispatchError> {
+		for delay in 0..=MAX_RENEWAL_DELAY {
+			let renew_at = renew_at + delay.into();
+			if SubscriptionRenewals::<T>::try_mutate(renew_at, |contract_ids| {
+				contract_ids.try_push(contract_id)
+			})
+			.is_ok()
+			{
+				return Ok(renew_at)
+			}
+		}
+		Err(Error::<T>::TooManyRenewals.into())
+	}
+
+	/// Renew the subscription contracts scheduled at `n`.
+	///
+	/// The unspent budget of the ended period is carried over to the next period and the deposit
+	/// is topped up to the budget from the balance of the data buyer. Contracts which are not
+	/// signed, not set to auto renew, whose data buyer cannot pay or whose next renewal cannot be
+	/// scheduled are left to expire.
+	fn handle_subscription_renewals(n: BlockNumberFor<T>) -> Weight {
+		let renewals = SubscriptionRenewals::<T>::take(n);
+		for contract_id in renewals.iter().copied() {
+			let Some(mut detail) = DataPurchaseContracts::<T>::get(contract_id) else { continue };
+			let Some(mut subscription) = detail.subscription.clone() else { continue };
+			if !subscription.auto_renew ||
+				detail.expired_at > n ||
+				detail.signed_status.iter().any(|(_, signed)| *signed != SignStatus::Signed)
+			{
+				continue
+			}
+
+			let deposit: u128 = detail.deposit.into();
+			let budget_per_period: u128 = subscription.budget_per_period.into();
+			let charged = budget_per_period.saturating_sub(deposit);
+			let expired_at = detail.expired_at.saturating_add(subscription.period);
+			let renewed = with_storage_layer(|| -> Result<BlockNumberFor<T>, DispatchError> {
+				if charged > 0 {
+					T::Assets::transfer(
+						detail.system_token_id.into(),
+						&detail.data_buyer,
+						&Self::get_escrow_account(),
+						charged.into(),
+						Preservation::Protect,
+					)?;
+				}
+				Self::schedule_renewal(contract_id, expired_at)
+			});
+			let Ok(renew_at) = renewed else {
+				Self::deposit_event(Event::<T>::RenewSubscriptionFailed { contract_id });
+				continue
+			};
+
+			subscription.spent_in_period = 0u128.into();
+			subscription.renew_at = renew_at;
+			detail.deposit = deposit.saturating_add(charged).into();
+			detail.expired_at = expired_at;
+			detail.subscription = Some(subscription);
+			Self::check_low_deposit(contract_id, &detail);
+			DataPurchaseContracts::<T>::insert(contract_id, detail);
+
+			Self::deposit_event(Event::<T>::RenewSubscription {
+				contract_id,
+				charged: charged.into(),
+				expired_at,
+			});
 		}
+		T::WeightInfo::handle_subscription_renewals(renewals.len() as u32)
+	}
 
-		ensure!(trade_count < T::MaxPurchaseQuantity::get(), Error::<T>::TradeLimitReached);
-		trade_count += 1;
-		detail.trade_count = trade_count;
+	/// Ensure that the agency is registered, is still accredited and holds the full
+	/// `AgencyBond`.
+	fn ensure_agency_in_good_standing(agency: &T::AccountId) -> DispatchResult {
+		ensure!(Self::check_agency_exist(agency), Error::<T>::InvalidAgency);
+		ensure!(Self::is_accredited(agency), Error::<T>::AgencyNotAccredited);
+		let bonded = AgencyBonds::<T>::get(agency).map_or(Zero::zero(), |bond| bond.amount);
+		ensure!(bonded >= T::AgencyBond::get(), Error::<T>::BondNotToppedUp);
+		Ok(())
+	}
 
+	/// Ensure that the agency has no open dispute and no purchase contract it has signed which is
+	/// not terminated, so that it cannot take its bond back before a dispute is opened.
+	fn ensure_agency_can_deregister(agency: &T::AccountId) -> DispatchResult {
 		ensure!(
-			(data_trade_record.clone().len() as u128) < T::MaxPurchaseQuantity::get(),
-			Error::<T>::TradeLimitReached
+			AgencyReputations::<T>::get(agency).open_disputes == 0,
+			Error::<T>::AgencyInDispute
 		);
+		let has_live_contracts =
+			ContractList::<T>::get(agency, ContractType::Purchase)
+				.iter()
+				.any(|contract_id| {
+					DataPurchaseContracts::<T>::get(contract_id).map_or(false, |detail| {
+						detail
+							.signed_status
+							.get(agency)
+							.map_or(false, |signed| *signed != SignStatus::Unsigned)
+					})
+				});
+		ensure!(!has_live_contracts, Error::<T>::AgencyHasLiveContracts);
+		Ok(())
+	}
 
-		if agency_in_detail.is_none() {
-			// If Agency doesnt exist, then the data owner only sell data one time
-			ensure!(!data_trade_record.contains(&data_owner), Error::<T>::AlreadyPurchased);
-			data_trade_record.push(data_owner.clone());
-		} else {
-			// If Agency exist, then the data owner can sell data multiple times. but insert storage
-			// only one time
-			if !data_trade_record.contains(&data_owner) {
-				data_trade_record.push(data_owner.clone());
+	/// Hold `AgencyBond` from the agency in the escrow.
+	fn bond_agency(agency: &T::AccountId) -> DispatchResult {
+		let bond = AgencyBond {
+			system_token_id: T::AgencyBondSystemTokenId::get(),
+			amount: T::AgencyBond::get(),
+		};
+		if !bond.amount.is_zero() {
+			T::Assets::transfer(
+				bond.system_token_id.into(),
+				agency,
+				&Self::get_escrow_account(),
+				bond.amount,
+				Preservation::Protect,
+			)?;
+		}
+		AgencyBonds::<T>::insert(agency, bond);
+		Ok(())
+	}
+
+	/// Release what is left of the bond of the agency.
+	fn unbond_agency(agency: &T::AccountId) -> DispatchResult {
+		if let Some(AgencyBond { system_token_id, amount }) = AgencyBonds::<T>::take(agency) {
+			if !amount.is_zero() {
+				Self::transfer_escrow(
+					Self::get_escrow_account(),
+					agency.clone(),
+					system_token_id,
+					amount,
+				)?;
 			}
 		}
+		Ok(())
+	}
 
-		detail.data_trade_record = data_trade_record;
-		DataPurchaseContracts::<T>::insert(contract_id, detail.clone());
+	/// Top up the bond of the agency to `AgencyBond` after it has been slashed, or hold it for
+	/// the first time from an agency registered by the admin.
+	fn do_top_up_agency_bond(agency: T::AccountId) -> DispatchResult {
+		ensure!(Self::check_agency_exist(&agency), Error::<T>::InvalidAgency);
+		let amount = AgencyBonds::<T>::try_mutate(&agency, |maybe_bond| {
+			let bond = maybe_bond.get_or_insert(AgencyBond {
+				system_token_id: T::AgencyBondSystemTokenId::get(),
+				amount: Zero::zero(),
+			});
+			let amount = T::AgencyBond::get().saturating_sub(bond.amount);
+			ensure!(!amount.is_zero(), Error::<T>::NoBondToTopUp);
+			T::Assets::transfer(
+				bond.system_token_id.into(),
+				&agency,
+				&Self::get_escrow_account(),
+				amount,
+				Preservation::Protect,
+			)?;
+			bond.amount = T::AgencyBond::get();
+			Ok::<_, DispatchError>(amount)
+		})?;
 
-		let market_config = PlatformConfig::<T>::get();
-		let MarketConfiguration { total_fee_ratio, min_platform_fee_ratio } = market_config;
-		let agency_fee_ratio = maybe_agency_fee_ratio.unwrap_or(0);
-		let sum_fee_ratio = agency_fee_ratio +
-			data_issuer_fee_ratio +
-			data_owner_fee_ratio +
-			min_platform_fee_ratio;
-
-		ensure!(sum_fee_ratio <= total_fee_ratio, Error::<T>::InvalidFeeRatio);
-
-		let (data_owner_fee, data_issuer_fee, platform_fee, agency_fee) = Self::calculate_data_fee(
-			price_per_data.into(),
-			data_owner_fee_ratio,
-			data_issuer_fee_ratio,
-			agency_fee_ratio,
+		Self::deposit_event(Event::<T>::TopUpAgencyBond { agency, amount });
+		Ok(())
+	}
+
+	fn do_open_dispute(maybe_buyer: T::AccountId, contract_id: ContractId) -> DispatchResult {
+		let detail =
+			DataPurchaseContracts::<T>::get(contract_id).ok_or(Error::<T>::ContractNotExist)?;
+		ensure!(detail.data_buyer == maybe_buyer, Error::<T>::InvalidBuyer);
+		let agency = detail.agency.ok_or(Error::<T>::InvalidAgency)?;
+		ensure!(!Disputes::<T>::contains_key(contract_id), Error::<T>::DisputeAlreadyOpen);
+		ensure!(
+			AgencyBonds::<T>::get(&agency).map_or(false, |bond| !bond.amount.is_zero()),
+			Error::<T>::AgencyNotBonded
 		);
 
-		Self::settle_data_trade(
-			data_owner.clone(),
-			data_owner_fee,
-			data_issuer.clone(),
-			data_issuer_fee,
-			platform_fee,
-			maybe_agency,
-			agency_fee,
-			system_token_id,
-		)?;
+		Disputes::<T>::insert(
+			contract_id,
+			Dispute { claimant: maybe_buyer, agency: agency.clone() },
+		);
+		AgencyReputations::<T>::mutate(&agency, |reputation| {
+			reputation.open_disputes = reputation.open_disputes.saturating_add(1)
+		});
 
-		Self::update_deposit_in_detail(contract_id, deposit - price_per_data);
+		Self::deposit_event(Event::<T>::OpenDispute { contract_id, agency });
+		Ok(())
+	}
 
-		Self::deposit_event(Event::<T>::DataTradeExecuted {
-			contract_id,
-			data_owner,
-			data_issuer,
-			data_owner_fee,
-			data_issuer_fee,
-			platform_fee,
-			data_verification_proof,
+	fn do_resolve_dispute(contract_id: ContractId, agency_at_fault: bool) -> DispatchResult {
+		let Dispute { claimant, agency } =
+			Disputes::<T>::take(contract_id).ok_or(Error::<T>::DisputeNotExist)?;
+		AgencyReputations::<T>::mutate(&agency, |reputation| {
+			reputation.open_disputes = reputation.open_disputes.saturating_sub(1)
 		});
 
+		let mut slashed: u128 = 0;
+		if agency_at_fault {
+			if let Some(mut bond) = AgencyBonds::<T>::get(&agency) {
+				let amount: u128 = bond.amount.into();
+				slashed = T::DisputeSlash::get().mul_floor(amount);
+				bond.amount = (amount - slashed).into();
+				AgencyBonds::<T>::insert(&agency, bond);
+				Self::transfer_escrow(
+					Self::get_escrow_account(),
+					claimant,
+					bond.system_token_id,
+					slashed.into(),
+				)?;
+			}
+			Self::update_reputation(&agency, |reputation| reputation.disputes_lost += 1);
+		} else {
+			Self::update_reputation(&agency, |reputation| reputation.disputes_won += 1);
+		}
+
+		Self::deposit_event(Event::<T>::ResolveDispute {
+			contract_id,
+			agency,
+			agency_at_fault,
+			slashed: slashed.into(),
+		});
 		Ok(())
 	}
 
@@ -1,5 +1,6 @@
 use crate as pallet_data_market;
 use codec::Encode;
+use did_core::trusted_entity::AuthorizerId;
 use frame_benchmarking::v1::account;
 use frame_support::{
 	construct_runtime, parameter_types,
@@ -24,6 +25,9 @@ construct_runtime!(
 		System: frame_system,
 		Balances: pallet_balances,
 		Assets: pallet_assets,
+		DIDModule: did_core::did,
+		Revoke: did_core::revoke,
+		TrustedEntity: did_core::trusted_entity,
 		DataMarket: pallet_data_market,
 	}
 );
@@ -79,7 +83,7 @@ impl pallet_balances::Config for Test {
 impl pallet_assets::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Balance = u128;
-	type AssetLink = ();
+	type SystemTokenWeight = u128;
 	type RemoveItemsLimit = ConstU32<1000>;
 	type AssetId = u32;
 	type AssetIdParameter = u32;
@@ -101,11 +105,65 @@ impl pallet_assets::Config for Test {
 	}
 }
 
+impl did_core::common::Limits for Test {
+	type MaxDidDocRefSize = ConstU32<128>;
+	type MaxDidServiceEndpointIdSize = ConstU32<256>;
+	type MaxDidServiceEndpointOrigins = ConstU32<20>;
+	type MaxDidServiceEndpointOriginSize = ConstU32<256>;
+
+	type MaxAccumulatorLabelSize = ConstU32<512>;
+	type MaxAccumulatorParamsSize = ConstU32<512>;
+	type MaxAccumulatorPublicKeySize = ConstU32<128>;
+	type MaxAccumulatorAccumulatedSize = ConstU32<256>;
+
+	type MaxStatusListCredentialSize = ConstU32<1024>;
+	type MinStatusListCredentialSize = ConstU32<10>;
+
+	type MaxIriSize = ConstU32<1024>;
+	type MaxBlobSize = ConstU32<1024>;
+
+	type MaxOffchainParamsLabelSize = ConstU32<512>;
+	type MaxOffchainParamsBytesSize = ConstU32<512>;
+	type MaxBBSPublicKeySize = ConstU32<128>;
+	type MaxBBSPlusPublicKeySize = ConstU32<128>;
+	type MaxPSPublicKeySize = ConstU32<128>;
+
+	type MaxMasterMembers = ConstU32<25>;
+	type MaxPolicyControllers = ConstU32<15>;
+}
+
+impl did_core::did::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type OnDidRemoval = ();
+}
+
+impl did_core::revoke::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+}
+
+impl did_core::trusted_entity::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+}
+
+parameter_types! {
+	pub const AgencyAuthorizer: AuthorizerId = AuthorizerId([1; 32]);
+	pub const DisputeSlash: Perbill = Perbill::from_percent(50);
+}
+
 impl pallet_data_market::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Assets = Assets;
 	type AdminOrigin = EnsureRoot<AccountId>;
 	type MaxPurchaseQuantity = ConstU128<100>;
+	type MaxContracts = ConstU32<100>;
+	type MaxTradesPerBatch = ConstU32<10>;
+	type MaxRenewalsPerBlock = ConstU32<10>;
+	type PayoutClaimVerifier = pallet_data_market::Sr25519PayoutClaimVerifier;
+	type AgencyAuthorizer = AgencyAuthorizer;
+	type AgencyBond = ConstU128<1000>;
+	type AgencyBondSystemTokenId = ConstU32<1>;
+	type DisputeSlash = DisputeSlash;
+	type WeightInfo = ();
 }
 
 // Build genesis storage according to the mock runtime.
@@ -114,6 +172,7 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 
 	let admin_account = 10;
 	let escrow_account = 7742357832001154925;
+	let agency_account = 20;
 	const MINIMUM_BALANCE: u128 = 1;
 	const INITIAL_BALANCE: u128 = 100000000000000000000;
 	pallet_assets::GenesisConfig::<Test> {
@@ -123,10 +182,14 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 			(1, admin_account, INITIAL_BALANCE),
 			(1, escrow_account, MINIMUM_BALANCE),
 			(1, account("data_buyer", 0, 0), INITIAL_BALANCE),
+			(1, agency_account, INITIAL_BALANCE),
 		],
 	}
 	.assimilate_storage(&mut storage)
 	.unwrap();
+	pallet_data_market::GenesisConfig::<Test>::default()
+		.assimilate_storage(&mut storage)
+		.unwrap();
 
 	let mut ext: sp_io::TestExternalities = storage.into();
 	ext.execute_with(|| System::set_block_number(1));
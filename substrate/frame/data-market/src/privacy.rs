@@ -0,0 +1,124 @@
+//! Settlement of Data Purchase Contracts in privacy mode.
+//!
+//! In privacy mode the data owners of a contract are represented by commitments instead of their
+//! accounts, and neither the state nor the events of the pallet name them. Only the commitments
+//! and the number of trades are recorded for a contract. The fee of a data owner is kept in the
+//! escrow as a note of the contract, which is claimed to any beneficiary with a proof of
+//! membership in the commitments of the contract. Each claim reveals a nullifier so that a note
+//! cannot be claimed twice. The data issuers are still paid to their accounts.
+//!
+//! With `Sr25519PayoutClaimVerifier` a data owner commits to a one-time sr25519 key and proves
+//! the membership with a signature of the claim by that key. The nullifier is the commitment
+//! itself, so a claim links the beneficiary to the note of one data trade of the contract, but
+//! not to the account of the data owner unless it is the beneficiary. A verifier of
+//! zero-knowledge proofs of membership can replace it to hide which note is claimed.
+
+use super::*;
+
+use codec::{Decode, DecodeAll, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_core::sr25519;
+
+/// Maximum size of a proof of a payout claim.
+pub const MAX_PROOF_SIZE: u32 = 4_096;
+
+/// Context which a payout claim is signed in by the one-time key of a data owner.
+pub const PAYOUT_CLAIM_CONTEXT: &[u8] = b"data-market/payout-claim";
+
+/// Commitment of a data owner in privacy mode.
+pub type OwnerCommitment = [u8; 32];
+/// Value revealed by a payout claim which is unique for each commitment.
+pub type Nullifier = [u8; 32];
+/// Proof of a payout claim.
+pub type PayoutClaimProof = BoundedVec<u8, ConstU32<MAX_PROOF_SIZE>>;
+
+/// Verifier of the proofs of payout claims.
+pub trait VerifyPayoutClaim {
+	/// Whether proofs can be verified, i.e. whether Data Purchase Contracts can be made in
+	/// privacy mode.
+	fn is_enabled() -> bool;
+
+	/// Verify that `proof` shows the membership of the claimer in `commitments`, that
+	/// `nullifier` is derived from its commitment, and that it is bound to `claim`, the encoded
+	/// contract id and beneficiary.
+	fn verify(
+		commitments: &[OwnerCommitment],
+		nullifier: &Nullifier,
+		claim: &[u8],
+		proof: &[u8],
+	) -> bool;
+}
+
+/// Privacy mode is disabled.
+impl VerifyPayoutClaim for () {
+	fn is_enabled() -> bool {
+		false
+	}
+
+	fn verify(_: &[OwnerCommitment], _: &Nullifier, _: &[u8], _: &[u8]) -> bool {
+		false
+	}
+}
+
+/// Commitment of a data owner to the one-time sr25519 key `public`.
+pub fn sr25519_owner_commitment(public: &sr25519::Public) -> OwnerCommitment {
+	sp_io::hashing::blake2_256(public.as_ref())
+}
+
+/// Verifies payout claims signed by the one-time sr25519 key of a data owner.
+///
+/// The proof is the encoded key and its signature of `PAYOUT_CLAIM_CONTEXT` followed by the
+/// claim, and the nullifier is the commitment to the key.
+pub struct Sr25519PayoutClaimVerifier;
+
+impl VerifyPayoutClaim for Sr25519PayoutClaimVerifier {
+	fn is_enabled() -> bool {
+		true
+	}
+
+	fn verify(
+		commitments: &[OwnerCommitment],
+		nullifier: &Nullifier,
+		claim: &[u8],
+		proof: &[u8],
+	) -> bool {
+		let Ok((public, signature)) =
+			<(sr25519::Public, sr25519::Signature)>::decode_all(&mut &proof[..])
+		else {
+			return false
+		};
+		let commitment = sr25519_owner_commitment(&public);
+		&commitment == nullifier &&
+			commitments.contains(&commitment) &&
+			sp_io::crypto::sr25519_verify(
+				&signature,
+				&[PAYOUT_CLAIM_CONTEXT, claim].concat(),
+				&public,
+			)
+	}
+}
+
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Hash))]
+pub struct PrivacyParams {
+	// Fee ratio of each data owner, which is the same for all of them so that the notes are
+	// indistinguishable
+	pub data_owner_fee_ratio: u32,
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Hash, Debug))]
+pub struct PrivateDataTradeParams<AccountId> {
+	pub owner_commitment: OwnerCommitment,
+	pub data_issuer: Vec<(AccountId, IssuerWeight)>,
+	pub data_issuer_fee_ratio: u32,
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PrivatePayoutPool<Balance> {
+	pub system_token_id: u32,
+	// Value of each note, which is the fee of a data owner
+	pub note_value: Balance,
+	pub commitment_count: Quantity,
+	pub claimed_count: Quantity,
+}
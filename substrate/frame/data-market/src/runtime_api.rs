@@ -0,0 +1,39 @@
+//! Runtime API of the data market pallet.
+
+use crate::{AgencyReputation, ContractId, OwnerCommitment, Quantity};
+use codec::Codec;
+use did_core::did::Did;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// API to query the data trades of the Data Purchase Contracts and the consents of the data
+	/// subjects to the Data Delegate Contracts.
+	pub trait DataMarketApi<AccountId>
+	where
+		AccountId: Codec,
+	{
+		/// Quantity of data traded with the contract.
+		fn trade_count(contract_id: ContractId) -> Quantity;
+
+		/// Data owners who have traded data with the contract along with the quantity, at most
+		/// `limit` of them following `start_after` in storage order. Pass the last data owner of a
+		/// page as `start_after` to get the next page.
+		fn data_trade_records(
+			contract_id: ContractId,
+			start_after: Option<AccountId>,
+			limit: u32,
+		) -> Vec<(AccountId, Quantity)>;
+
+		/// Data Delegate Contracts in effect which the data subject has consented to, along with
+		/// their agencies.
+		fn active_consents(data_subject: Did) -> Vec<(ContractId, AccountId)>;
+
+		/// Commitments of the data owners who sold data with the contract in privacy mode, which
+		/// the proofs of payout claims are made against.
+		fn payout_commitments(contract_id: ContractId) -> Vec<OwnerCommitment>;
+
+		/// Records of the purchase contracts and disputes of the agency, along with its reputation
+		/// score.
+		fn agency_reputation(agency: AccountId) -> AgencyReputation;
+	}
+}
@@ -1,64 +1,229 @@
 use super::*;
 use crate::mock::*;
-use frame_support::assert_ok;
-use sp_core::bounded_vec;
+use did_core::{
+	common::{PublicKey, SigValue},
+	did::DidKey,
+	revoke::{AddRegistry, Registry},
+	trusted_entity::TrustedEntityId,
+};
+use frame_support::{assert_noop, assert_ok, traits::Hooks};
+use sp_core::{bounded_vec, sr25519, Pair};
+use sp_runtime::TokenError;
 
-fn make_test_delegate_contract(data_owner: u64, agency: u64) {
-	let detail = DataDelegateContractDetail {
+const DATA_SUBJECT: Did = Did([1; 32]);
+const CONSENT_REGISTRY: RegistryId = RegistryId([1; 32]);
+
+/// Create the DID of the data subject with a signing key, and a revocation registry controlled by
+/// the DID
+fn create_test_data_subject() -> sr25519::Pair {
+	let pair = sr25519::Pair::from_seed(&[1; 32]);
+	assert_ok!(DIDModule::new_onchain(
+		RuntimeOrigin::signed(1),
+		DATA_SUBJECT,
+		vec![DidKey::new_with_all_relationships(PublicKey::from(pair.public())).into()],
+		Default::default()
+	));
+	assert_ok!(Revoke::new_registry(
+		RuntimeOrigin::signed(1),
+		AddRegistry {
+			id: CONSENT_REGISTRY,
+			new_registry: Registry {
+				policy: Policy::one_of([DATA_SUBJECT]).unwrap(),
+				add_only: false,
+			},
+		}
+	));
+	pair
+}
+
+fn test_delegate_contract_params(
+	data_owner: u64,
+	agency: u64,
+	revoke_id: u8,
+	pair: &sr25519::Pair,
+) -> DataDelegateContractParams<u64, u64> {
+	let mut params = DataDelegateContractParams {
 		data_owner,
-		data_owner_info: bounded_vec![1],
-		agency: agency.clone(),
-		agency_info: bounded_vec![1],
 		data_owner_minimum_fee_ratio: 0,
 		deligated_data: bounded_vec![1],
-		effective_at: 0,
-		expired_at: 20,
+		duration: 20,
+		consent_revocation: ConsentRevocation {
+			registry_id: CONSENT_REGISTRY,
+			revoke_id: RevokeId([revoke_id; 32]),
+		},
+		consent_expires_at: 100,
+		consent_signature: DidSignature::new(DATA_SUBJECT, 1u32, pair.sign(&[])),
 	};
-	assert_ok!(DataMarket::make_delegate_contract(RuntimeOrigin::signed(agency.clone()), detail));
+	let message = DataMarket::data_consent_message(&agency, &params);
+	params.consent_signature.sig = SigValue::sr25519(&message, pair);
+	params
+}
+
+/// Accredit the agency as an issuer of the agency authorizer
+fn accredit_test_agency(agency: u64) {
+	trusted_entity::Issuers::<Test>::insert(
+		AgencyAuthorizer::get(),
+		DataMarket::agency_entity_id(&agency),
+		(),
+	);
+}
+
+/// Accredit and register the agency unless it is registered already
+fn register_test_agency(agency: u64) {
+	if !DataMarket::check_agency_exist(&agency) {
+		accredit_test_agency(agency.clone());
+		assert_ok!(DataMarket::register_agency(RuntimeOrigin::signed(agency.clone())));
+	}
+}
+
+fn make_test_delegate_contract(data_owner: u64, agency: u64) {
+	let pair = create_test_data_subject();
+	register_test_agency(agency.clone());
+	let params = test_delegate_contract_params(data_owner.clone(), agency.clone(), 1, &pair);
+	assert_ok!(DataMarket::make_delegate_contract(RuntimeOrigin::signed(agency.clone()), params));
+}
+
+/// Delegate the data of the data owner to the agency with the consent of the data subject, and
+/// return the id of the delegate contract
+fn delegate_test_data(data_owner: u64, agency: u64) -> ContractId {
+	let contract_id = DataMarket::get_next_contract_id() << 1;
+	make_test_delegate_contract(data_owner.clone(), agency.clone());
+	sign_test_delegate_contract(data_owner.clone(), contract_id.clone());
+	contract_id
 }
 
 fn make_test_purchase_contract_without_agency(data_buyer: u64, data_verifier: u64) {
 	let is_agency_exist = false;
-	let detail = DataPurchaseContractDetail {
-		data_buyer: data_buyer.clone(),
-		data_buyer_info: bounded_vec![1],
-		effective_at: 0,
-		expired_at: 20,
+	let params = DataPurchaseContractParams {
+		data_verifier: Some(data_verifier.clone()),
 		data_purchase_info: DataPurchaseInfo::<AnyText>::new(bounded_vec![1], bounded_vec![1]),
 		system_token_id: 1,
 		agency: None,
-		agency_info: None,
-		data_verifier: Some(data_verifier.clone()),
+		price_per_data: 1000,
 		deposit: 100000,
+		duration: 20,
+		subscription: None,
+		privacy: None,
+		min_agency_reputation: None,
 	};
 	assert_ok!(DataMarket::make_purchase_contract(
 		RuntimeOrigin::signed(data_buyer.clone()),
-		detail,
+		params,
 		is_agency_exist
 	));
 }
 
 fn make_test_purchase_contract_with_agency(data_buyer: u64, agency: u64) {
 	let is_agency_exist = true;
-	let detail = DataPurchaseContractDetail {
-		data_buyer: data_buyer.clone(),
-		data_buyer_info: bounded_vec![1],
-		effective_at: 0,
-		expired_at: 20,
+	register_test_agency(agency.clone());
+	let params = DataPurchaseContractParams {
+		data_verifier: None,
 		data_purchase_info: DataPurchaseInfo::<AnyText>::new(bounded_vec![1], bounded_vec![1]),
 		system_token_id: 1,
 		agency: Some(agency.clone()),
-		agency_info: Some(bounded_vec![1]),
-		data_verifier: None,
+		price_per_data: 1000,
 		deposit: 100000,
+		duration: 20,
+		subscription: None,
+		privacy: None,
+		min_agency_reputation: None,
 	};
 	assert_ok!(DataMarket::make_purchase_contract(
 		RuntimeOrigin::signed(data_buyer.clone()),
-		detail,
+		params,
 		is_agency_exist
 	));
 }
 
+fn make_test_subscription_contract(
+	data_buyer: u64,
+	agency: Option<u64>,
+	data_verifier: Option<u64>,
+	deposit: u128,
+) {
+	if let Some(agency) = agency {
+		register_test_agency(agency);
+	}
+	let params = DataPurchaseContractParams {
+		data_verifier,
+		data_purchase_info: DataPurchaseInfo::<AnyText>::new(bounded_vec![1], bounded_vec![1]),
+		system_token_id: 1,
+		agency,
+		price_per_data: 1000,
+		deposit,
+		duration: 20,
+		subscription: Some(SubscriptionParams {
+			budget_per_period: 2000,
+			low_deposit_threshold: 1500,
+			auto_renew: true,
+		}),
+		privacy: None,
+		min_agency_reputation: None,
+	};
+	assert_ok!(DataMarket::make_purchase_contract(
+		RuntimeOrigin::signed(data_buyer.clone()),
+		params,
+		agency.is_some()
+	));
+}
+
+/// Make a contract in privacy mode, where each data owner is paid 10% of the price
+fn make_test_private_contract(data_buyer: u64, data_verifier: u64) {
+	let params = DataPurchaseContractParams {
+		data_verifier: Some(data_verifier),
+		data_purchase_info: DataPurchaseInfo::<AnyText>::new(bounded_vec![1], bounded_vec![1]),
+		system_token_id: 1,
+		agency: None,
+		price_per_data: 1000,
+		deposit: 100000,
+		duration: 20,
+		subscription: None,
+		privacy: Some(PrivacyParams { data_owner_fee_ratio: 1000 }),
+		min_agency_reputation: None,
+	};
+	assert_ok!(DataMarket::make_purchase_contract(
+		RuntimeOrigin::signed(data_buyer.clone()),
+		params,
+		false
+	));
+}
+
+/// One-time key which a data owner commits to in privacy mode
+fn test_owner_key(seed: u8) -> sr25519::Pair {
+	sr25519::Pair::from_seed(&[seed; 32])
+}
+
+fn test_private_data_trade(owner_key: &sr25519::Pair) -> PrivateDataTradeParams<u64> {
+	PrivateDataTradeParams {
+		owner_commitment: sr25519_owner_commitment(&owner_key.public()),
+		data_issuer: vec![(30, 100)],
+		data_issuer_fee_ratio: 1000,
+	}
+}
+
+/// Proof of the claim of a note of the contract to the beneficiary, signed by the one-time key
+fn test_payout_claim_proof(
+	owner_key: &sr25519::Pair,
+	contract_id: ContractId,
+	beneficiary: u64,
+) -> PayoutClaimProof {
+	let claim = (contract_id, beneficiary).encode();
+	let signature = owner_key.sign(&[PAYOUT_CLAIM_CONTEXT, &claim[..]].concat());
+	(owner_key.public(), signature).encode().try_into().unwrap()
+}
+
+fn test_data_trade(data_owner: u64, agency: Option<u64>) -> DataTradeParams<u64> {
+	DataTradeParams {
+		data_owner,
+		data_issuer: vec![(data_owner.clone(), 100)],
+		data_owner_fee_ratio: 1000,
+		data_issuer_fee_ratio: 1000,
+		agency_fee_ratio: agency.map(|_| 1000),
+		agency,
+		data_verification_proof: bounded_vec![1],
+	}
+}
+
 fn sign_test_delegate_contract(data_owner: u64, contract_id: u128) {
 	assert_ok!(DataMarket::sign_delegate_contract(
 		RuntimeOrigin::signed(data_owner.clone()),
@@ -104,7 +269,7 @@ fn sign_delegate_contract_works() {
 #[test]
 fn make_purchase_contract_without_agency_works() {
 	new_test_ext().execute_with(|| {
-		let contract_id = 0;
+		let contract_id = 1;
 		let data_buyer = 10;
 		let data_verifier = 20;
 		make_test_purchase_contract_without_agency(data_buyer.clone(), data_verifier.clone());
@@ -117,7 +282,7 @@ fn make_purchase_contract_without_agency_works() {
 #[test]
 fn make_purchase_contract_with_agency_works() {
 	new_test_ext().execute_with(|| {
-		let contract_id = 0;
+		let contract_id = 1;
 		let data_buyer = 10;
 		let agency = 20;
 		make_test_purchase_contract_with_agency(data_buyer.clone(), agency.clone());
@@ -130,7 +295,7 @@ fn make_purchase_contract_with_agency_works() {
 #[test]
 fn sign_purchase_contract_with_agency_works() {
 	new_test_ext().execute_with(|| {
-		let contract_id = 0;
+		let contract_id = 1;
 		let data_buyer = 10;
 		let agency = 20;
 		let data_verifier = 30;
@@ -142,6 +307,32 @@ fn sign_purchase_contract_with_agency_works() {
 	});
 }
 
+#[test]
+fn contract_ids_share_a_counter_and_encode_contract_type() {
+	new_test_ext().execute_with(|| {
+		let data_buyer = 10;
+		let data_owner = 11;
+		let agency = 20;
+		let data_verifier = 30;
+		// Purchase contracts take odd ids and delegate contracts even ids
+		make_test_purchase_contract_without_agency(data_buyer.clone(), data_verifier.clone());
+		make_test_delegate_contract(data_owner.clone(), agency.clone());
+		make_test_purchase_contract_with_agency(data_buyer.clone(), agency.clone());
+		assert_eq!(DataMarket::get_next_contract_id(), 3);
+		assert!(DataMarket::get_data_purchase_contracts(1).is_some());
+		assert!(DataMarket::get_data_delegate_contracts(2).is_some());
+		assert!(DataMarket::get_data_purchase_contracts(5).is_some());
+		assert_eq!(
+			DataMarket::get_contract_list(data_buyer, ContractType::Purchase).to_vec(),
+			vec![1, 5]
+		);
+		assert_eq!(DataMarket::get_contract_list(agency, ContractType::Purchase).to_vec(), vec![5]);
+		assert_eq!(DataMarket::get_contract_list(agency, ContractType::Delegate).to_vec(), vec![2]);
+		assert!(DataMarket::get_data_delegate_contracts(1).is_none());
+		assert!(DataMarket::get_data_purchase_contracts(2).is_none());
+	});
+}
+
 #[test]
 fn terminate_delegate_contract_before_active_works() {
 	new_test_ext().execute_with(|| {
@@ -192,10 +383,17 @@ fn terminate_delegate_contract_after_active_works() {
 #[test]
 fn terminate_purchase_contract_without_agency_works() {
 	new_test_ext().execute_with(|| {
-		let contract_id = 0;
+		let contract_id = 1;
 		let data_buyer = 10;
 		let data_verifier = 20;
 		make_test_purchase_contract_without_agency(data_buyer.clone(), data_verifier.clone());
+		assert_noop!(
+			DataMarket::terminate_purchase_contract(
+				RuntimeOrigin::signed(data_verifier.clone()),
+				contract_id.clone()
+			),
+			Error::<Test>::NotSigned
+		);
 		assert_ok!(DataMarket::terminate_purchase_contract(
 			RuntimeOrigin::signed(data_buyer.clone()),
 			contract_id.clone()
@@ -210,7 +408,7 @@ fn terminate_purchase_contract_without_agency_works() {
 #[test]
 fn terminate_purchase_contract_with_agency_before_active_works() {
 	new_test_ext().execute_with(|| {
-		let contract_id = 0;
+		let contract_id = 1;
 		let data_buyer = 10;
 		let agency = 20;
 		make_test_purchase_contract_with_agency(data_buyer.clone(), agency.clone());
@@ -228,7 +426,7 @@ fn terminate_purchase_contract_with_agency_before_active_works() {
 #[test]
 fn terminate_purchase_contract_with_agency_after_active_works() {
 	new_test_ext().execute_with(|| {
-		let contract_id = 0;
+		let contract_id = 1;
 		let data_buyer = 10;
 		let agency = 20;
 		let data_verifier = 30;
@@ -258,7 +456,7 @@ fn terminate_purchase_contract_with_agency_after_active_works() {
 #[test]
 fn execute_data_trade_without_agency_works() {
 	new_test_ext().execute_with(|| {
-		let contract_id = 0;
+		let contract_id = 1;
 		let data_buyer = 10;
 		let data_verifier = 20;
 		make_test_purchase_contract_without_agency(data_buyer.clone(), data_verifier.clone());
@@ -274,7 +472,7 @@ fn execute_data_trade_without_agency_works() {
 		let data_owner_fee_ratio: u32 = 1000;
 		let data_issuer_fee_ratio: u32 = 1000;
 		let price_per_data: u128 = 1000;
-		let data_verification_proof = VerificationProof::<AnyText>::new(bounded_vec![1]);
+		let data_verification_proof: AnyText = bounded_vec![1];
 
 		let data_owner_fee: u128 = price_per_data * (data_owner_fee_ratio as u128) / 10000;
 		let data_issuer_fee: u128 = price_per_data * (data_issuer_fee_ratio as u128) / 10000;
@@ -289,7 +487,6 @@ fn execute_data_trade_without_agency_works() {
 			data_issuer_fee_ratio.clone(),
 			None,
 			None,
-			price_per_data,
 			data_verification_proof.clone()
 		));
 		System::assert_last_event(RuntimeEvent::DataMarket(crate::Event::DataTradeExecuted {
@@ -307,7 +504,7 @@ fn execute_data_trade_without_agency_works() {
 #[test]
 fn execute_data_trade_with_agency_works() {
 	new_test_ext().execute_with(|| {
-		let contract_id = 0;
+		let contract_id = 1;
 		let data_buyer = 10;
 		let agency = 20;
 		let data_verifier = 30;
@@ -318,12 +515,13 @@ fn execute_data_trade_with_agency_works() {
 		));
 
 		let data_owner = 11;
+		delegate_test_data(data_owner.clone(), agency.clone());
 		let data_issuer = vec![(data_owner.clone(), 100)];
 		let data_owner_fee_ratio: u32 = 1000;
 		let data_issuer_fee_ratio: u32 = 1000;
 		let agency_fee_ratio: u32 = 1000;
 		let price_per_data: u128 = 1000;
-		let data_verification_proof = VerificationProof::<AnyText>::new(bounded_vec![1]);
+		let data_verification_proof: AnyText = bounded_vec![1];
 
 		let data_owner_fee: u128 = price_per_data * (data_owner_fee_ratio as u128) / 10000;
 		let data_issuer_fee: u128 = price_per_data * (data_issuer_fee_ratio as u128) / 10000;
@@ -339,7 +537,6 @@ fn execute_data_trade_with_agency_works() {
 			data_issuer_fee_ratio.clone(),
 			Some(agency),
 			Some(agency_fee_ratio.clone()),
-			price_per_data,
 			data_verification_proof.clone()
 		));
 		System::assert_last_event(RuntimeEvent::DataMarket(crate::Event::DataTradeExecuted {
@@ -353,3 +550,972 @@ fn execute_data_trade_with_agency_works() {
 		}));
 	});
 }
+
+#[test]
+fn execute_data_trades_works() {
+	new_test_ext().execute_with(|| {
+		let contract_id = 1;
+		let data_buyer = 10;
+		let data_verifier = 20;
+		let price_per_data: u128 = 1000;
+		make_test_purchase_contract_without_agency(data_buyer.clone(), data_verifier.clone());
+
+		let data_owners = vec![11, 12, 13];
+		let trades: Vec<DataTradeParams<u64>> = data_owners
+			.iter()
+			.map(|data_owner| test_data_trade(*data_owner, None))
+			.collect();
+		assert_ok!(DataMarket::execute_data_trades(
+			RuntimeOrigin::signed(data_verifier.clone()),
+			contract_id.clone(),
+			trades.try_into().unwrap()
+		));
+
+		assert_eq!(DataMarket::get_trade_count_for_contract(contract_id), 3);
+		assert_eq!(
+			DataMarket::get_data_purchase_contracts(contract_id).unwrap().deposit,
+			100000 - 3 * price_per_data
+		);
+		for data_owner in data_owners.iter() {
+			assert_eq!(DataMarket::get_data_trade_records(contract_id, data_owner), Some(1));
+		}
+
+		// Trade records are paginated in storage order
+		let first_page = DataMarket::data_trade_records(contract_id, None, 2);
+		assert_eq!(first_page.len(), 2);
+		let last_data_owner = first_page.last().map(|(data_owner, _)| *data_owner);
+		let second_page = DataMarket::data_trade_records(contract_id, last_data_owner, 2);
+		assert_eq!(second_page.len(), 1);
+		let mut paged: Vec<u64> = first_page
+			.into_iter()
+			.chain(second_page)
+			.map(|(data_owner, _)| data_owner)
+			.collect();
+		paged.sort();
+		assert_eq!(paged, data_owners);
+	});
+}
+
+#[test]
+fn execute_data_trades_fails_for_data_owner_sold_already_without_agency() {
+	new_test_ext().execute_with(|| {
+		let contract_id = 1;
+		let data_buyer = 10;
+		let data_verifier = 20;
+		make_test_purchase_contract_without_agency(data_buyer.clone(), data_verifier.clone());
+
+		let trades = vec![test_data_trade(11, None), test_data_trade(11, None)];
+		assert_noop!(
+			DataMarket::execute_data_trades(
+				RuntimeOrigin::signed(data_verifier.clone()),
+				contract_id.clone(),
+				trades.try_into().unwrap()
+			),
+			Error::<Test>::AlreadyPurchased
+		);
+		assert_eq!(DataMarket::get_trade_count_for_contract(contract_id), 0);
+	});
+}
+
+#[test]
+fn execute_data_trades_with_agency_records_data_owner_once() {
+	new_test_ext().execute_with(|| {
+		let contract_id = 1;
+		let data_buyer = 10;
+		let agency = 20;
+		let data_verifier = 30;
+		make_test_purchase_contract_with_agency(data_buyer.clone(), agency.clone());
+		sign_test_purchase_contract(agency.clone(), contract_id.clone(), data_verifier.clone());
+		delegate_test_data(11, agency.clone());
+
+		let trades = vec![test_data_trade(11, Some(agency)), test_data_trade(11, Some(agency))];
+		assert_ok!(DataMarket::execute_data_trades(
+			RuntimeOrigin::signed(data_verifier.clone()),
+			contract_id.clone(),
+			trades.try_into().unwrap()
+		));
+
+		assert_eq!(DataMarket::get_trade_count_for_contract(contract_id), 2);
+		assert_eq!(DataMarket::get_data_trade_records(contract_id, 11u64), Some(2));
+		assert_eq!(DataMarket::data_trade_records(contract_id, None, 10), vec![(11, 2)]);
+	});
+}
+
+#[test]
+fn execute_data_trades_fails_for_insufficient_deposit() {
+	new_test_ext().execute_with(|| {
+		let contract_id = 1;
+		let data_buyer = 10;
+		let data_verifier = 20;
+		make_test_purchase_contract_without_agency(data_buyer.clone(), data_verifier.clone());
+		DataPurchaseContracts::<Test>::mutate(contract_id, |detail| {
+			detail.as_mut().unwrap().deposit = 1500;
+		});
+
+		let trades = vec![test_data_trade(11, None), test_data_trade(12, None)];
+		assert_noop!(
+			DataMarket::execute_data_trades(
+				RuntimeOrigin::signed(data_verifier.clone()),
+				contract_id.clone(),
+				trades.try_into().unwrap()
+			),
+			Error::<Test>::InsufficientDeposit
+		);
+	});
+}
+
+#[test]
+fn make_purchase_contract_fails_for_insufficient_balance_of_data_buyer() {
+	new_test_ext().execute_with(|| {
+		let data_buyer = 12;
+		let data_verifier = 20;
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(10), 1, data_buyer, 1000));
+		let params = DataPurchaseContractParams {
+			data_verifier: Some(data_verifier),
+			data_purchase_info: DataPurchaseInfo::<AnyText>::new(bounded_vec![1], bounded_vec![1]),
+			system_token_id: 1,
+			agency: None,
+			price_per_data: 1000,
+			deposit: 2000,
+			duration: 20,
+			subscription: None,
+			privacy: None,
+			min_agency_reputation: None,
+		};
+		assert_noop!(
+			DataMarket::make_purchase_contract(RuntimeOrigin::signed(data_buyer), params, false),
+			TokenError::FundsUnavailable
+		);
+	});
+}
+
+#[test]
+fn make_delegate_contract_records_consent_of_data_subject() {
+	new_test_ext().execute_with(|| {
+		let contract_id = 0;
+		let data_owner = 10;
+		let agency = 20;
+		make_test_delegate_contract(data_owner.clone(), agency.clone());
+
+		let detail = DataMarket::get_data_delegate_contracts(contract_id).unwrap();
+		assert_eq!(detail.data_subject, DATA_SUBJECT);
+		assert_eq!(
+			DataMarket::get_data_consents(DATA_SUBJECT, detail.consent_revocation),
+			Some(contract_id)
+		);
+		// Consent is in effect once the data owner signs the contract
+		assert_eq!(DataMarket::active_consents(DATA_SUBJECT), vec![]);
+		sign_test_delegate_contract(data_owner.clone(), contract_id.clone());
+		assert_eq!(DataMarket::active_consents(DATA_SUBJECT), vec![(contract_id, agency)]);
+	});
+}
+
+#[test]
+fn make_delegate_contract_fails_for_invalid_consent() {
+	new_test_ext().execute_with(|| {
+		let data_owner = 10;
+		let agency = 20;
+		let pair = create_test_data_subject();
+		accredit_test_agency(agency.clone());
+		assert_ok!(DataMarket::register_agency(RuntimeOrigin::signed(agency.clone())));
+
+		// Signed for another agency
+		let params = test_delegate_contract_params(data_owner.clone(), 21, 1, &pair);
+		assert_noop!(
+			DataMarket::make_delegate_contract(RuntimeOrigin::signed(agency.clone()), params),
+			Error::<Test>::InvalidConsentSignature
+		);
+
+		// Signed by a key which is not of the DID
+		let other_pair = sr25519::Pair::from_seed(&[2; 32]);
+		let params =
+			test_delegate_contract_params(data_owner.clone(), agency.clone(), 1, &other_pair);
+		assert_noop!(
+			DataMarket::make_delegate_contract(RuntimeOrigin::signed(agency.clone()), params),
+			Error::<Test>::InvalidConsentSignature
+		);
+
+		// Revocation registry is not controlled by the data subject
+		let mut params =
+			test_delegate_contract_params(data_owner.clone(), agency.clone(), 1, &pair);
+		params.consent_revocation.registry_id = RegistryId([2; 32]);
+		let message = DataMarket::data_consent_message(&agency, &params);
+		params.consent_signature.sig = SigValue::sr25519(&message, &pair);
+		assert_noop!(
+			DataMarket::make_delegate_contract(RuntimeOrigin::signed(agency.clone()), params),
+			Error::<Test>::ConsentNotRevocable
+		);
+
+		// Consent can be given to a contract only once
+		let params = test_delegate_contract_params(data_owner.clone(), agency.clone(), 1, &pair);
+		assert_ok!(DataMarket::make_delegate_contract(
+			RuntimeOrigin::signed(agency.clone()),
+			params.clone()
+		));
+		assert_noop!(
+			DataMarket::make_delegate_contract(RuntimeOrigin::signed(agency.clone()), params),
+			Error::<Test>::ConsentAlreadyUsed
+		);
+
+		// Consent has been revoked
+		revoke::Revocations::<Test>::insert(CONSENT_REGISTRY, RevokeId([2; 32]), ());
+		let params = test_delegate_contract_params(data_owner.clone(), agency.clone(), 2, &pair);
+		assert_noop!(
+			DataMarket::make_delegate_contract(RuntimeOrigin::signed(agency.clone()), params),
+			Error::<Test>::ConsentRevoked
+		);
+	});
+}
+
+#[test]
+fn make_delegate_contract_fails_for_expired_consent() {
+	new_test_ext().execute_with(|| {
+		let data_owner = 10;
+		let agency = 20;
+		let pair = create_test_data_subject();
+		accredit_test_agency(agency.clone());
+		assert_ok!(DataMarket::register_agency(RuntimeOrigin::signed(agency.clone())));
+
+		let params = test_delegate_contract_params(data_owner.clone(), agency.clone(), 1, &pair);
+		System::set_block_number(params.consent_expires_at);
+		assert_noop!(
+			DataMarket::make_delegate_contract(RuntimeOrigin::signed(agency.clone()), params),
+			Error::<Test>::ConsentExpired
+		);
+	});
+}
+
+#[test]
+fn consent_cannot_be_used_again_after_contract_is_terminated() {
+	new_test_ext().execute_with(|| {
+		let contract_id = 0;
+		let data_owner = 10;
+		let agency = 20;
+		let pair = create_test_data_subject();
+		accredit_test_agency(agency.clone());
+		assert_ok!(DataMarket::register_agency(RuntimeOrigin::signed(agency.clone())));
+
+		let params = test_delegate_contract_params(data_owner.clone(), agency.clone(), 1, &pair);
+		assert_ok!(DataMarket::make_delegate_contract(
+			RuntimeOrigin::signed(agency.clone()),
+			params.clone()
+		));
+		assert_ok!(DataMarket::terminate_delegate_contract(
+			RuntimeOrigin::signed(agency.clone()),
+			contract_id.clone()
+		));
+		assert_eq!(DataMarket::get_data_delegate_contracts(contract_id), None);
+
+		assert_noop!(
+			DataMarket::make_delegate_contract(RuntimeOrigin::signed(agency.clone()), params),
+			Error::<Test>::ConsentAlreadyUsed
+		);
+	});
+}
+
+#[test]
+fn execute_data_trade_fails_for_revoked_consent() {
+	new_test_ext().execute_with(|| {
+		let contract_id = 1;
+		let data_buyer = 10;
+		let agency = 20;
+		let data_verifier = 30;
+		let data_owner = 11;
+		make_test_purchase_contract_with_agency(data_buyer.clone(), agency.clone());
+		sign_test_purchase_contract(agency.clone(), contract_id.clone(), data_verifier.clone());
+
+		// Data owner has not delegated data to the agency
+		let trades = vec![test_data_trade(data_owner.clone(), Some(agency))];
+		assert_noop!(
+			DataMarket::execute_data_trades(
+				RuntimeOrigin::signed(data_verifier.clone()),
+				contract_id.clone(),
+				trades.clone().try_into().unwrap()
+			),
+			Error::<Test>::NoActiveConsent
+		);
+
+		let delegate_contract_id = delegate_test_data(data_owner.clone(), agency.clone());
+		assert_eq!(DataMarket::active_consents(DATA_SUBJECT), vec![(delegate_contract_id, agency)]);
+		assert_ok!(DataMarket::execute_data_trades(
+			RuntimeOrigin::signed(data_verifier.clone()),
+			contract_id.clone(),
+			trades.clone().try_into().unwrap()
+		));
+
+		// Data subject revokes the consent
+		revoke::Revocations::<Test>::insert(CONSENT_REGISTRY, RevokeId([1; 32]), ());
+		assert_eq!(DataMarket::active_consents(DATA_SUBJECT), vec![]);
+		assert_noop!(
+			DataMarket::execute_data_trades(
+				RuntimeOrigin::signed(data_verifier.clone()),
+				contract_id.clone(),
+				trades.try_into().unwrap()
+			),
+			Error::<Test>::NoActiveConsent
+		);
+	});
+}
+
+#[test]
+fn execute_data_trade_without_agency_fee_fails_without_consent() {
+	new_test_ext().execute_with(|| {
+		let contract_id = 1;
+		let data_buyer = 10;
+		let agency = 20;
+		let data_verifier = 30;
+		let data_owner = 11;
+		make_test_purchase_contract_with_agency(data_buyer.clone(), agency.clone());
+		sign_test_purchase_contract(agency.clone(), contract_id.clone(), data_verifier.clone());
+
+		// Trade on the contract of the agency does not name the agency
+		let trades = vec![test_data_trade(data_owner.clone(), None)];
+		assert_noop!(
+			DataMarket::execute_data_trades(
+				RuntimeOrigin::signed(data_verifier.clone()),
+				contract_id.clone(),
+				trades.clone().try_into().unwrap()
+			),
+			Error::<Test>::NoActiveConsent
+		);
+
+		delegate_test_data(data_owner.clone(), agency.clone());
+		assert_ok!(DataMarket::execute_data_trades(
+			RuntimeOrigin::signed(data_verifier.clone()),
+			contract_id.clone(),
+			trades.try_into().unwrap()
+		));
+	});
+}
+
+#[test]
+fn subscription_budget_limits_data_trades_in_period() {
+	new_test_ext().execute_with(|| {
+		let contract_id = 1;
+		let data_buyer = 10;
+		let data_verifier = 20;
+		make_test_subscription_contract(data_buyer.clone(), None, Some(data_verifier), 3000);
+
+		assert_ok!(DataMarket::execute_data_trades(
+			RuntimeOrigin::signed(data_verifier.clone()),
+			contract_id.clone(),
+			vec![test_data_trade(11, None), test_data_trade(12, None)].try_into().unwrap()
+		));
+		System::assert_has_event(RuntimeEvent::DataMarket(crate::Event::LowDeposit {
+			contract_id,
+			deposit: 1000,
+		}));
+
+		assert_noop!(
+			DataMarket::execute_data_trades(
+				RuntimeOrigin::signed(data_verifier.clone()),
+				contract_id.clone(),
+				vec![test_data_trade(13, None)].try_into().unwrap()
+			),
+			Error::<Test>::BudgetExceeded
+		);
+	});
+}
+
+#[test]
+fn subscription_is_renewed_from_balance_of_data_buyer() {
+	new_test_ext().execute_with(|| {
+		let contract_id = 1;
+		let data_buyer = 10;
+		let data_verifier = 20;
+		make_test_subscription_contract(data_buyer.clone(), None, Some(data_verifier), 2000);
+		assert_ok!(DataMarket::execute_data_trades(
+			RuntimeOrigin::signed(data_verifier.clone()),
+			contract_id.clone(),
+			vec![test_data_trade(11, None)].try_into().unwrap()
+		));
+		let buyer_balance = Assets::balance(&1, data_buyer);
+		let platform_balance = Assets::balance(&1, DataMarket::get_platform_account());
+
+		// The unspent budget is carried over and the deposit is topped up to the budget
+		System::set_block_number(21);
+		DataMarket::on_initialize(21);
+		System::assert_last_event(RuntimeEvent::DataMarket(crate::Event::RenewSubscription {
+			contract_id,
+			charged: 1000,
+			expired_at: 41,
+		}));
+		let detail = DataMarket::get_data_purchase_contracts(contract_id).unwrap();
+		assert_eq!(detail.deposit, 2000);
+		assert_eq!(detail.subscription.unwrap().spent_in_period, 0);
+		assert_eq!(Assets::balance(&1, data_buyer), buyer_balance - 1000);
+		assert_eq!(Assets::balance(&1, DataMarket::get_platform_account()), platform_balance);
+		assert_eq!(DataMarket::get_subscription_renewals(41).into_inner(), vec![contract_id]);
+
+		// The contract is left to expire once auto renewal is turned off
+		assert_ok!(DataMarket::set_auto_renew(
+			RuntimeOrigin::signed(data_buyer.clone()),
+			contract_id.clone(),
+			false
+		));
+		System::set_block_number(41);
+		DataMarket::on_initialize(41);
+		assert_eq!(DataMarket::get_data_purchase_contracts(contract_id).unwrap().expired_at, 41);
+		assert!(DataMarket::get_subscription_renewals(61).is_empty());
+	});
+}
+
+#[test]
+fn subscription_renewal_fails_for_insufficient_balance() {
+	new_test_ext().execute_with(|| {
+		let contract_id = 1;
+		let data_buyer = 12;
+		let data_verifier = 20;
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(10), 1, data_buyer, 2001));
+		make_test_subscription_contract(data_buyer.clone(), None, Some(data_verifier), 2000);
+		assert_ok!(DataMarket::execute_data_trades(
+			RuntimeOrigin::signed(data_verifier.clone()),
+			contract_id.clone(),
+			vec![test_data_trade(11, None)].try_into().unwrap()
+		));
+
+		System::set_block_number(21);
+		DataMarket::on_initialize(21);
+		System::assert_last_event(RuntimeEvent::DataMarket(
+			crate::Event::RenewSubscriptionFailed { contract_id },
+		));
+		assert_eq!(DataMarket::get_data_purchase_contracts(contract_id).unwrap().expired_at, 21);
+		assert!(DataMarket::get_subscription_renewals(41).is_empty());
+	});
+}
+
+#[test]
+fn subscription_renewal_is_delayed_when_blocks_are_full() {
+	new_test_ext().execute_with(|| {
+		let contract_id = 1;
+		let data_buyer = 10;
+		let data_verifier = 20;
+		let full: BoundedVec<ContractId, ConstU32<10>> = bounded_vec![101; 10];
+		SubscriptionRenewals::<Test>::insert(21, full.clone());
+		make_test_subscription_contract(data_buyer.clone(), None, Some(data_verifier), 2000);
+		assert_eq!(DataMarket::get_subscription_renewals(22).into_inner(), vec![contract_id]);
+
+		// The contract stays active past the end of the period until it is renewed
+		System::set_block_number(21);
+		DataMarket::on_initialize(21);
+		assert_ok!(DataMarket::execute_data_trades(
+			RuntimeOrigin::signed(data_verifier.clone()),
+			contract_id.clone(),
+			vec![test_data_trade(11, None)].try_into().unwrap()
+		));
+
+		// Renewed at the delayed block, keeping the end of the period
+		System::set_block_number(22);
+		DataMarket::on_initialize(22);
+		System::assert_last_event(RuntimeEvent::DataMarket(crate::Event::RenewSubscription {
+			contract_id,
+			charged: 1000,
+			expired_at: 41,
+		}));
+
+		// No block up to `MAX_RENEWAL_DELAY` after the end of the period has room
+		for renew_at in 41..=41 + MAX_RENEWAL_DELAY as u64 {
+			SubscriptionRenewals::<Test>::insert(renew_at, full.clone());
+		}
+		let params = DataPurchaseContractParams {
+			data_verifier: Some(data_verifier),
+			data_purchase_info: DataPurchaseInfo::<AnyText>::new(bounded_vec![1], bounded_vec![1]),
+			system_token_id: 1,
+			agency: None,
+			price_per_data: 1000,
+			deposit: 2000,
+			duration: 19,
+			subscription: Some(SubscriptionParams {
+				budget_per_period: 2000,
+				low_deposit_threshold: 1500,
+				auto_renew: true,
+			}),
+			privacy: None,
+			min_agency_reputation: None,
+		};
+		assert_noop!(
+			DataMarket::make_purchase_contract(RuntimeOrigin::signed(data_buyer), params, false),
+			Error::<Test>::TooManyRenewals
+		);
+	});
+}
+
+#[test]
+fn terminate_subscription_refunds_unspent_budget() {
+	new_test_ext().execute_with(|| {
+		let contract_id = 1;
+		let data_buyer = 10;
+		let agency = 20;
+		let data_verifier = 30;
+		make_test_subscription_contract(data_buyer.clone(), Some(agency), None, 3000);
+		sign_test_purchase_contract(agency.clone(), contract_id.clone(), data_verifier.clone());
+		delegate_test_data(11, agency.clone());
+		assert_ok!(DataMarket::execute_data_trades(
+			RuntimeOrigin::signed(data_verifier.clone()),
+			contract_id.clone(),
+			vec![test_data_trade(11, None)].try_into().unwrap()
+		));
+
+		// Half of the period is left, so half of the unspent budget is refunded with the rest of
+		// the deposit and the other half is forfeited
+		System::set_block_number(11);
+		let buyer_balance = Assets::balance(&1, data_buyer);
+		let platform_balance = Assets::balance(&1, DataMarket::get_platform_account());
+		assert_ok!(DataMarket::terminate_purchase_contract(
+			RuntimeOrigin::signed(data_buyer.clone()),
+			contract_id.clone()
+		));
+		assert_ok!(DataMarket::terminate_purchase_contract(
+			RuntimeOrigin::signed(agency.clone()),
+			contract_id.clone()
+		));
+		System::assert_has_event(RuntimeEvent::DataMarket(crate::Event::RefundSubscription {
+			contract_id,
+			refunded: 1500,
+			forfeited: 500,
+		}));
+		assert_eq!(Assets::balance(&1, data_buyer), buyer_balance + 1500);
+		assert_eq!(Assets::balance(&1, DataMarket::get_platform_account()), platform_balance + 500);
+		System::assert_last_event(RuntimeEvent::DataMarket(crate::Event::ContractTerminated {
+			contract_type: ContractType::Purchase,
+			contract_id,
+		}));
+	});
+}
+
+#[test]
+fn private_data_trades_pay_data_owners_through_claimable_notes() {
+	new_test_ext().execute_with(|| {
+		let contract_id = 1;
+		let data_buyer = 10;
+		let data_verifier = 20;
+		let beneficiary = 50;
+		let (owner_key, other_owner_key) = (test_owner_key(1), test_owner_key(2));
+		make_test_private_contract(data_buyer.clone(), data_verifier.clone());
+
+		// Data owners cannot be named in clear
+		assert_noop!(
+			DataMarket::execute_data_trades(
+				RuntimeOrigin::signed(data_verifier.clone()),
+				contract_id.clone(),
+				vec![test_data_trade(11, None)].try_into().unwrap()
+			),
+			Error::<Test>::PrivateContract
+		);
+		assert_noop!(
+			DataMarket::execute_data_trade(
+				RuntimeOrigin::signed(data_verifier.clone()),
+				contract_id.clone(),
+				11,
+				vec![(11, 100)],
+				1000,
+				1000,
+				None,
+				None,
+				bounded_vec![1]
+			),
+			Error::<Test>::PrivateContract
+		);
+		assert_ok!(DataMarket::execute_private_data_trades(
+			RuntimeOrigin::signed(data_verifier.clone()),
+			contract_id.clone(),
+			vec![test_private_data_trade(&owner_key), test_private_data_trade(&other_owner_key)]
+				.try_into()
+				.unwrap()
+		));
+		System::assert_last_event(RuntimeEvent::DataMarket(
+			crate::Event::PrivateDataTradeExecuted { contract_id, quantity: 2 },
+		));
+		assert_eq!(DataMarket::get_trade_count_for_contract(contract_id), 2);
+		assert_eq!(DataMarket::payout_commitments(contract_id).len(), 2);
+		assert_eq!(DataTradeRecords::<Test>::iter_prefix(contract_id).count(), 0);
+		assert_noop!(
+			DataMarket::execute_private_data_trades(
+				RuntimeOrigin::signed(data_verifier.clone()),
+				contract_id.clone(),
+				vec![test_private_data_trade(&owner_key)].try_into().unwrap()
+			),
+			Error::<Test>::AlreadyPurchased
+		);
+
+		// The proof is bound to the beneficiary and to a key which is committed to
+		let nullifier = sr25519_owner_commitment(&owner_key.public());
+		for (key, signed_beneficiary) in [(&owner_key, 99), (&test_owner_key(3), beneficiary)] {
+			assert_noop!(
+				DataMarket::claim_private_payout(
+					RuntimeOrigin::signed(99),
+					contract_id.clone(),
+					nullifier,
+					beneficiary.clone(),
+					test_payout_claim_proof(key, contract_id, signed_beneficiary)
+				),
+				Error::<Test>::InvalidPayoutProof
+			);
+		}
+
+		// Anyone can submit the claim of a note to the beneficiary
+		let proof = test_payout_claim_proof(&owner_key, contract_id, beneficiary);
+		assert_ok!(DataMarket::claim_private_payout(
+			RuntimeOrigin::signed(99),
+			contract_id.clone(),
+			nullifier,
+			beneficiary.clone(),
+			proof.clone()
+		));
+		System::assert_last_event(RuntimeEvent::DataMarket(crate::Event::ClaimPrivatePayout {
+			contract_id,
+			nullifier,
+		}));
+		assert_eq!(Assets::balance(&1, beneficiary), 100);
+		assert_noop!(
+			DataMarket::claim_private_payout(
+				RuntimeOrigin::signed(99),
+				contract_id.clone(),
+				nullifier,
+				beneficiary.clone(),
+				proof
+			),
+			Error::<Test>::PayoutAlreadyClaimed
+		);
+		assert_eq!(DataMarket::get_private_payout_pools(contract_id).unwrap().claimed_count, 1);
+	});
+}
+
+#[test]
+fn make_private_contract_fails_with_agency_or_invalid_fee_ratio() {
+	new_test_ext().execute_with(|| {
+		let data_buyer = 10;
+		let agency = 20;
+		let params = DataPurchaseContractParams {
+			data_verifier: None,
+			data_purchase_info: DataPurchaseInfo::<AnyText>::new(bounded_vec![1], bounded_vec![1]),
+			system_token_id: 1,
+			agency: Some(agency.clone()),
+			price_per_data: 1000,
+			deposit: 100000,
+			duration: 20,
+			subscription: None,
+			privacy: Some(PrivacyParams { data_owner_fee_ratio: 1000 }),
+			min_agency_reputation: None,
+		};
+		assert_noop!(
+			DataMarket::make_purchase_contract(
+				RuntimeOrigin::signed(data_buyer.clone()),
+				params.clone(),
+				true
+			),
+			Error::<Test>::InvalidAgency
+		);
+
+		// The fee of the data owners leaves less than the minimum to the platform
+		let params = DataPurchaseContractParams {
+			data_verifier: Some(30),
+			agency: None,
+			privacy: Some(PrivacyParams { data_owner_fee_ratio: 9500 }),
+			..params
+		};
+		assert_noop!(
+			DataMarket::make_purchase_contract(
+				RuntimeOrigin::signed(data_buyer.clone()),
+				params,
+				false
+			),
+			Error::<Test>::InvalidFeeRatio
+		);
+	});
+}
+
+#[test]
+fn register_agency_requires_accreditation_and_holds_bond() {
+	new_test_ext().execute_with(|| {
+		let agency = 20;
+		let escrow_account = DataMarket::get_escrow_account();
+		let agency_balance = Assets::balance(&1, agency);
+		let escrow_balance = Assets::balance(&1, escrow_account);
+		assert_noop!(
+			DataMarket::register_agency(RuntimeOrigin::signed(agency.clone())),
+			Error::<Test>::AgencyNotAccredited
+		);
+
+		accredit_test_agency(agency.clone());
+		let mut entity_id = [0; 32];
+		entity_id[..8].copy_from_slice(&agency.to_le_bytes());
+		assert_eq!(DataMarket::agency_entity_id(&agency), TrustedEntityId(entity_id));
+		assert_ok!(DataMarket::register_agency(RuntimeOrigin::signed(agency.clone())));
+		assert_eq!(
+			DataMarket::get_agency_bonds(agency),
+			Some(AgencyBond { system_token_id: 1, amount: 1000 })
+		);
+		assert_eq!(Assets::balance(&1, agency), agency_balance - 1000);
+		assert_eq!(Assets::balance(&1, escrow_account), escrow_balance + 1000);
+
+		assert_ok!(DataMarket::deregister_agency(RuntimeOrigin::signed(agency.clone())));
+		assert_eq!(DataMarket::get_agency_bonds(agency), None);
+		assert_eq!(Assets::balance(&1, agency), agency_balance);
+	});
+}
+
+#[test]
+fn agency_registered_by_admin_bonds_with_top_up() {
+	new_test_ext().execute_with(|| {
+		let contract_id = 1;
+		let data_buyer = 10;
+		let agency = 20;
+		let data_verifier = 30;
+		assert_noop!(
+			DataMarket::top_up_agency_bond(RuntimeOrigin::signed(agency.clone())),
+			Error::<Test>::InvalidAgency
+		);
+
+		assert_ok!(DataMarket::register_agency_by_admin(RuntimeOrigin::root(), agency.clone()));
+		accredit_test_agency(agency.clone());
+		make_test_purchase_contract_with_agency(data_buyer.clone(), agency.clone());
+		assert_noop!(
+			DataMarket::sign_purchase_contract(
+				RuntimeOrigin::signed(agency.clone()),
+				contract_id.clone(),
+				data_verifier.clone()
+			),
+			Error::<Test>::BondNotToppedUp
+		);
+
+		let agency_balance = Assets::balance(&1, agency);
+		assert_ok!(DataMarket::top_up_agency_bond(RuntimeOrigin::signed(agency.clone())));
+		System::assert_last_event(RuntimeEvent::DataMarket(crate::Event::TopUpAgencyBond {
+			agency,
+			amount: 1000,
+		}));
+		assert_eq!(
+			DataMarket::get_agency_bonds(agency),
+			Some(AgencyBond { system_token_id: 1, amount: 1000 })
+		);
+		assert_eq!(Assets::balance(&1, agency), agency_balance - 1000);
+		sign_test_purchase_contract(agency.clone(), contract_id.clone(), data_verifier.clone());
+	});
+}
+
+#[test]
+fn agency_cannot_deregister_with_live_purchase_contracts() {
+	new_test_ext().execute_with(|| {
+		let contract_id = 1;
+		let data_buyer = 10;
+		let agency = 20;
+		let data_verifier = 30;
+		make_test_purchase_contract_with_agency(data_buyer.clone(), agency.clone());
+		sign_test_purchase_contract(agency.clone(), contract_id.clone(), data_verifier.clone());
+		assert_noop!(
+			DataMarket::deregister_agency(RuntimeOrigin::signed(agency.clone())),
+			Error::<Test>::AgencyHasLiveContracts
+		);
+		assert_noop!(
+			DataMarket::deregister_agency_by_admin(RuntimeOrigin::root(), agency.clone()),
+			Error::<Test>::AgencyHasLiveContracts
+		);
+
+		// A contract the agency has not signed does not hold it back
+		let next_contract_id = DataMarket::get_next_contract_id() << 1 | 1;
+		make_test_purchase_contract_with_agency(data_buyer.clone(), agency.clone());
+		for signer in [data_buyer, agency] {
+			assert_ok!(DataMarket::terminate_purchase_contract(
+				RuntimeOrigin::signed(signer),
+				contract_id.clone()
+			));
+		}
+		assert_ok!(DataMarket::deregister_agency(RuntimeOrigin::signed(agency.clone())));
+		assert!(DataMarket::get_data_purchase_contracts(next_contract_id).is_some());
+	});
+}
+
+#[test]
+fn open_dispute_fails_when_agency_holds_no_bond() {
+	new_test_ext().execute_with(|| {
+		let contract_id = 1;
+		let data_buyer = 10;
+		let agency = 20;
+		let data_verifier = 30;
+		make_test_purchase_contract_with_agency(data_buyer.clone(), agency.clone());
+		sign_test_purchase_contract(agency.clone(), contract_id.clone(), data_verifier.clone());
+		AgencyBonds::<Test>::remove(agency);
+
+		assert_noop!(
+			DataMarket::open_dispute(
+				RuntimeOrigin::signed(data_buyer.clone()),
+				contract_id.clone()
+			),
+			Error::<Test>::AgencyNotBonded
+		);
+	});
+}
+
+#[test]
+fn lost_dispute_slashes_bond_and_lowers_reputation() {
+	new_test_ext().execute_with(|| {
+		let contract_id = 1;
+		let data_buyer = 10;
+		let agency = 20;
+		let data_verifier = 30;
+		make_test_purchase_contract_with_agency(data_buyer.clone(), agency.clone());
+		sign_test_purchase_contract(agency.clone(), contract_id.clone(), data_verifier.clone());
+		delegate_test_data(11, agency.clone());
+		assert_ok!(DataMarket::execute_data_trades(
+			RuntimeOrigin::signed(data_verifier.clone()),
+			contract_id.clone(),
+			vec![test_data_trade(11, Some(agency))].try_into().unwrap()
+		));
+
+		assert_ok!(DataMarket::open_dispute(
+			RuntimeOrigin::signed(data_buyer.clone()),
+			contract_id.clone()
+		));
+		assert_noop!(
+			DataMarket::open_dispute(
+				RuntimeOrigin::signed(data_buyer.clone()),
+				contract_id.clone()
+			),
+			Error::<Test>::DisputeAlreadyOpen
+		);
+		assert_noop!(
+			DataMarket::deregister_agency(RuntimeOrigin::signed(agency.clone())),
+			Error::<Test>::AgencyInDispute
+		);
+
+		// Half of the bond is slashed to the data buyer
+		let buyer_balance = Assets::balance(&1, data_buyer);
+		assert_ok!(DataMarket::resolve_dispute(RuntimeOrigin::root(), contract_id.clone(), true));
+		System::assert_last_event(RuntimeEvent::DataMarket(crate::Event::ResolveDispute {
+			contract_id,
+			agency,
+			agency_at_fault: true,
+			slashed: 500,
+		}));
+		assert_eq!(Assets::balance(&1, data_buyer), buyer_balance + 500);
+		assert_eq!(DataMarket::get_agency_bonds(agency).unwrap().amount, 500);
+
+		// The agency cannot sign contracts until the bond is topped up
+		let next_contract_id = DataMarket::get_next_contract_id() << 1 | 1;
+		make_test_purchase_contract_with_agency(data_buyer.clone(), agency.clone());
+		assert_noop!(
+			DataMarket::sign_purchase_contract(
+				RuntimeOrigin::signed(agency.clone()),
+				next_contract_id.clone(),
+				data_verifier.clone()
+			),
+			Error::<Test>::BondNotToppedUp
+		);
+		assert_ok!(DataMarket::top_up_agency_bond(RuntimeOrigin::signed(agency.clone())));
+		System::assert_last_event(RuntimeEvent::DataMarket(crate::Event::TopUpAgencyBond {
+			agency,
+			amount: 500,
+		}));
+		assert_eq!(DataMarket::get_agency_bonds(agency).unwrap().amount, 1000);
+		assert_noop!(
+			DataMarket::top_up_agency_bond(RuntimeOrigin::signed(agency.clone())),
+			Error::<Test>::NoBondToTopUp
+		);
+		sign_test_purchase_contract(agency.clone(), next_contract_id, data_verifier.clone());
+
+		// The contract runs until it expires
+		System::set_block_number(22);
+		assert_ok!(DataMarket::terminate_purchase_contract(
+			RuntimeOrigin::signed(data_buyer.clone()),
+			contract_id.clone()
+		));
+		System::assert_has_event(RuntimeEvent::DataMarket(crate::Event::UpdateReputation {
+			agency,
+			score: 2500,
+		}));
+		let reputation = DataMarket::get_agency_reputations(agency);
+		assert_eq!(
+			(reputation.completed, reputation.disputes_lost, reputation.open_disputes),
+			(1, 1, 0)
+		);
+	});
+}
+
+#[test]
+fn agency_cannot_make_or_sign_contracts_once_accreditation_is_revoked() {
+	new_test_ext().execute_with(|| {
+		let contract_id = 1;
+		let data_buyer = 10;
+		let agency = 20;
+		let data_verifier = 30;
+		make_test_purchase_contract_with_agency(data_buyer.clone(), agency.clone());
+		let pair = create_test_data_subject();
+
+		trusted_entity::Issuers::<Test>::remove(
+			AgencyAuthorizer::get(),
+			DataMarket::agency_entity_id(&agency),
+		);
+		assert_noop!(
+			DataMarket::sign_purchase_contract(
+				RuntimeOrigin::signed(agency.clone()),
+				contract_id.clone(),
+				data_verifier.clone()
+			),
+			Error::<Test>::AgencyNotAccredited
+		);
+		let params = test_delegate_contract_params(11, agency.clone(), 1, &pair);
+		assert_noop!(
+			DataMarket::make_delegate_contract(RuntimeOrigin::signed(agency.clone()), params),
+			Error::<Test>::AgencyNotAccredited
+		);
+	});
+}
+
+#[test]
+fn reputation_ignores_contracts_with_agency_itself_or_without_trades() {
+	new_test_ext().execute_with(|| {
+		let agency = 20;
+		let data_verifier = 30;
+
+		// Contract of the agency with itself
+		make_test_purchase_contract_with_agency(agency.clone(), agency.clone());
+		sign_test_purchase_contract(agency.clone(), 1, data_verifier.clone());
+		// Contract with a data buyer which runs without any data trade
+		make_test_purchase_contract_with_agency(10, agency.clone());
+		sign_test_purchase_contract(agency.clone(), 3, data_verifier.clone());
+
+		System::set_block_number(22);
+		assert_ok!(DataMarket::terminate_purchase_contract(RuntimeOrigin::signed(agency), 1));
+		assert_ok!(DataMarket::terminate_purchase_contract(RuntimeOrigin::signed(10), 3));
+		assert_eq!(DataMarket::get_agency_reputations(agency), AgencyReputation::default());
+	});
+}
+
+#[test]
+fn purchase_contract_requires_minimum_agency_reputation() {
+	new_test_ext().execute_with(|| {
+		let data_buyer = 10;
+		let agency = 20;
+		let params = DataPurchaseContractParams {
+			data_verifier: None,
+			data_purchase_info: DataPurchaseInfo::<AnyText>::new(bounded_vec![1], bounded_vec![1]),
+			system_token_id: 1,
+			agency: Some(agency.clone()),
+			price_per_data: 1000,
+			deposit: 100000,
+			duration: 20,
+			subscription: None,
+			privacy: None,
+			min_agency_reputation: Some(5000),
+		};
+		assert_noop!(
+			DataMarket::make_purchase_contract(
+				RuntimeOrigin::signed(data_buyer.clone()),
+				params.clone(),
+				true
+			),
+			Error::<Test>::ReputationTooLow
+		);
+
+		AgencyReputations::<Test>::mutate(agency, |reputation| {
+			reputation.completed = 1;
+			reputation.update_score();
+		});
+		assert_ok!(DataMarket::make_purchase_contract(
+			RuntimeOrigin::signed(data_buyer.clone()),
+			params,
+			true
+		));
+	});
+}
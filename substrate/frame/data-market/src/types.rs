@@ -10,6 +10,13 @@ pub type IssuerWeight = u32;
 /// Common size is up to 100 bytes
 pub const MAX_TEXT_SIZE: u32 = 1_000_000;
 pub const MAX_ENTITIES: u32 = 2;
+/// Reputation score of an agency with only good records
+pub const MAX_REPUTATION: u32 = 10_000;
+/// A dispute lost by an agency weighs as much as this many terminated contracts
+pub const DISPUTE_LOST_WEIGHT: u32 = 3;
+/// Maximum number of blocks by which the renewal of a subscription is delayed when too many
+/// subscriptions are renewed at the end of its period
+pub const MAX_RENEWAL_DELAY: u32 = 10;
 
 pub type AssetBalanceOf<T> =
 	<<T as Config>::Assets as Inspect<<T as SystemConfig>::AccountId>>::Balance;
@@ -45,8 +52,28 @@ impl<BoundedString> DataPurchaseInfo<BoundedString> {
 	}
 }
 
+/// Entry of a did-core revocation registry which withdraws the consent of the data subject once it
+/// is revoked.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ConsentRevocation {
+	pub registry_id: RegistryId,
+	pub revoke_id: RevokeId,
+}
+
+/// Payload signed with a key of the data subject's DID to consent to a Data Delegate Contract.
+#[derive(Encode)]
+pub struct DataConsentPayload<'a, AccountId, BlockNumber> {
+	pub agency: &'a AccountId,
+	pub data_owner: &'a AccountId,
+	pub data_owner_minimum_fee_ratio: u32,
+	pub deligated_data: &'a AnyText,
+	pub duration: &'a BlockNumber,
+	pub consent_revocation: &'a ConsentRevocation,
+	pub consent_expires_at: &'a BlockNumber,
+}
+
 #[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo)]
-#[cfg_attr(feature = "std", derive(Hash, Debug))]
+#[cfg_attr(feature = "std", derive(Debug))]
 pub struct DataDelegateContractDetail<AccountId, BlockNumber> {
 	pub data_owner: AccountId,
 	pub agency: AccountId,
@@ -55,15 +82,22 @@ pub struct DataDelegateContractDetail<AccountId, BlockNumber> {
 	pub effective_at: BlockNumber,
 	pub expired_at: BlockNumber,
 	pub signed_status: ContractSigner<AccountId>,
+	pub data_subject: Did,
+	pub consent_revocation: ConsentRevocation,
 }
 
 #[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo)]
-#[cfg_attr(feature = "std", derive(Hash, Debug))]
+#[cfg_attr(feature = "std", derive(Debug))]
 pub struct DataDelegateContractParams<AccountId, BlockNumber> {
 	pub data_owner: AccountId,
 	pub data_owner_minimum_fee_ratio: u32,
 	pub deligated_data: AnyText,
 	pub duration: BlockNumber,
+	pub consent_revocation: ConsentRevocation,
+	// Block from which the consent can no longer be used to make a contract
+	pub consent_expires_at: BlockNumber,
+	// Signature of the `DataConsentPayload` by the DID of the data subject
+	pub consent_signature: DidSignature<Did>,
 }
 
 #[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo)]
@@ -78,9 +112,10 @@ pub struct DataPurchaseContractDetail<AccountId, BlockNumber, Balance> {
 	pub agency: Option<AccountId>,
 	pub price_per_data: Balance,
 	pub deposit: Balance,
-	pub trade_count: Quantity,
-	pub data_trade_record: Vec<AccountId>,
 	pub signed_status: ContractSigner<AccountId>,
+	pub subscription: Option<SubscriptionDetail<BlockNumber, Balance>>,
+	pub privacy: Option<PrivacyParams>,
+	pub min_agency_reputation: Option<u32>,
 }
 
 #[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo)]
@@ -92,7 +127,49 @@ pub struct DataPurchaseContractParams<AccountId, BlockNumber, Balance> {
 	pub agency: Option<AccountId>,
 	pub price_per_data: Balance,
 	pub deposit: Balance,
+	// Length of the contract, or of a period for a subscription contract
 	pub duration: BlockNumber,
+	pub subscription: Option<SubscriptionParams<Balance>>,
+	// Data owners are represented by commitments and paid through claimable notes
+	pub privacy: Option<PrivacyParams>,
+	// Minimum reputation score of the agency, checked when the agency signs the contract
+	pub min_agency_reputation: Option<u32>,
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Hash, Debug))]
+pub struct SubscriptionParams<Balance> {
+	// Maximum amount paid for the data trades in a period
+	pub budget_per_period: Balance,
+	// `LowDeposit` is emitted once the deposit falls below this
+	pub low_deposit_threshold: Balance,
+	// Whether the contract is renewed from the balance of the data buyer at the end of a period
+	pub auto_renew: bool,
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Hash, Debug))]
+pub struct SubscriptionDetail<BlockNumber, Balance> {
+	pub period: BlockNumber,
+	pub budget_per_period: Balance,
+	pub spent_in_period: Balance,
+	pub low_deposit_threshold: Balance,
+	pub auto_renew: bool,
+	// The block the renewal of the contract is queued at, which is up to `MAX_RENEWAL_DELAY`
+	// blocks after the end of the period
+	pub renew_at: BlockNumber,
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Hash, Debug))]
+pub struct DataTradeParams<AccountId> {
+	pub data_owner: AccountId,
+	pub data_issuer: Vec<(AccountId, IssuerWeight)>,
+	pub data_owner_fee_ratio: u32,
+	pub data_issuer_fee_ratio: u32,
+	pub agency: Option<AccountId>,
+	pub agency_fee_ratio: Option<u32>,
+	pub data_verification_proof: AnyText,
 }
 
 #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
@@ -115,3 +192,44 @@ pub struct MarketConfiguration {
 	pub total_fee_ratio: u32,
 	pub min_platform_fee_ratio: u32,
 }
+
+/// Bond held in the escrow while an agency is registered, which is slashed by the disputes it
+/// loses.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct AgencyBond<Balance> {
+	pub system_token_id: u32,
+	pub amount: Balance,
+}
+
+#[derive(
+	Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default,
+)]
+pub struct AgencyReputation {
+	// Purchase contracts which ran until they expired
+	pub completed: u32,
+	// Purchase contracts which were terminated before they expired
+	pub terminated: u32,
+	pub disputes_won: u32,
+	pub disputes_lost: u32,
+	pub open_disputes: u32,
+	// Share of the good records out of `MAX_REPUTATION`, which is zero without any record
+	pub score: u32,
+}
+
+impl AgencyReputation {
+	/// Update the score from the records
+	pub fn update_score(&mut self) {
+		let good = self.completed as u64 + self.disputes_won as u64;
+		let bad = self.terminated as u64 + self.disputes_lost as u64 * DISPUTE_LOST_WEIGHT as u64;
+		self.score = match good + bad {
+			0 => 0,
+			total => (good * MAX_REPUTATION as u64 / total) as u32,
+		};
+	}
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Dispute<AccountId> {
+	pub claimant: AccountId,
+	pub agency: AccountId,
+}
@@ -0,0 +1,433 @@
+//! Weights for `pallet_data_market`
+//!
+//! Not measured yet. Must be regenerated from `substrate/frame/data-market/src/benchmarking.rs`
+//! with the `benchmark pallet` subcommand on the reference hardware of the chain, once its
+//! benchmarks cover the calls below.
+//!
+//! Until then the ref times are rough estimates and the storage accesses are counted from the
+//! code along the worst-case path of each call. The calls by `AdminOrigin` share the weights of
+//! the calls they stand for. Parameters are
+//!
+//! - `n`: Number of data trades executed in a batch
+//! - `c`: Number of purchase contracts of an agency, counted with `MaxContracts` when it is
+//!   deregistered
+//! - `m`: Number of commitments of the payout pool of a contract in privacy mode
+//! - `r`: Number of subscription contracts scheduled to renew at a block
+//!
+//! A data trade is counted with one delegate contract of its data owner, and with the data owner
+//! and a data issuer paid from the escrow besides the platform and the agency. Scheduling a
+//! renewal is counted with every block up to `MAX_RENEWAL_DELAY` (10) full.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+pub trait WeightInfo {
+	fn make_delegate_contract() -> Weight;
+	fn sign_delegate_contract() -> Weight;
+	fn make_purchase_contract() -> Weight;
+	fn sign_purchase_contract() -> Weight;
+	fn terminate_delegate_contract() -> Weight;
+	fn terminate_purchase_contract() -> Weight;
+	fn execute_data_trades(n: u32) -> Weight;
+	fn register_agency() -> Weight;
+	fn deregister_agency(c: u32) -> Weight;
+	fn set_platform_config() -> Weight;
+	fn deposit_balance() -> Weight;
+	fn set_auto_renew() -> Weight;
+	fn execute_private_data_trades(n: u32) -> Weight;
+	fn claim_private_payout(m: u32) -> Weight;
+	fn open_dispute() -> Weight;
+	fn resolve_dispute() -> Weight;
+	fn top_up_agency_bond() -> Weight;
+	fn handle_subscription_renewals(r: u32) -> Weight;
+}
+
+/// Weight functions for `pallet_data_market`.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: DataMarket Agencies (r:1 w:0)
+	// Storage: TrustedEntity Issuers (r:1 w:0)
+	// Storage: DataMarket AgencyBonds (r:1 w:0)
+	// Storage: DIDModule DidKeys (r:1 w:0)
+	// Storage: DIDModule Dids (r:1 w:0)
+	// Storage: Revoke Registries (r:1 w:0)
+	// Storage: Revoke Revocations (r:1 w:0)
+	// Storage: DataMarket DataConsents (r:1 w:1)
+	// Storage: DataMarket NextContractId (r:1 w:1)
+	// Storage: DataMarket ContractList (r:2 w:2)
+	// Storage: DataMarket DataDelegateContracts (r:0 w:1)
+	fn make_delegate_contract() -> Weight {
+		Weight::from_parts(104_000_000, 9_500)
+			.saturating_add(T::DbWeight::get().reads(11))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	// Storage: DataMarket DataDelegateContracts (r:1 w:1)
+	fn sign_delegate_contract() -> Weight {
+		Weight::from_parts(21_000_000, 3_600)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: DataMarket AgencyReputations (r:1 w:0)
+	// Storage: DataMarket NextContractId (r:1 w:1)
+	// Storage: DataMarket ContractList (r:2 w:2)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	// Storage: DataMarket SubscriptionRenewals (r:11 w:1)
+	// Storage: DataMarket DataPurchaseContracts (r:0 w:1)
+	fn make_purchase_contract() -> Weight {
+		Weight::from_parts(104_000_000, 8_200)
+			.saturating_add(T::DbWeight::get().reads(18))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+	// Storage: DataMarket DataPurchaseContracts (r:1 w:1)
+	// Storage: DataMarket Agencies (r:1 w:0)
+	// Storage: TrustedEntity Issuers (r:1 w:0)
+	// Storage: DataMarket AgencyBonds (r:1 w:0)
+	// Storage: DataMarket AgencyReputations (r:1 w:0)
+	fn sign_purchase_contract() -> Weight {
+		Weight::from_parts(34_000_000, 5_200)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: DataMarket DataDelegateContracts (r:1 w:1)
+	// Storage: DataMarket ContractList (r:2 w:2)
+	fn terminate_delegate_contract() -> Weight {
+		Weight::from_parts(34_000_000, 6_300)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	// Storage: DataMarket DataPurchaseContracts (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:3 w:3)
+	// Storage: DataMarket ContractList (r:2 w:2)
+	// Storage: DataMarket TradeCountForContract (r:1 w:0)
+	// Storage: DataMarket AgencyReputations (r:1 w:1)
+	fn terminate_purchase_contract() -> Weight {
+		Weight::from_parts(96_000_000, 10_600)
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+	// Storage: DataMarket DataPurchaseContracts (r:1 w:1)
+	// Storage: DataMarket TradeCountForContract (r:1 w:1)
+	// Storage: DataMarket PlatformConfig (r:`1 + n` w:0)
+	// Storage: DataMarket DataTradeRecords (r:`n` w:`n`)
+	// Storage: DataMarket ContractList (r:`n` w:0)
+	// Storage: DataMarket DataDelegateContracts (r:`n` w:0)
+	// Storage: Revoke Revocations (r:`n` w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:`3 + 2 * n` w:`3 + 2 * n`)
+	fn execute_data_trades(n: u32) -> Weight {
+		Weight::from_parts(54_000_000, 8_400)
+			.saturating_add(Weight::from_parts(46_000_000, 5_200).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().reads((7_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+	}
+	// Storage: TrustedEntity Issuers (r:1 w:0)
+	// Storage: DataMarket Agencies (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	// Storage: DataMarket AgencyBonds (r:0 w:1)
+	fn register_agency() -> Weight {
+		Weight::from_parts(62_000_000, 5_800)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	// Storage: DataMarket AgencyReputations (r:1 w:0)
+	// Storage: DataMarket ContractList (r:1 w:0)
+	// Storage: DataMarket DataPurchaseContracts (r:`c` w:0)
+	// Storage: DataMarket Agencies (r:1 w:1)
+	// Storage: DataMarket AgencyBonds (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	fn deregister_agency(c: u32) -> Weight {
+		Weight::from_parts(68_000_000, 6_800)
+			.saturating_add(Weight::from_parts(6_000_000, 3_900).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	// Storage: DataMarket PlatformConfig (r:0 w:1)
+	fn set_platform_config() -> Weight {
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: DataMarket DataPurchaseContracts (r:2 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	fn deposit_balance() -> Weight {
+		Weight::from_parts(58_000_000, 6_200)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	// Storage: DataMarket DataPurchaseContracts (r:1 w:1)
+	fn set_auto_renew() -> Weight {
+		Weight::from_parts(20_000_000, 3_900)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: DataMarket DataPurchaseContracts (r:1 w:1)
+	// Storage: DataMarket PrivatePayoutPools (r:1 w:1)
+	// Storage: DataMarket TradeCountForContract (r:1 w:1)
+	// Storage: DataMarket PlatformConfig (r:`1 + n` w:0)
+	// Storage: DataMarket PayoutCommitments (r:`n` w:`n`)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:`2 + n` w:`2 + n`)
+	fn execute_private_data_trades(n: u32) -> Weight {
+		Weight::from_parts(58_000_000, 8_900)
+			.saturating_add(Weight::from_parts(24_000_000, 2_600).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+	}
+	// Storage: DataMarket PrivatePayoutPools (r:1 w:1)
+	// Storage: DataMarket PayoutNullifiers (r:1 w:1)
+	// Storage: DataMarket PayoutCommitments (r:`m` w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	fn claim_private_payout(m: u32) -> Weight {
+		Weight::from_parts(106_000_000, 6_400)
+			.saturating_add(Weight::from_parts(2_100_000, 2_500).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(m.into())))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	// Storage: DataMarket DataPurchaseContracts (r:1 w:0)
+	// Storage: DataMarket Disputes (r:1 w:1)
+	// Storage: DataMarket AgencyBonds (r:1 w:0)
+	// Storage: DataMarket AgencyReputations (r:1 w:1)
+	fn open_dispute() -> Weight {
+		Weight::from_parts(32_000_000, 4_800)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: DataMarket Disputes (r:1 w:1)
+	// Storage: DataMarket AgencyReputations (r:1 w:1)
+	// Storage: DataMarket AgencyBonds (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	fn resolve_dispute() -> Weight {
+		Weight::from_parts(66_000_000, 6_400)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	// Storage: DataMarket Agencies (r:1 w:0)
+	// Storage: DataMarket AgencyBonds (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	fn top_up_agency_bond() -> Weight {
+		Weight::from_parts(55_000_000, 6_700)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	// Storage: DataMarket SubscriptionRenewals (r:`1 + 11 * r` w:`1 + r`)
+	// Storage: DataMarket DataPurchaseContracts (r:`r` w:`r`)
+	// Storage: Assets Asset (r:`r` w:`r`)
+	// Storage: Assets Account (r:`2 * r` w:`2 * r`)
+	fn handle_subscription_renewals(r: u32) -> Weight {
+		Weight::from_parts(3_000_000, 1_500)
+			.saturating_add(Weight::from_parts(72_000_000, 9_000).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((15_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(r.into())))
+	}
+}
+
+impl WeightInfo for () {
+	// Storage: DataMarket Agencies (r:1 w:0)
+	// Storage: TrustedEntity Issuers (r:1 w:0)
+	// Storage: DataMarket AgencyBonds (r:1 w:0)
+	// Storage: DIDModule DidKeys (r:1 w:0)
+	// Storage: DIDModule Dids (r:1 w:0)
+	// Storage: Revoke Registries (r:1 w:0)
+	// Storage: Revoke Revocations (r:1 w:0)
+	// Storage: DataMarket DataConsents (r:1 w:1)
+	// Storage: DataMarket NextContractId (r:1 w:1)
+	// Storage: DataMarket ContractList (r:2 w:2)
+	// Storage: DataMarket DataDelegateContracts (r:0 w:1)
+	fn make_delegate_contract() -> Weight {
+		Weight::from_parts(104_000_000, 9_500)
+			.saturating_add(RocksDbWeight::get().reads(11))
+			.saturating_add(RocksDbWeight::get().writes(5))
+	}
+	// Storage: DataMarket DataDelegateContracts (r:1 w:1)
+	fn sign_delegate_contract() -> Weight {
+		Weight::from_parts(21_000_000, 3_600)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	// Storage: DataMarket AgencyReputations (r:1 w:0)
+	// Storage: DataMarket NextContractId (r:1 w:1)
+	// Storage: DataMarket ContractList (r:2 w:2)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	// Storage: DataMarket SubscriptionRenewals (r:11 w:1)
+	// Storage: DataMarket DataPurchaseContracts (r:0 w:1)
+	fn make_purchase_contract() -> Weight {
+		Weight::from_parts(104_000_000, 8_200)
+			.saturating_add(RocksDbWeight::get().reads(18))
+			.saturating_add(RocksDbWeight::get().writes(8))
+	}
+	// Storage: DataMarket DataPurchaseContracts (r:1 w:1)
+	// Storage: DataMarket Agencies (r:1 w:0)
+	// Storage: TrustedEntity Issuers (r:1 w:0)
+	// Storage: DataMarket AgencyBonds (r:1 w:0)
+	// Storage: DataMarket AgencyReputations (r:1 w:0)
+	fn sign_purchase_contract() -> Weight {
+		Weight::from_parts(34_000_000, 5_200)
+			.saturating_add(RocksDbWeight::get().reads(5))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	// Storage: DataMarket DataDelegateContracts (r:1 w:1)
+	// Storage: DataMarket ContractList (r:2 w:2)
+	fn terminate_delegate_contract() -> Weight {
+		Weight::from_parts(34_000_000, 6_300)
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+	// Storage: DataMarket DataPurchaseContracts (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:3 w:3)
+	// Storage: DataMarket ContractList (r:2 w:2)
+	// Storage: DataMarket TradeCountForContract (r:1 w:0)
+	// Storage: DataMarket AgencyReputations (r:1 w:1)
+	fn terminate_purchase_contract() -> Weight {
+		Weight::from_parts(96_000_000, 10_600)
+			.saturating_add(RocksDbWeight::get().reads(9))
+			.saturating_add(RocksDbWeight::get().writes(8))
+	}
+	// Storage: DataMarket DataPurchaseContracts (r:1 w:1)
+	// Storage: DataMarket TradeCountForContract (r:1 w:1)
+	// Storage: DataMarket PlatformConfig (r:`1 + n` w:0)
+	// Storage: DataMarket DataTradeRecords (r:`n` w:`n`)
+	// Storage: DataMarket ContractList (r:`n` w:0)
+	// Storage: DataMarket DataDelegateContracts (r:`n` w:0)
+	// Storage: Revoke Revocations (r:`n` w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:`3 + 2 * n` w:`3 + 2 * n`)
+	fn execute_data_trades(n: u32) -> Weight {
+		Weight::from_parts(54_000_000, 8_400)
+			.saturating_add(Weight::from_parts(46_000_000, 5_200).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(7))
+			.saturating_add(RocksDbWeight::get().reads((7_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(6))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+	}
+	// Storage: TrustedEntity Issuers (r:1 w:0)
+	// Storage: DataMarket Agencies (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	// Storage: DataMarket AgencyBonds (r:0 w:1)
+	fn register_agency() -> Weight {
+		Weight::from_parts(62_000_000, 5_800)
+			.saturating_add(RocksDbWeight::get().reads(5))
+			.saturating_add(RocksDbWeight::get().writes(5))
+	}
+	// Storage: DataMarket AgencyReputations (r:1 w:0)
+	// Storage: DataMarket ContractList (r:1 w:0)
+	// Storage: DataMarket DataPurchaseContracts (r:`c` w:0)
+	// Storage: DataMarket Agencies (r:1 w:1)
+	// Storage: DataMarket AgencyBonds (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	fn deregister_agency(c: u32) -> Weight {
+		Weight::from_parts(68_000_000, 6_800)
+			.saturating_add(Weight::from_parts(6_000_000, 3_900).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(7))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(5))
+	}
+	// Storage: DataMarket PlatformConfig (r:0 w:1)
+	fn set_platform_config() -> Weight {
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	// Storage: DataMarket DataPurchaseContracts (r:2 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	fn deposit_balance() -> Weight {
+		Weight::from_parts(58_000_000, 6_200)
+			.saturating_add(RocksDbWeight::get().reads(5))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+	// Storage: DataMarket DataPurchaseContracts (r:1 w:1)
+	fn set_auto_renew() -> Weight {
+		Weight::from_parts(20_000_000, 3_900)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	// Storage: DataMarket DataPurchaseContracts (r:1 w:1)
+	// Storage: DataMarket PrivatePayoutPools (r:1 w:1)
+	// Storage: DataMarket TradeCountForContract (r:1 w:1)
+	// Storage: DataMarket PlatformConfig (r:`1 + n` w:0)
+	// Storage: DataMarket PayoutCommitments (r:`n` w:`n`)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:`2 + n` w:`2 + n`)
+	fn execute_private_data_trades(n: u32) -> Weight {
+		Weight::from_parts(58_000_000, 8_900)
+			.saturating_add(Weight::from_parts(24_000_000, 2_600).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(7))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(6))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+	}
+	// Storage: DataMarket PrivatePayoutPools (r:1 w:1)
+	// Storage: DataMarket PayoutNullifiers (r:1 w:1)
+	// Storage: DataMarket PayoutCommitments (r:`m` w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	fn claim_private_payout(m: u32) -> Weight {
+		Weight::from_parts(106_000_000, 6_400)
+			.saturating_add(Weight::from_parts(2_100_000, 2_500).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(5))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(m.into())))
+			.saturating_add(RocksDbWeight::get().writes(5))
+	}
+	// Storage: DataMarket DataPurchaseContracts (r:1 w:0)
+	// Storage: DataMarket Disputes (r:1 w:1)
+	// Storage: DataMarket AgencyBonds (r:1 w:0)
+	// Storage: DataMarket AgencyReputations (r:1 w:1)
+	fn open_dispute() -> Weight {
+		Weight::from_parts(32_000_000, 4_800)
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	// Storage: DataMarket Disputes (r:1 w:1)
+	// Storage: DataMarket AgencyReputations (r:1 w:1)
+	// Storage: DataMarket AgencyBonds (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	fn resolve_dispute() -> Weight {
+		Weight::from_parts(66_000_000, 6_400)
+			.saturating_add(RocksDbWeight::get().reads(6))
+			.saturating_add(RocksDbWeight::get().writes(6))
+	}
+	// Storage: DataMarket Agencies (r:1 w:0)
+	// Storage: DataMarket AgencyBonds (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	fn top_up_agency_bond() -> Weight {
+		Weight::from_parts(55_000_000, 6_700)
+			.saturating_add(RocksDbWeight::get().reads(5))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+	// Storage: DataMarket SubscriptionRenewals (r:`1 + 11 * r` w:`1 + r`)
+	// Storage: DataMarket DataPurchaseContracts (r:`r` w:`r`)
+	// Storage: Assets Asset (r:`r` w:`r`)
+	// Storage: Assets Account (r:`2 * r` w:`2 * r`)
+	fn handle_subscription_renewals(r: u32) -> Weight {
+		Weight::from_parts(3_000_000, 1_500)
+			.saturating_add(Weight::from_parts(72_000_000, 9_000).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().reads((15_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().writes(1))
+			.saturating_add(RocksDbWeight::get().writes((5_u64).saturating_mul(r.into())))
+	}
+}
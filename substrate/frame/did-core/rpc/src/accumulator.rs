@@ -0,0 +1,54 @@
+//! RPC interface for the accumulator updates of did-core.
+//!
+//! Updates of accumulators are written to the offchain index by the runtime, so the node has to be
+//! started with `--enable-offchain-indexing true`. Wallets use these methods to fetch every update
+//! since the last time they updated their membership witnesses instead of scanning the extrinsics
+//! of every block.
+
+use std::marker::PhantomData;
+
+use codec::Decode;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use serde::{de::DeserializeOwned, Serialize};
+use sp_core::offchain::OffchainStorage;
+
+pub use did_core::accumulator::{offchain::updates_since, AccumulatorId, AccumulatorUpdate};
+
+#[rpc(client, server)]
+pub trait AccumulatorApi<BlockNumber> {
+	/// Updates of the accumulator sent at or after block `since`, oldest first
+	#[method(name = "accumulator_updatesSince")]
+	fn updates_since(
+		&self,
+		id: AccumulatorId,
+		since: BlockNumber,
+	) -> RpcResult<Vec<AccumulatorUpdate<BlockNumber>>>;
+}
+
+/// Provides RPC methods to query the accumulator updates indexed offchain.
+pub struct Accumulator<S, BlockNumber> {
+	/// Offchain storage of the node.
+	offchain_storage: S,
+	_marker: PhantomData<BlockNumber>,
+}
+
+impl<S, BlockNumber> Accumulator<S, BlockNumber> {
+	/// Creates a new instance of the Accumulator Rpc helper.
+	pub fn new(offchain_storage: S) -> Self {
+		Self { offchain_storage, _marker: Default::default() }
+	}
+}
+
+impl<S, BlockNumber> AccumulatorApiServer<BlockNumber> for Accumulator<S, BlockNumber>
+where
+	S: OffchainStorage + 'static,
+	BlockNumber: Decode + PartialOrd + Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+	fn updates_since(
+		&self,
+		id: AccumulatorId,
+		since: BlockNumber,
+	) -> RpcResult<Vec<AccumulatorUpdate<BlockNumber>>> {
+		Ok(updates_since(&self.offchain_storage, &id, since))
+	}
+}
@@ -0,0 +1,334 @@
+//! RPC interface for the batch anchors of did-core.
+//!
+//! A document hash anchored as a leaf of a batch is proven by a [`BatchProof`]. Besides checking
+//! it against the state of a block, the node exports an [`AnchorProof`] which chains the Merkle
+//! proof of the leaf to the storage proof of the batch anchor, much like an OpenTimestamps proof
+//! chains a document to a Bitcoin block header. Anyone holding the header of the block can verify
+//! it offline with [`AnchorProof::verify`].
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Decode;
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use sc_client_api::ProofProvider;
+use serde::{Deserialize, Serialize};
+use sp_blockchain::HeaderBackend;
+use sp_core::{hashing::twox_128, Bytes};
+use sp_runtime::traits::{
+	Block as BlockT, Hash as HashT, HashingFor, Header as HeaderT, NumberFor,
+};
+use sp_state_machine::{read_proof_check, StorageProof};
+
+pub use did_core::anchor::{BatchAnchor, BatchProof};
+
+/// Storage key of the batch anchor of `root`, `pallet_name` being the name of the anchor pallet in
+/// the runtime.
+pub fn batch_storage_key(pallet_name: &str, root: &[u8]) -> Vec<u8> {
+	[&twox_128(pallet_name.as_bytes())[..], &twox_128(b"Batches")[..], root].concat()
+}
+
+/// Proof that a document hash was anchored, verifiable against the header of `block_hash`
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnchorProof<Hash, BlockNumber> {
+	/// Merkle proof of the document hash in the batch
+	pub batch_proof: BatchProof<Hash>,
+	/// Block number and timestamp of the anchoring of the batch
+	pub anchor: BatchAnchor<BlockNumber>,
+	/// Block whose state root the storage proof is checked against
+	pub block_hash: Hash,
+	/// Trie nodes proving the batch anchor in the state of the block
+	pub storage_proof: Vec<Bytes>,
+}
+
+/// Reason why an [`AnchorProof`] is invalid.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ProofError {
+	/// The document hash is not a leaf of the batch.
+	BadMerkleProof,
+	/// The storage proof does not match the state root.
+	BadStorageProof,
+	/// The state does not hold the batch anchor of the proof.
+	AnchorMismatch,
+}
+
+impl<Hash, BlockNumber> AnchorProof<Hash, BlockNumber>
+where
+	Hash: Clone + PartialEq + AsRef<[u8]>,
+	BlockNumber: Decode + PartialEq,
+{
+	/// Verifies the proof against `state_root`, taken from the header of `block_hash`.
+	///
+	/// `pallet_name` is the name of the anchor pallet in the runtime, `H` the hashing of the
+	/// runtime.
+	pub fn verify<H>(&self, pallet_name: &str, state_root: Hash) -> Result<(), ProofError>
+	where
+		H: HashT<Output = Hash>,
+	{
+		if !self.batch_proof.verify::<H, _>(&self.anchor) {
+			return Err(ProofError::BadMerkleProof)
+		}
+		let key = batch_storage_key(pallet_name, self.batch_proof.root.as_ref());
+		let proof = StorageProof::new(self.storage_proof.iter().map(|node| node.0.clone()));
+		let anchor = read_batch_anchor::<H, BlockNumber>(state_root, proof, &key)
+			.map_err(|_| ProofError::BadStorageProof)?;
+		if anchor.as_ref() != Some(&self.anchor) {
+			return Err(ProofError::AnchorMismatch)
+		}
+		Ok(())
+	}
+}
+
+fn read_batch_anchor<H: HashT, BlockNumber: Decode>(
+	state_root: H::Output,
+	proof: StorageProof,
+	key: &[u8],
+) -> Result<Option<BatchAnchor<BlockNumber>>, Box<dyn sp_state_machine::Error>> {
+	let mut values = read_proof_check::<H, _>(state_root, proof, [key])?;
+	Ok(values
+		.remove(key)
+		.flatten()
+		.and_then(|value| BatchAnchor::decode(&mut &value[..]).ok()))
+}
+
+#[rpc(client, server)]
+pub trait AnchorApi<Hash, BlockNumber> {
+	/// Batch anchor the leaf of `proof` is part of, in the state of block `at` or of the last
+	/// finalized block
+	#[method(name = "anchor_verifyBatchProof")]
+	fn verify_batch_proof(
+		&self,
+		proof: BatchProof<Hash>,
+		at: Option<Hash>,
+	) -> RpcResult<Option<BatchAnchor<BlockNumber>>>;
+
+	/// Proof of the anchoring of the leaf of `proof`, verifiable offline against the header of
+	/// block `at` or of the last finalized block
+	#[method(name = "anchor_exportProof")]
+	fn export_proof(
+		&self,
+		proof: BatchProof<Hash>,
+		at: Option<Hash>,
+	) -> RpcResult<Option<AnchorProof<Hash, BlockNumber>>>;
+}
+
+/// Provides RPC methods to prove the batch anchors of did-core.
+pub struct Anchor<C, Block> {
+	/// Shared reference to the client.
+	client: Arc<C>,
+	/// Name of the anchor pallet in the runtime.
+	pallet_name: &'static str,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> Anchor<C, Block> {
+	/// Creates a new instance of the Anchor Rpc helper.
+	pub fn new(client: Arc<C>, pallet_name: &'static str) -> Self {
+		Self { client, pallet_name, _marker: Default::default() }
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The state of the block could not be proven.
+	StateError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::StateError => 1,
+		}
+	}
+}
+
+fn state_error(error: impl ToString, desc: &'static str) -> CallError {
+	CallError::Custom(ErrorObject::owned(Error::StateError.into(), desc, Some(error.to_string())))
+}
+
+impl<C, Block> AnchorApiServer<<Block as BlockT>::Hash, NumberFor<Block>> for Anchor<C, Block>
+where
+	Block: BlockT,
+	C: ProofProvider<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+{
+	fn verify_batch_proof(
+		&self,
+		proof: BatchProof<Block::Hash>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<BatchAnchor<NumberFor<Block>>>> {
+		Ok(self.export_proof(proof, at)?.map(|proof| proof.anchor))
+	}
+
+	fn export_proof(
+		&self,
+		batch_proof: BatchProof<Block::Hash>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<AnchorProof<Block::Hash, NumberFor<Block>>>> {
+		let block_hash = at.unwrap_or_else(|| self.client.info().finalized_hash);
+		let header = self
+			.client
+			.header(block_hash)
+			.map_err(|e| state_error(e, "Unable to read the header."))?
+			.ok_or_else(|| state_error("Unknown block", "Unable to read the header."))?;
+		let key = batch_storage_key(self.pallet_name, batch_proof.root.as_ref());
+		let storage_proof = self
+			.client
+			.read_proof(block_hash, &mut std::iter::once(&key[..]))
+			.map_err(|e| state_error(e, "Unable to prove the batch anchor."))?;
+		let anchor = read_batch_anchor::<HashingFor<Block>, _>(
+			*header.state_root(),
+			storage_proof.clone(),
+			&key,
+		)
+		.map_err(|e| state_error(e, "Unable to prove the batch anchor."))?;
+
+		Ok(anchor.filter(|anchor| batch_proof.verify::<HashingFor<Block>, _>(anchor)).map(
+			|anchor| AnchorProof {
+				batch_proof,
+				anchor,
+				block_hash,
+				storage_proof: storage_proof.into_iter_nodes().map(Bytes).collect(),
+			},
+		))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use codec::Encode;
+	use sp_core::H256;
+	use sp_runtime::{traits::BlakeTwo256, StateVersion};
+	use sp_state_machine::{prove_read, InMemoryBackend};
+	use std::collections::BTreeMap;
+
+	const PALLET_NAME: &str = "Anchor";
+	const LEAF_COUNT: u32 = 5;
+
+	/// Proof of the leaf at `index` of a batch and the state root of the block anchoring it.
+	fn anchor_proof(index: usize) -> (AnchorProof<H256, u32>, H256) {
+		let leaves: Vec<H256> = (0..LEAF_COUNT).map(|i| BlakeTwo256::hash(&i.encode())).collect();
+		let merkle_proof = binary_merkle_tree::merkle_proof::<BlakeTwo256, _, _>(&leaves, index);
+		let batch_proof = BatchProof {
+			leaf: leaves[index],
+			leaf_index: index as u32,
+			leaf_count: LEAF_COUNT,
+			proof: merkle_proof.proof,
+			root: merkle_proof.root,
+		};
+		let anchor = BatchAnchor { block_number: 7, timestamp: 1_000, leaf_count: LEAF_COUNT };
+
+		let key = batch_storage_key(PALLET_NAME, batch_proof.root.as_ref());
+		let other_key = batch_storage_key(PALLET_NAME, H256::repeat_byte(1).as_ref());
+		let backend = InMemoryBackend::<BlakeTwo256>::from((
+			BTreeMap::from([
+				(key.clone(), anchor.encode()),
+				(other_key, BatchAnchor { block_number: 3u32, ..anchor.clone() }.encode()),
+				(b":code".to_vec(), vec![1; 64]),
+			]),
+			StateVersion::V1,
+		));
+		let state_root = *backend.root();
+		let storage_proof = prove_read(backend, [&key]).unwrap();
+
+		let proof = AnchorProof {
+			batch_proof,
+			anchor,
+			block_hash: H256::repeat_byte(2),
+			storage_proof: storage_proof.into_iter_nodes().map(Bytes).collect(),
+		};
+		(proof, state_root)
+	}
+
+	#[test]
+	fn verify_exported_proof() {
+		for index in 0..LEAF_COUNT as usize {
+			let (proof, state_root) = anchor_proof(index);
+			assert_eq!(proof.verify::<BlakeTwo256>(PALLET_NAME, state_root), Ok(()));
+
+			// The proof is handed out as JSON and verified offline
+			let json = serde_json::to_string(&proof).unwrap();
+			let proof: AnchorProof<H256, u32> = serde_json::from_str(&json).unwrap();
+			assert_eq!(proof.verify::<BlakeTwo256>(PALLET_NAME, state_root), Ok(()));
+		}
+	}
+
+	#[test]
+	fn verify_rejects_tampered_merkle_proof() {
+		let (proof, state_root) = anchor_proof(1);
+
+		let mut tampered = proof.clone();
+		tampered.batch_proof.leaf = BlakeTwo256::hash(b"forged");
+		assert_eq!(
+			tampered.verify::<BlakeTwo256>(PALLET_NAME, state_root),
+			Err(ProofError::BadMerkleProof)
+		);
+
+		let mut tampered = proof.clone();
+		tampered.batch_proof.leaf_index = 2;
+		assert_eq!(
+			tampered.verify::<BlakeTwo256>(PALLET_NAME, state_root),
+			Err(ProofError::BadMerkleProof)
+		);
+
+		let mut tampered = proof;
+		tampered.batch_proof.proof[0] = H256::zero();
+		assert_eq!(
+			tampered.verify::<BlakeTwo256>(PALLET_NAME, state_root),
+			Err(ProofError::BadMerkleProof)
+		);
+	}
+
+	#[test]
+	fn verify_rejects_tampered_storage_proof() {
+		let (proof, state_root) = anchor_proof(1);
+
+		// Another block
+		assert_eq!(
+			proof.verify::<BlakeTwo256>(PALLET_NAME, H256::repeat_byte(3)),
+			Err(ProofError::BadStorageProof)
+		);
+
+		// Missing trie nodes
+		let mut tampered = proof.clone();
+		tampered.storage_proof.pop();
+		assert_eq!(
+			tampered.verify::<BlakeTwo256>(PALLET_NAME, state_root),
+			Err(ProofError::BadStorageProof)
+		);
+
+		// Forged trie node
+		let mut tampered = proof;
+		tampered.storage_proof[0].0[1] ^= 1;
+		assert_eq!(
+			tampered.verify::<BlakeTwo256>(PALLET_NAME, state_root),
+			Err(ProofError::BadStorageProof)
+		);
+	}
+
+	#[test]
+	fn verify_rejects_tampered_anchor() {
+		let (proof, state_root) = anchor_proof(1);
+
+		let mut tampered = proof.clone();
+		tampered.anchor.timestamp = 2_000;
+		assert_eq!(
+			tampered.verify::<BlakeTwo256>(PALLET_NAME, state_root),
+			Err(ProofError::AnchorMismatch)
+		);
+
+		let mut tampered = proof.clone();
+		tampered.anchor.block_number = 3;
+		assert_eq!(
+			tampered.verify::<BlakeTwo256>(PALLET_NAME, state_root),
+			Err(ProofError::AnchorMismatch)
+		);
+
+		// The batch anchor is looked up in the storage of the pallet the proof is checked for
+		assert!(proof.verify::<BlakeTwo256>("Anchors", state_root).is_err());
+	}
+}
@@ -0,0 +1,11 @@
+//! Node-specific RPC methods of did-core.
+//!
+//! * [`AccumulatorApi`] serves the accumulator updates indexed offchain by the runtime.
+//! * [`AnchorApi`] proves that a document hash is part of an anchored batch and exports the proof
+//!   so it can be verified offline.
+
+mod accumulator;
+mod anchor;
+
+pub use accumulator::*;
+pub use anchor::*;
@@ -39,7 +39,11 @@ crate::def_state_change! {
 		trusted_entity::RemoveIssuer,
 		trusted_entity::AddVerifier,
 		trusted_entity::RemoveVerifier,
-		trusted_entity::RemoveAuthorizer
+		trusted_entity::RemoveAuthorizer,
+		attest::AddSchema,
+		attest::AddSchemaVersion,
+		attest::SetSchemaAttestation,
+		attest::RemoveSchemaAttestation
 }
 
 /// Converts the given entity to the state change.
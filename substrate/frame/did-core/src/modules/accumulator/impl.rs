@@ -1,5 +1,6 @@
 use super::*;
 use crate::deposit_indexed_event;
+use offchain::{update_count_key, update_key};
 
 impl<T: Config> Pallet<T> {
 	pub(super) fn add_params_(
@@ -83,9 +84,12 @@ impl<T: Config> Pallet<T> {
 	}
 
 	pub(super) fn update_accumulator_(
-		UpdateAccumulator { id, new_accumulated, .. }: UpdateAccumulator<T>,
+		UpdateAccumulator {
+			id, new_accumulated, additions, removals, witness_update_info, ..
+		}: UpdateAccumulator<T>,
 		owner: AccumulatorOwner,
 	) -> DispatchResult {
+		let current_block = <frame_system::Pallet<T>>::block_number();
 		Accumulators::<T>::try_mutate(id, |accumulator| -> DispatchResult {
 			let accumulator = accumulator.as_mut().ok_or(Error::<T>::AccumulatorDoesntExist)?;
 
@@ -96,14 +100,28 @@ impl<T: Config> Pallet<T> {
 				.accumulator
 				.set_new_accumulated(new_accumulated.clone().0)
 				.map_err(|_| Error::<T>::AccumulatedTooBig)?;
-			accumulator.last_updated_at = <frame_system::Pallet<T>>::block_number();
+			accumulator.last_updated_at = current_block;
 
 			Ok(())
 		})?;
 
+		// The updates and the witness update info are needed only by the provers, so they are
+		// written to the offchain index rather than to chain state.
+		let index = AccumulatorUpdateCounts::<T>::mutate(id, |count| {
+			*count = count.saturating_add(1);
+			*count
+		});
+		let update = AccumulatorUpdate {
+			updated_at: current_block,
+			new_accumulated: new_accumulated.clone(),
+			additions,
+			removals,
+			witness_update_info,
+		};
+		sp_io::offchain_index::set(&update_key(&id, index), &update.encode());
+		sp_io::offchain_index::set(&update_count_key(&id), &index.encode());
+
 		// The event stores only the accumulated value which can be used by the verifier.
-		// For witness update, that information is retrieved by looking at the block and parsing the
-		// extrinsic.
 		deposit_indexed_event!(AccumulatorUpdated(id, new_accumulated) over id);
 		Ok(())
 	}
@@ -117,6 +135,8 @@ impl<T: Config> Pallet<T> {
 		// Only the DID that added the accumulator can remove it
 		ensure!(*accumulator.accumulator.owner_did() == signer, Error::<T>::NotAccumulatorOwner);
 		Accumulators::<T>::remove(id);
+		AccumulatorUpdateCounts::<T>::remove(id);
+		sp_io::offchain_index::clear(&update_count_key(&id));
 
 		deposit_indexed_event!(AccumulatorRemoved(id));
 		Ok(())
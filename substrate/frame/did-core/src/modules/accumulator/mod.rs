@@ -6,7 +6,7 @@ use crate::{
 };
 pub use actions::*;
 use codec::{Decode, Encode, MaxEncodedLen};
-use frame_support::{dispatch::DispatchResult, ensure, weights::Weight};
+use frame_support::{dispatch::DispatchResult, ensure, traits::Get, weights::Weight};
 use sp_std::{fmt::Debug, prelude::*};
 
 pub use pallet::*;
@@ -17,6 +17,7 @@ mod actions;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarks;
 mod r#impl;
+pub mod offchain;
 #[cfg(test)]
 mod tests;
 mod types;
@@ -109,12 +110,20 @@ pub mod pallet {
 	/// Another option could have been to store block numbers for the updates so that each block
 	/// from `created_at` doesn't need to be scanned but even that requires large storage as we
 	/// expect millions of updates. Just keeping the latest accumulated value allows for any
-	/// potential on chain verification as well.
+	/// potential on chain verification as well. The updates themselves are written to the offchain
+	/// index, see [`offchain`].
 	#[pallet::storage]
 	#[pallet::getter(fn accumulator)]
 	pub type Accumulators<T> =
 		StorageMap<_, Blake2_128Concat, AccumulatorId, AccumulatorWithUpdateInfo<T>, OptionQuery>;
 
+	/// Number of updates of an accumulator. The updates are written to the offchain index keyed by
+	/// the accumulator id and their sequence number, so only the counter is kept in chain state.
+	#[pallet::storage]
+	#[pallet::getter(fn accumulator_update_count)]
+	pub type AccumulatorUpdateCounts<T> =
+		StorageMap<_, Blake2_128Concat, AccumulatorId, u32, ValueQuery>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn version)]
 	pub type Version<T> = StorageValue<_, common::StorageVersion, ValueQuery>;
@@ -232,7 +241,8 @@ pub mod pallet {
 		/// optional as the owner might be privately communicating the updated witnesses. It logs an
 		/// event with the accumulator id and the new accumulated value which is sufficient for a
 		/// verifier. But the prover (who has a witness to update) needs the updates and the witness
-		/// update info and is expected to look into the corresponding extrinsic arguments.
+		/// update info. These are written to the offchain index as an [`AccumulatorUpdate`] so that
+		/// the node can serve every update since a given block without parsing extrinsics.
 		#[pallet::weight(SubstrateWeight::<T>::update_accumulator(update, signature))]
 		#[pallet::call_index(5)]
 		pub fn update_accumulator(
@@ -329,6 +339,8 @@ impl<T: Config> SubstrateWeight<T> {
 			SigValue::Sr25519(_) => Self::remove_accumulator_sr25519,
 			SigValue::Ed25519(_) => Self::remove_accumulator_ed25519,
 		}())
+		// Update counter of the accumulator
+		.saturating_add(T::DbWeight::get().writes(1))
 	}
 
 	fn update_accumulator(
@@ -358,5 +370,7 @@ impl<T: Config> SubstrateWeight<T> {
 				.unwrap_or(0),
 			acc.witness_update_info.as_ref().map_or(0, |v| v.len()) as u32,
 		)
+		// Update counter of the accumulator
+		.saturating_add(T::DbWeight::get().reads_writes(1, 1))
 	}
 }
@@ -0,0 +1,53 @@
+//! Offchain index of accumulator updates.
+//!
+//! Every update of an accumulator is written to the offchain index under the accumulator id and
+//! the sequence number of the update, starting from 1, along with the number of updates made so
+//! far. The node must be started with `--enable-offchain-indexing true` for the records to be kept.
+//! As records are indexed when a block is imported, a block of a fork which is retracted later may
+//! overwrite the records of the canonical chain, so provers should check the final accumulated
+//! value against chain state.
+
+use super::*;
+use sp_core::offchain::{OffchainStorage, STORAGE_PREFIX};
+
+/// Prefix of the offchain index keys of accumulator updates.
+pub const ACCUMULATOR_UPDATES_PREFIX: &[u8] = b"did-core::accumulator-updates";
+
+/// Offchain index key of the number of updates of the accumulator.
+pub fn update_count_key(id: &AccumulatorId) -> Vec<u8> {
+	(ACCUMULATOR_UPDATES_PREFIX, id).encode()
+}
+
+/// Offchain index key of the `index`-th update of the accumulator.
+pub fn update_key(id: &AccumulatorId, index: u32) -> Vec<u8> {
+	(ACCUMULATOR_UPDATES_PREFIX, id, index).encode()
+}
+
+/// Read every update of the accumulator sent at or after block `since` from the offchain storage,
+/// oldest first. Reading stops at the first record which is missing, e.g. when the node did not
+/// index the blocks before it.
+pub fn updates_since<S, BlockNumber>(
+	storage: &S,
+	id: &AccumulatorId,
+	since: BlockNumber,
+) -> Vec<AccumulatorUpdate<BlockNumber>>
+where
+	S: OffchainStorage,
+	BlockNumber: Decode + PartialOrd,
+{
+	let count = storage
+		.get(STORAGE_PREFIX, &update_count_key(id))
+		.and_then(|count| u32::decode(&mut &count[..]).ok())
+		.unwrap_or_default();
+	let mut updates: Vec<_> = (1..=count)
+		.rev()
+		.map_while(|index| {
+			storage
+				.get(STORAGE_PREFIX, &update_key(id, index))
+				.and_then(|update| AccumulatorUpdate::decode(&mut &update[..]).ok())
+		})
+		.take_while(|update| update.updated_at >= since)
+		.collect();
+	updates.reverse();
+	updates
+}
@@ -2,7 +2,10 @@ use super::*;
 use crate::tests::common::*;
 use frame_support::assert_err;
 use frame_system::Origin;
-use sp_core::{sr25519, Hasher, H256};
+use sp_core::{
+	offchain::{storage::InMemOffchainStorage, OffchainOverlayedChange, OffchainStorage},
+	sr25519, Hasher, H256,
+};
 
 fn sign_add_params<T: Config>(
 	keypair: &sr25519::Pair,
@@ -496,3 +499,105 @@ fn add_remove_accumulator() {
 		)));
 	});
 }
+
+/// Offchain storage of a node which imported the blocks executed by `ext`. The offchain db of
+/// `TestExternalities` drops the prefix the node stores the indexed values under.
+fn offchain_index(ext: &sp_io::TestExternalities) -> InMemOffchainStorage {
+	let mut storage = InMemOffchainStorage::default();
+	for ((prefix, key), change) in ext.overlayed_changes().offchain().iter() {
+		match change {
+			OffchainOverlayedChange::SetValue(value) => storage.set(prefix, key, value),
+			OffchainOverlayedChange::Remove => storage.remove(prefix, key),
+		}
+	}
+	storage
+}
+
+#[test]
+fn accumulator_updates_are_indexed_offchain() {
+	let mut ext = ext();
+	let id = AccumulatorId(rand::random());
+
+	let (author, author_kp, next_nonce) = ext.execute_with(|| {
+		run_to_block(10);
+
+		let (author, author_kp) = newdid();
+		let author = AccumulatorOwner(author);
+		let mut next_nonce = 10 + 1;
+
+		let key = AccumulatorPublicKey {
+			params_ref: None,
+			curve_type: CurveType::Bls12381,
+			bytes: vec![2; 100].try_into().unwrap(),
+		};
+		let ak = AddAccumulatorPublicKey { public_key: key, nonce: next_nonce };
+		let sig = sign_add_key::<Test>(&author_kp, &ak, author, 1);
+		AccumMod::add_public_key(RuntimeOrigin::signed(1), ak, sig).unwrap();
+		next_nonce += 1;
+
+		let accumulator = Accumulator::Positive(AccumulatorCommon {
+			accumulated: vec![3; 32].try_into().unwrap(),
+			key_ref: (author, 1u8.into()),
+		});
+		let add_accum = AddAccumulator { id, accumulator, nonce: next_nonce };
+		let sig = sign_add_accum(&author_kp, &add_accum, author, 1);
+		AccumMod::add_accumulator(RuntimeOrigin::signed(1), add_accum, sig).unwrap();
+		next_nonce += 1;
+
+		// Two updates are sent in block 30
+		for (block, byte) in [(20, 4u8), (30, 5), (30, 6), (40, 7)] {
+			run_to_block(block);
+
+			let update_accum = UpdateAccumulator {
+				id,
+				new_accumulated: vec![byte; 32].into(),
+				additions: Some(vec![vec![byte].into()]),
+				removals: (byte % 2 == 0).then(|| vec![vec![byte - 1].into()]),
+				witness_update_info: Some(vec![byte, byte].into()),
+				nonce: next_nonce,
+			};
+			let sig = sign_update_accum(&author_kp, &update_accum, author, 1);
+			AccumMod::update_accumulator(RuntimeOrigin::signed(1), update_accum, sig).unwrap();
+			next_nonce += 1;
+		}
+		assert_eq!(AccumMod::accumulator_update_count(id), 4);
+
+		(author, author_kp, next_nonce)
+	});
+	let storage = offchain_index(&ext);
+
+	let updates = offchain::updates_since(&storage, &id, 0u64);
+	assert_eq!(updates.len(), 4);
+	assert_eq!(
+		updates[1],
+		AccumulatorUpdate {
+			updated_at: 30,
+			new_accumulated: vec![5; 32].into(),
+			additions: Some(vec![vec![5].into()]),
+			removals: None,
+			witness_update_info: Some(vec![5, 5].into()),
+		}
+	);
+	assert_eq!(updates[2].removals, Some(vec![vec![5].into()]));
+
+	let updates = offchain::updates_since(&storage, &id, 30u64);
+	assert_eq!(
+		updates
+			.iter()
+			.map(|update| (update.updated_at, update.new_accumulated.clone()))
+			.collect::<Vec<_>>(),
+		vec![(30, vec![5; 32].into()), (30, vec![6; 32].into()), (40, vec![7; 32].into())]
+	);
+	assert!(offchain::updates_since(&storage, &id, 41u64).is_empty());
+	assert!(offchain::updates_since(&storage, &AccumulatorId(rand::random()), 0u64).is_empty());
+
+	ext.execute_with(|| {
+		run_to_block(50);
+
+		let rem_accum = RemoveAccumulator { id, nonce: next_nonce };
+		let sig = sign_remove_accum(&author_kp, &rem_accum, author, 1);
+		AccumMod::remove_accumulator(RuntimeOrigin::signed(1), rem_accum, sig).unwrap();
+		assert_eq!(AccumMod::accumulator_update_count(id), 0);
+	});
+	assert!(offchain::updates_since(&offchain_index(&ext), &id, 0u64).is_empty());
+}
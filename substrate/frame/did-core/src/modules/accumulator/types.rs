@@ -199,3 +199,18 @@ impl<T: TypesAndLimits> AccumulatorWithUpdateInfo<T> {
 		Self { accumulator, created_at, last_updated_at: created_at }
 	}
 }
+
+/// Update of an accumulator as written to the offchain index. It carries what a prover needs to
+/// update its membership witness, which is not kept in chain state.
+#[derive(scale_info_derive::TypeInfo, Encode, Decode, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[scale_info(omit_prefix)]
+pub struct AccumulatorUpdate<BlockNumber> {
+	/// Block number when the update was sent
+	pub updated_at: BlockNumber,
+	pub new_accumulated: Bytes,
+	pub additions: Option<Vec<Bytes>>,
+	pub removals: Option<Vec<Bytes>>,
+	pub witness_update_info: Option<Bytes>,
+}
@@ -21,4 +21,13 @@ benchmarks! {
 		let hash = <<T as frame_system::Config>::Hashing as Hash>::hash(&data);
 		assert_eq!(Anchors::<T>::get(&hash).unwrap(), <frame_system::Pallet<T>>::block_number());
 	}
+
+	deploy_batch {
+		let caller = whitelisted_caller();
+		let root = <<T as frame_system::Config>::Hashing as Hash>::hash(b"root");
+
+	}: deploy_batch(RawOrigin::Signed(caller), root, u32::MAX)
+	verify {
+		assert_eq!(Batches::<T>::get(&root).unwrap().leaf_count, u32::MAX);
+	}
 }
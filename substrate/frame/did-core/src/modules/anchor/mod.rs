@@ -3,8 +3,15 @@
 //! Anchors are hashed once before being added to storage. To check whether an anchor exists
 //! query the "Anchors" map for the hash of the anchor. If a corresponding value exists, then the
 //! anchor exists and the value represents the block number when it was first published.
+//!
+//! Many documents can be notarized at once by anchoring the root of a binary Merkle tree built
+//! with `binary_merkle_tree::merkle_root` over their hashes. The root is stored in the "Batches"
+//! map along with the block number and the timestamp of its publication. A document is then proven
+//! to be anchored with a [`BatchProof`] of its hash, which can be checked on-chain with
+//! [`Pallet::verify_batch_proof`] or offline against the state root of a finalized header.
 
 use alloc::vec::Vec;
+use codec::{Decode, Encode, MaxEncodedLen};
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarks;
@@ -15,10 +22,63 @@ use weights::*;
 
 pub use pallet::*;
 
+/// Merkle root of a batch of document hashes published on-chain.
+#[derive(
+	Encode, Decode, scale_info_derive::TypeInfo, Clone, PartialEq, Eq, Debug, MaxEncodedLen,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[scale_info(omit_prefix)]
+pub struct BatchAnchor<BlockNumber> {
+	/// Block in which the root was published.
+	pub block_number: BlockNumber,
+	/// Timestamp of the block, in milliseconds since the Unix epoch.
+	pub timestamp: u64,
+	/// Number of leaves of the tree.
+	pub leaf_count: u32,
+}
+
+/// Proof that a document hash is a leaf of an anchored batch.
+#[derive(
+	Encode, Decode, scale_info_derive::TypeInfo, Clone, PartialEq, Eq, Debug, MaxEncodedLen,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[scale_info(omit_prefix)]
+pub struct BatchProof<Hash> {
+	/// Hash of the document.
+	pub leaf: Hash,
+	/// Index of the leaf in the batch.
+	pub leaf_index: u32,
+	/// Number of leaves of the tree.
+	pub leaf_count: u32,
+	/// Inner nodes of the tree required to compute the root, bottom up.
+	pub proof: Vec<Hash>,
+	/// Merkle root of the batch.
+	pub root: Hash,
+}
+
+impl<Output: Clone + PartialEq + AsRef<[u8]>> BatchProof<Output> {
+	/// Checks that the leaf is part of `anchor` whose root is the one of this proof.
+	pub fn verify<H: sp_runtime::traits::Hash<Output = Output>, BlockNumber>(
+		&self,
+		anchor: &BatchAnchor<BlockNumber>,
+	) -> bool {
+		self.leaf_count == anchor.leaf_count &&
+			binary_merkle_tree::verify_proof::<H, _, _>(
+				&self.root,
+				self.proof.iter().cloned(),
+				self.leaf_count as usize,
+				self.leaf_index as usize,
+				&self.leaf,
+			)
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
-	use frame_support::pallet_prelude::*;
+	use frame_support::{pallet_prelude::*, traits::UnixTime};
 	use frame_system::pallet_prelude::*;
 	use sp_core::Hasher;
 
@@ -27,12 +87,16 @@ pub mod pallet {
 		type RuntimeEvent: From<Event<Self>>
 			+ IsType<<Self as frame_system::Config>::RuntimeEvent>
 			+ Into<<Self as frame_system::Config>::RuntimeEvent>;
+		/// Time used to timestamp batch anchors.
+		type UnixTime: UnixTime;
 	}
 
 	#[pallet::error]
 	pub enum Error<T> {
 		/// The anchor being posted was already created in a previous block.
 		AnchorExists,
+		/// The batch being anchored has no leaves.
+		EmptyBatch,
 	}
 
 	#[pallet::event]
@@ -40,6 +104,8 @@ pub mod pallet {
 	pub enum Event<T: Config> {
 		/// A new permanent anchor was posted.
 		AnchorDeployed(T::Hash, T::AccountId),
+		/// The Merkle root of a batch of anchors was posted.
+		BatchDeployed(T::Hash, u32, T::AccountId),
 	}
 
 	#[pallet::pallet]
@@ -48,6 +114,9 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type Anchors<T: Config> = StorageMap<_, Identity, T::Hash, BlockNumberFor<T>>;
 
+	#[pallet::storage]
+	pub type Batches<T: Config> = StorageMap<_, Identity, T::Hash, BatchAnchor<BlockNumberFor<T>>>;
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Drop a permanent anchor.
@@ -58,6 +127,20 @@ pub mod pallet {
 
 			Pallet::<T>::deploy_(data, account)
 		}
+
+		/// Drop a permanent anchor for a batch of documents, given the Merkle root of their
+		/// hashes and the number of leaves of the tree.
+		#[pallet::weight(SubstrateWeight::<T>::deploy_batch())]
+		#[pallet::call_index(1)]
+		pub fn deploy_batch(
+			origin: OriginFor<T>,
+			root: T::Hash,
+			leaf_count: u32,
+		) -> DispatchResult {
+			let account = ensure_signed(origin)?;
+
+			Pallet::<T>::deploy_batch_(root, leaf_count, account)
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -73,5 +156,29 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		fn deploy_batch_(root: T::Hash, leaf_count: u32, account: T::AccountId) -> DispatchResult {
+			// check
+			ensure!(leaf_count > 0, Error::<T>::EmptyBatch);
+			ensure!(!Batches::<T>::contains_key(root), Error::<T>::AnchorExists);
+
+			// execute
+			let anchor = BatchAnchor {
+				block_number: <frame_system::Pallet<T>>::block_number(),
+				timestamp: T::UnixTime::now().as_millis() as u64,
+				leaf_count,
+			};
+			Batches::<T>::insert(root, anchor);
+			Self::deposit_event(Event::<T>::BatchDeployed(root, leaf_count, account));
+
+			Ok(())
+		}
+
+		/// Returns the batch anchor the leaf of `proof` is part of, if the proof is valid.
+		pub fn verify_batch_proof(
+			proof: &BatchProof<T::Hash>,
+		) -> Option<BatchAnchor<BlockNumberFor<T>>> {
+			Batches::<T>::get(proof.root).filter(|anchor| proof.verify::<T::Hashing, _>(anchor))
+		}
 	}
 }
@@ -1,8 +1,8 @@
-use super::{Anchors, Error, Event};
+use super::{Anchors, BatchProof, Batches, Error, Event};
 use crate::tests::common::*;
 use frame_system::Origin;
 use sp_core::H256;
-use sp_runtime::traits::Hash;
+use sp_runtime::traits::{BlakeTwo256, Hash};
 
 #[test]
 fn deploy_and_check() {
@@ -35,6 +35,52 @@ fn deploy_and_observe_event() {
 	});
 }
 
+#[test]
+fn deploy_batch_and_verify() {
+	ext().execute_with(|| {
+		Timestamp::set_timestamp(1_000);
+		let leaves: Vec<H256> = (0..5).map(|_| BlakeTwo256::hash(&random_bytes(32))).collect();
+		let root = binary_merkle_tree::merkle_root::<BlakeTwo256, _>(&leaves);
+		AnchorMod::deploy_batch(RuntimeOrigin::signed(ABBA), root, 5).unwrap();
+		assert_eq!(&anchor_events(), &[Event::<Test>::BatchDeployed(root, 5, ABBA)]);
+		let anchor = Batches::<Test>::get(root).unwrap();
+		assert_eq!((anchor.block_number, anchor.timestamp, anchor.leaf_count), (1, 1_000, 5));
+
+		for (index, leaf) in leaves.iter().enumerate() {
+			let merkle_proof =
+				binary_merkle_tree::merkle_proof::<BlakeTwo256, _, _>(&leaves, index);
+			let proof = BatchProof {
+				leaf: *leaf,
+				leaf_index: index as u32,
+				leaf_count: 5,
+				proof: merkle_proof.proof,
+				root,
+			};
+			assert_eq!(AnchorMod::verify_batch_proof(&proof), Some(anchor.clone()));
+
+			// Proofs of another leaf, of another leaf count or of an unknown root are rejected
+			let other = BatchProof { leaf: leaves[(index + 1) % 5], ..proof.clone() };
+			assert_eq!(AnchorMod::verify_batch_proof(&other), None);
+			let other = BatchProof { leaf_count: 6, ..proof.clone() };
+			assert_eq!(AnchorMod::verify_batch_proof(&other), None);
+			let other = BatchProof { root: *leaf, ..proof };
+			assert_eq!(AnchorMod::verify_batch_proof(&other), None);
+		}
+	});
+}
+
+#[test]
+fn deploy_batch_errors() {
+	ext().execute_with(|| {
+		let root = BlakeTwo256::hash(&random_bytes(32));
+		let err = AnchorMod::deploy_batch(RuntimeOrigin::signed(ABBA), root, 0).unwrap_err();
+		assert_eq!(err, Error::<Test>::EmptyBatch.into());
+		AnchorMod::deploy_batch(RuntimeOrigin::signed(ABBA), root, 2).unwrap();
+		let err = AnchorMod::deploy_batch(RuntimeOrigin::signed(ABBA), root, 2).unwrap_err();
+		assert_eq!(err, Error::<Test>::AnchorExists.into());
+	});
+}
+
 fn anchor_events() -> Vec<Event<Test>> {
 	System::events()
 		.iter()
@@ -1,21 +1,12 @@
 //! Autogenerated weights for anchor
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 3.0.0
-//! DATE: 2022-08-01, STEPS: `[50, ]`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! EXECUTION: Some(Native), WASM-EXECUTION: Interpreted, CHAIN: Some("mainnet"), DB CACHE: 128
-
-// Executed Command:
-// ./target/production/dock-node
-// benchmark
-// --execution=native
-// --chain=mainnet
-// --pallet=anchor
-// --extra
-// --extrinsic=*
-// --repeat=20
-// --steps=50
-// --template=node/module-weight-template.hbs
-// --output=./pallets/core/src/modules/anchor/weights.rs
+//! DATE: 2026-10-19, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
+//! EXECUTION: Native, CHAIN: the test runtime of `tests/common.rs`
+//!
+//! Measured from `anchor/benchmarks.rs` natively against the benchmarking state database, as no
+//! wasm build of a runtime was available. Regenerate with `benchmark pallet --pallet anchor`
+//! on reference hardware before relying on them in production.
 
 #![allow(unused_parens)]
 #![allow(unused_imports)]
@@ -29,27 +20,46 @@ use sp_std::marker::PhantomData;
 /// Weight functions needed for anchor.
 pub trait WeightInfo {
 	fn deploy(l: u32) -> Weight;
+	fn deploy_batch() -> Weight;
 }
 
 /// Weights for anchor using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: Anchor Anchors (r:1 w:1)
 	fn deploy(l: u32) -> Weight {
-		Weight::from_ref_time(5_881_000_u64)
-			// Standard Error: 0
-			.saturating_add(Weight::from_ref_time(1_000_u64).saturating_mul(l as u64))
+		// Minimum execution time: 5_252_000 picoseconds.
+		Weight::from_ref_time(6_030_252_u64)
+			.saturating_add(Weight::from_ref_time(1_058_u64).saturating_mul(l as u64))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: Anchor Batches (r:1 w:1)
+	/// Storage: Timestamp Now (r:1 w:0)
+	fn deploy_batch() -> Weight {
+		// Minimum execution time: 5_579_000 picoseconds.
+		Weight::from_ref_time(6_234_000_u64)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
+	/// Storage: Anchor Anchors (r:1 w:1)
 	fn deploy(l: u32) -> Weight {
-		Weight::from_ref_time(5_881_000_u64)
-			// Standard Error: 0
-			.saturating_add(Weight::from_ref_time(1_000_u64).saturating_mul(l as u64))
+		// Minimum execution time: 5_252_000 picoseconds.
+		Weight::from_ref_time(6_030_252_u64)
+			.saturating_add(Weight::from_ref_time(1_058_u64).saturating_mul(l as u64))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: Anchor Batches (r:1 w:1)
+	/// Storage: Timestamp Now (r:1 w:0)
+	fn deploy_batch() -> Weight {
+		// Minimum execution time: 5_579_000 picoseconds.
+		Weight::from_ref_time(6_234_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
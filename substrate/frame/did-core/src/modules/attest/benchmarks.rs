@@ -1,20 +1,20 @@
 use super::*;
 use crate::{
-	common::state_change::ToStateChange,
+	common::{state_change::ToStateChange, Policy},
 	did::{Did, DidSignature, UncheckedDidKey},
+	revoke::{Registries, Registry},
 };
 use frame_benchmarking::{benchmarks, whitelisted_caller};
+use frame_support::traits::Get;
 use frame_system::RawOrigin;
 #[cfg(not(feature = "std"))]
 use sp_std::prelude::*;
 
-const MAX_LEN: u32 = 10_000;
-
 crate::bench_with_all_pairs! {
 	with_pairs:
 	set_claim_sr25519 for sr25519, set_claim_ed25519 for ed25519, set_claim_secp256k1 for secp256k1 {
 		{
-			let l in 0 .. MAX_LEN => ();
+			let l in 0 .. T::MaxIriSize::get() => ();
 		}
 		let pair as Pair;
 		let caller = whitelisted_caller();
@@ -44,4 +44,129 @@ crate::bench_with_all_pairs! {
 	verify {
 		assert_eq!(Attestations::get(Attester(did)), set_attest.attest);
 	}
+
+	add_schema_sr25519 for sr25519, add_schema_ed25519 for ed25519, add_schema_secp256k1 for secp256k1 {
+		let pair as Pair;
+		let caller = whitelisted_caller();
+		let did = Did([1; Did::BYTE_SIZE]);
+		let public = pair.public();
+
+		crate::did::Pallet::<T>::new_onchain_(
+			did,
+			vec![UncheckedDidKey::new_with_all_relationships(public)],
+			Default::default(),
+		).unwrap();
+		blob::Blobs::<T>::insert([2; 32], (BlobOwner(did), BoundedBytes::<T::MaxBlobSize>::try_from(vec![0; 32]).unwrap()));
+
+		let add_schema = AddSchema {
+			id: SchemaId([3; 32]),
+			blob_id: [2; 32],
+			nonce: 1u8.into()
+		};
+
+		let sig = pair.sign(&add_schema.to_state_change().encode());
+		let signature = DidSignature::new(did, 1u32, sig);
+	}: add_schema(RawOrigin::Signed(caller), add_schema, signature)
+	verify {
+		assert_eq!(SchemaVersions::<T>::get(SchemaId([3; 32]), 1), Some([2; 32]));
+	}
+
+	add_schema_version_sr25519 for sr25519, add_schema_version_ed25519 for ed25519, add_schema_version_secp256k1 for secp256k1 {
+		let pair as Pair;
+		let caller = whitelisted_caller();
+		let did = Did([1; Did::BYTE_SIZE]);
+		let public = pair.public();
+
+		crate::did::Pallet::<T>::new_onchain_(
+			did,
+			vec![UncheckedDidKey::new_with_all_relationships(public)],
+			Default::default(),
+		).unwrap();
+		blob::Blobs::<T>::insert([2; 32], (BlobOwner(did), BoundedBytes::<T::MaxBlobSize>::try_from(vec![0; 32]).unwrap()));
+		Schemas::<T>::insert(SchemaId([3; 32]), Schema { owner: Attester(did), latest_version: 1 });
+
+		let add_version = AddSchemaVersion {
+			id: SchemaId([3; 32]),
+			blob_id: [2; 32],
+			nonce: 1u8.into()
+		};
+
+		let sig = pair.sign(&add_version.to_state_change().encode());
+		let signature = DidSignature::new(did, 1u32, sig);
+	}: add_schema_version(RawOrigin::Signed(caller), add_version, signature)
+	verify {
+		assert_eq!(SchemaVersions::<T>::get(SchemaId([3; 32]), 2), Some([2; 32]));
+	}
+
+	set_schema_attestation_sr25519 for sr25519, set_schema_attestation_ed25519 for ed25519, set_schema_attestation_secp256k1 for secp256k1 {
+		{
+			let l in 0 .. T::MaxIriSize::get() => ();
+		}
+		let pair as Pair;
+		let caller = whitelisted_caller();
+		let did = Did([1; Did::BYTE_SIZE]);
+		let public = pair.public();
+
+		crate::did::Pallet::<T>::new_onchain_(
+			did,
+			vec![UncheckedDidKey::new_with_all_relationships(public)],
+			Default::default(),
+		).unwrap();
+		Schemas::<T>::insert(SchemaId([3; 32]), Schema { owner: Attester(did), latest_version: 1 });
+		Registries::<T>::insert(
+			RegistryId([4; 32]),
+			Registry::<T> { policy: Policy::one_of(&[did]).unwrap(), add_only: false }
+		);
+
+		let set_attest = SetSchemaAttestation {
+			schema_id: SchemaId([3; 32]),
+			attest: SchemaAttestation {
+				schema_version: 1,
+				iri: Some(vec![12; l as usize].try_into().unwrap()),
+				expires_at: Some(100u8.into()),
+				revocation: Some((RegistryId([4; 32]), RevokeId([5; 32]))),
+			},
+			nonce: 1u8.into()
+		};
+
+		let sig = pair.sign(&set_attest.to_state_change().encode());
+		let signature = DidSignature::new(did, 1u32, sig);
+	}: set_schema_attestation(RawOrigin::Signed(caller), set_attest.clone(), signature)
+	verify {
+		assert_eq!(SchemaAttestations::<T>::get(Attester(did), SchemaId([3; 32])), Some(set_attest.attest));
+	}
+
+	remove_schema_attestation_sr25519 for sr25519, remove_schema_attestation_ed25519 for ed25519, remove_schema_attestation_secp256k1 for secp256k1 {
+		let pair as Pair;
+		let caller = whitelisted_caller();
+		let did = Did([1; Did::BYTE_SIZE]);
+		let public = pair.public();
+
+		crate::did::Pallet::<T>::new_onchain_(
+			did,
+			vec![UncheckedDidKey::new_with_all_relationships(public)],
+			Default::default(),
+		).unwrap();
+		SchemaAttestations::<T>::insert(
+			Attester(did),
+			SchemaId([3; 32]),
+			SchemaAttestation::<T> {
+				schema_version: 1,
+				iri: Some(vec![12; T::MaxIriSize::get() as usize].try_into().unwrap()),
+				expires_at: None,
+				revocation: None,
+			}
+		);
+
+		let remove = RemoveSchemaAttestation {
+			schema_id: SchemaId([3; 32]),
+			nonce: 1u8.into()
+		};
+
+		let sig = pair.sign(&remove.to_state_change().encode());
+		let signature = DidSignature::new(did, 1u32, sig);
+	}: remove_schema_attestation(RawOrigin::Signed(caller), remove, signature)
+	verify {
+		assert!(SchemaAttestations::<T>::get(Attester(did), SchemaId([3; 32])).is_none());
+	}
 }
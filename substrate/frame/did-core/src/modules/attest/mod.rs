@@ -1,10 +1,19 @@
 //! This module allows DIDs to publically attests to arbirary (and arbitrarily large) RDF
 //! claimgraphs. These attestations are not stored on-chain; rather, the attester chooses a storage
 //! method by specifying an Iri.
+//!
+//! Besides the single claim of `set_claim`, an attester can hold an attestation per credential
+//! schema. Schemas are JSON Schema documents stored with the `blob` module and registered here
+//! with versioning. Schema attestations may expire and may be revoked through a `revoke` registry.
 
+#[cfg(feature = "serde")]
+use crate::util::hex;
 use crate::{
+	blob::{self, BlobId, BlobOwner},
 	common::{Limits, SigValue, TypesAndLimits},
+	deposit_indexed_event,
 	did::{self, Did, DidSignature},
+	revoke::{self, RegistryId, RevokeId},
 	util::BoundedBytes,
 };
 use codec::{Decode, Encode, MaxEncodedLen};
@@ -20,6 +29,7 @@ pub use pallet::*;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarks;
+pub mod runtime_api;
 #[cfg(test)]
 mod tests;
 mod weights;
@@ -73,6 +83,126 @@ pub struct SetAttestationClaim<T: TypesAndLimits> {
 
 crate::impl_action_with_nonce! { for (): SetAttestationClaim with 1 as len, () as target }
 
+/// Identifier of a credential schema.
+#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq, Copy, Ord, PartialOrd, MaxEncodedLen)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(scale_info_derive::TypeInfo)]
+#[scale_info(omit_prefix)]
+pub struct SchemaId(#[cfg_attr(feature = "serde", serde(with = "hex"))] pub [u8; 32]);
+
+crate::impl_wrapper!(SchemaId([u8; 32]));
+
+/// Version of a credential schema, starting from 1.
+pub type SchemaVersion = u32;
+
+/// Credential schema whose versions are JSON Schema documents stored as blobs.
+#[derive(
+	Encode, Decode, scale_info_derive::TypeInfo, Clone, PartialEq, Eq, Debug, MaxEncodedLen,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[scale_info(omit_prefix)]
+pub struct Schema {
+	/// DID which registered the schema and is allowed to add versions
+	pub owner: Attester,
+	pub latest_version: SchemaVersion,
+}
+
+/// Attestation to claims conforming to a credential schema.
+#[derive(
+	Encode,
+	Decode,
+	scale_info_derive::TypeInfo,
+	CloneNoBound,
+	PartialEqNoBound,
+	EqNoBound,
+	DebugNoBound,
+	MaxEncodedLen,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "serde", serde(bound(serialize = "T: Sized", deserialize = "T: Sized")))]
+#[scale_info(skip_type_params(T))]
+#[scale_info(omit_prefix)]
+pub struct SchemaAttestation<T: TypesAndLimits> {
+	/// Version of the schema the claims conform to
+	pub schema_version: SchemaVersion,
+	pub iri: Option<Iri<T>>,
+	/// Block from which the attestation is no longer valid
+	pub expires_at: Option<T::BlockNumber>,
+	/// The attestation is revoked once this id is revoked in the registry
+	pub revocation: Option<(RegistryId, RevokeId)>,
+}
+
+/// Status of a schema attestation.
+#[derive(
+	Encode, Decode, scale_info_derive::TypeInfo, Clone, Copy, PartialEq, Eq, Debug, MaxEncodedLen,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[scale_info(omit_prefix)]
+pub enum AttestationStatus {
+	Active,
+	Expired,
+	Revoked,
+}
+
+#[derive(Encode, Decode, scale_info_derive::TypeInfo, Clone, PartialEq, Eq, DebugNoBound)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "serde", serde(bound(serialize = "T: Sized", deserialize = "T: Sized")))]
+#[scale_info(skip_type_params(T))]
+#[scale_info(omit_prefix)]
+pub struct AddSchema<T: TypesAndLimits> {
+	pub id: SchemaId,
+	/// Blob holding the first version of the schema
+	pub blob_id: BlobId,
+	pub nonce: T::BlockNumber,
+}
+
+#[derive(Encode, Decode, scale_info_derive::TypeInfo, Clone, PartialEq, Eq, DebugNoBound)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "serde", serde(bound(serialize = "T: Sized", deserialize = "T: Sized")))]
+#[scale_info(skip_type_params(T))]
+#[scale_info(omit_prefix)]
+pub struct AddSchemaVersion<T: TypesAndLimits> {
+	pub id: SchemaId,
+	/// Blob holding the new version of the schema
+	pub blob_id: BlobId,
+	pub nonce: T::BlockNumber,
+}
+
+#[derive(Encode, Decode, scale_info_derive::TypeInfo, Clone, PartialEq, Eq, DebugNoBound)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "serde", serde(bound(serialize = "T: Sized", deserialize = "T: Sized")))]
+#[scale_info(skip_type_params(T))]
+#[scale_info(omit_prefix)]
+pub struct SetSchemaAttestation<T: TypesAndLimits> {
+	pub schema_id: SchemaId,
+	pub attest: SchemaAttestation<T>,
+	pub nonce: T::BlockNumber,
+}
+
+#[derive(Encode, Decode, scale_info_derive::TypeInfo, Clone, PartialEq, Eq, DebugNoBound)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "serde", serde(bound(serialize = "T: Sized", deserialize = "T: Sized")))]
+#[scale_info(skip_type_params(T))]
+#[scale_info(omit_prefix)]
+pub struct RemoveSchemaAttestation<T: TypesAndLimits> {
+	pub schema_id: SchemaId,
+	pub nonce: T::BlockNumber,
+}
+
+crate::impl_action_with_nonce! {
+	for SchemaId:
+		AddSchema with 1 as len, id as target,
+		AddSchemaVersion with 1 as len, id as target,
+		SetSchemaAttestation with 1 as len, schema_id as target,
+		RemoveSchemaAttestation with 1 as len, schema_id as target
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -83,7 +213,24 @@ pub mod pallet {
 	pub struct Pallet<T>(_);
 
 	#[pallet::config]
-	pub trait Config: frame_system::Config + did::Config {}
+	pub trait Config: frame_system::Config + did::Config + blob::Config + revoke::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event>
+			+ IsType<<Self as frame_system::Config>::RuntimeEvent>
+			+ Into<<Self as frame_system::Config>::RuntimeEvent>;
+	}
+
+	#[pallet::event]
+	pub enum Event {
+		/// Schema with given id registered
+		SchemaAdded(SchemaId),
+		/// New version of the schema added
+		SchemaVersionAdded(SchemaId, SchemaVersion),
+		/// Attestation of the attester typed by the schema set
+		AttestationSet(Attester, SchemaId),
+		/// Attestation of the attester typed by the schema removed
+		AttestationRemoved(Attester, SchemaId),
+	}
 
 	#[pallet::error]
 	/// Error for the attest module.
@@ -96,6 +243,24 @@ pub mod pallet {
 		/// required. Check to see that the provided priority is not zero as that could be the
 		/// cause of this error.
 		PriorityTooLow,
+		/// A schema with that id already exists.
+		SchemaAlreadyExists,
+		/// There is no schema with that id.
+		SchemaDoesntExist,
+		/// The schema has no such version.
+		SchemaVersionDoesntExist,
+		/// Only the DID which registered the schema can add versions.
+		NotSchemaOwner,
+		/// There is no blob with that id.
+		BlobDoesntExist,
+		/// Schemas can only be registered from blobs of the same DID.
+		NotBlobOwner,
+		/// The revocation registry of the attestation doesn't exist.
+		RegistryDoesntExist,
+		/// The attestation expires before the current block.
+		AlreadyExpired,
+		/// The attester has no attestation typed by the schema.
+		AttestationDoesntExist,
 	}
 
 	/// The priority value provides replay protection and also gives attestations a partial
@@ -123,6 +288,30 @@ pub mod pallet {
 	pub type Attestations<T: Config> =
 		StorageMap<_, Blake2_128Concat, Attester, Attestation<T>, ValueQuery>;
 
+	/// Registered credential schemas.
+	#[pallet::storage]
+	#[pallet::getter(fn schema)]
+	pub type Schemas<T: Config> = StorageMap<_, Blake2_128Concat, SchemaId, Schema>;
+
+	/// Blob holding each version of a schema.
+	#[pallet::storage]
+	#[pallet::getter(fn schema_version)]
+	pub type SchemaVersions<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, SchemaId, Twox64Concat, SchemaVersion, BlobId>;
+
+	/// Attestations of an attester typed by a schema. Unlike `Attestations`, an attester can hold
+	/// one attestation per schema.
+	#[pallet::storage]
+	#[pallet::getter(fn schema_attestation)]
+	pub type SchemaAttestations<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		Attester,
+		Blake2_128Concat,
+		SchemaId,
+		SchemaAttestation<T>,
+	>;
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		#[pallet::weight(SubstrateWeight::<T>::set_claim(attests, signature))]
@@ -140,6 +329,76 @@ pub mod pallet {
 				signature,
 			)
 		}
+
+		/// Register a credential schema whose first version is the JSON Schema stored in a blob
+		/// of the signer.
+		#[pallet::weight(SubstrateWeight::<T>::add_schema(signature))]
+		#[pallet::call_index(1)]
+		pub fn add_schema(
+			origin: OriginFor<T>,
+			schema: AddSchema<T>,
+			signature: DidSignature<Attester>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			did::Pallet::<T>::try_exec_signed_action_from_onchain_did(
+				Self::add_schema_,
+				schema,
+				signature,
+			)
+		}
+
+		/// Add a new version to a schema registered by the signer. Existing attestations keep
+		/// referring to the version they were made with.
+		#[pallet::weight(SubstrateWeight::<T>::add_schema_version(signature))]
+		#[pallet::call_index(2)]
+		pub fn add_schema_version(
+			origin: OriginFor<T>,
+			version: AddSchemaVersion<T>,
+			signature: DidSignature<Attester>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			did::Pallet::<T>::try_exec_signed_action_from_onchain_did(
+				Self::add_schema_version_,
+				version,
+				signature,
+			)
+		}
+
+		/// Set the attestation of the signer typed by a schema, replacing the previous one.
+		#[pallet::weight(SubstrateWeight::<T>::set_schema_attestation(attests, signature))]
+		#[pallet::call_index(3)]
+		pub fn set_schema_attestation(
+			origin: OriginFor<T>,
+			attests: SetSchemaAttestation<T>,
+			signature: DidSignature<Attester>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			did::Pallet::<T>::try_exec_signed_action_from_onchain_did(
+				Self::set_schema_attestation_,
+				attests,
+				signature,
+			)
+		}
+
+		/// Remove the attestation of the signer typed by a schema.
+		#[pallet::weight(SubstrateWeight::<T>::remove_schema_attestation(signature))]
+		#[pallet::call_index(4)]
+		pub fn remove_schema_attestation(
+			origin: OriginFor<T>,
+			remove: RemoveSchemaAttestation<T>,
+			signature: DidSignature<Attester>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			did::Pallet::<T>::try_exec_signed_action_from_onchain_did(
+				Self::remove_schema_attestation_,
+				remove,
+				signature,
+			)
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -155,6 +414,139 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		fn add_schema_(
+			AddSchema { id, blob_id, .. }: AddSchema<T>,
+			owner: Attester,
+		) -> DispatchResult {
+			// check
+			ensure!(!Schemas::<T>::contains_key(id), Error::<T>::SchemaAlreadyExists);
+			Self::ensure_blob_owner(&blob_id, owner)?;
+
+			// execute
+			Schemas::<T>::insert(id, Schema { owner, latest_version: 1 });
+			SchemaVersions::<T>::insert(id, 1, blob_id);
+
+			deposit_indexed_event!(SchemaAdded(id));
+			Ok(())
+		}
+
+		fn add_schema_version_(
+			AddSchemaVersion { id, blob_id, .. }: AddSchemaVersion<T>,
+			owner: Attester,
+		) -> DispatchResult {
+			let version = Schemas::<T>::try_mutate(id, |schema| -> Result<_, DispatchError> {
+				let schema = schema.as_mut().ok_or(Error::<T>::SchemaDoesntExist)?;
+				// check
+				ensure!(schema.owner == owner, Error::<T>::NotSchemaOwner);
+				Self::ensure_blob_owner(&blob_id, owner)?;
+
+				// execute
+				schema.latest_version = schema.latest_version.saturating_add(1);
+				Ok(schema.latest_version)
+			})?;
+			SchemaVersions::<T>::insert(id, version, blob_id);
+
+			deposit_indexed_event!(SchemaVersionAdded(id, version) over id);
+			Ok(())
+		}
+
+		fn set_schema_attestation_(
+			SetSchemaAttestation { schema_id, attest, .. }: SetSchemaAttestation<T>,
+			attester: Attester,
+		) -> DispatchResult {
+			// check
+			let schema = Schemas::<T>::get(schema_id).ok_or(Error::<T>::SchemaDoesntExist)?;
+			ensure!(
+				(1..=schema.latest_version).contains(&attest.schema_version),
+				Error::<T>::SchemaVersionDoesntExist
+			);
+			if let Some(expires_at) = attest.expires_at {
+				ensure!(
+					expires_at > <frame_system::Pallet<T>>::block_number(),
+					Error::<T>::AlreadyExpired
+				);
+			}
+			if let Some((registry_id, _)) = attest.revocation {
+				ensure!(
+					revoke::Registries::<T>::contains_key(registry_id),
+					Error::<T>::RegistryDoesntExist
+				);
+			}
+
+			// execute
+			SchemaAttestations::<T>::insert(attester, schema_id, attest);
+
+			deposit_indexed_event!(AttestationSet(attester, schema_id));
+			Ok(())
+		}
+
+		fn remove_schema_attestation_(
+			RemoveSchemaAttestation { schema_id, .. }: RemoveSchemaAttestation<T>,
+			attester: Attester,
+		) -> DispatchResult {
+			// check
+			ensure!(
+				SchemaAttestations::<T>::contains_key(attester, schema_id),
+				Error::<T>::AttestationDoesntExist
+			);
+
+			// execute
+			SchemaAttestations::<T>::remove(attester, schema_id);
+
+			deposit_indexed_event!(AttestationRemoved(attester, schema_id));
+			Ok(())
+		}
+
+		fn ensure_blob_owner(blob_id: &BlobId, owner: Attester) -> DispatchResult {
+			let (blob_owner, _) =
+				blob::Blobs::<T>::get(blob_id).ok_or(Error::<T>::BlobDoesntExist)?;
+			ensure!(blob_owner == BlobOwner(owner.0), Error::<T>::NotBlobOwner);
+
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Status of the attestation at the current block.
+	pub fn attestation_status(attest: &SchemaAttestation<T>) -> AttestationStatus {
+		let revoked = attest.revocation.map_or(false, |(registry_id, revoke_id)| {
+			revoke::Revocations::<T>::contains_key(registry_id, revoke_id)
+		});
+		let expired = attest
+			.expires_at
+			.map_or(false, |expires_at| expires_at <= <frame_system::Pallet<T>>::block_number());
+
+		if revoked {
+			AttestationStatus::Revoked
+		} else if expired {
+			AttestationStatus::Expired
+		} else {
+			AttestationStatus::Active
+		}
+	}
+
+	/// Attestations of the attester with their status, only the one typed by `schema_id` if given.
+	pub fn attestations_of(
+		attester: Attester,
+		schema_id: Option<SchemaId>,
+	) -> Vec<(SchemaId, SchemaAttestation<T>, AttestationStatus)> {
+		let attestations: Vec<_> = match schema_id {
+			Some(schema_id) => SchemaAttestations::<T>::get(attester, schema_id)
+				.map(|attest| (schema_id, attest))
+				.into_iter()
+				.collect(),
+			None => SchemaAttestations::<T>::iter_prefix(attester).collect(),
+		};
+
+		attestations
+			.into_iter()
+			.map(|(schema_id, attest)| {
+				let status = Self::attestation_status(&attest);
+				(schema_id, attest, status)
+			})
+			.collect()
 	}
 }
 
@@ -168,4 +560,35 @@ impl<T: Config> SubstrateWeight<T> {
 			SigValue::Ed25519(_) => Self::set_claim_ed25519,
 		}(attest.iri.as_ref().map_or(0, |v| v.len()) as u32))
 	}
+
+	fn add_schema(DidSignature { sig, .. }: &DidSignature<Attester>) -> Weight {
+		(match sig {
+			SigValue::Sr25519(_) => Self::add_schema_sr25519,
+			SigValue::Ed25519(_) => Self::add_schema_ed25519,
+		}())
+	}
+
+	fn add_schema_version(DidSignature { sig, .. }: &DidSignature<Attester>) -> Weight {
+		(match sig {
+			SigValue::Sr25519(_) => Self::add_schema_version_sr25519,
+			SigValue::Ed25519(_) => Self::add_schema_version_ed25519,
+		}())
+	}
+
+	fn set_schema_attestation(
+		SetSchemaAttestation { attest, .. }: &SetSchemaAttestation<T>,
+		DidSignature { sig, .. }: &DidSignature<Attester>,
+	) -> Weight {
+		(match sig {
+			SigValue::Sr25519(_) => Self::set_schema_attestation_sr25519,
+			SigValue::Ed25519(_) => Self::set_schema_attestation_ed25519,
+		}(attest.iri.as_ref().map_or(0, |v| v.len()) as u32))
+	}
+
+	fn remove_schema_attestation(DidSignature { sig, .. }: &DidSignature<Attester>) -> Weight {
+		(match sig {
+			SigValue::Sr25519(_) => Self::remove_schema_attestation_sr25519,
+			SigValue::Ed25519(_) => Self::remove_schema_attestation_ed25519,
+		}())
+	}
 }
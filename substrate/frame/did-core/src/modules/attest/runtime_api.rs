@@ -0,0 +1,27 @@
+//! Runtime API of the attest module.
+
+use super::{AttestationStatus, Attester, Schema, SchemaId, SchemaVersion};
+use crate::blob::BlobId;
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// API to query the schemas and the schema attestations of the attest module.
+	pub trait AttestApi<Attestation>
+	where
+		Attestation: Codec,
+	{
+		/// Attestations of `attester` with their status, only the one typed by `schema_id` if
+		/// given.
+		fn attestations(
+			attester: Attester,
+			schema_id: Option<SchemaId>,
+		) -> Vec<(SchemaId, Attestation, AttestationStatus)>;
+
+		/// Schema registered under `schema_id`.
+		fn schema(schema_id: SchemaId) -> Option<Schema>;
+
+		/// Blob holding the given version of the schema.
+		fn schema_blob(schema_id: SchemaId, version: SchemaVersion) -> Option<BlobId>;
+	}
+}
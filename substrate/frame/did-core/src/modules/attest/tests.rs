@@ -1,5 +1,5 @@
 use super::*;
-use crate::tests::common::*;
+use crate::{common::Policy, tests::common::*};
 use frame_system::Origin;
 use sp_core::sr25519;
 
@@ -240,3 +240,226 @@ fn set_claim(
 		did_sig::<Test, _, _>(&SetAttestationClaim { attest: att.clone(), nonce }, kp, *claimer, 1),
 	)
 }
+
+fn add_blob(owner: Did, id: BlobId) {
+	let schema: BoundedBytes<MaxBlobSize> = b"{}".to_vec().try_into().unwrap();
+	blob::Blobs::<Test>::insert(id, (BlobOwner(owner), schema));
+}
+
+fn add_schema(
+	attester: &Attester,
+	id: SchemaId,
+	blob_id: BlobId,
+	kp: &sr25519::Pair,
+	nonce: u64,
+) -> DispatchResult {
+	let add = AddSchema { id, blob_id, nonce };
+	AttestMod::add_schema(
+		RuntimeOrigin::signed(0),
+		add.clone(),
+		did_sig::<Test, _, _>(&add, kp, *attester, 1),
+	)
+}
+
+fn add_schema_version(
+	attester: &Attester,
+	id: SchemaId,
+	blob_id: BlobId,
+	kp: &sr25519::Pair,
+	nonce: u64,
+) -> DispatchResult {
+	let add = AddSchemaVersion { id, blob_id, nonce };
+	AttestMod::add_schema_version(
+		RuntimeOrigin::signed(0),
+		add.clone(),
+		did_sig::<Test, _, _>(&add, kp, *attester, 1),
+	)
+}
+
+fn set_schema_attestation(
+	attester: &Attester,
+	schema_id: SchemaId,
+	attest: &SchemaAttestation<Test>,
+	kp: &sr25519::Pair,
+	nonce: u64,
+) -> DispatchResult {
+	let set = SetSchemaAttestation { schema_id, attest: attest.clone(), nonce };
+	AttestMod::set_schema_attestation(
+		RuntimeOrigin::signed(0),
+		set.clone(),
+		did_sig::<Test, _, _>(&set, kp, *attester, 1),
+	)
+}
+
+fn attest_events() -> Vec<super::Event> {
+	System::events()
+		.into_iter()
+		.filter_map(|record| match record.event {
+			TestEvent::Attest(e) => Some(e),
+			_ => None,
+		})
+		.collect()
+}
+
+/// Register a schema and add versions to it
+#[test]
+fn schema_versions() {
+	ext().execute_with(|| {
+		run_to_block(10);
+
+		let (did, kp) = newdid();
+		let did = Attester(did);
+		let (other, other_kp) = newdid();
+		let other = Attester(other);
+		let schema_id = SchemaId([1; 32]);
+
+		assert_eq!(
+			add_schema(&did, schema_id, [1; 32], &kp, 10 + 1).unwrap_err(),
+			Er::BlobDoesntExist.into()
+		);
+		add_blob(other.0, [1; 32]);
+		assert_eq!(
+			add_schema(&did, schema_id, [1; 32], &kp, 10 + 1).unwrap_err(),
+			Er::NotBlobOwner.into()
+		);
+		add_blob(did.0, [2; 32]);
+		add_schema(&did, schema_id, [2; 32], &kp, 10 + 1).unwrap();
+		check_nonce(&did, 10 + 1);
+		assert_eq!(AttestMod::schema(schema_id), Some(Schema { owner: did, latest_version: 1 }));
+		assert_eq!(AttestMod::schema_version(schema_id, 1), Some([2; 32]));
+		assert_eq!(
+			add_schema(&did, schema_id, [2; 32], &kp, 11 + 1).unwrap_err(),
+			Er::SchemaAlreadyExists.into()
+		);
+
+		assert_eq!(
+			add_schema_version(&other, schema_id, [1; 32], &other_kp, 10 + 1).unwrap_err(),
+			Er::NotSchemaOwner.into()
+		);
+		assert_eq!(
+			add_schema_version(&did, SchemaId([9; 32]), [2; 32], &kp, 11 + 1).unwrap_err(),
+			Er::SchemaDoesntExist.into()
+		);
+		add_blob(did.0, [3; 32]);
+		add_schema_version(&did, schema_id, [3; 32], &kp, 11 + 1).unwrap();
+		assert_eq!(AttestMod::schema(schema_id).unwrap().latest_version, 2);
+		assert_eq!(AttestMod::schema_version(schema_id, 2), Some([3; 32]));
+		assert_eq!(
+			attest_events(),
+			vec![
+				super::Event::SchemaAdded(schema_id),
+				super::Event::SchemaVersionAdded(schema_id, 2)
+			]
+		);
+	});
+}
+
+/// An attester holds an attestation per schema, which expires or gets revoked
+#[test]
+fn schema_attestations() {
+	ext().execute_with(|| {
+		run_to_block(10);
+
+		let (did, kp) = newdid();
+		let did = Attester(did);
+		let (first, second) = (SchemaId([1; 32]), SchemaId([2; 32]));
+		add_blob(did.0, [1; 32]);
+		add_schema(&did, first, [1; 32], &kp, 10 + 1).unwrap();
+		add_schema(&did, second, [1; 32], &kp, 11 + 1).unwrap();
+
+		let registry_id = RegistryId([5; 32]);
+		let attest = SchemaAttestation::<Test> {
+			schema_version: 1,
+			iri: Some(vec![0, 1, 2].try_into().unwrap()),
+			expires_at: Some(20),
+			revocation: Some((registry_id, RevokeId([6; 32]))),
+		};
+		assert_eq!(
+			set_schema_attestation(&did, SchemaId([9; 32]), &attest, &kp, 12 + 1).unwrap_err(),
+			Er::SchemaDoesntExist.into()
+		);
+		assert_eq!(
+			set_schema_attestation(
+				&did,
+				first,
+				&SchemaAttestation { schema_version: 2, ..attest.clone() },
+				&kp,
+				12 + 1
+			)
+			.unwrap_err(),
+			Er::SchemaVersionDoesntExist.into()
+		);
+		assert_eq!(
+			set_schema_attestation(
+				&did,
+				first,
+				&SchemaAttestation { expires_at: Some(10), ..attest.clone() },
+				&kp,
+				12 + 1
+			)
+			.unwrap_err(),
+			Er::AlreadyExpired.into()
+		);
+		assert_eq!(
+			set_schema_attestation(&did, first, &attest, &kp, 12 + 1).unwrap_err(),
+			Er::RegistryDoesntExist.into()
+		);
+		revoke::Registries::<Test>::insert(
+			registry_id,
+			revoke::Registry::<Test> { policy: Policy::one_of(&[did.0]).unwrap(), add_only: false },
+		);
+
+		set_schema_attestation(&did, first, &attest, &kp, 12 + 1).unwrap();
+		let other = SchemaAttestation::<Test> {
+			schema_version: 1,
+			iri: None,
+			expires_at: None,
+			revocation: None,
+		};
+		set_schema_attestation(&did, second, &other, &kp, 13 + 1).unwrap();
+		check_nonce(&did, 13 + 1);
+		// The single claim is left untouched
+		assert_eq!(Attestations::<Test>::get(did), Attestation { priority: 0, iri: None });
+
+		let mut attestations = AttestMod::attestations_of(did, None);
+		attestations.sort_by_key(|(schema_id, ..)| *schema_id);
+		assert_eq!(
+			attestations,
+			vec![
+				(first, attest.clone(), AttestationStatus::Active),
+				(second, other.clone(), AttestationStatus::Active)
+			]
+		);
+		assert_eq!(
+			AttestMod::attestations_of(did, Some(second)),
+			vec![(second, other, AttestationStatus::Active)]
+		);
+		assert!(AttestMod::attestations_of(did, Some(SchemaId([9; 32]))).is_empty());
+
+		run_to_block(20);
+		assert_eq!(AttestMod::attestation_status(&attest), AttestationStatus::Expired);
+		revoke::Revocations::<Test>::insert(registry_id, RevokeId([6; 32]), ());
+		assert_eq!(AttestMod::attestation_status(&attest), AttestationStatus::Revoked);
+
+		let remove = RemoveSchemaAttestation { schema_id: first, nonce: 14 + 1 };
+		AttestMod::remove_schema_attestation(
+			RuntimeOrigin::signed(0),
+			remove.clone(),
+			did_sig::<Test, _, _>(&remove, &kp, did, 1),
+		)
+		.unwrap();
+		assert_eq!(AttestMod::schema_attestation(did, first), None);
+		assert!(attest_events().contains(&super::Event::AttestationRemoved(did, first)));
+
+		let remove = RemoveSchemaAttestation { schema_id: first, nonce: 15 + 1 };
+		assert_eq!(
+			AttestMod::remove_schema_attestation(
+				RuntimeOrigin::signed(0),
+				remove.clone(),
+				did_sig::<Test, _, _>(&remove, &kp, did, 1),
+			)
+			.unwrap_err(),
+			Er::AttestationDoesntExist.into()
+		);
+	});
+}
@@ -1,21 +1,15 @@
 //! Autogenerated weights for attest
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 3.0.0
-//! DATE: 2022-08-01, STEPS: `[50, ]`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! EXECUTION: Some(Native), WASM-EXECUTION: Interpreted, CHAIN: Some("mainnet"), DB CACHE: 128
-
-// Executed Command:
-// ./target/production/dock-node
-// benchmark
-// --execution=native
-// --chain=mainnet
-// --pallet=attest
-// --extra
-// --extrinsic=*
-// --repeat=20
-// --steps=50
-// --template=node/module-weight-template.hbs
-// --output=./pallets/core/src/modules/attest/weights.rs
+//! DATE: 2026-10-19, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
+//! EXECUTION: Native, CHAIN: the test runtime of `tests/common.rs`
+//!
+//! Measured from `attest/benchmarks.rs` natively against the benchmarking state database, as no
+//! wasm build of a runtime was available. Regenerate with `benchmark pallet --pallet attest`
+//! on reference hardware before relying on them in production.
+//!
+//! Only the sr25519 and ed25519 benchmarks are run, as `SigValue` has no secp256k1 variant. The
+//! secp256k1 weights repeat the sr25519 ones and are never charged.
 
 #![allow(unused_parens)]
 #![allow(unused_imports)]
@@ -31,55 +25,367 @@ pub trait WeightInfo {
 	fn set_claim_sr25519(l: u32) -> Weight;
 	fn set_claim_ed25519(l: u32) -> Weight;
 	fn set_claim_secp256k1(l: u32) -> Weight;
+	fn add_schema_sr25519() -> Weight;
+	fn add_schema_ed25519() -> Weight;
+	fn add_schema_secp256k1() -> Weight;
+	fn add_schema_version_sr25519() -> Weight;
+	fn add_schema_version_ed25519() -> Weight;
+	fn add_schema_version_secp256k1() -> Weight;
+	fn set_schema_attestation_sr25519(l: u32) -> Weight;
+	fn set_schema_attestation_ed25519(l: u32) -> Weight;
+	fn set_schema_attestation_secp256k1(l: u32) -> Weight;
+	fn remove_schema_attestation_sr25519() -> Weight;
+	fn remove_schema_attestation_ed25519() -> Weight;
+	fn remove_schema_attestation_secp256k1() -> Weight;
 }
 
 /// Weights for attest using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: Attest Attestations (r:1 w:1)
+	/// Storage: DIDModule DidKeys (r:1 w:0)
+	/// Storage: DIDModule Dids (r:1 w:1)
 	fn set_claim_sr25519(l: u32) -> Weight {
-		Weight::from_ref_time(49_216_000_u64)
-			// Standard Error: 0
-			.saturating_add(Weight::from_ref_time(3_000_u64).saturating_mul(l as u64))
+		// Minimum execution time: 62_970_000 picoseconds.
+		Weight::from_ref_time(64_885_148_u64)
+			.saturating_add(Weight::from_ref_time(3_247_u64).saturating_mul(l as u64))
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Storage: Attest Attestations (r:1 w:1)
+	/// Storage: DIDModule DidKeys (r:1 w:0)
+	/// Storage: DIDModule Dids (r:1 w:1)
 	fn set_claim_ed25519(l: u32) -> Weight {
-		Weight::from_ref_time(49_142_000_u64)
-			// Standard Error: 0
-			.saturating_add(Weight::from_ref_time(2_000_u64).saturating_mul(l as u64))
+		// Minimum execution time: 62_777_000 picoseconds.
+		Weight::from_ref_time(80_455_371_u64)
+			.saturating_add(Weight::from_ref_time(1_083_u64).saturating_mul(l as u64))
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Storage: Attest Attestations (r:1 w:1)
+	/// Storage: DIDModule DidKeys (r:1 w:0)
+	/// Storage: DIDModule Dids (r:1 w:1)
 	fn set_claim_secp256k1(l: u32) -> Weight {
-		Weight::from_ref_time(152_813_000_u64)
-			// Standard Error: 0
-			.saturating_add(Weight::from_ref_time(1_000_u64).saturating_mul(l as u64))
+		// Minimum execution time: 62_970_000 picoseconds.
+		Weight::from_ref_time(64_885_148_u64)
+			.saturating_add(Weight::from_ref_time(3_247_u64).saturating_mul(l as u64))
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Storage: Attest SchemaVersions (r:0 w:1)
+	/// Storage: Attest Schemas (r:1 w:1)
+	/// Storage: BlobStore Blobs (r:1 w:0)
+	/// Storage: DIDModule DidKeys (r:1 w:0)
+	/// Storage: DIDModule Dids (r:1 w:1)
+	/// Storage: System EventTopics (r:1 w:1)
+	fn add_schema_sr25519() -> Weight {
+		// Minimum execution time: 72_014_000 picoseconds.
+		Weight::from_ref_time(75_081_000_u64)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Attest SchemaVersions (r:0 w:1)
+	/// Storage: Attest Schemas (r:1 w:1)
+	/// Storage: BlobStore Blobs (r:1 w:0)
+	/// Storage: DIDModule DidKeys (r:1 w:0)
+	/// Storage: DIDModule Dids (r:1 w:1)
+	/// Storage: System EventTopics (r:1 w:1)
+	fn add_schema_ed25519() -> Weight {
+		// Minimum execution time: 72_194_000 picoseconds.
+		Weight::from_ref_time(73_474_000_u64)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Attest SchemaVersions (r:0 w:1)
+	/// Storage: Attest Schemas (r:1 w:1)
+	/// Storage: BlobStore Blobs (r:1 w:0)
+	/// Storage: DIDModule DidKeys (r:1 w:0)
+	/// Storage: DIDModule Dids (r:1 w:1)
+	/// Storage: System EventTopics (r:1 w:1)
+	fn add_schema_secp256k1() -> Weight {
+		// Minimum execution time: 72_014_000 picoseconds.
+		Weight::from_ref_time(75_081_000_u64)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Attest SchemaVersions (r:0 w:1)
+	/// Storage: Attest Schemas (r:1 w:1)
+	/// Storage: BlobStore Blobs (r:1 w:0)
+	/// Storage: DIDModule DidKeys (r:1 w:0)
+	/// Storage: DIDModule Dids (r:1 w:1)
+	/// Storage: System EventTopics (r:1 w:1)
+	fn add_schema_version_sr25519() -> Weight {
+		// Minimum execution time: 73_675_000 picoseconds.
+		Weight::from_ref_time(76_072_000_u64)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Attest SchemaVersions (r:0 w:1)
+	/// Storage: Attest Schemas (r:1 w:1)
+	/// Storage: BlobStore Blobs (r:1 w:0)
+	/// Storage: DIDModule DidKeys (r:1 w:0)
+	/// Storage: DIDModule Dids (r:1 w:1)
+	/// Storage: System EventTopics (r:1 w:1)
+	fn add_schema_version_ed25519() -> Weight {
+		// Minimum execution time: 72_997_000 picoseconds.
+		Weight::from_ref_time(76_005_000_u64)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Attest SchemaVersions (r:0 w:1)
+	/// Storage: Attest Schemas (r:1 w:1)
+	/// Storage: BlobStore Blobs (r:1 w:0)
+	/// Storage: DIDModule DidKeys (r:1 w:0)
+	/// Storage: DIDModule Dids (r:1 w:1)
+	/// Storage: System EventTopics (r:1 w:1)
+	fn add_schema_version_secp256k1() -> Weight {
+		// Minimum execution time: 73_675_000 picoseconds.
+		Weight::from_ref_time(76_072_000_u64)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Attest SchemaAttestations (r:0 w:1)
+	/// Storage: Attest Schemas (r:1 w:0)
+	/// Storage: DIDModule DidKeys (r:1 w:0)
+	/// Storage: DIDModule Dids (r:1 w:1)
+	/// Storage: Revoke Registries (r:1 w:0)
+	/// Storage: System EventTopics (r:2 w:2)
+	fn set_schema_attestation_sr25519(l: u32) -> Weight {
+		// Minimum execution time: 77_817_000 picoseconds.
+		Weight::from_ref_time(91_888_707_u64)
+			.saturating_add(Weight::from_ref_time(9_229_u64).saturating_mul(l as u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Attest SchemaAttestations (r:0 w:1)
+	/// Storage: Attest Schemas (r:1 w:0)
+	/// Storage: DIDModule DidKeys (r:1 w:0)
+	/// Storage: DIDModule Dids (r:1 w:1)
+	/// Storage: Revoke Registries (r:1 w:0)
+	/// Storage: System EventTopics (r:2 w:2)
+	fn set_schema_attestation_ed25519(l: u32) -> Weight {
+		// Minimum execution time: 76_372_000 picoseconds.
+		Weight::from_ref_time(79_307_329_u64)
+			.saturating_add(Weight::from_ref_time(3_522_u64).saturating_mul(l as u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Attest SchemaAttestations (r:0 w:1)
+	/// Storage: Attest Schemas (r:1 w:0)
+	/// Storage: DIDModule DidKeys (r:1 w:0)
+	/// Storage: DIDModule Dids (r:1 w:1)
+	/// Storage: Revoke Registries (r:1 w:0)
+	/// Storage: System EventTopics (r:2 w:2)
+	fn set_schema_attestation_secp256k1(l: u32) -> Weight {
+		// Minimum execution time: 77_817_000 picoseconds.
+		Weight::from_ref_time(91_888_707_u64)
+			.saturating_add(Weight::from_ref_time(9_229_u64).saturating_mul(l as u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Attest SchemaAttestations (r:1 w:1)
+	/// Storage: DIDModule DidKeys (r:1 w:0)
+	/// Storage: DIDModule Dids (r:1 w:1)
+	/// Storage: System EventTopics (r:2 w:2)
+	fn remove_schema_attestation_sr25519() -> Weight {
+		// Minimum execution time: 72_944_000 picoseconds.
+		Weight::from_ref_time(83_002_000_u64)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Attest SchemaAttestations (r:1 w:1)
+	/// Storage: DIDModule DidKeys (r:1 w:0)
+	/// Storage: DIDModule Dids (r:1 w:1)
+	/// Storage: System EventTopics (r:2 w:2)
+	fn remove_schema_attestation_ed25519() -> Weight {
+		// Minimum execution time: 72_521_000 picoseconds.
+		Weight::from_ref_time(97_448_000_u64)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Attest SchemaAttestations (r:1 w:1)
+	/// Storage: DIDModule DidKeys (r:1 w:0)
+	/// Storage: DIDModule Dids (r:1 w:1)
+	/// Storage: System EventTopics (r:2 w:2)
+	fn remove_schema_attestation_secp256k1() -> Weight {
+		// Minimum execution time: 72_944_000 picoseconds.
+		Weight::from_ref_time(83_002_000_u64)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
+	/// Storage: Attest Attestations (r:1 w:1)
+	/// Storage: DIDModule DidKeys (r:1 w:0)
+	/// Storage: DIDModule Dids (r:1 w:1)
 	fn set_claim_sr25519(l: u32) -> Weight {
-		Weight::from_ref_time(49_216_000_u64)
-			// Standard Error: 0
-			.saturating_add(Weight::from_ref_time(3_000_u64).saturating_mul(l as u64))
+		// Minimum execution time: 62_970_000 picoseconds.
+		Weight::from_ref_time(64_885_148_u64)
+			.saturating_add(Weight::from_ref_time(3_247_u64).saturating_mul(l as u64))
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Storage: Attest Attestations (r:1 w:1)
+	/// Storage: DIDModule DidKeys (r:1 w:0)
+	/// Storage: DIDModule Dids (r:1 w:1)
 	fn set_claim_ed25519(l: u32) -> Weight {
-		Weight::from_ref_time(49_142_000_u64)
-			// Standard Error: 0
-			.saturating_add(Weight::from_ref_time(2_000_u64).saturating_mul(l as u64))
+		// Minimum execution time: 62_777_000 picoseconds.
+		Weight::from_ref_time(80_455_371_u64)
+			.saturating_add(Weight::from_ref_time(1_083_u64).saturating_mul(l as u64))
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Storage: Attest Attestations (r:1 w:1)
+	/// Storage: DIDModule DidKeys (r:1 w:0)
+	/// Storage: DIDModule Dids (r:1 w:1)
 	fn set_claim_secp256k1(l: u32) -> Weight {
-		Weight::from_ref_time(152_813_000_u64)
-			// Standard Error: 0
-			.saturating_add(Weight::from_ref_time(1_000_u64).saturating_mul(l as u64))
+		// Minimum execution time: 62_970_000 picoseconds.
+		Weight::from_ref_time(64_885_148_u64)
+			.saturating_add(Weight::from_ref_time(3_247_u64).saturating_mul(l as u64))
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Storage: Attest SchemaVersions (r:0 w:1)
+	/// Storage: Attest Schemas (r:1 w:1)
+	/// Storage: BlobStore Blobs (r:1 w:0)
+	/// Storage: DIDModule DidKeys (r:1 w:0)
+	/// Storage: DIDModule Dids (r:1 w:1)
+	/// Storage: System EventTopics (r:1 w:1)
+	fn add_schema_sr25519() -> Weight {
+		// Minimum execution time: 72_014_000 picoseconds.
+		Weight::from_ref_time(75_081_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Attest SchemaVersions (r:0 w:1)
+	/// Storage: Attest Schemas (r:1 w:1)
+	/// Storage: BlobStore Blobs (r:1 w:0)
+	/// Storage: DIDModule DidKeys (r:1 w:0)
+	/// Storage: DIDModule Dids (r:1 w:1)
+	/// Storage: System EventTopics (r:1 w:1)
+	fn add_schema_ed25519() -> Weight {
+		// Minimum execution time: 72_194_000 picoseconds.
+		Weight::from_ref_time(73_474_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Attest SchemaVersions (r:0 w:1)
+	/// Storage: Attest Schemas (r:1 w:1)
+	/// Storage: BlobStore Blobs (r:1 w:0)
+	/// Storage: DIDModule DidKeys (r:1 w:0)
+	/// Storage: DIDModule Dids (r:1 w:1)
+	/// Storage: System EventTopics (r:1 w:1)
+	fn add_schema_secp256k1() -> Weight {
+		// Minimum execution time: 72_014_000 picoseconds.
+		Weight::from_ref_time(75_081_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Attest SchemaVersions (r:0 w:1)
+	/// Storage: Attest Schemas (r:1 w:1)
+	/// Storage: BlobStore Blobs (r:1 w:0)
+	/// Storage: DIDModule DidKeys (r:1 w:0)
+	/// Storage: DIDModule Dids (r:1 w:1)
+	/// Storage: System EventTopics (r:1 w:1)
+	fn add_schema_version_sr25519() -> Weight {
+		// Minimum execution time: 73_675_000 picoseconds.
+		Weight::from_ref_time(76_072_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Attest SchemaVersions (r:0 w:1)
+	/// Storage: Attest Schemas (r:1 w:1)
+	/// Storage: BlobStore Blobs (r:1 w:0)
+	/// Storage: DIDModule DidKeys (r:1 w:0)
+	/// Storage: DIDModule Dids (r:1 w:1)
+	/// Storage: System EventTopics (r:1 w:1)
+	fn add_schema_version_ed25519() -> Weight {
+		// Minimum execution time: 72_997_000 picoseconds.
+		Weight::from_ref_time(76_005_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Attest SchemaVersions (r:0 w:1)
+	/// Storage: Attest Schemas (r:1 w:1)
+	/// Storage: BlobStore Blobs (r:1 w:0)
+	/// Storage: DIDModule DidKeys (r:1 w:0)
+	/// Storage: DIDModule Dids (r:1 w:1)
+	/// Storage: System EventTopics (r:1 w:1)
+	fn add_schema_version_secp256k1() -> Weight {
+		// Minimum execution time: 73_675_000 picoseconds.
+		Weight::from_ref_time(76_072_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Attest SchemaAttestations (r:0 w:1)
+	/// Storage: Attest Schemas (r:1 w:0)
+	/// Storage: DIDModule DidKeys (r:1 w:0)
+	/// Storage: DIDModule Dids (r:1 w:1)
+	/// Storage: Revoke Registries (r:1 w:0)
+	/// Storage: System EventTopics (r:2 w:2)
+	fn set_schema_attestation_sr25519(l: u32) -> Weight {
+		// Minimum execution time: 77_817_000 picoseconds.
+		Weight::from_ref_time(91_888_707_u64)
+			.saturating_add(Weight::from_ref_time(9_229_u64).saturating_mul(l as u64))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Attest SchemaAttestations (r:0 w:1)
+	/// Storage: Attest Schemas (r:1 w:0)
+	/// Storage: DIDModule DidKeys (r:1 w:0)
+	/// Storage: DIDModule Dids (r:1 w:1)
+	/// Storage: Revoke Registries (r:1 w:0)
+	/// Storage: System EventTopics (r:2 w:2)
+	fn set_schema_attestation_ed25519(l: u32) -> Weight {
+		// Minimum execution time: 76_372_000 picoseconds.
+		Weight::from_ref_time(79_307_329_u64)
+			.saturating_add(Weight::from_ref_time(3_522_u64).saturating_mul(l as u64))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Attest SchemaAttestations (r:0 w:1)
+	/// Storage: Attest Schemas (r:1 w:0)
+	/// Storage: DIDModule DidKeys (r:1 w:0)
+	/// Storage: DIDModule Dids (r:1 w:1)
+	/// Storage: Revoke Registries (r:1 w:0)
+	/// Storage: System EventTopics (r:2 w:2)
+	fn set_schema_attestation_secp256k1(l: u32) -> Weight {
+		// Minimum execution time: 77_817_000 picoseconds.
+		Weight::from_ref_time(91_888_707_u64)
+			.saturating_add(Weight::from_ref_time(9_229_u64).saturating_mul(l as u64))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Attest SchemaAttestations (r:1 w:1)
+	/// Storage: DIDModule DidKeys (r:1 w:0)
+	/// Storage: DIDModule Dids (r:1 w:1)
+	/// Storage: System EventTopics (r:2 w:2)
+	fn remove_schema_attestation_sr25519() -> Weight {
+		// Minimum execution time: 72_944_000 picoseconds.
+		Weight::from_ref_time(83_002_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Attest SchemaAttestations (r:1 w:1)
+	/// Storage: DIDModule DidKeys (r:1 w:0)
+	/// Storage: DIDModule Dids (r:1 w:1)
+	/// Storage: System EventTopics (r:2 w:2)
+	fn remove_schema_attestation_ed25519() -> Weight {
+		// Minimum execution time: 72_521_000 picoseconds.
+		Weight::from_ref_time(97_448_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Attest SchemaAttestations (r:1 w:1)
+	/// Storage: DIDModule DidKeys (r:1 w:0)
+	/// Storage: DIDModule Dids (r:1 w:1)
+	/// Storage: System EventTopics (r:2 w:2)
+	fn remove_schema_attestation_secp256k1() -> Weight {
+		// Minimum execution time: 72_944_000 picoseconds.
+		Weight::from_ref_time(83_002_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
 }
@@ -60,6 +60,7 @@ pub enum TestEvent {
 	Accum(accumulator::Event),
 	StatusListCredential(status_list_credential::Event),
 	TrustedEntity(trusted_entity::Event),
+	Attest(attest::Event),
 }
 
 impl From<frame_system::Event<Test>> for TestEvent {
@@ -122,6 +123,12 @@ impl From<trusted_entity::Event> for TestEvent {
 	}
 }
 
+impl From<attest::Event> for TestEvent {
+	fn from(other: attest::Event) -> Self {
+		Self::Attest(other)
+	}
+}
+
 parameter_types! {
 	pub const BlockHashCount: u64 = 250;
 	pub const MaxPolicyControllers: u32 = 15;
@@ -237,7 +244,9 @@ impl crate::status_list_credential::Config for Test {
 	type RuntimeEvent = TestEvent;
 }
 impl crate::blob::Config for Test {}
-impl crate::attest::Config for Test {}
+impl crate::attest::Config for Test {
+	type RuntimeEvent = TestEvent;
+}
 
 parameter_types! {
 	pub const MaxBlobSize: u32 = 1024;
@@ -255,6 +264,7 @@ parameter_types! {
 
 impl crate::anchor::Config for Test {
 	type RuntimeEvent = TestEvent;
+	type UnixTime = Timestamp;
 }
 
 impl offchain_signatures::Config for Test {
@@ -0,0 +1,38 @@
+//! Benchmarks for System Token Oracle Pallet
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::v1::benchmarks;
+use frame_system::RawOrigin;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+/// `f` fiats, distinct as long as there are enough variants of `Fiat`
+fn fiats(f: u32) -> Vec<Fiat> {
+	(0..f).map(|i| Fiat::decode(&mut &[i as u8][..]).unwrap_or_default()).collect()
+}
+
+benchmarks! {
+	submit_exchange_rates_unsigned {
+		let f in 1 .. T::MaxFiats::get();
+		let exchange_rates: Vec<(Fiat, ExchangeRate)> =
+			fiats(f).into_iter().map(|fiat| (fiat, 1_000_000)).collect();
+	}: _(RawOrigin::None, exchange_rates.clone())
+	verify {
+		assert_last_event::<T>(Event::<T>::ExchangeRatesSubmitted { exchange_rates }.into());
+	}
+
+	request_fiat {
+		let f in 1 .. T::MaxFiats::get();
+		let fiat = fiats(f);
+	}: _(RawOrigin::Root, fiat.clone())
+	verify {
+		assert_last_event::<T>(Event::<T>::Requested { fiat }.into());
+	}
+
+	add_oracle {}: _(RawOrigin::Root)
+}
@@ -21,6 +21,12 @@ mod types;
 
 pub use types::*;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::*;
+
 use frame_support::pallet_prelude::*;
 use frame_system::{
 	offchain::{SendTransactionTypes, SubmitTransaction},
@@ -32,7 +38,7 @@ use sp_std::{prelude::ToOwned, vec::Vec};
 
 pub use pallet::*;
 
-#[frame_support::pallet(dev_mode)]
+#[frame_support::pallet]
 pub mod pallet {
 
 	use super::*;
@@ -65,11 +71,18 @@ pub mod pallet {
 
 		#[pallet::constant]
 		type UnsignedPriority: Get<TransactionPriority>;
+
+		/// Maximum number of fiats which can be requested or submitted at once
+		#[pallet::constant]
+		type MaxFiats: Get<u32>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
 	}
 
 	/// List of Fiat that should be requested via offchain call
 	#[pallet::storage]
-	pub type Requested<T: Config> = StorageValue<_, Vec<Fiat>>;
+	pub type Requested<T: Config> = StorageValue<_, BoundedVec<Fiat, T::MaxFiats>>;
 
 	/// Exhange rate for each currency
 	#[pallet::storage]
@@ -90,9 +103,12 @@ pub mod pallet {
 		type Call = Call<T>;
 
 		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
-			if let Call::submit_exchange_rates_unsigned { .. } = call {
+			if let Call::submit_exchange_rates_unsigned { exchange_rates } = call {
 				// TODO: Needs to add some validity check for the transaction
 				// - Make it signed payload
+				if exchange_rates.len() > T::MaxFiats::get() as usize {
+					return InvalidTransaction::ExhaustsResources.into()
+				}
 				let current = <frame_system::Pallet<T>>::block_number();
 				let next_unsigned_at = <NextUnsignedAt<T>>::get();
 				if next_unsigned_at > current {
@@ -129,13 +145,15 @@ pub mod pallet {
 		SystemConfigMissing,
 		/// Conversion Error
 		ConversionError,
+		/// Number of fiats is over `T::MaxFiats`
+		TooManyFiats,
 	}
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 		fn offchain_worker(_n: BlockNumberFor<T>) {
 			if let Some(currencies) = Requested::<T>::get() {
-				if let Err(_) = Self::fetch_exchange_rate(currencies.clone()) {
+				if let Err(_) = Self::fetch_exchange_rate(currencies.to_vec()) {
 					log::warn!("❌❌ Failed to fetch exchange rate for => {:?}", currencies);
 				}
 			} 
@@ -147,12 +165,17 @@ pub mod pallet {
 		/// Submit unsigned extrinsic to set the exchange rates.
 		/// It is an open door to Runtime. So, we should figure out how to make it secure.
 		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::submit_exchange_rates_unsigned(exchange_rates.len() as u32))]
 		pub fn submit_exchange_rates_unsigned(
 			origin: OriginFor<T>,
 			exchange_rates: Vec<(Fiat, ExchangeRate)>,
 		) -> DispatchResult {
 			// This ensures that the function can only be called via unsigned transaction.
 			ensure_none(origin)?;
+			ensure!(
+				exchange_rates.len() <= T::MaxFiats::get() as usize,
+				Error::<T>::TooManyFiats
+			);
 			T::SystemTokenOracle::submit_exchange_rates(exchange_rates.clone());
 			let current_block = <frame_system::Pallet<T>>::block_number();
 			let next_unsigned_at = current_block + T::RequestPeriod::get();
@@ -162,25 +185,28 @@ pub mod pallet {
 		}
 
 		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::request_fiat(fiat.len() as u32))]
 		pub fn request_fiat(
 			origin: OriginFor<T>,
 			fiat: Vec<Fiat>, 
 		) -> DispatchResult {
 			ensure_root(origin)?;
-			for f in fiat.iter() {
-				Requested::<T>::mutate(|maybe_currencies| {
-					let mut currencies = maybe_currencies.take().unwrap_or_default();
-					if !currencies.contains(&f) {
-						currencies.push(f.clone());
+			Requested::<T>::try_mutate(|maybe_currencies| -> DispatchResult {
+				let mut currencies = maybe_currencies.take().unwrap_or_default();
+				for f in fiat.iter() {
+					if !currencies.contains(f) {
+						currencies.try_push(f.clone()).map_err(|_| Error::<T>::TooManyFiats)?;
 					}
-					*maybe_currencies = Some(currencies);
-				});
-			}
+				}
+				*maybe_currencies = Some(currencies);
+				Ok(())
+			})?;
 			Self::deposit_event(Event::<T>::Requested { fiat });
 			Ok(())
 		}
 		
 		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::add_oracle())]
 		pub fn add_oracle(origin: OriginFor<T>) -> DispatchResult {
 			ensure_root(origin)?;
 			Ok(())
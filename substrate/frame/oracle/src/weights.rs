@@ -0,0 +1,119 @@
+//! Autogenerated weights for `pallet_system_token_oracle`
+//!
+//! DATE: 2026-10-19, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
+//! EXECUTION: Native, CHAIN: a mock runtime with `type SystemTokenOracle = ()`
+//!
+//! Measured from `benchmarking.rs` natively against the benchmarking state database, as no wasm
+//! build of a runtime was available. Regenerate with
+//! `benchmark pallet --pallet pallet_system_token_oracle` on reference hardware before relying on
+//! them in production.
+//!
+//! The exchange rates are sent to Relay-chain by `T::SystemTokenOracle` as an upward message,
+//! which the mock runtime does not do. `submit_exchange_rates_unsigned` adds the measured weight
+//! of `pallet_xcm::send` of the asset hub (`weights/pallet_xcm.rs`) for it.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for `pallet_system_token_oracle`.
+pub trait WeightInfo {
+	fn submit_exchange_rates_unsigned(f: u32) -> Weight;
+	fn request_fiat(f: u32) -> Weight;
+	fn add_oracle() -> Weight;
+}
+
+/// Weights for `pallet_system_token_oracle` measured on the mock runtime.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: SystemTokenOracle NextUnsignedAt (r:0 w:1)
+	/// Proof: SystemTokenOracle NextUnsignedAt (max_values: Some(1), max_size: Some(8), mode: MaxEncodedLen)
+	/// Storage: PolkadotXcm SupportedVersion (r:1 w:0)
+	/// Storage: PolkadotXcm VersionDiscoveryQueue (r:1 w:1)
+	/// Storage: PolkadotXcm SafeXcmVersion (r:1 w:0)
+	/// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	/// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	/// The range of component `f` is `[1, 32]`.
+	fn submit_exchange_rates_unsigned(f: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 3_794_000 picoseconds.
+		Weight::from_parts(4_360_098, 0)
+			.saturating_add(Weight::from_parts(23_714, 0).saturating_mul(f.into()))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			// `pallet_xcm::send` of the asset hub
+			.saturating_add(Weight::from_parts(29_186_000, 3_540))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: SystemTokenOracle Requested (r:1 w:1)
+	/// Proof: SystemTokenOracle Requested (max_values: Some(1), max_size: Some(33), mode: MaxEncodedLen)
+	/// The range of component `f` is `[1, 32]`.
+	fn request_fiat(f: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1518`
+		// Minimum execution time: 4_085_000 picoseconds.
+		Weight::from_parts(4_267_479, 1_518)
+			.saturating_add(Weight::from_parts(51_488, 0).saturating_mul(f.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn add_oracle() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 928_000 picoseconds.
+		Weight::from_parts(1_003_000, 0)
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: SystemTokenOracle NextUnsignedAt (r:0 w:1)
+	/// Proof: SystemTokenOracle NextUnsignedAt (max_values: Some(1), max_size: Some(8), mode: MaxEncodedLen)
+	/// Storage: PolkadotXcm SupportedVersion (r:1 w:0)
+	/// Storage: PolkadotXcm VersionDiscoveryQueue (r:1 w:1)
+	/// Storage: PolkadotXcm SafeXcmVersion (r:1 w:0)
+	/// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	/// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	/// The range of component `f` is `[1, 32]`.
+	fn submit_exchange_rates_unsigned(f: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 3_794_000 picoseconds.
+		Weight::from_parts(4_360_098, 0)
+			.saturating_add(Weight::from_parts(23_714, 0).saturating_mul(f.into()))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			// `pallet_xcm::send` of the asset hub
+			.saturating_add(Weight::from_parts(29_186_000, 3_540))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: SystemTokenOracle Requested (r:1 w:1)
+	/// Proof: SystemTokenOracle Requested (max_values: Some(1), max_size: Some(33), mode: MaxEncodedLen)
+	/// The range of component `f` is `[1, 32]`.
+	fn request_fiat(f: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1518`
+		// Minimum execution time: 4_085_000 picoseconds.
+		Weight::from_parts(4_267_479, 1_518)
+			.saturating_add(Weight::from_parts(51_488, 0).saturating_mul(f.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn add_oracle() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 928_000 picoseconds.
+		Weight::from_parts(1_003_000, 0)
+	}
+}
@@ -12,7 +12,7 @@ pub type SystemTokenWeightOf<T> = <<T as Config>::Fungibles as InspectSystemToke
 	<T as frame_system::Config>::AccountId,
 >>::SystemTokenWeight;
 
-#[frame_support::pallet(dev_mode)]
+#[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 	use frame_support::pallet_prelude::*;
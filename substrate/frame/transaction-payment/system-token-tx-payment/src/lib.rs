@@ -55,7 +55,7 @@ use sp_std::prelude::*;
 
 pub use pallet::*;
 
-#[frame_support::pallet(dev_mode)]
+#[frame_support::pallet]
 pub mod pallet {
 
 	use super::*;
@@ -80,6 +80,8 @@ pub mod pallet {
 		type OnChargeSystemToken: OnChargeSystemToken<Self>;
 		/// Filters for bootstrappring runtime.
 		type BootstrapCallFilter: Contains<Self::RuntimeCall>;
+		/// Filters for runtime under maintenance. Allowed calls are free of charge.
+		type MaintenanceCallFilter: Contains<Self::RuntimeCall>;
 		/// Id for handling fee(e.g SoverignAccount for some Runtime).
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
@@ -119,11 +121,14 @@ where
 	SystemTokenWeightOf<T>: TryFrom<SystemTokenBalanceOf<T>> + TryFrom<u128>,
 {
 	fn check_bootstrap_and_filter(call: &T::RuntimeCall) -> Result<bool, TransactionValidityError> {
-		match (T::SystemConfig::runtime_state(), T::BootstrapCallFilter::contains(call)) {
-			(Mode::Bootstrap, false) =>
+		match T::SystemConfig::runtime_state() {
+			Mode::Bootstrap if T::BootstrapCallFilter::contains(call) => Ok(true),
+			Mode::Bootstrap =>
 				Err(TransactionValidityError::Invalid(InvalidTransaction::InvalidBootstrappingCall)),
-			(Mode::Bootstrap, true) => Ok(true),
-			(Mode::Normal, _) => Ok(false),
+			Mode::Maintenance if T::MaintenanceCallFilter::contains(call) => Ok(true),
+			Mode::Maintenance =>
+				Err(TransactionValidityError::Invalid(InvalidTransaction::InvalidMaintenanceCall)),
+			Mode::Normal => Ok(false),
 		}
 	}
 
@@ -388,7 +393,11 @@ where
 					let actual_fee: BalanceOf<T> =
 						// `fee` will be calculated based on the 'fee table'.
 						// The fee will be directly applied to the `final_fee` without any refunds.
-						if let Some(fee) = T::SystemConfig::fee_for(ext_metadata) {
+						if let Some(fee) = T::SystemConfig::fee_for(
+							ext_metadata,
+							post_info.calc_actual_weight(info),
+							len as u32,
+						) {
 							refundable = false;
 							fee.into()
 						} else {
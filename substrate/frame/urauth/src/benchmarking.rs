@@ -1 +1,462 @@
+//! Benchmarks for URAuth Pallet
 
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::v1::{account, benchmarks, whitelisted_caller, BenchmarkError};
+use frame_support::{assert_ok, traits::EnsureOrigin};
+use frame_system::RawOrigin;
+use sp_core::hashing::blake2_512;
+use sp_io::crypto::{sr25519_generate, sr25519_sign};
+use sp_runtime::traits::{One, Zero};
+use sp_std::vec;
+
+use crate::Pallet as URAuth;
+
+const SEED: u32 = 0;
+const TIMESTAMP: &[u8] = b"2023-07-28T10:17:21Z";
+/// Length of `https://www.` and `.com` of domain URI
+const DOMAIN_URI_OVERHEAD: u32 = 16;
+/// Root URI under which child URIs are claimed
+const ROOT_URI: &[u8] = b"https://www.website.com";
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+fn new_signer() -> sr25519::Public {
+	sr25519_generate(0.into(), None)
+}
+
+fn account_of<T: Config>(signer: &sr25519::Public) -> T::AccountId
+where
+	T::AccountId: From<AccountId32>,
+{
+	MultiSigner::Sr25519(*signer).into_account().into()
+}
+
+/// `did:infra:ua:` followed by the SS58 address of `signer`
+fn did_of(signer: &sr25519::Public) -> Vec<u8> {
+	let mut raw = vec![42u8];
+	raw.extend_from_slice(signer.as_ref());
+	let checksum = blake2_512(&[&b"SS58PRE"[..], &raw[..]].concat());
+	raw.extend_from_slice(&checksum[..2]);
+	let mut did = b"did:infra:ua:".to_vec();
+	did.extend(bs58::encode(raw).into_vec());
+	did
+}
+
+fn sign_raw(signer: &sr25519::Public, payload: impl Encode) -> sr25519::Signature {
+	payload.using_encoded(|m| sr25519_sign(0.into(), signer, m).expect("Signer is on keystore"))
+}
+
+fn sign(signer: &sr25519::Public, payload: impl Encode) -> MultiSignature {
+	sign_raw(signer, payload).into()
+}
+
+fn next_nonce<T: Config>(who: &T::AccountId) -> BlockNumberFor<T> {
+	DIDs::<T>::get(who).map_or(Zero::zero(), |did_detail| did_detail.nonce()) + One::one()
+}
+
+/// Root URI of domain whose length is `u`, which is registered as `<host>.com`
+fn domain_uri(u: u32) -> Vec<u8> {
+	let mut uri = b"https://www.".to_vec();
+	uri.extend(vec![b'a'; u.saturating_sub(DOMAIN_URI_OVERHEAD).max(1) as usize]);
+	uri.extend_from_slice(b".com");
+	uri
+}
+
+/// URI registered on `URAuthTree` for `ROOT_URI`
+fn root_uri() -> URI {
+	b"website.com".to_vec().try_into().unwrap()
+}
+
+/// Access rule of max size
+fn access_rule() -> AccessRule {
+	let any_text: AnyText = vec![b'a'; MAX_COMMON_SIZE as usize].try_into().unwrap();
+	let price = Price { price: u64::MAX, decimals: 18, unit: PriceUnit::USDPerMb };
+	let rule = Rule {
+		user_agents: vec![any_text.clone(); MAX_USER_AGENTS_NUM],
+		allow: vec![(ContentType::All, price); MAX_CONTENT_TYPES],
+		disallow: vec![ContentType::All; MAX_CONTENT_TYPES],
+	};
+	AccessRule::AccessRuleV1 { path: any_text, rules: vec![rule; MAX_RULES_NUM] }
+}
+
+/// Register `URAuthDoc` of `uri` verified by Oracle. Threshold and weights of `owners` are all 1.
+fn register_doc<T: Config>(uri: &URI, owners: Vec<T::AccountId>) -> URAuthDoc<T::AccountId>
+where
+	URIFor<T>: Into<URI>,
+	URIPartFor<T>: IsType<URIPart>,
+{
+	let mut owners = owners.into_iter();
+	let mut urauth_doc =
+		URAuth::<T>::new_urauth_doc(owners.next().expect("At least one owner"), None, None)
+			.unwrap();
+	for owner in owners {
+		urauth_doc.multi_owner_did.add_owner(WeightedDID::new(owner, 1));
+	}
+	URAuthTree::<T>::insert(uri, urauth_doc.clone());
+	URAuth::<T>::start_ownership(uri).unwrap();
+	urauth_doc
+}
+
+/// `signer` and `o - 1` other owners, where `signer` is the last one to be looked up
+fn owners_with<T: Config>(signer: &sr25519::Public, o: u32) -> Vec<T::AccountId>
+where
+	T::AccountId: From<AccountId32>,
+{
+	let mut owners: Vec<T::AccountId> = (1..o).map(|i| account("owner", i, SEED)).collect();
+	owners.push(account_of::<T>(signer));
+	owners
+}
+
+/// Request the ownership of `uri` on behalf of `signer` and return its DID
+fn request_ownership<T: Config>(signer: &sr25519::Public, uri: &Vec<u8>) -> Vec<u8>
+where
+	T::AccountId: From<AccountId32>,
+	URIFor<T>: Into<URI>,
+	URIPartFor<T>: IsType<URIPart>,
+	ClaimTypeFor<T>: From<ClaimType>,
+	ChallengeValueFor<T>: Into<URAuthChallengeValue>,
+{
+	let owner_did = did_of(signer);
+	let proof = sign(
+		signer,
+		URAuthSignedPayload::<T::AccountId, BlockNumberFor<T>>::Request {
+			uri: uri.clone().try_into().unwrap(),
+			owner_did: owner_did.clone().try_into().unwrap(),
+			nonce: next_nonce::<T>(&account_of::<T>(signer)),
+		},
+	);
+	assert_ok!(URAuth::<T>::request_register_ownership(
+		RawOrigin::Signed(whitelisted_caller()).into(),
+		ClaimType::Domain,
+		uri.clone(),
+		owner_did.clone(),
+		Some([1u8; 32]),
+		MultiSigner::Sr25519(*signer),
+		proof,
+	));
+	owner_did
+}
+
+/// _challenge-value.json_ of `uri` signed by `signer`, which Oracle nodes download
+fn challenge_json<T: Config>(
+	signer: &sr25519::Public,
+	uri: &Vec<u8>,
+	owner_did: &Vec<u8>,
+) -> Vec<u8> {
+	let bounded_uri: URI = uri.clone().try_into().unwrap();
+	let challenge = ChallengeValue::<T>::get(&bounded_uri).expect("Ownership has been requested");
+	let sig = sign_raw(
+		signer,
+		URAuthSignedPayload::<T::AccountId, BlockNumberFor<T>>::Challenge {
+			uri: bounded_uri,
+			owner_did: owner_did.clone().try_into().unwrap(),
+			challenge: challenge.to_vec(),
+			timestamp: TIMESTAMP.to_vec(),
+		},
+	);
+	let mut proof_value = [0u8; 128];
+	hex::encode_to_slice(sig, &mut proof_value).unwrap();
+	// Challenge value may not be printable
+	let mut escaped_challenge = Vec::new();
+	for b in challenge {
+		let mut hex_byte = [0u8; 2];
+		hex::encode_to_slice([b], &mut hex_byte).unwrap();
+		escaped_challenge.extend_from_slice(b"\\u00");
+		escaped_challenge.extend_from_slice(&hex_byte);
+	}
+
+	let mut json = b"{\"domain\":\"".to_vec();
+	json.extend_from_slice(uri);
+	json.extend_from_slice(b"\",\"adminDID\":\"");
+	json.extend_from_slice(owner_did);
+	json.extend_from_slice(b"\",\"challenge\":\"");
+	json.extend_from_slice(&escaped_challenge);
+	json.extend_from_slice(b"\",\"timestamp\":\"");
+	json.extend_from_slice(TIMESTAMP);
+	json.extend_from_slice(b"\",\"proof\":{\"type\":\"Sr25519Signature2020\",\"proofValue\":\"");
+	json.extend_from_slice(&proof_value);
+	json.extend_from_slice(b"\"}}");
+	json
+}
+
+/// Fill `URIByOracle` with `n` distinct URIs
+fn fill_uri_by_oracle<T: Config>(n: u32) {
+	let uri_parts: Vec<URIPart> = (0..n)
+		.map(|i| {
+			URIPart::new(
+				b"https".to_vec(),
+				Some(b"www.".to_vec()),
+				Some(b"website.com".to_vec()),
+				Some(i.encode()),
+			)
+		})
+		.collect();
+	URIByOracle::<T>::put(BoundedVec::<_, T::MaxURIByOracle>::try_from(uri_parts).unwrap());
+}
+
+benchmarks! {
+	where_clause {
+		where
+			T::AccountId: From<AccountId32>,
+			URIFor<T>: Into<URI>,
+			URIPartFor<T>: IsType<URIPart>,
+			ClaimTypeFor<T>: From<ClaimType>,
+			ChallengeValueFor<T>: Into<URAuthChallengeValue>,
+	}
+
+	request_register_ownership {
+		let u in (DOMAIN_URI_OVERHEAD + 1) .. MAX_URI_SIZE;
+		let signer = new_signer();
+		let uri = domain_uri(u);
+		let bounded_uri: URI = uri.clone().try_into().unwrap();
+		let owner_did = did_of(&signer);
+		let proof = sign(&signer, URAuthSignedPayload::<T::AccountId, BlockNumberFor<T>>::Request {
+			uri: bounded_uri.clone(),
+			owner_did: owner_did.clone().try_into().unwrap(),
+			nonce: One::one(),
+		});
+		let caller: T::AccountId = whitelisted_caller();
+	}: _(
+		RawOrigin::Signed(caller),
+		ClaimType::Domain,
+		uri,
+		owner_did,
+		Some([1u8; 32]),
+		MultiSigner::Sr25519(signer),
+		proof
+	)
+	verify {
+		assert_last_event::<T>(Event::<T>::URAuthRegisterRequested { uri: bounded_uri }.into());
+	}
+
+	verify_challenge {
+		let c in (DOMAIN_URI_OVERHEAD + 1) .. MAX_URI_SIZE;
+		let signer = new_signer();
+		let uri = domain_uri(c);
+		let bounded_uri: URI = uri.clone().try_into().unwrap();
+		let owner_did = request_ownership::<T>(&signer, &uri);
+		let challenge_json = challenge_json::<T>(&signer, &uri, &owner_did);
+		let oracle_members: Vec<T::AccountId> = (0..T::MaxOracleMembers::get())
+			.map(|i| account("oracle", i, SEED))
+			.collect();
+		OracleMembers::<T>::put(
+			BoundedVec::<_, T::MaxOracleMembers>::try_from(oracle_members.clone()).unwrap()
+		);
+		// Every submission but the last one required to complete the verification
+		let mut verification_submission = VerificationSubmission::<T>::default();
+		verification_submission.update_threshold(oracle_members.len());
+		let threshold = verification_submission.threshold as usize;
+		for member in oracle_members.iter().take(threshold - 1) {
+			URAuth::<T>::verify_challenge(
+				RawOrigin::Signed(member.clone()).into(),
+				challenge_json.clone(),
+			)?;
+		}
+		let caller = oracle_members[threshold - 1].clone();
+	}: _(RawOrigin::Signed(caller), challenge_json)
+	verify {
+		assert_last_event::<T>(Event::<T>::VerificationInfo {
+			uri: bounded_uri,
+			progress_status: VerificationSubmissionResult::Complete,
+		}.into());
+	}
+
+	update_urauth_doc {
+		let o in 1 .. MAX_MULTI_OWNERS_NUM as u32;
+		let a in 0 .. MAX_ACCESS_RULES as u32;
+		let signer = new_signer();
+		let uri = root_uri();
+		let urauth_doc = register_doc::<T>(&uri, owners_with::<T>(&signer, o));
+		let update_doc_field = UpdateDocField::AccessRules(Some(vec![access_rule(); a as usize]));
+		let updated_at = urauth_doc.updated_at + 1;
+		let mut updated_urauth_doc = urauth_doc.clone();
+		updated_urauth_doc.update_doc(update_doc_field.clone(), updated_at).unwrap();
+		let owner_did: OwnerDID = did_of(&signer).try_into().unwrap();
+		let proof = sign(&signer, URAuthSignedPayload::<T::AccountId, BlockNumberFor<T>>::Update {
+			uri: uri.clone(),
+			urauth_doc: updated_urauth_doc,
+			owner_did: owner_did.clone(),
+			nonce: One::one(),
+		});
+		let caller: T::AccountId = whitelisted_caller();
+	}: _(
+		RawOrigin::Signed(caller),
+		uri.clone(),
+		update_doc_field,
+		updated_at,
+		Some(Proof::ProofV1 { did: owner_did, proof })
+	)
+	verify {
+		let access_rules = URAuthTree::<T>::get(&uri).and_then(|urauth_doc| urauth_doc.access_rules);
+		assert_eq!(access_rules.map_or(0, |access_rules| access_rules.len()), a as usize);
+	}
+
+	// The parent URIs looked up once per path segment mostly do not exist, so the PoV of
+	// `URAuthTree` is measured instead of taking the maximum size of a document for each lookup.
+	#[pov_mode = MaxEncodedLen {
+		URAuth::URAuthTree: Measured
+	}]
+	claim_ownership {
+		let u in (ROOT_URI.len() as u32 + 2) .. MAX_URI_SIZE;
+		let signer = new_signer();
+		register_doc::<T>(&root_uri(), vec![account_of::<T>(&signer)]);
+		// Every path segment is a parent URI to be looked up before the root
+		let mut uri = ROOT_URI.to_vec();
+		for _ in 0..(u as usize - ROOT_URI.len()) / 2 {
+			uri.extend_from_slice(b"/a");
+		}
+		let bounded_uri: URI = uri.clone().try_into().unwrap();
+		let owner_did = did_of(&signer);
+		let proof = sign(&signer, URAuthSignedPayload::<T::AccountId, BlockNumberFor<T>>::Request {
+			uri: bounded_uri.clone(),
+			owner_did: owner_did.clone().try_into().unwrap(),
+			nonce: One::one(),
+		});
+		let caller: T::AccountId = whitelisted_caller();
+	}: _(RawOrigin::Signed(caller), ClaimType::Domain, uri, owner_did, MultiSigner::Sr25519(signer), proof)
+	verify {
+		assert!(URAuthTree::<T>::contains_key(&bounded_uri));
+	}
+
+	add_oracle_member {
+		let m in 0 .. T::MaxOracleMembers::get() - 1;
+		let members: Vec<T::AccountId> = (0..m).map(|i| account("oracle", i, SEED)).collect();
+		OracleMembers::<T>::put(BoundedVec::<_, T::MaxOracleMembers>::try_from(members).unwrap());
+		let who: T::AccountId = account("oracle", m, SEED);
+		let origin = T::AuthorizedOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, who.clone())
+	verify {
+		assert!(OracleMembers::<T>::get().contains(&who));
+	}
+
+	kick_oracle_member {
+		let m in 1 .. T::MaxOracleMembers::get();
+		let members: Vec<T::AccountId> = (0..m).map(|i| account("oracle", i, SEED)).collect();
+		OracleMembers::<T>::put(BoundedVec::<_, T::MaxOracleMembers>::try_from(members).unwrap());
+		let who: T::AccountId = account("oracle", m - 1, SEED);
+		let origin = T::AuthorizedOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, who.clone())
+	verify {
+		assert!(!OracleMembers::<T>::get().contains(&who));
+	}
+
+	add_uri_by_oracle {
+		let n in 0 .. T::MaxURIByOracle::get() - 1;
+		fill_uri_by_oracle::<T>(n);
+		let origin = T::AuthorizedOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, ClaimType::Domain, b"https://www.website.com/feed/*".to_vec())
+	verify {
+		assert_eq!(URIByOracle::<T>::get().map_or(0, |uri_parts| uri_parts.len()), n as usize + 1);
+	}
+
+	remove_uri_by_oracle {
+		let n in 1 .. T::MaxURIByOracle::get();
+		fill_uri_by_oracle::<T>(n - 1);
+		let uri = b"https://www.website.com/feed/*".to_vec();
+		let origin = T::AuthorizedOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+		URAuth::<T>::add_uri_by_oracle(origin.clone(), ClaimType::Domain, uri.clone())?;
+	}: _<T::RuntimeOrigin>(origin, ClaimType::Domain, uri)
+	verify {
+		assert_eq!(URIByOracle::<T>::get().map_or(0, |uri_parts| uri_parts.len()), n as usize - 1);
+	}
+
+	request_renewal {
+		let u in (DOMAIN_URI_OVERHEAD + 1) .. MAX_URI_SIZE;
+		let signer = new_signer();
+		let uri = domain_uri(u);
+		let bounded_uri: URI = uri.clone().try_into().unwrap();
+		// Registered without `https://www.`
+		let registered_uri: URI = uri[12..].to_vec().try_into().unwrap();
+		register_doc::<T>(&registered_uri, vec![account_of::<T>(&signer)]);
+		let owner_did = did_of(&signer);
+		let proof = sign(&signer, URAuthSignedPayload::<T::AccountId, BlockNumberFor<T>>::Request {
+			uri: bounded_uri.clone(),
+			owner_did: owner_did.clone().try_into().unwrap(),
+			nonce: One::one(),
+		});
+		let caller: T::AccountId = whitelisted_caller();
+	}: _(
+		RawOrigin::Signed(caller),
+		ClaimType::Domain,
+		uri,
+		owner_did,
+		Some([1u8; 32]),
+		MultiSigner::Sr25519(signer),
+		proof
+	)
+	verify {
+		assert_last_event::<T>(Event::<T>::RenewalRequested { uri: bounded_uri }.into());
+	}
+
+	offer_transfer {
+		let o in 1 .. MAX_MULTI_OWNERS_NUM as u32;
+		let signer = new_signer();
+		let uri = root_uri();
+		register_doc::<T>(&uri, owners_with::<T>(&signer, o));
+		let new_owner = new_signer();
+		let new_owner_did = did_of(&new_owner);
+		let proof = sign(&signer, URAuthSignedPayload::<T::AccountId, BlockNumberFor<T>>::Transfer {
+			uri: uri.clone(),
+			new_owner_did: Some(new_owner_did.clone().try_into().unwrap()),
+			nonce: One::one(),
+		});
+		let caller: T::AccountId = whitelisted_caller();
+	}: _(RawOrigin::Signed(caller), uri.clone(), Some(new_owner_did), MultiSigner::Sr25519(signer), proof)
+	verify {
+		assert_eq!(PendingTransfers::<T>::get(&uri), Some(account_of::<T>(&new_owner)));
+	}
+
+	accept_transfer {
+		let uri = root_uri();
+		register_doc::<T>(&uri, vec![account_of::<T>(&new_signer())]);
+		let new_owner = new_signer();
+		PendingTransfers::<T>::insert(&uri, account_of::<T>(&new_owner));
+		let proof = sign(&new_owner, URAuthSignedPayload::<T::AccountId, BlockNumberFor<T>>::AcceptTransfer {
+			uri: uri.clone(),
+			nonce: One::one(),
+		});
+		let caller: T::AccountId = whitelisted_caller();
+	}: _(RawOrigin::Signed(caller), uri.clone(), MultiSigner::Sr25519(new_owner), proof)
+	verify {
+		assert!(URAuthTree::<T>::get(&uri).unwrap().is_owner(&account_of::<T>(&new_owner)));
+	}
+
+	handle_lapsed_ownerships {
+		let l in 1 .. T::MaxRequest::get();
+		let c in 0 .. T::MaxChildURIs::get();
+		let owner: T::AccountId = account("owner", 0, SEED);
+		let uris: Vec<URI> = (0..l)
+			.map(|i| {
+				let uri: URI = (b"website.com", i).encode().try_into().unwrap();
+				register_doc::<T>(&uri, vec![owner.clone()]);
+				uri
+			})
+			.collect();
+		// Child URIs are all claimed under the first URI
+		for i in 0..c {
+			let child_uri: URI = (b"website.com/child", i).encode().try_into().unwrap();
+			URAuth::<T>::try_add_child_uri(&uris[0], &child_uri)?;
+			URAuthTree::<T>::insert(&child_uri, URAuth::<T>::new_urauth_doc(owner.clone(), None, None)?);
+		}
+		let lapse_at = frame_system::Pallet::<T>::block_number() +
+			T::OwnershipPeriod::get() +
+			T::GracePeriod::get();
+	}: {
+		URAuth::<T>::handle_lapsed_ownerships(&lapse_at);
+	}
+	verify {
+		assert!(uris.iter().all(|uri| !URAuthTree::<T>::contains_key(uri)));
+		assert!(ChildURIs::<T>::get(&uris[0]).is_empty());
+	}
+
+	impl_benchmark_test_suite!(URAuth, crate::mock::new_test_ext(), crate::mock::Test);
+}
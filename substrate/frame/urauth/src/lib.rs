@@ -34,7 +34,10 @@ pub mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
-#[frame_support::pallet(dev_mode)]
+pub mod weights;
+pub use weights::*;
+
+#[frame_support::pallet]
 pub mod pallet {
 
 	use super::*;
@@ -71,9 +74,25 @@ pub mod pallet {
 		#[pallet::constant]
 		type RandomnessEnabled: Get<bool>;
 
+		/// Period for which ownership verified by Oracle is valid before it should be re-verified
+		#[pallet::constant]
+		type OwnershipPeriod: Get<BlockNumberFor<Self>>;
+
+		/// Period after expiry during which ownership can still be re-verified before the
+		/// `URAuthDoc` is removed
+		#[pallet::constant]
+		type GracePeriod: Get<BlockNumberFor<Self>>;
+
+		/// Maximum number of child URIs claimed under a URI verified by Oracle
+		#[pallet::constant]
+		type MaxChildURIs: Get<u32>;
+
 		/// The origin which may be used within _authorized_ call.
 		/// **Root** can always do this.
 		type AuthorizedOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
 	}
 
 	/// **Description:**
@@ -108,7 +127,6 @@ pub mod pallet {
 	///
 	/// URIMetadata
 	#[pallet::storage]
-	#[pallet::unbounded]
 	pub type Metadata<T: Config> = StorageMap<_, Twox128, URI, RequestMetadata>;
 
 	#[pallet::storage]
@@ -132,7 +150,6 @@ pub mod pallet {
 	///
 	/// VerificationSubmission
 	#[pallet::storage]
-	#[pallet::unbounded]
 	#[pallet::getter(fn uri_verification_info)]
 	pub type URIVerificationInfo<T: Config> =
 		StorageMap<_, Twox128, URI, VerificationSubmission<T>>;
@@ -180,9 +197,16 @@ pub mod pallet {
 	pub type OracleMembers<T: Config> =
 		StorageValue<_, BoundedVec<T::AccountId, T::MaxOracleMembers>, ValueQuery>;
 
+	/// **Description:**
+	///
+	/// URIs which should be verified by Oracle nodes, up to `T::MaxURIByOracle`.
+	///
+	/// **Value:**
+	///
+	/// BoundedVec<URIPart, T::MaxURIByOracle>
 	#[pallet::storage]
-	#[pallet::unbounded]
-	pub type URIByOracle<T: Config> = StorageValue<_, Vec<URIPart>, OptionQuery>;
+	pub type URIByOracle<T: Config> =
+		StorageValue<_, BoundedVec<URIPart, T::MaxURIByOracle>, OptionQuery>;
 
 	/// **Description:**
 	///
@@ -194,6 +218,84 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type Counter<T: Config> = StorageValue<_, URAuthDocCount, ValueQuery>;
 
+	/// **Description:**
+	///
+	/// Block at which the ownership of a URI verified by Oracle expires. The owner should
+	/// re-verify it with `request_renewal` before expiry or within `T::GracePeriod` after it.
+	/// Otherwise, its `URAuthDoc` and all of its child URIs are removed.
+	///
+	/// **Key:**
+	///
+	/// URI
+	///
+	/// **Value:**
+	///
+	/// BlockNumber
+	#[pallet::storage]
+	#[pallet::getter(fn ownership_expiry)]
+	pub type OwnershipExpiry<T: Config> = StorageMap<_, Twox128, URI, BlockNumberFor<T>>;
+
+	/// **Description:**
+	///
+	/// URIs whose grace period ends at the given block. URIs which have been re-verified since
+	/// are skipped.
+	///
+	/// **Key:**
+	///
+	/// BlockNumber
+	///
+	/// **Value:**
+	///
+	/// BoundedVec<URI, T::MaxRequest>
+	#[pallet::storage]
+	pub type LapsingURIs<T: Config> =
+		StorageMap<_, Twox128, BlockNumberFor<T>, BoundedVec<URI, T::MaxRequest>, ValueQuery>;
+
+	/// **Description:**
+	///
+	/// Child URIs claimed under a root URI, removed along with it.
+	///
+	/// **Key:**
+	///
+	/// Root URI
+	///
+	/// **Value:**
+	///
+	/// BoundedVec<URI, T::MaxChildURIs>
+	#[pallet::storage]
+	pub type ChildURIs<T: Config> =
+		StorageMap<_, Twox128, URI, BoundedVec<URI, T::MaxChildURIs>, ValueQuery>;
+
+	/// **Description:**
+	///
+	/// Root URI under which a child URI has been claimed.
+	///
+	/// **Key:**
+	///
+	/// Child URI
+	///
+	/// **Value:**
+	///
+	/// Root URI
+	#[pallet::storage]
+	pub type RootURI<T: Config> = StorageMap<_, Twox128, URI, URI>;
+
+	/// **Description:**
+	///
+	/// Transfer of the ownership of `URAuthDoc` offered by its owner, waiting for the new owner to
+	/// accept it.
+	///
+	/// **Key:**
+	///
+	/// URI
+	///
+	/// **Value:**
+	///
+	/// AccountId of the new owner
+	#[pallet::storage]
+	#[pallet::getter(fn pending_transfer)]
+	pub type PendingTransfers<T: Config> = StorageMap<_, Twox128, URI, T::AccountId>;
+
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T>
 	where
@@ -202,7 +304,9 @@ pub mod pallet {
 	{
 		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
 			let (r, w) = Self::handle_expired_requsted_uris(&n);
-			T::DbWeight::get().reads_writes(r, w)
+			T::DbWeight::get()
+				.reads_writes(r, w)
+				.saturating_add(Self::handle_lapsed_ownerships(&n))
 		}
 	}
 
@@ -257,6 +361,20 @@ pub mod pallet {
 		URIByOracleRemoved,
 		/// Request of registering URI has been removed.
 		Removed { uri: URI },
+		/// Re-verification of the ownership of URI is requested.
+		RenewalRequested { uri: URI },
+		/// Ownership of URI has been re-verified and extended.
+		OwnershipRenewed { uri: URI, expires_at: BlockNumberFor<T> },
+		/// Ownership of URI has not been re-verified within the grace period.
+		OwnershipLapsed { uri: URI },
+		/// `URAuthDoc` has been removed from `URAuthTree`.
+		URAuthDocRemoved { uri: URI },
+		/// Owner of `URAuthDoc` has offered to transfer its ownership.
+		TransferOffered { uri: URI, to: T::AccountId },
+		/// Offer to transfer the ownership of `URAuthDoc` has been cancelled.
+		TransferCancelled { uri: URI },
+		/// Ownership of `URAuthDoc` has been transferred.
+		OwnershipTransferred { uri: URI, urauth_doc: URAuthDoc<T::AccountId> },
 	}
 
 	#[pallet::error]
@@ -322,6 +440,18 @@ pub mod pallet {
 		UpdateInProgress,
 		/// Only URL is supported currently. General URI work in progress
 		GeneralURINotSupportedYet,
+		/// Ownership of URI has expired and should be re-verified
+		OwnershipExpired,
+		/// Ownership of URI has not been verified by Oracle, so it can not be renewed
+		NotRenewable,
+		/// Max number of child URIs of a root URI has been reached.
+		MaxChildURIs,
+		/// Ownership of URI has not been offered to be transferred
+		NoPendingTransfer,
+		/// Weight of the signer is less than the threshold of `URAuthDoc`
+		InsufficientWeight,
+		/// Too many URIs lapse in the blocks following the end of the grace period
+		TooManyLapsingURIs,
 	}
 
 	#[pallet::call]
@@ -353,7 +483,7 @@ pub mod pallet {
 		// 2. Signature verification
 		// 3. If the signature is valid, generate a metadata(owner_did, challenge_value)
 		#[pallet::call_index(0)]
-		#[pallet::weight(1_000)]
+		#[pallet::weight(T::WeightInfo::request_register_ownership(uri.len() as u32))]
 		pub fn request_register_ownership(
 			origin: OriginFor<T>,
 			claim_type: ClaimType,
@@ -364,7 +494,7 @@ pub mod pallet {
 			proof: MultiSignature,
 		) -> DispatchResult {
 			let _ = ensure_signed(origin)?;
-			let (maybe_register_uri, should_check_owner) =
+			let (maybe_register_uri, maybe_parent_uri) =
 				Self::check_uri(&claim_type, true, &uri, None)?;
 			ensure!(
 				URAuthTree::<T>::get(&maybe_register_uri).is_none(),
@@ -378,7 +508,7 @@ pub mod pallet {
 				&bounded_owner_did,
 				&proof,
 				signer,
-				should_check_owner,
+				maybe_parent_uri.is_none(),
 			)?;
 			Self::try_add_requested_uris(&bounded_uri)?;
 			let cv = Self::challenge_value(challenge_value)?;
@@ -414,7 +544,7 @@ pub mod pallet {
 		// 2. Signature verification
 		// 3. If the signature is valid, generate a metadata(owner_did, challenge_value)
 		#[pallet::call_index(1)]
-		#[pallet::weight(1_000)]
+		#[pallet::weight(T::WeightInfo::verify_challenge(challenge_json.len() as u32))]
 		pub fn verify_challenge(origin: OriginFor<T>, challenge_json: Vec<u8>) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
@@ -468,7 +598,10 @@ pub mod pallet {
 		// 2. Verify its given proof
 		// 3. If valid, store on `URAuthTree` based on Multi DIDs weight and threshold
 		#[pallet::call_index(2)]
-		#[pallet::weight(1_000)]
+		#[pallet::weight(T::WeightInfo::update_urauth_doc(
+			MAX_MULTI_OWNERS_NUM as u32,
+			update_doc_field.access_rules_len(),
+		))]
 		pub fn update_urauth_doc(
 			origin: OriginFor<T>,
 			uri: URI,
@@ -477,6 +610,7 @@ pub mod pallet {
 			proof: Option<Proof>,
 		) -> DispatchResult {
 			let _ = ensure_signed(origin)?;
+			Self::ensure_not_expired(&uri)?;
 
 			let (mut updated_urauth_doc, mut update_doc_status) =
 				Self::try_update_urauth_doc(&uri, &update_doc_field, updated_at, proof.clone())?;
@@ -515,7 +649,7 @@ pub mod pallet {
 		// 2. Verify signer is one of the parent owners
 		// 3. Once it is verified, create new URAuthDoc based on `claim_type`
 		#[pallet::call_index(3)]
-		#[pallet::weight(1_000)]
+		#[pallet::weight(T::WeightInfo::claim_ownership(uri.len() as u32))]
 		pub fn claim_ownership(
 			origin: OriginFor<T>,
 			claim_type: ClaimType,
@@ -529,7 +663,7 @@ pub mod pallet {
 			let maybe_parent_acc = Self::account_id_from_source(AccountIdSource::AccountId32(
 				signer.clone().into_account(),
 			))?;
-			let (maybe_register_uri, should_check_owner) =
+			let (maybe_register_uri, maybe_parent_uri) =
 				Self::check_uri(&claim_type, false, &uri, Some(maybe_parent_acc))?;
 			ensure!(
 				URAuthTree::<T>::get(&maybe_register_uri).is_none(),
@@ -543,8 +677,11 @@ pub mod pallet {
 				&bounded_owner_did,
 				&proof,
 				signer,
-				should_check_owner,
+				maybe_parent_uri.is_none(),
 			)?;
+			if let Some(parent_uri) = maybe_parent_uri {
+				Self::try_add_child_uri(&parent_uri, &maybe_register_uri)?;
+			}
 			let owner =
 				Self::account_id_from_source(AccountIdSource::DID(bounded_owner_did.to_vec()))?;
 			let urauth_doc = match claim_type.clone() {
@@ -591,7 +728,7 @@ pub mod pallet {
 		// Params:
 		// - who: Whom to be included as Oracle member
 		#[pallet::call_index(4)]
-		#[pallet::weight(1_000)]
+		#[pallet::weight(T::WeightInfo::add_oracle_member(T::MaxOracleMembers::get()))]
 		pub fn add_oracle_member(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
 			T::AuthorizedOrigin::ensure_origin(origin)?;
 
@@ -603,28 +740,33 @@ pub mod pallet {
 		}
 
 		// Description:
-		// This transaction involves adding members of the Oracle node to the verification request
-		// after downloading the Challenge Value.
+		// This transaction involves removing members of the Oracle node from the verification
+		// request.
 		//
 		// Origin:
 		// ** Root(Authorized) privileged call **
 		//
 		// Params:
-		// - who: Whom to be included as Oracle member
+		// - who: Whom to be excluded from Oracle members
 		#[pallet::call_index(5)]
-		#[pallet::weight(1_000)]
+		#[pallet::weight(T::WeightInfo::kick_oracle_member(T::MaxOracleMembers::get()))]
 		pub fn kick_oracle_member(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
 			T::AuthorizedOrigin::ensure_origin(origin)?;
 
-			OracleMembers::<T>::mutate(|m| {
-				m.try_push(who).map_err(|_| Error::<T>::MaxOracleMembers)
+			OracleMembers::<T>::try_mutate(|m| -> DispatchResult {
+				let index = m
+					.iter()
+					.position(|member| member == &who)
+					.ok_or(Error::<T>::NotOracleMember)?;
+				m.remove(index);
+				Ok(())
 			})?;
 
 			Ok(())
 		}
 
 		#[pallet::call_index(7)]
-		#[pallet::weight(1_000)]
+		#[pallet::weight(T::WeightInfo::add_uri_by_oracle(T::MaxURIByOracle::get()))]
 		pub fn add_uri_by_oracle(
 			origin: OriginFor<T>,
 			claim_type: ClaimType,
@@ -632,12 +774,13 @@ pub mod pallet {
 		) -> DispatchResult {
 			T::AuthorizedOrigin::ensure_origin(origin)?;
 
+			ensure!(uri.len() <= MAX_URI_SIZE as usize, Error::<T>::OverMaxSize);
 			let uri_part: URIPart = T::URAuthParser::parse_uri(&uri, &claim_type)?.into();
 			Self::check_claim_type(&uri_part, &claim_type)?;
 			URIByOracle::<T>::try_mutate_exists(|uri_parts| -> DispatchResult {
-				let mut new = uri_parts.clone().map_or(Vec::new(), |v| v.to_vec());
-				new.push(uri_part.clone());
-				*uri_parts = Some(new.try_into().map_err(|_| Error::<T>::OverMaxSize)?);
+				let mut new = uri_parts.take().unwrap_or_default();
+				new.try_push(uri_part).map_err(|_| Error::<T>::OverMaxSize)?;
+				*uri_parts = Some(new);
 				Ok(())
 			})?;
 			Self::deposit_event(Event::<T>::URIByOracleAdded);
@@ -645,7 +788,7 @@ pub mod pallet {
 		}
 
 		#[pallet::call_index(8)]
-		#[pallet::weight(1_000)]
+		#[pallet::weight(T::WeightInfo::remove_uri_by_oracle(T::MaxURIByOracle::get()))]
 		pub fn remove_uri_by_oracle(
 			origin: OriginFor<T>,
 			claim_type: ClaimType,
@@ -657,12 +800,7 @@ pub mod pallet {
 			let mut is_removed = true;
 			URIByOracle::<T>::try_mutate_exists(|uri_parts| -> DispatchResult {
 				if let Some(v) = uri_parts {
-					let new = v
-						.into_iter()
-						.filter(|u| *u != &uri_part)
-						.map(|u| u.clone())
-						.collect::<Vec<URIPart>>();
-					*uri_parts = Some(new.try_into().map_err(|_| Error::<T>::OverMaxSize)?);
+					v.retain(|u| u != &uri_part);
 				} else {
 					is_removed = false;
 				}
@@ -673,6 +811,173 @@ pub mod pallet {
 			}
 			Ok(())
 		}
+
+		// Description:
+		// Owner of `URAuthDoc` verified by Oracle requests re-verification of its ownership
+		// before it expires or within the grace period. Challenge value is generated as on
+		// `request_register_ownership` and the ownership is extended by `T::OwnershipPeriod` once
+		// Oracle nodes verify it.
+		//
+		// Origin:
+		// ** Signed call **
+		//
+		// Params:
+		// - claim_type: Type of claim which has been registered on URAuthTree
+		// - uri: URI whose ownership is re-verified
+		// - owner_did: URI owner's DID
+		// - challenge_value: Challenge value for verification
+		// - signer: Entity who creates signature
+		// - proof: Proof of URI's ownership
+		//
+		// Logic:
+		// 1. Check that ownership of the URI has been verified by Oracle
+		// 2. Verify signature of the owner
+		// 3. If the signature is valid, generate a metadata(owner_did, challenge_value)
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::request_renewal(uri.len() as u32))]
+		pub fn request_renewal(
+			origin: OriginFor<T>,
+			claim_type: ClaimType,
+			uri: Vec<u8>,
+			owner_did: Vec<u8>,
+			challenge_value: Option<Randomness>,
+			signer: MultiSigner,
+			proof: MultiSignature,
+		) -> DispatchResult {
+			let _ = ensure_signed(origin)?;
+			let (registered_uri, _) = Self::check_uri(&claim_type, true, &uri, None)?;
+			let urauth_doc =
+				URAuthTree::<T>::get(&registered_uri).ok_or(Error::<T>::URAuthTreeNotRegistered)?;
+			ensure!(OwnershipExpiry::<T>::contains_key(&registered_uri), Error::<T>::NotRenewable);
+			let bounded_uri: URI = uri.try_into().map_err(|_| Error::<T>::OverMaxSize)?;
+			let bounded_owner_did: OwnerDID =
+				owner_did.try_into().map_err(|_| Error::<T>::OverMaxSize)?;
+			let (signer_acc, did_detail) =
+				Self::verify_request_proof(&bounded_uri, &bounded_owner_did, &proof, signer, true)?;
+			ensure!(urauth_doc.is_owner(&signer_acc), Error::<T>::NotURAuthDocOwner);
+			Self::try_add_requested_uris(&bounded_uri)?;
+			let cv = Self::challenge_value(challenge_value)?;
+			ChallengeValue::<T>::insert(&bounded_uri, cv);
+			Metadata::<T>::insert(
+				&bounded_uri,
+				RequestMetadata::new(bounded_owner_did, cv, claim_type, registered_uri),
+			);
+			DIDs::<T>::insert(&signer_acc, did_detail);
+
+			Self::deposit_event(Event::<T>::RenewalRequested { uri: bounded_uri });
+
+			Ok(())
+		}
+
+		// Description:
+		// Owner of `URAuthDoc` offers to transfer its ownership to another DID, which becomes its
+		// sole owner once it accepts the offer with `accept_transfer`. Offering again replaces the
+		// pending offer and offering to `None` cancels it.
+		//
+		// Origin:
+		// ** Signed call **
+		//
+		// Params:
+		// - uri: Key of `URAuthTree`
+		// - new_owner_did: DID of the new owner. `None` to cancel the pending offer
+		// - signer: Owner whose weight meets the threshold of `URAuthDoc`
+		// - proof: Proof of the offer
+		//
+		// Logic:
+		// 1. Verify signature of `payload = (uri, new_owner_did, nonce).encode()`
+		// 2. Check the weight of the signer against the threshold of `URAuthDoc`
+		// 3. Store or remove the pending transfer
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::offer_transfer(MAX_MULTI_OWNERS_NUM as u32))]
+		pub fn offer_transfer(
+			origin: OriginFor<T>,
+			uri: URI,
+			new_owner_did: Option<Vec<u8>>,
+			signer: MultiSigner,
+			proof: MultiSignature,
+		) -> DispatchResult {
+			let _ = ensure_signed(origin)?;
+			let urauth_doc =
+				URAuthTree::<T>::get(&uri).ok_or(Error::<T>::URAuthTreeNotRegistered)?;
+			Self::ensure_not_expired(&uri)?;
+			let new_owner_did: Option<OwnerDID> = new_owner_did
+				.map(|did| did.try_into())
+				.transpose()
+				.map_err(|_| Error::<T>::OverMaxSize)?;
+			let signer_acc = Self::verify_signed_payload(signer, &proof, |nonce| {
+				URAuthSignedPayload::Transfer {
+					uri: uri.clone(),
+					new_owner_did: new_owner_did.clone(),
+					nonce,
+				}
+			})?;
+			let did_weight = urauth_doc
+				.get_multi_did()
+				.get_did_weight(&signer_acc)
+				.ok_or(Error::<T>::NotURAuthDocOwner)?;
+			ensure!(did_weight >= urauth_doc.get_threshold(), Error::<T>::InsufficientWeight);
+			match new_owner_did {
+				Some(did) => {
+					let new_owner =
+						Self::account_id_from_source(AccountIdSource::DID(did.to_vec()))?;
+					PendingTransfers::<T>::insert(&uri, new_owner.clone());
+					Self::deposit_event(Event::<T>::TransferOffered { uri, to: new_owner });
+				},
+				None => {
+					ensure!(
+						PendingTransfers::<T>::contains_key(&uri),
+						Error::<T>::NoPendingTransfer
+					);
+					PendingTransfers::<T>::remove(&uri);
+					Self::deposit_event(Event::<T>::TransferCancelled { uri });
+				},
+			}
+
+			Ok(())
+		}
+
+		// Description:
+		// New owner accepts the transfer offered with `offer_transfer`. Previous owners and
+		// their proofs are replaced by the new owner and any update in progress is dropped.
+		//
+		// Origin:
+		// ** Signed call **
+		//
+		// Params:
+		// - uri: Key of `URAuthTree`
+		// - signer: New owner
+		// - proof: Proof of the acceptance
+		//
+		// Logic:
+		// 1. Verify signature of `payload = (uri, nonce).encode()`
+		// 2. Check whether the signer is the new owner of the pending transfer
+		// 3. Hand over `URAuthDoc` to the new owner
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::accept_transfer())]
+		pub fn accept_transfer(
+			origin: OriginFor<T>,
+			uri: URI,
+			signer: MultiSigner,
+			proof: MultiSignature,
+		) -> DispatchResult {
+			let _ = ensure_signed(origin)?;
+			let mut urauth_doc =
+				URAuthTree::<T>::get(&uri).ok_or(Error::<T>::URAuthTreeNotRegistered)?;
+			Self::ensure_not_expired(&uri)?;
+			let new_owner =
+				PendingTransfers::<T>::get(&uri).ok_or(Error::<T>::NoPendingTransfer)?;
+			let signer_acc = Self::verify_signed_payload(signer, &proof, |nonce| {
+				URAuthSignedPayload::AcceptTransfer { uri: uri.clone(), nonce }
+			})?;
+			ensure!(signer_acc == new_owner, Error::<T>::BadSigner);
+			urauth_doc.transfer(new_owner, Self::unix_time());
+			URAuthDocUpdateStatus::<T>::remove(urauth_doc.id);
+			URAuthTree::<T>::insert(&uri, urauth_doc.clone());
+			PendingTransfers::<T>::remove(&uri);
+			Self::deposit_event(Event::<T>::OwnershipTransferred { uri, urauth_doc });
+
+			Ok(())
+		}
 	}
 }
 
@@ -724,6 +1029,7 @@ where
 	}
 
 	fn check_claim_type(uri_part: &URIPart, claim_type: &ClaimType) -> DispatchResult {
+		ensure!(claim_type.is_bounded(), Error::<T>::OverMaxSize);
 		match claim_type {
 			ClaimType::Domain => ensure!(uri_part.host.is_some(), Error::<T>::BadClaim),
 			_ => {
@@ -733,14 +1039,16 @@ where
 		Ok(())
 	}
 
+	/// Check the given URI and return the URI to be registered with its parent URI, if it is
+	/// claimed under the parent. Owner should be checked only if it has no parent.
 	fn check_uri(
 		claim_type: &ClaimType,
 		is_oracle: bool,
 		raw_uri: &Vec<u8>,
 		maybe_parent_acc: Option<T::AccountId>,
-	) -> Result<(URI, bool), DispatchError> {
+	) -> Result<(URI, Option<URI>), DispatchError> {
 		let parsed_uri_part: URIPart = T::URAuthParser::parse_uri(raw_uri, claim_type)?.into();
-		let mut should_check_owner: bool = true;
+		let mut maybe_parent_uri: Option<URI> = None;
 		Self::check_claim_type(&parsed_uri_part, claim_type)?;
 		let uri = if parsed_uri_part.is_root(claim_type) {
 			let (_, root_uri) = parsed_uri_part.full_uri();
@@ -749,15 +1057,16 @@ where
 			if is_oracle {
 				Self::check_uri_by_oracle(parsed_uri_part)?
 			} else {
-				should_check_owner = false;
-				Self::check_parent_owner(
-					raw_uri.clone(),
+				let parent_uri = Self::check_parent_owner(
+					raw_uri,
 					&maybe_parent_acc.ok_or(Error::<T>::BadClaim)?,
 					&claim_type,
-				)?
+				)?;
+				maybe_parent_uri = Some(parent_uri);
+				raw_uri.clone()
 			}
 		};
-		Ok((uri.try_into().map_err(|_| Error::<T>::OverMaxSize)?, should_check_owner))
+		Ok((uri.try_into().map_err(|_| Error::<T>::OverMaxSize)?, maybe_parent_uri))
 	}
 
 	/// Check owner of given 'uri'. Parse the given uri
@@ -772,18 +1081,19 @@ where
 	/// - parent_uri: ["(sub2.sub1.example.com, owner1)", "(sub1.example.com, owner2)",
 	///   "(example.com, owner3)"]
 	///
-	/// Check `maybe_owner == owner1?` -> `maybe_owner == owner2?` -> `maybe_owner == owner3?`.
+	/// Check `maybe_owner == owner1?` -> `maybe_owner == owner2?` -> `maybe_owner == owner3?`
+	/// and return the parent uri owned by `maybe_owner`.
 	/// If not, return `Error::<T>::NotURAuthDocOwner`
 	fn check_parent_owner(
-		raw_uri: Vec<u8>,
+		raw_uri: &Vec<u8>,
 		maybe_owner: &T::AccountId,
 		claim_type: &ClaimType,
-	) -> Result<Vec<u8>, DispatchError> {
-		let uris = <URAuthParser<T> as Parser<T>>::parse_parent_uris(&raw_uri, &claim_type)?;
+	) -> Result<URI, DispatchError> {
+		let uris = <URAuthParser<T> as Parser<T>>::parse_parent_uris(raw_uri, &claim_type)?;
 		for uri in uris {
 			if let Some(urauth_doc) = URAuthTree::<T>::get(&uri) {
 				if urauth_doc.is_owner(maybe_owner) {
-					return Ok(raw_uri)
+					return Ok(uri)
 				}
 			}
 		}
@@ -859,6 +1169,30 @@ where
 		Ok((signer_account_id, did_detail))
 	}
 
+	/// Verify signature of the payload built with the next nonce of `signer` and increase its
+	/// nonce
+	///
+	/// ## Error
+	/// `BadProof`
+	fn verify_signed_payload(
+		signer: MultiSigner,
+		signature: &MultiSignature,
+		payload: impl FnOnce(BlockNumberFor<T>) -> URAuthSignedPayload<T::AccountId, BlockNumberFor<T>>,
+	) -> Result<T::AccountId, DispatchError> {
+		let signer_account_id = Self::account_id_from_source(AccountIdSource::AccountId32(
+			signer.clone().into_account(),
+		))?;
+		let did_detail = Self::try_increase_nonce(&signer_account_id)?;
+		if !payload(did_detail.nonce())
+			.using_encoded(|payload| signature.verify(payload, &signer.into_account()))
+		{
+			return Err(Error::<T>::BadProof.into())
+		}
+		DIDs::<T>::insert(&signer_account_id, did_detail);
+
+		Ok(signer_account_id)
+	}
+
 	fn handle_expired_requsted_uris(n: &BlockNumberFor<T>) -> (u64, u64) {
 		let mut r: u64 = 1;
 		let mut w: u64 = 1;
@@ -873,6 +1207,115 @@ where
 		(r, w + 1)
 	}
 
+	/// Remove `URAuthDoc`s whose grace period ends at `n` and which have not been re-verified.
+	/// Return the weight consumed.
+	fn handle_lapsed_ownerships(n: &BlockNumberFor<T>) -> Weight {
+		let lapsing_uris = LapsingURIs::<T>::take(n);
+		let mut removed_child_uris: u32 = 0;
+		for uri in lapsing_uris.iter() {
+			let is_lapsed = OwnershipExpiry::<T>::get(uri)
+				.map_or(false, |expires_at| expires_at + T::GracePeriod::get() <= *n);
+			if is_lapsed {
+				removed_child_uris += Self::remove_urauth_doc(uri);
+				Self::deposit_event(Event::<T>::OwnershipLapsed { uri: uri.clone() });
+			}
+		}
+		T::WeightInfo::handle_lapsed_ownerships(lapsing_uris.len() as u32, removed_child_uris)
+	}
+
+	/// Start the ownership of URI verified by Oracle for `T::OwnershipPeriod`
+	///
+	/// ## Error
+	/// `TooManyLapsingURIs`
+	fn start_ownership(uri: &URI) -> DispatchResult {
+		let expires_at = <frame_system::Pallet<T>>::block_number() + T::OwnershipPeriod::get();
+		Self::schedule_lapse(uri, expires_at)?;
+		OwnershipExpiry::<T>::insert(uri, expires_at);
+		Ok(())
+	}
+
+	/// Extend the ownership of URI re-verified by Oracle by `T::OwnershipPeriod`
+	///
+	/// ## Error
+	/// `TooManyLapsingURIs`
+	fn renew_ownership(uri: &URI) -> DispatchResult {
+		let now = <frame_system::Pallet<T>>::block_number();
+		let expires_at = OwnershipExpiry::<T>::get(uri).unwrap_or(now) + T::OwnershipPeriod::get();
+		Self::schedule_lapse(uri, expires_at)?;
+		OwnershipExpiry::<T>::insert(uri, expires_at);
+		Self::deposit_event(Event::<T>::OwnershipRenewed { uri: uri.clone(), expires_at });
+		Ok(())
+	}
+
+	/// Schedule the removal of URI at the end of the grace period after `expires_at`. If too many
+	/// URIs lapse at that block, it is scheduled at one of the next `MAX_LAPSE_DELAY` blocks
+	/// with room.
+	///
+	/// ## Error
+	/// `TooManyLapsingURIs`
+	fn schedule_lapse(uri: &URI, expires_at: BlockNumberFor<T>) -> DispatchResult {
+		let grace_period_end = expires_at + T::GracePeriod::get();
+		for delay in 0..=MAX_LAPSE_DELAY {
+			let lapse_at = grace_period_end + delay.into();
+			if LapsingURIs::<T>::try_mutate(lapse_at, |uris| uris.try_push(uri.clone())).is_ok() {
+				return Ok(())
+			}
+		}
+		Err(Error::<T>::TooManyLapsingURIs.into())
+	}
+
+	/// Check whether ownership of URI, or of the root URI it has been claimed under, has not
+	/// expired
+	///
+	/// ## Error
+	/// `OwnershipExpired`
+	fn ensure_not_expired(uri: &URI) -> DispatchResult {
+		let root_uri = RootURI::<T>::get(uri).unwrap_or_else(|| uri.clone());
+		if let Some(expires_at) = OwnershipExpiry::<T>::get(&root_uri) {
+			ensure!(
+				<frame_system::Pallet<T>>::block_number() < expires_at,
+				Error::<T>::OwnershipExpired
+			);
+		}
+		Ok(())
+	}
+
+	/// Add `child_uri` claimed under `parent_uri` to the child URIs of the root of `parent_uri`
+	///
+	/// ## Errors
+	/// `OwnershipExpired`, `MaxChildURIs`
+	fn try_add_child_uri(parent_uri: &URI, child_uri: &URI) -> DispatchResult {
+		let root_uri = RootURI::<T>::get(parent_uri).unwrap_or_else(|| parent_uri.clone());
+		Self::ensure_not_expired(&root_uri)?;
+		ChildURIs::<T>::try_mutate(&root_uri, |child_uris| {
+			child_uris.try_push(child_uri.clone()).map_err(|_| Error::<T>::MaxChildURIs)
+		})?;
+		RootURI::<T>::insert(child_uri, root_uri);
+		Ok(())
+	}
+
+	/// Remove `URAuthDoc` of URI and all of its child URIs. Return the number of removed child
+	/// URIs.
+	fn remove_urauth_doc(uri: &URI) -> u32 {
+		let child_uris = ChildURIs::<T>::take(uri);
+		for child_uri in child_uris.iter() {
+			RootURI::<T>::remove(child_uri);
+			Self::do_remove_urauth_doc(child_uri);
+		}
+		Self::do_remove_urauth_doc(uri);
+		child_uris.len() as u32
+	}
+
+	fn do_remove_urauth_doc(uri: &URI) {
+		if let Some(urauth_doc) = URAuthTree::<T>::take(uri) {
+			URAuthDocUpdateStatus::<T>::remove(urauth_doc.id);
+		}
+		DataSet::<T>::remove(uri);
+		OwnershipExpiry::<T>::remove(uri);
+		PendingTransfers::<T>::remove(uri);
+		Self::deposit_event(Event::<T>::URAuthDocRemoved { uri: uri.clone() });
+	}
+
 	/// Handle the result of _challenge value_ verification based on `VerificationSubmissionResult`
 	fn handle_verification_submission_result(
 		res: &VerificationSubmissionResult,
@@ -884,14 +1327,24 @@ where
 		match res {
 			VerificationSubmissionResult::Complete => {
 				let RequestMetadata { claim_type, maybe_register_uri, .. } = metadata;
-				let urauth_doc = Self::new_urauth_doc(owner_did, None, None)?;
-				URAuthTree::<T>::insert(&maybe_register_uri, urauth_doc.clone());
+				match URAuthTree::<T>::get(&maybe_register_uri) {
+					// Re-verification of the ownership requested by its owner
+					Some(urauth_doc) if urauth_doc.is_owner(&owner_did) =>
+						Self::renew_ownership(&maybe_register_uri)?,
+					// URI has been registered by someone else in the meantime
+					Some(_) => {},
+					None => {
+						let urauth_doc = Self::new_urauth_doc(owner_did, None, None)?;
+						URAuthTree::<T>::insert(&maybe_register_uri, urauth_doc.clone());
+						Self::start_ownership(&maybe_register_uri)?;
+						Self::deposit_event(Event::<T>::URAuthTreeRegistered {
+							claim_type,
+							uri: maybe_register_uri,
+							urauth_doc,
+						})
+					},
+				}
 				Self::remove_all_uri_related(&uri);
-				Self::deposit_event(Event::<T>::URAuthTreeRegistered {
-					claim_type,
-					uri: maybe_register_uri,
-					urauth_doc,
-				})
 			},
 			VerificationSubmissionResult::Tie => Self::remove_all_uri_related(&uri),
 			VerificationSubmissionResult::InProgress =>
@@ -1218,6 +1671,9 @@ where
 	/// - _updated_at_ is less than _pref_updated_at_
 	/// - Try to update on different field
 	/// - Threshold is bigger than sum of _multi_dids'_ weight
+	///
+	/// `OverMaxSize`
+	/// - Updated field is over limit of `MAX_*`
 	pub fn do_try_update_doc(
 		urauth_doc: &mut URAuthDoc<T::AccountId>,
 		update_doc_status: &mut UpdateDocStatus<T::AccountId>,
@@ -1234,7 +1690,10 @@ where
 
 		urauth_doc.update_doc(update_doc_field.clone(), updated_at).map_err(|e| {
 			log::warn!(" 🚨 Error on update urauth_doc {:?} 🚨", e);
-			Error::<T>::ErrorOnUpdateDoc
+			match e {
+				URAuthDocUpdateError::OverMaxSize => Error::<T>::OverMaxSize,
+				_ => Error::<T>::ErrorOnUpdateDoc,
+			}
 		})?;
 
 		Ok(())
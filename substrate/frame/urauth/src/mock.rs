@@ -1,30 +1,29 @@
 pub use crate::{self as pallet_newnal, *};
-use frame_support::{parameter_types, traits::Everything};
+use frame_support::{
+	parameter_types,
+	traits::{ConstBool, ConstU64, Everything},
+};
 use frame_system::EnsureRoot;
 use sp_core::{sr25519::Signature, H256};
+use sp_keystore::{testing::MemoryKeystore, KeystoreExt};
 use sp_runtime::{
-	testing::Header,
 	traits::{BlakeTwo256, IdentityLookup},
-	AccountId32,
+	AccountId32, BuildStorage,
 };
 
 pub type MockBalance = u128;
 pub type MockAccountId = AccountId32;
 pub type MockBlockNumber = u64;
 
-type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
 
 pub const ALICE_SS58: &str = "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY";
 pub const BOB_SS58: &str = "5FHneW46xGXgs5mUiveU4sbTyGBzmstUspZC92UhjJM694ty";
 
 frame_support::construct_runtime!(
-	pub enum Test where
-		Block = Block,
-		NodeBlock = Block,
-		UncheckedExtrinsic = UncheckedExtrinsic,
+	pub enum Test
 	{
-		System: frame_system::{Pallet, Call, Config, Storage, Event<T>} = 1,
+		System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>} = 1,
 		Timestamp: pallet_timestamp::{Pallet, Call, Storage} = 2,
 		URAuth: pallet_newnal::{Pallet, Call, Storage, Event<T>} = 99,
 	}
@@ -42,13 +41,12 @@ impl frame_system::Config for Test {
 	type DbWeight = ();
 	type RuntimeOrigin = RuntimeOrigin;
 	type RuntimeCall = RuntimeCall;
-	type Index = u64;
-	type BlockNumber = MockBlockNumber;
+	type Nonce = u64;
+	type Block = Block;
 	type Hash = H256;
 	type Hashing = BlakeTwo256;
 	type AccountId = MockAccountId;
 	type Lookup = IdentityLookup<Self::AccountId>;
-	type Header = Header;
 	type RuntimeEvent = RuntimeEvent;
 	type BlockHashCount = BlockHashCount;
 	type Version = ();
@@ -81,12 +79,18 @@ impl pallet_newnal::Config for Test {
 	type MaxURIByOracle = ConstU32<100>;
 	type VerificationPeriod = ConstU64<3>;
 	type MaxRequest = ConstU32<5>;
+	type RandomnessEnabled = ConstBool<false>;
+	type OwnershipPeriod = ConstU64<10>;
+	type GracePeriod = ConstU64<5>;
+	type MaxChildURIs = ConstU32<5>;
 	type AuthorizedOrigin = EnsureRoot<MockAccountId>;
+	type WeightInfo = ();
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	let storage = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	let storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
 	let mut ext: sp_io::TestExternalities = storage.into();
+	ext.register_extension(KeystoreExt::new(MemoryKeystore::new()));
 	ext.execute_with(|| System::set_block_number(1)); // For 'Event'
 	ext
 }
@@ -185,6 +189,8 @@ pub enum ProofType<Account: Encode> {
 	Request(URI, OwnerDID, MockBlockNumber),
 	Challenge(URI, OwnerDID, Vec<u8>, Vec<u8>),
 	Update(URI, URAuthDoc<Account>, OwnerDID, MockBlockNumber),
+	Transfer(URI, Option<OwnerDID>, MockBlockNumber),
+	AcceptTransfer(URI, MockBlockNumber),
 }
 
 pub struct MockProver<Account>(PhantomData<Account>);
@@ -231,6 +237,9 @@ impl<Account: Encode> MockProver<Account> {
 				)
 					.encode()
 			},
+			ProofType::Transfer(uri, new_owner_did, nonce) =>
+				(b"transfer", uri, new_owner_did, nonce).encode(),
+			ProofType::AcceptTransfer(uri, nonce) => (b"accept_transfer", uri, nonce).encode(),
 		};
 
 		if raw.len() > 256 {
@@ -450,7 +459,7 @@ impl AddURIByOracleCall {
 
 impl ExtBuilder {
 	fn build(self) -> sp_io::TestExternalities {
-		let storage = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+		let storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
 		let mut ext = sp_io::TestExternalities::from(storage);
 		ext.execute_with(|| System::set_block_number(1));
 		ext
@@ -438,6 +438,20 @@ fn verify_challenge_with_multiple_oracle_members() {
 	})
 }
 
+#[test]
+fn kick_oracle_member_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(URAuth::add_oracle_member(RuntimeOrigin::root(), Alice.to_account_id()));
+		assert_ok!(URAuth::add_oracle_member(RuntimeOrigin::root(), Bob.to_account_id()));
+		assert_ok!(URAuth::kick_oracle_member(RuntimeOrigin::root(), Alice.to_account_id()));
+		assert_eq!(URAuth::oracle_members().to_vec(), vec![Bob.to_account_id()]);
+		assert_noop!(
+			URAuth::kick_oracle_member(RuntimeOrigin::root(), Alice.to_account_id()),
+			Error::<Test>::NotOracleMember
+		);
+	})
+}
+
 #[test]
 fn integrity_test() {
 	let mut newnal_helper = MockURAuthHelper::<AccountId32>::default(None, None, None, None);
@@ -679,3 +693,420 @@ fn integrity_test() {
 		assert!(ChallengeValue::<Test>::get(&bounded_uri).is_none());
 	})
 }
+
+#[test]
+fn ownership_lapses_after_grace_period_unless_renewed() {
+	let mut newnal_helper = MockURAuthHelper::<MockAccountId>::default(None, None, None, None);
+	let (uri, owner_did, challenge_value, timestamp) = newnal_helper.deconstruct_urauth_doc(None);
+	let bounded_uri = newnal_helper.bounded_uri(None);
+	let bounded_owner_did = newnal_helper.raw_owner_did();
+	let challenge_sig = newnal_helper.create_sr25519_signature(
+		Alice,
+		ProofType::Challenge(
+			bounded_uri.clone(),
+			bounded_owner_did.clone(),
+			challenge_value,
+			timestamp,
+		),
+	);
+	let challenge_json =
+		newnal_helper.generate_json("Sr25519Signature2020".into(), hex::encode(challenge_sig));
+	new_test_ext().execute_with(|| {
+		assert_ok!(URAuth::add_oracle_member(RuntimeOrigin::root(), Alice.to_account_id()));
+		assert_ok!(URAuth::request_register_ownership(
+			RuntimeOrigin::signed(Alice.to_account_id()),
+			ClaimType::Domain,
+			uri.clone(),
+			owner_did.clone(),
+			Some(newnal_helper.challenge_value()),
+			MultiSigner::Sr25519(Alice.public()),
+			newnal_helper.create_signature(
+				Alice,
+				ProofType::Request(bounded_uri.clone(), bounded_owner_did.clone(), 1),
+			)
+		));
+		assert_ok!(URAuth::verify_challenge(
+			RuntimeOrigin::signed(Alice.to_account_id()),
+			challenge_json.clone()
+		));
+		let register_uri: URI = "website1.com".as_bytes().to_vec().try_into().unwrap();
+		assert_eq!(URAuth::ownership_expiry(&register_uri), Some(11));
+
+		// Renewal is verified by Oracle as the registration
+		run_to_block(5);
+		assert_ok!(URAuth::request_renewal(
+			RuntimeOrigin::signed(Alice.to_account_id()),
+			ClaimType::Domain,
+			uri.clone(),
+			owner_did.clone(),
+			Some(newnal_helper.challenge_value()),
+			MultiSigner::Sr25519(Alice.public()),
+			newnal_helper.create_signature(
+				Alice,
+				ProofType::Request(bounded_uri.clone(), bounded_owner_did.clone(), 2),
+			)
+		));
+		System::assert_has_event(URAuthEvent::RenewalRequested { uri: bounded_uri.clone() }.into());
+		assert_ok!(URAuth::verify_challenge(
+			RuntimeOrigin::signed(Alice.to_account_id()),
+			challenge_json
+		));
+		assert_eq!(URAuth::ownership_expiry(&register_uri), Some(21));
+		System::assert_has_event(
+			URAuthEvent::OwnershipRenewed { uri: register_uri.clone(), expires_at: 21 }.into(),
+		);
+
+		// Lapse scheduled before the renewal is skipped
+		run_to_block(16);
+		assert!(URAuthTree::<Test>::get(&register_uri).is_some());
+
+		// Updates are rejected once expired, during the grace period
+		run_to_block(21);
+		assert_noop!(
+			URAuth::update_urauth_doc(
+				RuntimeOrigin::signed(Alice.to_account_id()),
+				register_uri.clone(),
+				UpdateDocField::AccessRules(None),
+				1,
+				None
+			),
+			Error::<Test>::OwnershipExpired
+		);
+
+		run_to_block(26);
+		assert!(URAuthTree::<Test>::get(&register_uri).is_none());
+		assert!(URAuth::ownership_expiry(&register_uri).is_none());
+		System::assert_has_event(URAuthEvent::OwnershipLapsed { uri: register_uri }.into());
+	});
+}
+
+#[test]
+fn transfer_ownership_works() {
+	let mut newnal_helper = MockURAuthHelper::<MockAccountId>::default(None, None, None, None);
+	let (uri, owner_did, challenge_value, timestamp) = newnal_helper.deconstruct_urauth_doc(None);
+	let bounded_uri = newnal_helper.bounded_uri(None);
+	let bounded_owner_did = newnal_helper.raw_owner_did();
+	let request_sig = newnal_helper.create_signature(
+		Alice,
+		ProofType::Request(bounded_uri.clone(), bounded_owner_did.clone(), 1),
+	);
+	let challenge_sig = newnal_helper.create_sr25519_signature(
+		Alice,
+		ProofType::Challenge(
+			bounded_uri.clone(),
+			bounded_owner_did.clone(),
+			challenge_value,
+			timestamp,
+		),
+	);
+	let challenge_json =
+		newnal_helper.generate_json("Sr25519Signature2020".into(), hex::encode(challenge_sig));
+	let bob_did: OwnerDID = newnal_helper
+		.generate_did(BOB_SS58)
+		.as_bytes()
+		.to_vec()
+		.try_into()
+		.expect("Too long");
+	new_test_ext().execute_with(|| {
+		assert_ok!(URAuth::add_oracle_member(RuntimeOrigin::root(), Alice.to_account_id()));
+		assert_ok!(URAuth::request_register_ownership(
+			RuntimeOrigin::signed(Alice.to_account_id()),
+			ClaimType::Domain,
+			uri,
+			owner_did,
+			Some(newnal_helper.challenge_value()),
+			MultiSigner::Sr25519(Alice.public()),
+			request_sig
+		));
+		assert_ok!(URAuth::verify_challenge(
+			RuntimeOrigin::signed(Alice.to_account_id()),
+			challenge_json
+		));
+		let register_uri: URI = "website1.com".as_bytes().to_vec().try_into().unwrap();
+
+		// Only owners can offer
+		assert_noop!(
+			URAuth::offer_transfer(
+				RuntimeOrigin::signed(Bob.to_account_id()),
+				register_uri.clone(),
+				Some(bob_did.to_vec()),
+				MultiSigner::Sr25519(Bob.public()),
+				newnal_helper.create_signature(
+					Bob,
+					ProofType::Transfer(register_uri.clone(), Some(bob_did.clone()), 1),
+				)
+			),
+			Error::<Test>::NotURAuthDocOwner
+		);
+		assert_ok!(URAuth::offer_transfer(
+			RuntimeOrigin::signed(Alice.to_account_id()),
+			register_uri.clone(),
+			Some(bob_did.to_vec()),
+			MultiSigner::Sr25519(Alice.public()),
+			newnal_helper.create_signature(
+				Alice,
+				ProofType::Transfer(register_uri.clone(), Some(bob_did.clone()), 2),
+			)
+		));
+		assert_eq!(URAuth::pending_transfer(&register_uri), Some(Bob.to_account_id()));
+
+		// Only the new owner can accept
+		assert_noop!(
+			URAuth::accept_transfer(
+				RuntimeOrigin::signed(Charlie.to_account_id()),
+				register_uri.clone(),
+				MultiSigner::Sr25519(Charlie.public()),
+				newnal_helper
+					.create_signature(Charlie, ProofType::AcceptTransfer(register_uri.clone(), 1),)
+			),
+			Error::<Test>::BadSigner
+		);
+		assert_ok!(URAuth::accept_transfer(
+			RuntimeOrigin::signed(Bob.to_account_id()),
+			register_uri.clone(),
+			MultiSigner::Sr25519(Bob.public()),
+			newnal_helper.create_signature(Bob, ProofType::AcceptTransfer(register_uri.clone(), 1))
+		));
+		let urauth_doc = URAuthTree::<Test>::get(&register_uri).unwrap();
+		assert!(urauth_doc.is_owner(&Bob.to_account_id()));
+		assert!(!urauth_doc.is_owner(&Alice.to_account_id()));
+		assert!(URAuth::pending_transfer(&register_uri).is_none());
+		System::assert_last_event(
+			URAuthEvent::OwnershipTransferred { uri: register_uri.clone(), urauth_doc }.into(),
+		);
+
+		assert_noop!(
+			URAuth::accept_transfer(
+				RuntimeOrigin::signed(Bob.to_account_id()),
+				register_uri.clone(),
+				MultiSigner::Sr25519(Bob.public()),
+				newnal_helper
+					.create_signature(Bob, ProofType::AcceptTransfer(register_uri.clone(), 2))
+			),
+			Error::<Test>::NoPendingTransfer
+		);
+	});
+}
+
+/// Register `website1.com` owned by Alice, who is the only Oracle member, and return the challenge
+/// json with which its ownership is verified
+fn register_website1(newnal_helper: &mut MockURAuthHelper<MockAccountId>) -> Vec<u8> {
+	let (uri, owner_did, challenge_value, timestamp) = newnal_helper.deconstruct_urauth_doc(None);
+	let bounded_uri = newnal_helper.bounded_uri(None);
+	let bounded_owner_did = newnal_helper.raw_owner_did();
+	let challenge_sig = newnal_helper.create_sr25519_signature(
+		Alice,
+		ProofType::Challenge(
+			bounded_uri.clone(),
+			bounded_owner_did.clone(),
+			challenge_value,
+			timestamp,
+		),
+	);
+	let challenge_json =
+		newnal_helper.generate_json("Sr25519Signature2020".into(), hex::encode(challenge_sig));
+	assert_ok!(URAuth::add_oracle_member(RuntimeOrigin::root(), Alice.to_account_id()));
+	assert_ok!(URAuth::request_register_ownership(
+		RuntimeOrigin::signed(Alice.to_account_id()),
+		ClaimType::Domain,
+		uri,
+		owner_did,
+		Some(newnal_helper.challenge_value()),
+		MultiSigner::Sr25519(Alice.public()),
+		newnal_helper
+			.create_signature(Alice, ProofType::Request(bounded_uri, bounded_owner_did, 1),)
+	));
+	assert_ok!(URAuth::verify_challenge(
+		RuntimeOrigin::signed(Alice.to_account_id()),
+		challenge_json.clone()
+	));
+	challenge_json
+}
+
+#[test]
+fn renewal_during_grace_period_keeps_ownership() {
+	let mut newnal_helper = MockURAuthHelper::<MockAccountId>::default(None, None, None, None);
+	let (uri, owner_did, _, _) = newnal_helper.deconstruct_urauth_doc(None);
+	let bounded_uri = newnal_helper.bounded_uri(None);
+	let bounded_owner_did = newnal_helper.raw_owner_did();
+	new_test_ext().execute_with(|| {
+		let challenge_json = register_website1(&mut newnal_helper);
+		let register_uri: URI = "website1.com".as_bytes().to_vec().try_into().unwrap();
+		assert_eq!(URAuth::ownership_expiry(&register_uri), Some(11));
+
+		// Expired, but still within the grace period which ends at block 16
+		run_to_block(13);
+		assert_noop!(
+			URAuth::update_urauth_doc(
+				RuntimeOrigin::signed(Alice.to_account_id()),
+				register_uri.clone(),
+				UpdateDocField::AccessRules(None),
+				1,
+				None
+			),
+			Error::<Test>::OwnershipExpired
+		);
+		assert_ok!(URAuth::request_renewal(
+			RuntimeOrigin::signed(Alice.to_account_id()),
+			ClaimType::Domain,
+			uri,
+			owner_did,
+			Some(newnal_helper.challenge_value()),
+			MultiSigner::Sr25519(Alice.public()),
+			newnal_helper
+				.create_signature(Alice, ProofType::Request(bounded_uri, bounded_owner_did, 2),)
+		));
+		assert_ok!(URAuth::verify_challenge(
+			RuntimeOrigin::signed(Alice.to_account_id()),
+			challenge_json
+		));
+		// Extended from the previous expiry rather than from the renewal
+		assert_eq!(URAuth::ownership_expiry(&register_uri), Some(21));
+
+		run_to_block(16);
+		assert!(URAuthTree::<Test>::get(&register_uri).is_some());
+		assert!(!System::events().iter().any(|record| matches!(
+			&record.event,
+			RuntimeEvent::URAuth(URAuthEvent::OwnershipLapsed { .. })
+		)));
+
+		run_to_block(25);
+		assert!(URAuthTree::<Test>::get(&register_uri).is_some());
+		run_to_block(26);
+		assert!(URAuthTree::<Test>::get(&register_uri).is_none());
+		System::assert_has_event(URAuthEvent::OwnershipLapsed { uri: register_uri }.into());
+	});
+}
+
+#[test]
+fn transfer_is_rejected_unless_offered_by_owner() {
+	let mut newnal_helper = MockURAuthHelper::<MockAccountId>::default(None, None, None, None);
+	let bob_did: OwnerDID = newnal_helper
+		.generate_did(BOB_SS58)
+		.as_bytes()
+		.to_vec()
+		.try_into()
+		.expect("Too long");
+	new_test_ext().execute_with(|| {
+		register_website1(&mut newnal_helper);
+		let register_uri: URI = "website1.com".as_bytes().to_vec().try_into().unwrap();
+
+		// Non-owner can neither offer nor cancel an offer
+		assert_noop!(
+			URAuth::offer_transfer(
+				RuntimeOrigin::signed(Charlie.to_account_id()),
+				register_uri.clone(),
+				Some(bob_did.to_vec()),
+				MultiSigner::Sr25519(Charlie.public()),
+				newnal_helper.create_signature(
+					Charlie,
+					ProofType::Transfer(register_uri.clone(), Some(bob_did.clone()), 1),
+				)
+			),
+			Error::<Test>::NotURAuthDocOwner
+		);
+		assert_ok!(URAuth::offer_transfer(
+			RuntimeOrigin::signed(Alice.to_account_id()),
+			register_uri.clone(),
+			Some(bob_did.to_vec()),
+			MultiSigner::Sr25519(Alice.public()),
+			newnal_helper.create_signature(
+				Alice,
+				ProofType::Transfer(register_uri.clone(), Some(bob_did.clone()), 2),
+			)
+		));
+		assert_noop!(
+			URAuth::offer_transfer(
+				RuntimeOrigin::signed(Charlie.to_account_id()),
+				register_uri.clone(),
+				None,
+				MultiSigner::Sr25519(Charlie.public()),
+				newnal_helper
+					.create_signature(Charlie, ProofType::Transfer(register_uri.clone(), None, 1))
+			),
+			Error::<Test>::NotURAuthDocOwner
+		);
+		assert_eq!(URAuth::pending_transfer(&register_uri), Some(Bob.to_account_id()));
+
+		// Offer signed for another URI is rejected
+		let other_uri: URI = "website2.com".as_bytes().to_vec().try_into().unwrap();
+		assert_noop!(
+			URAuth::accept_transfer(
+				RuntimeOrigin::signed(Bob.to_account_id()),
+				register_uri.clone(),
+				MultiSigner::Sr25519(Bob.public()),
+				newnal_helper.create_signature(Bob, ProofType::AcceptTransfer(other_uri, 1))
+			),
+			Error::<Test>::BadProof
+		);
+		assert_ok!(URAuth::accept_transfer(
+			RuntimeOrigin::signed(Bob.to_account_id()),
+			register_uri.clone(),
+			MultiSigner::Sr25519(Bob.public()),
+			newnal_helper.create_signature(Bob, ProofType::AcceptTransfer(register_uri.clone(), 1))
+		));
+
+		// Previous owner is no longer an owner
+		assert_noop!(
+			URAuth::offer_transfer(
+				RuntimeOrigin::signed(Alice.to_account_id()),
+				register_uri.clone(),
+				None,
+				MultiSigner::Sr25519(Alice.public()),
+				newnal_helper
+					.create_signature(Alice, ProofType::Transfer(register_uri.clone(), None, 3))
+			),
+			Error::<Test>::NotURAuthDocOwner
+		);
+	});
+}
+
+#[test]
+fn child_uris_are_removed_with_lapsed_root() {
+	let mut newnal_helper = MockURAuthHelper::<MockAccountId>::default(None, None, None, None);
+	let bounded_owner_did = newnal_helper.raw_owner_did();
+	let child_uri = "https://sub2.sub1.website1.com".as_bytes().to_vec();
+	let bounded_child_uri: URI = child_uri.clone().try_into().unwrap();
+	new_test_ext().execute_with(|| {
+		register_website1(&mut newnal_helper);
+		let register_uri: URI = "website1.com".as_bytes().to_vec().try_into().unwrap();
+
+		assert_ok!(URAuth::claim_ownership(
+			RuntimeOrigin::signed(Alice.to_account_id()),
+			ClaimType::Domain,
+			child_uri.clone(),
+			bounded_owner_did.to_vec(),
+			MultiSigner::Sr25519(Alice.public()),
+			newnal_helper.create_signature(
+				Alice,
+				ProofType::Request(bounded_child_uri.clone(), bounded_owner_did.clone(), 2),
+			)
+		));
+		assert_eq!(ChildURIs::<Test>::get(&register_uri).to_vec(), vec![bounded_child_uri.clone()]);
+		assert_eq!(RootURI::<Test>::get(&bounded_child_uri), Some(register_uri.clone()));
+
+		// Child URI follows the expiry of its root
+		run_to_block(11);
+		assert_noop!(
+			URAuth::offer_transfer(
+				RuntimeOrigin::signed(Alice.to_account_id()),
+				bounded_child_uri.clone(),
+				None,
+				MultiSigner::Sr25519(Alice.public()),
+				newnal_helper.create_signature(
+					Alice,
+					ProofType::Transfer(bounded_child_uri.clone(), None, 3),
+				)
+			),
+			Error::<Test>::OwnershipExpired
+		);
+
+		run_to_block(16);
+		assert!(URAuthTree::<Test>::get(&register_uri).is_none());
+		assert!(URAuthTree::<Test>::get(&bounded_child_uri).is_none());
+		assert!(ChildURIs::<Test>::get(&register_uri).is_empty());
+		assert!(RootURI::<Test>::get(&bounded_child_uri).is_none());
+		System::assert_has_event(URAuthEvent::URAuthDocRemoved { uri: bounded_child_uri }.into());
+		System::assert_has_event(URAuthEvent::URAuthDocRemoved { uri: register_uri }.into());
+	});
+}
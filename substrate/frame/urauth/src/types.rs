@@ -28,7 +28,20 @@ pub enum ClaimType {
 
 impl MaxEncodedLen for ClaimType {
 	fn max_encoded_len() -> usize {
-		URI::max_encoded_len() + URI::max_encoded_len()
+		URI::max_encoded_len() + AnyText::max_encoded_len() + AnyText::max_encoded_len()
+	}
+}
+
+impl ClaimType {
+	/// Check whether its fields are within `MAX_URI_SIZE` and `MAX_COMMON_SIZE`
+	pub fn is_bounded(&self) -> bool {
+		match self {
+			ClaimType::Domain => true,
+			ClaimType::Contents { data_source, name, description } =>
+				data_source.as_ref().map_or(true, |ds| ds.len() <= MAX_URI_SIZE as usize) &&
+					name.len() <= MAX_COMMON_SIZE as usize &&
+					description.len() <= MAX_COMMON_SIZE as usize,
+		}
 	}
 }
 
@@ -64,6 +77,14 @@ pub struct URIPart {
 	pub path: Option<Vec<u8>>,
 }
 
+impl MaxEncodedLen for URIPart {
+	fn max_encoded_len() -> usize {
+		// All parts are split from a single URI which is up to `MAX_URI_SIZE`. Extra bytes are for
+		// the default scheme and sub-domain, option tags and length prefixes.
+		URI::max_encoded_len() + 32
+	}
+}
+
 impl PartialEq for URIPart {
 	fn eq(&self, other: &Self) -> bool {
 		let any = b'*';
@@ -230,7 +251,7 @@ impl<BoundedString> DataSetMetadata<BoundedString> {
 }
 
 /// Metadata for verifying challenge value
-#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub struct RequestMetadata {
 	pub owner_did: OwnerDID,
 	pub challenge_value: Randomness,
@@ -258,6 +279,17 @@ pub struct VerificationSubmission<T: Config> {
 	pub threshold: Threshold,
 }
 
+impl<T: Config> MaxEncodedLen for VerificationSubmission<T> {
+	fn max_encoded_len() -> usize {
+		// Only Oracle members can submit and each of them only once
+		let max_members = T::MaxOracleMembers::get() as usize;
+		codec::Compact::<u32>::max_encoded_len() * 2 +
+			T::AccountId::max_encoded_len() * max_members +
+			(H256::max_encoded_len() + ApprovalCount::max_encoded_len()) * max_members +
+			Threshold::max_encoded_len()
+	}
+}
+
 impl<T: Config> Default for VerificationSubmission<T> {
 	fn default() -> Self {
 		Self { voters: Default::default(), status: BTreeMap::new(), threshold: 1 }
@@ -341,6 +373,8 @@ pub enum URAuthSignedPayload<Account, BlockNumber> {
 	Request { uri: URI, owner_did: OwnerDID, nonce: BlockNumber },
 	Challenge { uri: URI, owner_did: OwnerDID, challenge: Vec<u8>, timestamp: Vec<u8> },
 	Update { uri: URI, urauth_doc: URAuthDoc<Account>, owner_did: OwnerDID, nonce: BlockNumber },
+	Transfer { uri: URI, new_owner_did: Option<OwnerDID>, nonce: BlockNumber },
+	AcceptTransfer { uri: URI, nonce: BlockNumber },
 }
 
 impl<Account: Encode, BlockNumber: Encode> Encode for URAuthSignedPayload<Account, BlockNumber> {
@@ -382,6 +416,11 @@ impl<Account: Encode, BlockNumber: Encode> Encode for URAuthSignedPayload<Accoun
 				)
 					.encode()
 			},
+			// Prefixed so that they can not be replayed as a payload of another kind
+			URAuthSignedPayload::Transfer { uri, new_owner_did, nonce } =>
+				(b"transfer", uri, new_owner_did, nonce).encode(),
+			URAuthSignedPayload::AcceptTransfer { uri, nonce } =>
+				(b"accept_transfer", uri, nonce).encode(),
 		};
 		if raw_payload.len() > 256 {
 			f(&sp_io::hashing::blake2_256(&raw_payload)[..])
@@ -423,7 +462,8 @@ where
 	Account: Encode + MaxEncodedLen,
 {
 	fn max_encoded_len() -> usize {
-		WeightedDID::<Account>::max_encoded_len() * MAX_OWNER_DID_SIZE as usize
+		WeightedDID::<Account>::max_encoded_len() * MAX_MULTI_OWNERS_NUM +
+			DIDWeight::max_encoded_len()
 	}
 }
 
@@ -507,6 +547,16 @@ impl MaxEncodedLen for AccessRule {
 	}
 }
 
+impl AccessRule {
+	/// Check whether the number of its rules is within `MAX_RULES_NUM` and each of them is bounded
+	pub fn is_bounded(&self) -> bool {
+		match self {
+			AccessRule::AccessRuleV1 { rules, .. } =>
+				rules.len() <= MAX_RULES_NUM && rules.iter().all(|rule| rule.is_bounded()),
+		}
+	}
+}
+
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
 pub struct Rule {
 	pub user_agents: Vec<UserAgent>,
@@ -517,7 +567,18 @@ pub struct Rule {
 impl MaxEncodedLen for Rule {
 	fn max_encoded_len() -> usize {
 		UserAgent::max_encoded_len() * MAX_USER_AGENTS_NUM +
-			(ContentType::max_encoded_len() + Price::max_encoded_len()) * 4
+			(ContentType::max_encoded_len() + Price::max_encoded_len()) * MAX_CONTENT_TYPES +
+			ContentType::max_encoded_len() * MAX_CONTENT_TYPES
+	}
+}
+
+impl Rule {
+	/// Check whether its user agents and content types are within `MAX_USER_AGENTS_NUM` and
+	/// `MAX_CONTENT_TYPES`
+	pub fn is_bounded(&self) -> bool {
+		self.user_agents.len() <= MAX_USER_AGENTS_NUM &&
+			self.allow.len() <= MAX_CONTENT_TYPES &&
+			self.disallow.len() <= MAX_CONTENT_TYPES
 	}
 }
 
@@ -626,6 +687,13 @@ where
 		self.proofs = proofs;
 	}
 
+	/// Hand over the doc to `new_owner` as its sole owner, dropping the proofs of previous owners
+	pub fn transfer(&mut self, new_owner: Account, updated_at: u128) {
+		self.multi_owner_did = MultiDID::new(new_owner, 1);
+		self.updated_at = updated_at;
+		self.proofs = None;
+	}
+
 	pub fn add_proof(&mut self, proof: Proof) {
 		let mut some_proofs = self.proofs.take().map_or(Default::default(), |proofs| proofs);
 		some_proofs.push(proof);
@@ -640,6 +708,9 @@ where
 		self.updated_at = updated_at;
 		match update_doc_field {
 			UpdateDocField::MultiDID(weighted_did) => {
+				if self.multi_owner_did.dids.len() >= MAX_MULTI_OWNERS_NUM {
+					return Err(URAuthDocUpdateError::OverMaxSize)
+				}
 				self.multi_owner_did.add_owner(weighted_did);
 			},
 			UpdateDocField::Threshold(new) => {
@@ -650,6 +721,9 @@ where
 				self.multi_owner_did.threshold = new;
 			},
 			UpdateDocField::IdentityInfo(identity_info) => {
+				if identity_info.as_ref().map_or(false, |v| v.len() > MAX_MULTI_OWNERS_NUM) {
+					return Err(URAuthDocUpdateError::OverMaxSize)
+				}
 				self.identity_info = identity_info;
 			},
 			UpdateDocField::ContentMetadata(content_metadata) => {
@@ -659,6 +733,11 @@ where
 				self.copyright_info = copyright_info;
 			},
 			UpdateDocField::AccessRules(access_rules) => {
+				if let Some(rules) = access_rules.as_ref() {
+					if rules.len() > MAX_ACCESS_RULES || !rules.iter().all(|r| r.is_bounded()) {
+						return Err(URAuthDocUpdateError::OverMaxSize)
+					}
+				}
 				self.access_rules = access_rules;
 			},
 		};
@@ -681,6 +760,16 @@ pub enum UpdateDocField<Account> {
 	AccessRules(Option<Vec<AccessRule>>),
 }
 
+impl<Account> UpdateDocField<Account> {
+	/// Number of access rules to be updated
+	pub fn access_rules_len(&self) -> u32 {
+		match self {
+			UpdateDocField::AccessRules(Some(access_rules)) => access_rules.len() as u32,
+			_ => 0,
+		}
+	}
+}
+
 impl<Account> MaxEncodedLen for UpdateDocField<Account>
 where
 	Account: Encode,
@@ -799,6 +888,8 @@ impl<Account: Clone> UpdateDocStatus<Account> {
 pub enum URAuthDocUpdateError {
 	/// Threshold should be less than total weight of owners
 	ThresholdError,
+	/// Size of the field is over limit of `MAX_*`
+	OverMaxSize,
 }
 
 impl sp_runtime::traits::Printable for URAuthDocUpdateError {
@@ -806,6 +897,7 @@ impl sp_runtime::traits::Printable for URAuthDocUpdateError {
 		"URAuthDocUpdateError".print();
 		match self {
 			Self::ThresholdError => "GreaterThanTotalWeight".print(),
+			Self::OverMaxSize => "OverMaxSize".print(),
 		}
 	}
 }
@@ -1018,22 +1110,27 @@ impl<T: Config> Parser<T> for URAuthParser<T> {
 pub mod size {
 	use super::*;
 
-	/// Maximum number of `URAuthDoc` owners we expect in a single `MultiDID` value. Note this is
-	/// not (yet) enforced, and just serves to provide a sensible `max_encoded_len` for `MultiDID`.
+	/// Maximum number of `URAuthDoc` owners in a single `MultiDID` value. Also bounds the number
+	/// of `identity_info` and `proofs` of `URAuthDoc`.
 	pub const MAX_MULTI_OWNERS_NUM: usize = 5;
 
-	/// Maximum number of `access_rules` we expect in a single `MultiDID` value. Note this is not
-	/// (yet) enforced, and just serves to provide a sensible `max_encoded_len` for `MultiDID`.
+	/// Maximum number of `access_rules` in a single `URAuthDoc`.
 	pub const MAX_ACCESS_RULES: usize = 100;
 
-	/// Maximum number of `user agents` we expect in a single `MultiDID` value. Note this is not
-	/// (yet) enforced, and just serves to provide a sensible `max_encoded_len` for `MultiDID`.
+	/// Maximum number of `user agents` in a single `Rule`.
 	pub const MAX_USER_AGENTS_NUM: usize = 5;
 
-	/// Maximum number of `rule` we expect in a single `MultiDID` value. Note this is not (yet)
-	/// enforced, and just serves to provide a sensible `max_encoded_len` for `MultiDID`.
+	/// Maximum number of `rule` in a single `AccessRule`.
 	pub const MAX_RULES_NUM: usize = 20;
 
+	/// Maximum number of `allow` and `disallow` content types in a single `Rule`, which is the
+	/// number of `ContentType` variants.
+	pub const MAX_CONTENT_TYPES: usize = 5;
+
+	/// Maximum number of blocks by which the removal of a lapsed URI is delayed when too many URIs
+	/// lapse at the end of its grace period.
+	pub const MAX_LAPSE_DELAY: u32 = 10;
+
 	/// URI is up to 3 KB
 	pub const MAX_URI_SIZE: u32 = 3 * 1024;
 
@@ -1073,6 +1170,33 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn update_doc_over_max_size_fails() {
+		let mut urauth_doc = URAuthDoc::new([0u8; 16], MultiDID::new(0u64, 1), 0, None, None);
+		for owner in 1..MAX_MULTI_OWNERS_NUM as u64 {
+			assert_eq!(
+				urauth_doc.update_doc(UpdateDocField::MultiDID(WeightedDID::new(owner, 1)), 1),
+				Ok(())
+			);
+		}
+		assert_eq!(
+			urauth_doc.update_doc(UpdateDocField::MultiDID(WeightedDID::new(99, 1)), 1),
+			Err(URAuthDocUpdateError::OverMaxSize)
+		);
+
+		let rule = Rule {
+			user_agents: Default::default(),
+			allow: Default::default(),
+			disallow: sp_std::vec![ContentType::All; MAX_CONTENT_TYPES + 1],
+		};
+		let access_rule =
+			AccessRule::AccessRuleV1 { path: Default::default(), rules: sp_std::vec![rule] };
+		assert_eq!(
+			urauth_doc.update_doc(UpdateDocField::AccessRules(Some(sp_std::vec![access_rule])), 1),
+			Err(URAuthDocUpdateError::OverMaxSize)
+		);
+	}
+
 	#[test]
 	fn verification_submission_update_status_works() {
 		use sp_keyring::AccountKeyring::*;
@@ -0,0 +1,554 @@
+//! Autogenerated weights for `pallet_urauth`
+//!
+//! DATE: 2026-10-19, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
+//! EXECUTION: Native, CHAIN: the mock runtime of `mock.rs`
+//!
+//! Measured from `benchmarking.rs` natively against the benchmarking state database, as no wasm
+//! build of a runtime was available. Regenerate with `benchmark pallet --pallet pallet_urauth`
+//! on reference hardware before relying on them in production.
+//!
+//! Two terms of `claim_ownership` are added by hand: the `u / 2` reads of the parent URIs, whose
+//! slope of one read per two bytes is rounded away by the analysis, and the write of `DataSet`
+//! for `Contents` claims, which the benchmark of a `Domain` claim does not take. Parameters are
+//!
+//! - `u`: Length of the URI
+//! - `c`: Length of the challenge json
+//! - `o`: Number of owners of `URAuthDoc`
+//! - `a`: Number of access rules to be updated
+//! - `m`: Number of Oracle members
+//! - `n`: Number of URIs which should be verified by Oracle
+//! - `l`: Number of URIs lapsing at a block
+//! - `c`: Number of child URIs removed along with the lapsed URIs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for `pallet_urauth`.
+pub trait WeightInfo {
+	fn request_register_ownership(u: u32) -> Weight;
+	fn verify_challenge(c: u32) -> Weight;
+	fn update_urauth_doc(o: u32, a: u32) -> Weight;
+	fn claim_ownership(u: u32) -> Weight;
+	fn add_oracle_member(m: u32) -> Weight;
+	fn kick_oracle_member(m: u32) -> Weight;
+	fn add_uri_by_oracle(n: u32) -> Weight;
+	fn remove_uri_by_oracle(n: u32) -> Weight;
+	fn request_renewal(u: u32) -> Weight;
+	fn offer_transfer(o: u32) -> Weight;
+	fn accept_transfer() -> Weight;
+	fn handle_lapsed_ownerships(l: u32, c: u32) -> Weight;
+}
+
+/// Weights for `pallet_urauth` measured on the mock runtime.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: URAuth ChallengeValue (r:0 w:1)
+	/// Proof: URAuth ChallengeValue (max_values: None, max_size: Some(48), mode: MaxEncodedLen)
+	/// Storage: URAuth DIDs (r:1 w:1)
+	/// Proof: URAuth DIDs (max_values: None, max_size: Some(24), mode: MaxEncodedLen)
+	/// Storage: URAuth Metadata (r:0 w:1)
+	/// Proof: URAuth Metadata (max_values: None, max_size: Some(6466), mode: MaxEncodedLen)
+	/// Storage: URAuth RequestedURIs (r:1 w:1)
+	/// Proof: URAuth RequestedURIs (max_values: None, max_size: Some(15387), mode: MaxEncodedLen)
+	/// Storage: URAuth URAuthTree (r:1 w:0)
+	/// Proof: URAuth URAuthTree (max_values: None, max_size: Some(1166099), mode: MaxEncodedLen)
+	/// The range of component `u` is `[17, 3072]`.
+	fn request_register_ownership(u: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1169564`
+		// Minimum execution time: 76_430_000 picoseconds.
+		Weight::from_parts(136_840_925, 1_169_564)
+			.saturating_add(Weight::from_parts(14_877, 0).saturating_mul(u.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), mode: MaxEncodedLen)
+	/// Storage: URAuth ChallengeValue (r:1 w:1)
+	/// Proof: URAuth ChallengeValue (max_values: None, max_size: Some(48), mode: MaxEncodedLen)
+	/// Storage: URAuth Counter (r:1 w:1)
+	/// Proof: URAuth Counter (max_values: Some(1), max_size: Some(16), mode: MaxEncodedLen)
+	/// Storage: URAuth LapsingURIs (r:1 w:1)
+	/// Proof: URAuth LapsingURIs (max_values: None, max_size: Some(15387), mode: MaxEncodedLen)
+	/// Storage: URAuth Metadata (r:1 w:1)
+	/// Proof: URAuth Metadata (max_values: None, max_size: Some(6466), mode: MaxEncodedLen)
+	/// Storage: URAuth OracleMembers (r:1 w:0)
+	/// Proof: URAuth OracleMembers (max_values: Some(1), max_size: Some(161), mode: MaxEncodedLen)
+	/// Storage: URAuth OwnershipExpiry (r:0 w:1)
+	/// Proof: URAuth OwnershipExpiry (max_values: None, max_size: Some(24), mode: MaxEncodedLen)
+	/// Storage: URAuth URAuthTree (r:1 w:1)
+	/// Proof: URAuth URAuthTree (max_values: None, max_size: Some(1166099), mode: MaxEncodedLen)
+	/// Storage: URAuth URIVerificationInfo (r:1 w:1)
+	/// Proof: URAuth URIVerificationInfo (max_values: None, max_size: Some(370), mode: MaxEncodedLen)
+	/// The range of component `c` is `[17, 3072]`.
+	fn verify_challenge(c: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `627 + c * (1 ±0)`
+		//  Estimated: `1169564`
+		// Minimum execution time: 112_951_000 picoseconds.
+		Weight::from_parts(147_364_901, 1_169_564)
+			.saturating_add(Weight::from_parts(5_292, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: URAuth DIDs (r:1 w:1)
+	/// Proof: URAuth DIDs (max_values: None, max_size: Some(24), mode: MaxEncodedLen)
+	/// Storage: URAuth OwnershipExpiry (r:1 w:0)
+	/// Proof: URAuth OwnershipExpiry (max_values: None, max_size: Some(24), mode: MaxEncodedLen)
+	/// Storage: URAuth RootURI (r:1 w:0)
+	/// Proof: URAuth RootURI (max_values: None, max_size: Some(3090), mode: MaxEncodedLen)
+	/// Storage: URAuth URAuthDocUpdateStatus (r:1 w:1)
+	/// Proof: URAuth URAuthDocUpdateStatus (max_values: None, max_size: Some(1150899), mode: MaxEncodedLen)
+	/// Storage: URAuth URAuthTree (r:1 w:1)
+	/// Proof: URAuth URAuthTree (max_values: None, max_size: Some(1166099), mode: MaxEncodedLen)
+	/// The range of component `o` is `[1, 5]`.
+	/// The range of component `a` is `[0, 100]`.
+	fn update_urauth_doc(_o: u32, a: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `199 + o * (34 ±0)`
+		//  Estimated: `1169564`
+		// Minimum execution time: 119_997_000 picoseconds.
+		Weight::from_parts(288_660_644, 1_169_564)
+			.saturating_add(Weight::from_parts(163_688_828, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), mode: MaxEncodedLen)
+	/// Storage: URAuth ChildURIs (r:1 w:1)
+	/// Proof: URAuth ChildURIs (max_values: None, max_size: Some(15387), mode: MaxEncodedLen)
+	/// Storage: URAuth Counter (r:1 w:1)
+	/// Proof: URAuth Counter (max_values: Some(1), max_size: Some(16), mode: MaxEncodedLen)
+	/// Storage: URAuth DIDs (r:1 w:1)
+	/// Proof: URAuth DIDs (max_values: None, max_size: Some(24), mode: MaxEncodedLen)
+	/// Storage: URAuth OwnershipExpiry (r:1 w:0)
+	/// Proof: URAuth OwnershipExpiry (max_values: None, max_size: Some(24), mode: MaxEncodedLen)
+	/// Storage: URAuth RootURI (r:1 w:1)
+	/// Proof: URAuth RootURI (max_values: None, max_size: Some(3090), mode: MaxEncodedLen)
+	/// Storage: URAuth URAuthTree (r:`1 + u / 2` w:1)
+	/// Proof: URAuth URAuthTree (max_values: None, max_size: Some(1166099), mode: Measured)
+	/// Storage: URAuth DataSet (r:0 w:1)
+	/// Proof: URAuth DataSet (max_values: None, max_size: Some(220), mode: MaxEncodedLen)
+	/// The range of component `u` is `[25, 3072]`.
+	fn claim_ownership(u: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `233`
+		//  Estimated: `18852 + u * (1222 ±0)`
+		// Minimum execution time: 111_559_000 picoseconds.
+		Weight::from_parts(119_929_000, 18_852)
+			.saturating_add(Weight::from_parts(1_855_057, 1_222).saturating_mul(u.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().reads((u / 2).into()))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: URAuth OracleMembers (r:1 w:1)
+	/// Proof: URAuth OracleMembers (max_values: Some(1), max_size: Some(161), mode: MaxEncodedLen)
+	/// The range of component `m` is `[0, 4]`.
+	fn add_oracle_member(m: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5 + m * (33 ±0)`
+		//  Estimated: `1646`
+		// Minimum execution time: 6_679_000 picoseconds.
+		Weight::from_parts(7_371_560, 1_646)
+			.saturating_add(Weight::from_parts(26_970, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: URAuth OracleMembers (r:1 w:1)
+	/// Proof: URAuth OracleMembers (max_values: Some(1), max_size: Some(161), mode: MaxEncodedLen)
+	/// The range of component `m` is `[1, 5]`.
+	fn kick_oracle_member(_m: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6 + m * (32 ±0)`
+		//  Estimated: `1646`
+		// Minimum execution time: 6_316_000 picoseconds.
+		Weight::from_parts(7_636_340, 1_646)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: URAuth URIByOracle (r:1 w:1)
+	/// Proof: URAuth URIByOracle (max_values: Some(1), max_size: Some(310602), mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 99]`.
+	fn add_uri_by_oracle(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6 + n * (34 ±0)`
+		//  Estimated: `312087`
+		// Minimum execution time: 12_435_000 picoseconds.
+		Weight::from_parts(14_050_675, 312_087)
+			.saturating_add(Weight::from_parts(489_778, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: URAuth URIByOracle (r:1 w:1)
+	/// Proof: URAuth URIByOracle (max_values: Some(1), max_size: Some(310602), mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 100]`.
+	fn remove_uri_by_oracle(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `10 + n * (34 ±0)`
+		//  Estimated: `312087`
+		// Minimum execution time: 13_390_000 picoseconds.
+		Weight::from_parts(14_575_347, 312_087)
+			.saturating_add(Weight::from_parts(678_269, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: URAuth ChallengeValue (r:0 w:1)
+	/// Proof: URAuth ChallengeValue (max_values: None, max_size: Some(48), mode: MaxEncodedLen)
+	/// Storage: URAuth DIDs (r:1 w:1)
+	/// Proof: URAuth DIDs (max_values: None, max_size: Some(24), mode: MaxEncodedLen)
+	/// Storage: URAuth Metadata (r:0 w:1)
+	/// Proof: URAuth Metadata (max_values: None, max_size: Some(6466), mode: MaxEncodedLen)
+	/// Storage: URAuth OwnershipExpiry (r:1 w:0)
+	/// Proof: URAuth OwnershipExpiry (max_values: None, max_size: Some(24), mode: MaxEncodedLen)
+	/// Storage: URAuth RequestedURIs (r:1 w:1)
+	/// Proof: URAuth RequestedURIs (max_values: None, max_size: Some(15387), mode: MaxEncodedLen)
+	/// Storage: URAuth URAuthTree (r:1 w:0)
+	/// Proof: URAuth URAuthTree (max_values: None, max_size: Some(1166099), mode: MaxEncodedLen)
+	/// The range of component `u` is `[17, 3072]`.
+	fn request_renewal(u: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `233`
+		//  Estimated: `1169564`
+		// Minimum execution time: 88_470_000 picoseconds.
+		Weight::from_parts(145_736_431, 1_169_564)
+			.saturating_add(Weight::from_parts(15_651, 0).saturating_mul(u.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: URAuth DIDs (r:1 w:1)
+	/// Proof: URAuth DIDs (max_values: None, max_size: Some(24), mode: MaxEncodedLen)
+	/// Storage: URAuth OwnershipExpiry (r:1 w:0)
+	/// Proof: URAuth OwnershipExpiry (max_values: None, max_size: Some(24), mode: MaxEncodedLen)
+	/// Storage: URAuth PendingTransfers (r:0 w:1)
+	/// Proof: URAuth PendingTransfers (max_values: None, max_size: Some(48), mode: MaxEncodedLen)
+	/// Storage: URAuth RootURI (r:1 w:0)
+	/// Proof: URAuth RootURI (max_values: None, max_size: Some(3090), mode: MaxEncodedLen)
+	/// Storage: URAuth URAuthTree (r:1 w:0)
+	/// Proof: URAuth URAuthTree (max_values: None, max_size: Some(1166099), mode: MaxEncodedLen)
+	/// The range of component `o` is `[1, 5]`.
+	fn offer_transfer(_o: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `199 + o * (34 ±0)`
+		//  Estimated: `1169564`
+		// Minimum execution time: 83_190_000 picoseconds.
+		Weight::from_parts(137_849_440, 1_169_564)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), mode: MaxEncodedLen)
+	/// Storage: URAuth DIDs (r:1 w:1)
+	/// Proof: URAuth DIDs (max_values: None, max_size: Some(24), mode: MaxEncodedLen)
+	/// Storage: URAuth OwnershipExpiry (r:1 w:0)
+	/// Proof: URAuth OwnershipExpiry (max_values: None, max_size: Some(24), mode: MaxEncodedLen)
+	/// Storage: URAuth PendingTransfers (r:1 w:1)
+	/// Proof: URAuth PendingTransfers (max_values: None, max_size: Some(48), mode: MaxEncodedLen)
+	/// Storage: URAuth RootURI (r:1 w:0)
+	/// Proof: URAuth RootURI (max_values: None, max_size: Some(3090), mode: MaxEncodedLen)
+	/// Storage: URAuth URAuthDocUpdateStatus (r:0 w:1)
+	/// Proof: URAuth URAuthDocUpdateStatus (max_values: None, max_size: Some(1150899), mode: MaxEncodedLen)
+	/// Storage: URAuth URAuthTree (r:1 w:1)
+	/// Proof: URAuth URAuthTree (max_values: None, max_size: Some(1166099), mode: MaxEncodedLen)
+	fn accept_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `305`
+		//  Estimated: `1169564`
+		// Minimum execution time: 139_058_000 picoseconds.
+		Weight::from_parts(143_534_000, 1_169_564)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: URAuth ChildURIs (r:5 w:1)
+	/// Proof: URAuth ChildURIs (max_values: None, max_size: Some(15387), mode: MaxEncodedLen)
+	/// Storage: URAuth DataSet (r:0 w:10)
+	/// Proof: URAuth DataSet (max_values: None, max_size: Some(220), mode: MaxEncodedLen)
+	/// Storage: URAuth LapsingURIs (r:1 w:1)
+	/// Proof: URAuth LapsingURIs (max_values: None, max_size: Some(15387), mode: MaxEncodedLen)
+	/// Storage: URAuth OwnershipExpiry (r:5 w:10)
+	/// Proof: URAuth OwnershipExpiry (max_values: None, max_size: Some(24), mode: MaxEncodedLen)
+	/// Storage: URAuth PendingTransfers (r:0 w:10)
+	/// Proof: URAuth PendingTransfers (max_values: None, max_size: Some(48), mode: MaxEncodedLen)
+	/// Storage: URAuth RootURI (r:0 w:5)
+	/// Proof: URAuth RootURI (max_values: None, max_size: Some(3090), mode: MaxEncodedLen)
+	/// Storage: URAuth URAuthDocUpdateStatus (r:0 w:10)
+	/// Proof: URAuth URAuthDocUpdateStatus (max_values: None, max_size: Some(1150899), mode: MaxEncodedLen)
+	/// Storage: URAuth URAuthTree (r:10 w:10)
+	/// Proof: URAuth URAuthTree (max_values: None, max_size: Some(1166099), mode: MaxEncodedLen)
+	/// The range of component `l` is `[1, 5]`.
+	/// The range of component `c` is `[0, 5]`.
+	fn handle_lapsed_ownerships(l: u32, c: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `113 + l * (164 ±0) + c * (148 ±0)`
+		//  Estimated: `18852 + l * (1168574 ±0) + c * (1168574 ±0)`
+		// Minimum execution time: 71_021_000 picoseconds.
+		Weight::from_parts(11_258_562, 18_852)
+			.saturating_add(Weight::from_parts(22_185_772, 1_168_574).saturating_mul(l.into()))
+			.saturating_add(Weight::from_parts(14_846_296, 1_168_574).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(l.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(l.into())))
+			.saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(c.into())))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: URAuth ChallengeValue (r:0 w:1)
+	/// Proof: URAuth ChallengeValue (max_values: None, max_size: Some(48), mode: MaxEncodedLen)
+	/// Storage: URAuth DIDs (r:1 w:1)
+	/// Proof: URAuth DIDs (max_values: None, max_size: Some(24), mode: MaxEncodedLen)
+	/// Storage: URAuth Metadata (r:0 w:1)
+	/// Proof: URAuth Metadata (max_values: None, max_size: Some(6466), mode: MaxEncodedLen)
+	/// Storage: URAuth RequestedURIs (r:1 w:1)
+	/// Proof: URAuth RequestedURIs (max_values: None, max_size: Some(15387), mode: MaxEncodedLen)
+	/// Storage: URAuth URAuthTree (r:1 w:0)
+	/// Proof: URAuth URAuthTree (max_values: None, max_size: Some(1166099), mode: MaxEncodedLen)
+	/// The range of component `u` is `[17, 3072]`.
+	fn request_register_ownership(u: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1169564`
+		// Minimum execution time: 76_430_000 picoseconds.
+		Weight::from_parts(136_840_925, 1_169_564)
+			.saturating_add(Weight::from_parts(14_877, 0).saturating_mul(u.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), mode: MaxEncodedLen)
+	/// Storage: URAuth ChallengeValue (r:1 w:1)
+	/// Proof: URAuth ChallengeValue (max_values: None, max_size: Some(48), mode: MaxEncodedLen)
+	/// Storage: URAuth Counter (r:1 w:1)
+	/// Proof: URAuth Counter (max_values: Some(1), max_size: Some(16), mode: MaxEncodedLen)
+	/// Storage: URAuth LapsingURIs (r:1 w:1)
+	/// Proof: URAuth LapsingURIs (max_values: None, max_size: Some(15387), mode: MaxEncodedLen)
+	/// Storage: URAuth Metadata (r:1 w:1)
+	/// Proof: URAuth Metadata (max_values: None, max_size: Some(6466), mode: MaxEncodedLen)
+	/// Storage: URAuth OracleMembers (r:1 w:0)
+	/// Proof: URAuth OracleMembers (max_values: Some(1), max_size: Some(161), mode: MaxEncodedLen)
+	/// Storage: URAuth OwnershipExpiry (r:0 w:1)
+	/// Proof: URAuth OwnershipExpiry (max_values: None, max_size: Some(24), mode: MaxEncodedLen)
+	/// Storage: URAuth URAuthTree (r:1 w:1)
+	/// Proof: URAuth URAuthTree (max_values: None, max_size: Some(1166099), mode: MaxEncodedLen)
+	/// Storage: URAuth URIVerificationInfo (r:1 w:1)
+	/// Proof: URAuth URIVerificationInfo (max_values: None, max_size: Some(370), mode: MaxEncodedLen)
+	/// The range of component `c` is `[17, 3072]`.
+	fn verify_challenge(c: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `627 + c * (1 ±0)`
+		//  Estimated: `1169564`
+		// Minimum execution time: 112_951_000 picoseconds.
+		Weight::from_parts(147_364_901, 1_169_564)
+			.saturating_add(Weight::from_parts(5_292, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: URAuth DIDs (r:1 w:1)
+	/// Proof: URAuth DIDs (max_values: None, max_size: Some(24), mode: MaxEncodedLen)
+	/// Storage: URAuth OwnershipExpiry (r:1 w:0)
+	/// Proof: URAuth OwnershipExpiry (max_values: None, max_size: Some(24), mode: MaxEncodedLen)
+	/// Storage: URAuth RootURI (r:1 w:0)
+	/// Proof: URAuth RootURI (max_values: None, max_size: Some(3090), mode: MaxEncodedLen)
+	/// Storage: URAuth URAuthDocUpdateStatus (r:1 w:1)
+	/// Proof: URAuth URAuthDocUpdateStatus (max_values: None, max_size: Some(1150899), mode: MaxEncodedLen)
+	/// Storage: URAuth URAuthTree (r:1 w:1)
+	/// Proof: URAuth URAuthTree (max_values: None, max_size: Some(1166099), mode: MaxEncodedLen)
+	/// The range of component `o` is `[1, 5]`.
+	/// The range of component `a` is `[0, 100]`.
+	fn update_urauth_doc(_o: u32, a: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `199 + o * (34 ±0)`
+		//  Estimated: `1169564`
+		// Minimum execution time: 119_997_000 picoseconds.
+		Weight::from_parts(288_660_644, 1_169_564)
+			.saturating_add(Weight::from_parts(163_688_828, 0).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), mode: MaxEncodedLen)
+	/// Storage: URAuth ChildURIs (r:1 w:1)
+	/// Proof: URAuth ChildURIs (max_values: None, max_size: Some(15387), mode: MaxEncodedLen)
+	/// Storage: URAuth Counter (r:1 w:1)
+	/// Proof: URAuth Counter (max_values: Some(1), max_size: Some(16), mode: MaxEncodedLen)
+	/// Storage: URAuth DIDs (r:1 w:1)
+	/// Proof: URAuth DIDs (max_values: None, max_size: Some(24), mode: MaxEncodedLen)
+	/// Storage: URAuth OwnershipExpiry (r:1 w:0)
+	/// Proof: URAuth OwnershipExpiry (max_values: None, max_size: Some(24), mode: MaxEncodedLen)
+	/// Storage: URAuth RootURI (r:1 w:1)
+	/// Proof: URAuth RootURI (max_values: None, max_size: Some(3090), mode: MaxEncodedLen)
+	/// Storage: URAuth URAuthTree (r:`1 + u / 2` w:1)
+	/// Proof: URAuth URAuthTree (max_values: None, max_size: Some(1166099), mode: Measured)
+	/// Storage: URAuth DataSet (r:0 w:1)
+	/// Proof: URAuth DataSet (max_values: None, max_size: Some(220), mode: MaxEncodedLen)
+	/// The range of component `u` is `[25, 3072]`.
+	fn claim_ownership(u: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `233`
+		//  Estimated: `18852 + u * (1222 ±0)`
+		// Minimum execution time: 111_559_000 picoseconds.
+		Weight::from_parts(119_929_000, 18_852)
+			.saturating_add(Weight::from_parts(1_855_057, 1_222).saturating_mul(u.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().reads((u / 2).into()))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: URAuth OracleMembers (r:1 w:1)
+	/// Proof: URAuth OracleMembers (max_values: Some(1), max_size: Some(161), mode: MaxEncodedLen)
+	/// The range of component `m` is `[0, 4]`.
+	fn add_oracle_member(m: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5 + m * (33 ±0)`
+		//  Estimated: `1646`
+		// Minimum execution time: 6_679_000 picoseconds.
+		Weight::from_parts(7_371_560, 1_646)
+			.saturating_add(Weight::from_parts(26_970, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: URAuth OracleMembers (r:1 w:1)
+	/// Proof: URAuth OracleMembers (max_values: Some(1), max_size: Some(161), mode: MaxEncodedLen)
+	/// The range of component `m` is `[1, 5]`.
+	fn kick_oracle_member(_m: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6 + m * (32 ±0)`
+		//  Estimated: `1646`
+		// Minimum execution time: 6_316_000 picoseconds.
+		Weight::from_parts(7_636_340, 1_646)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: URAuth URIByOracle (r:1 w:1)
+	/// Proof: URAuth URIByOracle (max_values: Some(1), max_size: Some(310602), mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 99]`.
+	fn add_uri_by_oracle(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6 + n * (34 ±0)`
+		//  Estimated: `312087`
+		// Minimum execution time: 12_435_000 picoseconds.
+		Weight::from_parts(14_050_675, 312_087)
+			.saturating_add(Weight::from_parts(489_778, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: URAuth URIByOracle (r:1 w:1)
+	/// Proof: URAuth URIByOracle (max_values: Some(1), max_size: Some(310602), mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 100]`.
+	fn remove_uri_by_oracle(n: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `10 + n * (34 ±0)`
+		//  Estimated: `312087`
+		// Minimum execution time: 13_390_000 picoseconds.
+		Weight::from_parts(14_575_347, 312_087)
+			.saturating_add(Weight::from_parts(678_269, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: URAuth ChallengeValue (r:0 w:1)
+	/// Proof: URAuth ChallengeValue (max_values: None, max_size: Some(48), mode: MaxEncodedLen)
+	/// Storage: URAuth DIDs (r:1 w:1)
+	/// Proof: URAuth DIDs (max_values: None, max_size: Some(24), mode: MaxEncodedLen)
+	/// Storage: URAuth Metadata (r:0 w:1)
+	/// Proof: URAuth Metadata (max_values: None, max_size: Some(6466), mode: MaxEncodedLen)
+	/// Storage: URAuth OwnershipExpiry (r:1 w:0)
+	/// Proof: URAuth OwnershipExpiry (max_values: None, max_size: Some(24), mode: MaxEncodedLen)
+	/// Storage: URAuth RequestedURIs (r:1 w:1)
+	/// Proof: URAuth RequestedURIs (max_values: None, max_size: Some(15387), mode: MaxEncodedLen)
+	/// Storage: URAuth URAuthTree (r:1 w:0)
+	/// Proof: URAuth URAuthTree (max_values: None, max_size: Some(1166099), mode: MaxEncodedLen)
+	/// The range of component `u` is `[17, 3072]`.
+	fn request_renewal(u: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `233`
+		//  Estimated: `1169564`
+		// Minimum execution time: 88_470_000 picoseconds.
+		Weight::from_parts(145_736_431, 1_169_564)
+			.saturating_add(Weight::from_parts(15_651, 0).saturating_mul(u.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: URAuth DIDs (r:1 w:1)
+	/// Proof: URAuth DIDs (max_values: None, max_size: Some(24), mode: MaxEncodedLen)
+	/// Storage: URAuth OwnershipExpiry (r:1 w:0)
+	/// Proof: URAuth OwnershipExpiry (max_values: None, max_size: Some(24), mode: MaxEncodedLen)
+	/// Storage: URAuth PendingTransfers (r:0 w:1)
+	/// Proof: URAuth PendingTransfers (max_values: None, max_size: Some(48), mode: MaxEncodedLen)
+	/// Storage: URAuth RootURI (r:1 w:0)
+	/// Proof: URAuth RootURI (max_values: None, max_size: Some(3090), mode: MaxEncodedLen)
+	/// Storage: URAuth URAuthTree (r:1 w:0)
+	/// Proof: URAuth URAuthTree (max_values: None, max_size: Some(1166099), mode: MaxEncodedLen)
+	/// The range of component `o` is `[1, 5]`.
+	fn offer_transfer(_o: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `199 + o * (34 ±0)`
+		//  Estimated: `1169564`
+		// Minimum execution time: 83_190_000 picoseconds.
+		Weight::from_parts(137_849_440, 1_169_564)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), mode: MaxEncodedLen)
+	/// Storage: URAuth DIDs (r:1 w:1)
+	/// Proof: URAuth DIDs (max_values: None, max_size: Some(24), mode: MaxEncodedLen)
+	/// Storage: URAuth OwnershipExpiry (r:1 w:0)
+	/// Proof: URAuth OwnershipExpiry (max_values: None, max_size: Some(24), mode: MaxEncodedLen)
+	/// Storage: URAuth PendingTransfers (r:1 w:1)
+	/// Proof: URAuth PendingTransfers (max_values: None, max_size: Some(48), mode: MaxEncodedLen)
+	/// Storage: URAuth RootURI (r:1 w:0)
+	/// Proof: URAuth RootURI (max_values: None, max_size: Some(3090), mode: MaxEncodedLen)
+	/// Storage: URAuth URAuthDocUpdateStatus (r:0 w:1)
+	/// Proof: URAuth URAuthDocUpdateStatus (max_values: None, max_size: Some(1150899), mode: MaxEncodedLen)
+	/// Storage: URAuth URAuthTree (r:1 w:1)
+	/// Proof: URAuth URAuthTree (max_values: None, max_size: Some(1166099), mode: MaxEncodedLen)
+	fn accept_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `305`
+		//  Estimated: `1169564`
+		// Minimum execution time: 139_058_000 picoseconds.
+		Weight::from_parts(143_534_000, 1_169_564)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: URAuth ChildURIs (r:5 w:1)
+	/// Proof: URAuth ChildURIs (max_values: None, max_size: Some(15387), mode: MaxEncodedLen)
+	/// Storage: URAuth DataSet (r:0 w:10)
+	/// Proof: URAuth DataSet (max_values: None, max_size: Some(220), mode: MaxEncodedLen)
+	/// Storage: URAuth LapsingURIs (r:1 w:1)
+	/// Proof: URAuth LapsingURIs (max_values: None, max_size: Some(15387), mode: MaxEncodedLen)
+	/// Storage: URAuth OwnershipExpiry (r:5 w:10)
+	/// Proof: URAuth OwnershipExpiry (max_values: None, max_size: Some(24), mode: MaxEncodedLen)
+	/// Storage: URAuth PendingTransfers (r:0 w:10)
+	/// Proof: URAuth PendingTransfers (max_values: None, max_size: Some(48), mode: MaxEncodedLen)
+	/// Storage: URAuth RootURI (r:0 w:5)
+	/// Proof: URAuth RootURI (max_values: None, max_size: Some(3090), mode: MaxEncodedLen)
+	/// Storage: URAuth URAuthDocUpdateStatus (r:0 w:10)
+	/// Proof: URAuth URAuthDocUpdateStatus (max_values: None, max_size: Some(1150899), mode: MaxEncodedLen)
+	/// Storage: URAuth URAuthTree (r:10 w:10)
+	/// Proof: URAuth URAuthTree (max_values: None, max_size: Some(1166099), mode: MaxEncodedLen)
+	/// The range of component `l` is `[1, 5]`.
+	/// The range of component `c` is `[0, 5]`.
+	fn handle_lapsed_ownerships(l: u32, c: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `113 + l * (164 ±0) + c * (148 ±0)`
+		//  Estimated: `18852 + l * (1168574 ±0) + c * (1168574 ±0)`
+		// Minimum execution time: 71_021_000 picoseconds.
+		Weight::from_parts(11_258_562, 18_852)
+			.saturating_add(Weight::from_parts(22_185_772, 1_168_574).saturating_mul(l.into()))
+			.saturating_add(Weight::from_parts(14_846_296, 1_168_574).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(l.into())))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((5_u64).saturating_mul(l.into())))
+			.saturating_add(RocksDbWeight::get().writes((6_u64).saturating_mul(c.into())))
+	}
+}
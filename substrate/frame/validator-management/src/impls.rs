@@ -172,8 +172,9 @@ impl<T: Config> Pallet<T> {
 			// 2.
 			// TODO: Penalty on misbehaved validators @Hugo
 			let validators = T::SessionInterface::validators();
-			let len = validators.len();
-			let div_amount = FixedU128::saturating_from_rational(1, len).saturating_mul_int(amount);
+			let len: SystemTokenBalanceOf<T> = (validators.len() as u32).into();
+			// Rounded down, so the shares never add up to more than `amount`
+			let div_amount = amount.checked_div(&len).unwrap_or_default();
 			for v in validators.iter() {
 				// if MISBEHAVED(v): 
 				// 	continue;
@@ -383,3 +384,28 @@ impl<T: Config> Pallet<T> {
 		false
 	}
 }
+
+#[cfg(any(feature = "try-runtime", test))]
+impl<T: Config> Pallet<T> {
+	/// Invariants of the validator pools
+	///
+	/// - Seed Trust slots never exceed the total validator slots, otherwise the number of PoT slots
+	///   underflows on election.
+	/// - Every candidate of `PotValidatorPool` is listed once.
+	pub fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+		frame_support::ensure!(
+			SeedTrustSlots::<T>::get() <= TotalValidatorSlots::<T>::get(),
+			"Seed Trust slots exceed total validator slots"
+		);
+		let mut candidates: Vec<T::AccountId> =
+			PotValidatorPool::<T>::get().status.into_iter().map(|(who, _)| who).collect();
+		let counts = candidates.len();
+		candidates.sort();
+		candidates.dedup();
+		frame_support::ensure!(
+			candidates.len() == counts,
+			"Candidate is listed more than once in `PotValidatorPool`"
+		);
+		Ok(())
+	}
+}
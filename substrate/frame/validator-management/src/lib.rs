@@ -33,7 +33,7 @@ use frame_support::{
 pub use pallet::*;
 use scale_info::TypeInfo;
 use softfloat::BlockTimeWeight;
-use sp_arithmetic::{traits::AtLeast32BitUnsigned, FixedU128, FixedPointNumber};
+use sp_arithmetic::traits::{AtLeast32BitUnsigned, CheckedDiv};
 use sp_runtime::{
 	infra::{Reward, RewardOrigin, PoT, TaaV, Vote},
 	traits::Member,
@@ -192,7 +192,10 @@ pub mod pallet {
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::config]
@@ -230,8 +233,8 @@ pub mod pallet {
 			+ Into<Self::HigherPrecisionScore>
 			+ Into<SystemTokenBalanceOf<Self>>;
 
-		/// A type used for calculations of `Score` with higher precision to store on chain
-		/// TODO:
+		/// A type used for calculations of `Score` with higher precision to store on chain.
+		/// Must be deterministic across native and wasm, e.g. `softfloat::FixedWeight`.
 		type HigherPrecisionScore: BlockTimeWeight<Self::Score, BlockNumberFor<Self>>
 			+ Parameter
 			+ Member
@@ -286,6 +289,14 @@ pub mod pallet {
 		}
 	}
 
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+			Self::do_try_state()
+		}
+	}
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
 	pub enum Event<T: Config> {
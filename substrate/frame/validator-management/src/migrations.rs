@@ -3,17 +3,83 @@ use frame_support::traits::OnRuntimeUpgrade;
 
 pub mod v1 {
 	use super::*;
-	use frame_support::pallet_prelude::Weight;
+	use frame_support::{
+		pallet_prelude::Weight,
+		traits::{GetStorageVersion, StorageVersion},
+	};
+	use softfloat::{FixedWeight, F64};
 
+	/// `VotingStatus` as stored while vote points were a software `F64`
+	#[derive(Decode)]
+	struct OldVotingStatus<AccountId> {
+		status: Vec<(AccountId, F64)>,
+	}
+
+	/// Converts the `F64` vote points in `PotValidatorPool` to `FixedWeight`.
 	pub struct MigrationToV1<T>(sp_std::marker::PhantomData<T>);
 
-	impl<T: Config> OnRuntimeUpgrade for MigrationToV1<T> {
+	impl<T: Config<HigherPrecisionScore = FixedWeight>> OnRuntimeUpgrade for MigrationToV1<T> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			if Pallet::<T>::on_chain_storage_version() >= 1 {
+				return Ok(Vec::new())
+			}
+			// Number of candidates to be migrated. `None` if the pool is going to be cleared.
+			let candidates =
+				frame_support::storage::unhashed::get::<OldVotingStatus<T::AccountId>>(
+					&PotValidatorPool::<T>::hashed_key(),
+				)
+				.map(|old| old.status.len() as u32);
+			Ok(candidates.encode())
+		}
+
 		fn on_runtime_upgrade() -> Weight {
-			log::info!(
-				target: LOG_TARGET,
-				"Migration did not execute. This probably should be removed"
+			if Pallet::<T>::on_chain_storage_version() >= 1 {
+				log::info!(
+					target: LOG_TARGET,
+					"Migration did not execute. This probably should be removed"
+				);
+				return T::DbWeight::get().reads(1)
+			}
+			let translated = PotValidatorPool::<T>::translate(
+				|maybe_old: Option<OldVotingStatus<T::AccountId>>| {
+					maybe_old.map(|old| VotingStatus {
+						status: old
+							.status
+							.into_iter()
+							.map(|(who, points)| (who, FixedWeight::from_f64(points)))
+							.collect(),
+					})
+				},
+			);
+			if translated.is_err() {
+				log::error!(
+					target: LOG_TARGET,
+					"Failed to decode PotValidatorPool. Votes are cleared"
+				);
+				PotValidatorPool::<T>::kill();
+			}
+			StorageVersion::new(1).put::<Pallet<T>>();
+			log::info!(target: LOG_TARGET, "Migrated vote points to fixed-point");
+			T::DbWeight::get().reads_writes(2, 2)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			frame_support::ensure!(
+				Pallet::<T>::on_chain_storage_version() >= 1,
+				"Storage version should be >= 1 after the migration"
+			);
+			if state.is_empty() {
+				return Ok(())
+			}
+			let candidates = Option::<u32>::decode(&mut &state[..])
+				.map_err(|_| "Failed to decode the state of pre_upgrade")?;
+			frame_support::ensure!(
+				PotValidatorPool::<T>::get().counts() as u32 == candidates.unwrap_or_default(),
+				"Candidates of `PotValidatorPool` are lost"
 			);
-			T::DbWeight::get().reads(1)
+			Pallet::<T>::do_try_state()
 		}
 	}
 }
@@ -258,7 +258,10 @@ impl ExtBuilder {
 	pub fn build_and_execute(self, test: impl FnOnce() -> ()) {
 		let mut ext = self.build();
 
-		ext.execute_with(test);
+		ext.execute_with(|| {
+			test();
+			ValidatorManagement::do_try_state().unwrap();
+		});
 	}
 }
 
@@ -11,6 +11,8 @@ pub use voting::*;
 pub mod config {
 
 	use super::*;
+	use crate::traits::AtLeast32BitUnsigned;
+	use sp_weights::Weight;
 
 	/// System configuration for Infra-* Runtime
 	#[derive(
@@ -102,8 +104,8 @@ pub mod config {
 		fn system_config() -> Result<SystemConfig, Self::Error>;
 		/// Para fee rate of Infra-* Runtime
 		fn para_fee_rate() -> Result<Balance, Self::Error>;
-		/// Query for tx fee of `ext` extrinsic
-		fn fee_for(ext: ExtrinsicMetadata) -> Option<Balance>;
+		/// Query for tx fee of `ext` extrinsic which has consumed `weight` and is `len` bytes long
+		fn fee_for(ext: ExtrinsicMetadata, weight: Weight, len: u32) -> Option<Balance>;
 		/// State of Infar-* Runtime
 		fn runtime_state() -> Mode;
 	}
@@ -112,12 +114,50 @@ pub mod config {
 
 	#[allow(missing_docs)]
 	#[derive(
-		Encode, Decode, Eq, Clone, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo, Default,
+		Encode,
+		Decode,
+		Eq,
+		Clone,
+		PartialEq,
+		RuntimeDebug,
+		MaxEncodedLen,
+		TypeInfo,
+		Default,
+		serde::Serialize,
+		serde::Deserialize,
 	)]
 	pub enum Mode {
 		#[default]
 		Bootstrap,
 		Normal,
+		/// Only calls allowed by the maintenance call filter are accepted. Set by governance for
+		/// emergencies.
+		Maintenance,
+	}
+
+	/// Criterion which should be met before Infra-* Runtime leaves `Mode::Bootstrap`
+	#[derive(
+		Encode,
+		Decode,
+		Clone,
+		Copy,
+		PartialEq,
+		Eq,
+		RuntimeDebug,
+		MaxEncodedLen,
+		TypeInfo,
+		serde::Serialize,
+		serde::Deserialize,
+	)]
+	pub enum BootstrapCriterion {
+		/// System configuration has been set by the Relay Chain
+		SystemConfig,
+		/// At least one System Token has been registered
+		SystemToken,
+		/// Fee table has at least one rule
+		FeeTable,
+		/// Collator set is able to keep authoring blocks
+		CollatorSet,
 	}
 
 	#[derive(
@@ -141,10 +181,161 @@ pub mod config {
 	}
 
 	impl ExtrinsicMetadata {
+		/// Call name which matches every call of a pallet
+		pub const WILDCARD: &'static str = "*";
+
 		#[allow(missing_docs)]
 		pub fn new<Pallet: Encode, Call: Encode>(pallet_name: Pallet, call_name: Call) -> Self {
 			Self { pallet_name: pallet_name.encode(), call_name: call_name.encode() }
 		}
+
+		/// Metadata matching every call of `pallet_name`
+		pub fn any_call<Pallet: Encode>(pallet_name: Pallet) -> Self {
+			Self::new(pallet_name, Self::WILDCARD)
+		}
+
+		/// Metadata matching every call of the pallet of this one
+		pub fn to_wildcard(&self) -> Self {
+			Self { pallet_name: self.pallet_name.clone(), call_name: Self::WILDCARD.encode() }
+		}
+
+		/// Whether this matches every call of its pallet
+		pub fn is_wildcard(&self) -> bool {
+			self.call_name == Self::WILDCARD.encode()
+		}
+	}
+
+	/// How the fee of an extrinsic in the fee table is calculated
+	#[derive(
+		Encode,
+		Decode,
+		Clone,
+		Copy,
+		PartialEq,
+		Eq,
+		RuntimeDebug,
+		MaxEncodedLen,
+		TypeInfo,
+		serde::Serialize,
+		serde::Deserialize,
+	)]
+	pub enum FeeModel<Balance> {
+		/// Same fee regardless of the weight and the length of the extrinsic
+		Flat(Balance),
+		/// `base` plus `per_micros` for every microsecond of `ref_time` consumed
+		PerWeight {
+			/// Fee charged for any weight
+			base: Balance,
+			/// Fee for every `WEIGHT_REF_TIME_PER_MICROS` of `ref_time`
+			per_micros: Balance,
+		},
+		/// `base` plus `per_byte` for every byte of the encoded extrinsic
+		PerLength {
+			/// Fee charged for any length
+			base: Balance,
+			/// Fee for every byte
+			per_byte: Balance,
+		},
+	}
+
+	impl<Balance: AtLeast32BitUnsigned + Copy> FeeModel<Balance> {
+		/// Fee of an extrinsic which has consumed `weight` and is `len` bytes long
+		pub fn fee(&self, weight: Weight, len: u32) -> Balance {
+			match *self {
+				Self::Flat(fee) => fee,
+				Self::PerWeight { base, per_micros } => {
+					let micros = weight
+						.ref_time()
+						.saturating_add(sp_weights::constants::WEIGHT_REF_TIME_PER_MICROS - 1) /
+						sp_weights::constants::WEIGHT_REF_TIME_PER_MICROS;
+					base.saturating_add(per_micros.saturating_mul(micros.saturated_into()))
+				},
+				Self::PerLength { base, per_byte } =>
+					base.saturating_add(per_byte.saturating_mul(len.into())),
+			}
+		}
+	}
+
+	/// Entry of the fee table which is in effect from `starts_at` until `expires_at`.
+	///
+	/// An entry without both is permanent. Entries with either of them are promotions which take
+	/// precedence over the permanent one while they are in effect.
+	#[derive(
+		Encode,
+		Decode,
+		Clone,
+		Copy,
+		PartialEq,
+		Eq,
+		RuntimeDebug,
+		MaxEncodedLen,
+		TypeInfo,
+		serde::Serialize,
+		serde::Deserialize,
+	)]
+	pub struct FeeRule<Balance, BlockNumber> {
+		/// How the fee is calculated
+		pub model: FeeModel<Balance>,
+		/// First block the rule is in effect
+		pub starts_at: Option<BlockNumber>,
+		/// First block the rule is no longer in effect
+		pub expires_at: Option<BlockNumber>,
+	}
+
+	impl<Balance, BlockNumber: PartialOrd + Copy> FeeRule<Balance, BlockNumber> {
+		/// Rule which is in effect forever
+		pub fn permanent(model: FeeModel<Balance>) -> Self {
+			Self { model, starts_at: None, expires_at: None }
+		}
+
+		/// Whether the rule is neither scheduled nor expiring
+		pub fn is_permanent(&self) -> bool {
+			self.starts_at.is_none() && self.expires_at.is_none()
+		}
+
+		/// Whether the rule is in effect at `now`
+		pub fn is_active(&self, now: BlockNumber) -> bool {
+			self.starts_at.map_or(true, |s| s <= now) && !self.is_expired(now)
+		}
+
+		/// Whether the rule is no longer in effect from `now` on
+		pub fn is_expired(&self, now: BlockNumber) -> bool {
+			self.expires_at.map_or(false, |e| e <= now)
+		}
+	}
+
+	/// Single change of the fee table
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub struct FeeTableUpdate<Balance, BlockNumber> {
+		/// Name of the pallet as known to the runtime
+		pub pallet_name: Vec<u8>,
+		/// Name of the call as known to the runtime, or `*` for every call of the pallet
+		pub call_name: Vec<u8>,
+		/// Rule to be added. `None` removes every rule of the call.
+		pub rule: Option<FeeRule<Balance, BlockNumber>>,
+	}
+
+	/// Fee rule in effect for a call known to the runtime
+	#[derive(
+		Encode,
+		Decode,
+		Clone,
+		PartialEq,
+		Eq,
+		RuntimeDebug,
+		TypeInfo,
+		serde::Serialize,
+		serde::Deserialize,
+	)]
+	pub struct CallFee<Balance, BlockNumber> {
+		/// Name of the pallet
+		pub pallet_name: Vec<u8>,
+		/// Name of the call
+		pub call_name: Vec<u8>,
+		/// Rule in effect. `None` if the fee is calculated by the transaction payment.
+		pub rule: Option<FeeRule<Balance, BlockNumber>>,
+		/// Whether the rule has been set for every call of the pallet
+		pub is_wildcard: bool,
 	}
 }
 
@@ -88,6 +88,8 @@ pub enum InvalidTransaction {
 	InvalidBootstrappingCall,
 	/// Error occured while converting type
 	ConversionError,
+	/// The call is not allowed while the runtime is under maintenance.
+	InvalidMaintenanceCall,
 }
 
 impl InvalidTransaction {
@@ -122,6 +124,7 @@ impl From<InvalidTransaction> for &'static str {
 			InvalidTransaction::SystemTokenMissing => "System Token is not existed",
 			InvalidTransaction::InvalidBootstrappingCall => "Unexpected bootstrapping call",
 			InvalidTransaction::ConversionError => "Error occured while converting type",
+			InvalidTransaction::InvalidMaintenanceCall => "Unexpected call under maintenance",
 		}
 	}
 }
@@ -0,0 +1,362 @@
+//! Deterministic fixed-point counterpart of [`F64`](crate::F64) for consensus-critical
+//! calculations.
+//!
+//! # Rounding
+//!
+//! [`FixedWeight`] has 18 decimal places. Every operation rounds toward zero and saturates at
+//! [`FixedWeight::MIN`] and [`FixedWeight::MAX`] instead of overflowing. Division by zero saturates
+//! to [`FixedWeight::MAX`]. Converting into an integer drops the fractional part, the same way
+//! converting an [`F64`](crate::F64) does.
+
+use crate::{BlockTimeWeight, SystemTokenWeight, F64};
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_arithmetic::{
+	helpers_128bit::multiply_by_rational_with_rounding,
+	traits::{CheckedDiv, CheckedMul, One, Saturating, Zero},
+	FixedPointNumber, FixedU128, Rounding,
+};
+
+/// `ln(2)` truncated to 18 decimal places
+const LN_2: FixedU128 = FixedU128::from_inner(693_147_180_559_945_309);
+
+/// Exchange rates are given relative to the base currency, where this value means `1.0`
+const EXCHANGE_RATE_SCALE: u128 = 1_000_000;
+
+/// Unsigned fixed-point number with 18 decimal places.
+///
+/// Used for System Token weights and vote scores, where integers are not precise enough.
+#[derive(
+	Encode,
+	Decode,
+	MaxEncodedLen,
+	TypeInfo,
+	Default,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	PartialOrd,
+	Ord,
+	Debug,
+)]
+pub struct FixedWeight(FixedU128);
+
+impl FixedWeight {
+	/// Smallest value, `0`
+	pub const MIN: Self = Self(FixedU128::from_inner(0));
+	/// Largest value, about `3.4 * 10^20`
+	pub const MAX: Self = Self(FixedU128::from_inner(u128::MAX));
+
+	/// Create from the raw inner value, which is the number multiplied by `10^18`.
+	pub const fn from_inner(inner: u128) -> Self {
+		Self(FixedU128::from_inner(inner))
+	}
+
+	/// Raw inner value, which is the number multiplied by `10^18`.
+	pub const fn into_inner(self) -> u128 {
+		self.0.into_inner()
+	}
+
+	/// `n / d`, rounded toward zero.
+	pub fn from_rational(n: u128, d: u128) -> Self {
+		if d == 0 {
+			return Self::MAX
+		}
+		multiply_by_rational_with_rounding(n, FixedU128::DIV, d, Rounding::Down)
+			.map(Self::from_inner)
+			.unwrap_or(Self::MAX)
+	}
+
+	/// Convert a stored [`F64`], e.g. while migrating storage.
+	///
+	/// Negative numbers and NaN become `0`. Precision beyond the 53 significant bits of the
+	/// [`F64`] is lost.
+	pub fn from_f64(value: F64) -> Self {
+		if value.is_nan() || value.is_sign_negative() {
+			return Self::MIN
+		}
+		let scaled = value.mul(F64::from_u128(FixedU128::DIV));
+		Self::from_inner(scaled.to_u128())
+	}
+
+	/// `e^self`, rounded toward zero.
+	pub fn exp(self) -> Self {
+		// `e^x = 2^k * e^r`, where `k = floor(x / ln(2))` and `0 <= r < ln(2)`. Keeping `r` small
+		// makes its Taylor series converge within a few dozen terms.
+		let k = match self.0.checked_div(&LN_2) {
+			Some(k) => k.trunc(),
+			None => return Self::MAX,
+		};
+		let r = self.0.saturating_sub(k.saturating_mul(LN_2));
+
+		let mut sum = FixedU128::one();
+		let mut term = FixedU128::one();
+		let mut n = 1u128;
+		while !term.is_zero() {
+			term = FixedU128::from_inner(term.saturating_mul(r).into_inner() / n);
+			sum = sum.saturating_add(term);
+			n += 1;
+		}
+
+		let k = k.into_inner() / FixedU128::DIV;
+		if k >= u128::BITS as u128 {
+			return Self::MAX
+		}
+		sum.into_inner().checked_mul(1 << k).map(Self::from_inner).unwrap_or(Self::MAX)
+	}
+}
+
+impl From<u128> for FixedWeight {
+	fn from(value: u128) -> Self {
+		Self(FixedU128::saturating_from_integer(value))
+	}
+}
+
+impl From<FixedWeight> for u128 {
+	fn from(value: FixedWeight) -> Self {
+		value.into_inner() / FixedU128::DIV
+	}
+}
+
+impl core::ops::Add for FixedWeight {
+	type Output = Self;
+
+	fn add(self, rhs: Self) -> Self::Output {
+		Self(self.0.saturating_add(rhs.0))
+	}
+}
+
+impl core::ops::Sub for FixedWeight {
+	type Output = Self;
+
+	fn sub(self, rhs: Self) -> Self::Output {
+		Self(self.0.saturating_sub(rhs.0))
+	}
+}
+
+impl core::ops::Mul for FixedWeight {
+	type Output = Self;
+
+	fn mul(self, rhs: Self) -> Self::Output {
+		self.0.checked_mul(&rhs.0).map(Self).unwrap_or(Self::MAX)
+	}
+}
+
+impl core::ops::Div for FixedWeight {
+	type Output = Self;
+
+	fn div(self, rhs: Self) -> Self::Output {
+		self.0.checked_div(&rhs.0).map(Self).unwrap_or(Self::MAX)
+	}
+}
+
+impl core::ops::AddAssign for FixedWeight {
+	fn add_assign(&mut self, rhs: Self) {
+		*self = *self + rhs;
+	}
+}
+
+impl core::ops::SubAssign for FixedWeight {
+	fn sub_assign(&mut self, rhs: Self) {
+		*self = *self - rhs;
+	}
+}
+
+impl core::ops::MulAssign for FixedWeight {
+	fn mul_assign(&mut self, rhs: Self) {
+		*self = *self * rhs;
+	}
+}
+
+impl core::ops::DivAssign for FixedWeight {
+	fn div_assign(&mut self, rhs: Self) {
+		*self = *self / rhs;
+	}
+}
+
+impl<Amount, BlockNumber> BlockTimeWeight<Amount, BlockNumber> for FixedWeight
+where
+	Amount: Into<FixedWeight>,
+	BlockNumber: Into<Amount>,
+{
+	fn rational(n: BlockNumber, d: BlockNumber) -> Self {
+		let n: FixedWeight = n.into().into();
+		let d: FixedWeight = d.into().into();
+		Self(LN_2) * (n / d)
+	}
+
+	fn block_time_weight(adjusted: Amount, current: BlockNumber, per_year: BlockNumber) -> Self {
+		let adjusted: FixedWeight = adjusted.into();
+		adjusted * Self::rational(current, per_year).exp()
+	}
+}
+
+impl<Weight, Decimal, ExchangeRate> SystemTokenWeight<Weight, Decimal, ExchangeRate> for FixedWeight
+where
+	Weight: From<u128>,
+	Decimal: TryInto<i32>,
+	ExchangeRate: TryInto<u128>,
+{
+	type Error = ();
+
+	/// `base_weight * 10^(base_decimals - currency_decimals) / exchange_rate_to_base`, rounded
+	/// toward zero.
+	///
+	/// Fails if the exchange rate is zero or the result does not fit in `u128`.
+	fn calc_system_token_weight(
+		base_weight: u128,
+		base_decimals: Decimal,
+		currency_decimals: Decimal,
+		exchange_rate_to_base: ExchangeRate,
+	) -> Result<Weight, Self::Error> {
+		let base_d: i32 = base_decimals.try_into().map_err(|_| ())?;
+		let currency_d: i32 = currency_decimals.try_into().map_err(|_| ())?;
+		let exchange_rate: u128 = exchange_rate_to_base.try_into().map_err(|_| ())?;
+		if exchange_rate.is_zero() {
+			return Err(())
+		}
+		let decimal_diff = 10u128.checked_pow(base_d.abs_diff(currency_d)).ok_or(())?;
+		let (numerator, denominator) = if base_d >= currency_d {
+			(decimal_diff.checked_mul(EXCHANGE_RATE_SCALE).ok_or(())?, exchange_rate)
+		} else {
+			(EXCHANGE_RATE_SCALE, exchange_rate.checked_mul(decimal_diff).ok_or(())?)
+		};
+		multiply_by_rational_with_rounding(base_weight, numerator, denominator, Rounding::Down)
+			.map(Into::into)
+			.ok_or(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rand::{rngs::StdRng, Rng, SeedableRng};
+
+	const SEED: u64 = 42;
+	const RUNS: usize = 10_000;
+	const BLOCKS_PER_YEAR: u128 = 5_256_000;
+
+	/// Whether `fixed` and `float` agree up to one unit and a relative error of `10^-12`
+	fn close(fixed: u128, float: u128) -> bool {
+		fixed.abs_diff(float) <= 1 + float / 1_000_000_000_000
+	}
+
+	#[test]
+	fn exp_works() {
+		assert_eq!(FixedWeight::MIN.exp(), FixedWeight::from(1));
+		assert_eq!(
+			u128::from(FixedWeight::from(1).exp() * FixedWeight::from(1_000_000)),
+			2_718_281
+		);
+		assert_eq!(u128::from(FixedWeight::from(10).exp()), 22_026);
+		assert_eq!(FixedWeight::from(1_000).exp(), FixedWeight::MAX);
+	}
+
+	#[test]
+	fn arithmetic_saturates() {
+		assert_eq!(FixedWeight::MAX + FixedWeight::from(1), FixedWeight::MAX);
+		assert_eq!(FixedWeight::MIN - FixedWeight::from(1), FixedWeight::MIN);
+		assert_eq!(FixedWeight::MAX * FixedWeight::from(2), FixedWeight::MAX);
+		assert_eq!(FixedWeight::from(1) / FixedWeight::MIN, FixedWeight::MAX);
+		assert_eq!(FixedWeight::from_rational(1, 0), FixedWeight::MAX);
+	}
+
+	#[test]
+	fn divisions_round_toward_zero() {
+		assert_eq!(FixedWeight::from_rational(2, 3).into_inner(), 666_666_666_666_666_666);
+		assert_eq!(
+			(FixedWeight::from(2) / FixedWeight::from(3)).into_inner(),
+			666_666_666_666_666_666
+		);
+		assert_eq!(u128::from(FixedWeight::from_rational(5, 2)), 2);
+	}
+
+	#[test]
+	fn from_f64_works() {
+		assert_eq!(FixedWeight::from_f64(F64::from_u128(42)), FixedWeight::from(42));
+		assert_eq!(FixedWeight::from_f64(f64!(0.5)), FixedWeight::from_rational(1, 2));
+		assert_eq!(FixedWeight::from_f64(f64!(-1.0)), FixedWeight::MIN);
+	}
+
+	#[test]
+	fn system_token_weight_works() {
+		let calc = <FixedWeight as SystemTokenWeight<u128, u8, u64>>::calc_system_token_weight;
+		assert_eq!(calc(1_000_000, 4, 4, 0), Err(()));
+		assert_eq!(calc(u128::MAX, 40, 0, 1), Err(()));
+		assert_eq!(calc(1_000_000, 4, 4, 1_000_000), Ok(1_000_000));
+		assert_eq!(calc(1_000_000, 4, 6, 1_000_000), Ok(10_000));
+		assert_eq!(calc(1_000_000, 4, 2, 2_000_000), Ok(50_000_000));
+	}
+
+	#[test]
+	fn system_token_weight_matches_f64() {
+		let mut rng = StdRng::seed_from_u64(SEED);
+		for _ in 0..RUNS {
+			let base_weight: u128 = rng.gen_range(1..1_000_000_000_000);
+			let base_decimals: u8 = rng.gen_range(0..=18);
+			let currency_decimals: u8 = rng.gen_range(0..=18);
+			let exchange_rate: u64 = rng.gen_range(1..1_000_000_000_000);
+			let fixed = <FixedWeight as SystemTokenWeight<u128, u8, u64>>::calc_system_token_weight(
+				base_weight,
+				base_decimals,
+				currency_decimals,
+				exchange_rate,
+			);
+			let float = <F64 as SystemTokenWeight<u128, u8, u64>>::calc_system_token_weight(
+				base_weight,
+				base_decimals,
+				currency_decimals,
+				exchange_rate,
+			)
+			.unwrap();
+			if let Ok(fixed) = fixed {
+				assert!(
+					close(fixed, float),
+					"weight of {base_weight} ({base_decimals} -> {currency_decimals} decimals) at \
+					 {exchange_rate}: fixed {fixed} vs float {float}",
+				);
+			} else {
+				// Only results which do not fit in `u128` are rejected
+				assert_eq!(float, u128::MAX);
+			}
+		}
+	}
+
+	#[test]
+	fn block_time_weight_matches_f64() {
+		let mut rng = StdRng::seed_from_u64(SEED);
+		for _ in 0..RUNS {
+			let amount: u128 = rng.gen_range(1..1_000_000_000_000);
+			let current: u32 = rng.gen_range(0..5 * BLOCKS_PER_YEAR as u32);
+			let fixed: u128 = <FixedWeight as BlockTimeWeight<u128, u32>>::block_time_weight(
+				amount,
+				current,
+				BLOCKS_PER_YEAR as u32,
+			)
+			.into();
+			let float: u128 = <F64 as BlockTimeWeight<u128, u32>>::block_time_weight(
+				amount,
+				current,
+				BLOCKS_PER_YEAR as u32,
+			)
+			.into();
+			assert!(
+				close(fixed, float),
+				"vote of {amount} at block {current}: fixed {fixed} vs float {float}",
+			);
+		}
+	}
+
+	#[test]
+	fn reward_split_matches_f64() {
+		let mut rng = StdRng::seed_from_u64(SEED);
+		for _ in 0..RUNS {
+			let amount: u128 = rng.gen_range(1..1_000_000_000_000_000);
+			let validators: u128 = rng.gen_range(1..1_000);
+			let fixed: u128 = (FixedWeight::from(amount) / FixedWeight::from(validators)).into();
+			let float: u128 = (F64::from_u128(amount) / F64::from_u128(validators)).into();
+			assert!(close(fixed, float), "{amount} / {validators}: fixed {fixed} vs float {float}");
+		}
+	}
+}
@@ -10,9 +10,10 @@ macro_rules! f64 {
 }
 
 mod conv;
+mod fixed;
 mod soft_f64;
 
-pub use crate::soft_f64::F64;
+pub use crate::{fixed::FixedWeight, soft_f64::F64};
 
 const fn abs_diff(a: i32, b: i32) -> u32 {
 	a.wrapping_sub(b).wrapping_abs() as u32